@@ -34,6 +34,15 @@ pub enum Error {
     /// Two or more optional arguments passed into functions conflict.
     #[cfg(not(feature = "alloc"))]
     ConflictArg,
+    /// Shapes of the arrays passed into functions are incompatible.
+    #[cfg(feature = "alloc")]
+    ShapeMismatch {
+        /// Explaining how the shapes mismatch.
+        reason: alloc::string::String,
+    },
+    /// Shapes of the arrays passed into functions are incompatible.
+    #[cfg(not(feature = "alloc"))]
+    ShapeMismatch,
     /// Errors raised by [ndarray_conv::Error]
     #[cfg(feature = "alloc")]
     Conv { reason: alloc::string::String },
@@ -61,6 +70,12 @@ impl fmt::Display for Error {
                 Error::ConflictArg =>
                     "There were conflicting arguments. Reasons not shown without `alloc` feature.",
                 #[cfg(feature = "alloc")]
+                Error::ShapeMismatch { reason } =>
+                    format!("Mismatched Shapes with reason = {}", reason),
+                #[cfg(not(feature = "alloc"))]
+                Error::ShapeMismatch =>
+                    "There were mismatched shapes. Reasons not shown without `alloc` feature.",
+                #[cfg(feature = "alloc")]
                 Error::Conv { reason } => format!(
                     "An error occurred during the convolution from ndarray_conv with reason {}.",
                     reason
@@ -0,0 +1,138 @@
+use ndarray::{Array, ArrayBase, Data, DataMut, Dimension};
+use num_traits::Float;
+
+/// Clip (limit) the values in an array, in place.
+///
+/// Given an interval, values outside the interval are clipped to the interval edges. For
+/// example, if an interval of `[0, 1]` is specified, values smaller than 0 become 0, and values
+/// larger than 1 become 1.
+///
+/// Equivalent to but faster than `minimum(maximum(x, min), max)`, and so, as in numpy, a NaN in
+/// `x` or as a bound is propagated to the output, and every value is set to `max` if
+/// `min > max`.
+///
+/// # Parameters
+/// * `x` : array_like  
+///   Array containing elements to clip.
+/// * `min` : float, optional  
+///   Minimum value. If `None`, clipping is not performed on the lower interval edge.
+/// * `max` : float, optional  
+///   Maximum value. If `None`, clipping is not performed on the upper interval edge.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs_core::num_rs::clip;
+///
+/// let mut x = array![0., 1., 2., 3., 4., 5., 6., 7., 8., 9.];
+/// clip(&mut x, Some(1.), Some(8.));
+/// assert_eq!(x, array![1., 1., 2., 3., 4., 5., 6., 7., 8., 8.]);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.clip.html>
+pub fn clip<F, S, D>(x: &mut ArrayBase<S, D>, min: Option<F>, max: Option<F>)
+where
+    F: Float,
+    S: DataMut<Elem = F>,
+    D: Dimension,
+{
+    x.mapv_inplace(|xi| clip_scalar(xi, min, max));
+}
+
+/// Clip (limit) the values in an array.
+///
+/// As [clip], returning the clipped values in a new array.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs_core::num_rs::clipped;
+///
+/// let x = array![-1.5, 0.5, f64::NAN, 2.5];
+/// let y = clipped(&x, None, Some(1.));
+/// assert_eq!(y.slice(ndarray::s![..2]), array![-1.5, 0.5]);
+/// assert!(y[2].is_nan());
+/// assert_eq!(y[3], 1.);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.clip.html>
+pub fn clipped<F, S, D>(x: &ArrayBase<S, D>, min: Option<F>, max: Option<F>) -> Array<F, D>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    x.mapv(|xi| clip_scalar(xi, min, max))
+}
+
+/// `minimum(maximum(x, min), max)`, propagating NaN as `numpy.maximum` and `numpy.minimum` do.
+fn clip_scalar<F: Float>(x: F, min: Option<F>, max: Option<F>) -> F {
+    let x = match min {
+        Some(min) if min.is_nan() || min > x => min,
+        _ => x,
+    };
+    match max {
+        Some(max) if max.is_nan() || (max < x && !x.is_nan()) => max,
+        _ => x,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{array, Array1};
+
+    #[test]
+    fn matches_numpy() {
+        // >>> a = np.arange(10.)
+        let a = Array1::from_iter((0..10).map(f64::from));
+        // >>> np.clip(a, 1, 8)
+        // array([1., 1., 2., 3., 4., 5., 6., 7., 8., 8.])
+        assert_eq!(
+            clipped(&a, Some(1.), Some(8.)),
+            array![1., 1., 2., 3., 4., 5., 6., 7., 8., 8.]
+        );
+        // >>> np.clip(a, 8, 1)
+        // array([1., 1., 1., 1., 1., 1., 1., 1., 1., 1.])
+        assert_eq!(clipped(&a, Some(8.), Some(1.)), Array1::from_elem(10, 1.));
+        // >>> np.clip(a, 3, None)
+        // array([3., 3., 3., 3., 4., 5., 6., 7., 8., 9.])
+        assert_eq!(
+            clipped(&a, Some(3.), None),
+            array![3., 3., 3., 3., 4., 5., 6., 7., 8., 9.]
+        );
+        // >>> np.clip(a, None, 6)
+        // array([0., 1., 2., 3., 4., 5., 6., 6., 6., 6.])
+        assert_eq!(
+            clipped(&a, None, Some(6.)),
+            array![0., 1., 2., 3., 4., 5., 6., 6., 6., 6.]
+        );
+        assert_eq!(clipped(&a, None, None), a);
+
+        let mut b = a.clone();
+        clip(&mut b, Some(1.), Some(8.));
+        assert_eq!(b, clipped(&a, Some(1.), Some(8.)));
+    }
+
+    #[test]
+    fn propagates_nan() {
+        // >>> np.clip([np.nan, -1., 2.], 0, 1)
+        // array([nan,  0.,  1.])
+        let y = clipped(&array![f64::NAN, -1., 2.], Some(0.), Some(1.));
+        assert!(y[0].is_nan());
+        assert_eq!(y.slice(ndarray::s![1..]), array![0., 1.]);
+
+        // >>> np.clip([-1., 0.5, 2.], np.nan, 1)
+        // array([nan, nan, nan])
+        // >>> np.clip([-1., 0.5, 2.], 0, np.nan)
+        // array([nan, nan, nan])
+        let x = array![-1f32, 0.5, 2.];
+        assert!(clipped(&x, Some(f32::NAN), Some(1.))
+            .iter()
+            .all(|y| y.is_nan()));
+        assert!(clipped(&x, Some(0.), Some(f32::NAN))
+            .iter()
+            .all(|y| y.is_nan()));
+    }
+}
@@ -0,0 +1,173 @@
+use super::ConvolveMode;
+use crate::{Error, Result};
+use alloc::vec;
+use core::any::{Any, TypeId};
+use ndarray::{Array, ArrayBase, ArrayView1, Axis, Data, Dimension};
+use rustfft::{num_complex::Complex, FftNum, FftPlanner};
+
+/// Shortest kernel for which [convolve](super::convolve) and
+/// [convolve_axis](super::convolve_axis) switch to the FFT method.
+///
+/// The direct method costs `O(N * M)` against `O((N + M) log(N + M))` for the FFT method. With a
+/// 10000-sample signal, the two break even at a kernel of a few dozen taps.
+pub(super) const FFT_MIN_KERNEL: usize = 64;
+
+/// Convolve through [fftconvolve_axis] if `T` is `f32` or `f64`, returning `None` otherwise.
+pub(super) fn try_fftconvolve_axis<T, S, D>(
+    a: &ArrayBase<S, D>,
+    v: ArrayView1<T>,
+    mode: ConvolveMode,
+    axis: Axis,
+) -> Option<Result<Array<T, D>>>
+where
+    T: Copy + 'static,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    if TypeId::of::<T>() == TypeId::of::<f64>() {
+        Some(fftconvolve_axis_as::<T, f64, S, D>(a, v, mode, axis))
+    } else if TypeId::of::<T>() == TypeId::of::<f32>() {
+        Some(fftconvolve_axis_as::<T, f32, S, D>(a, v, mode, axis))
+    } else {
+        None
+    }
+}
+
+/// [fftconvolve_axis] for `T` known to be the float type `F`.
+fn fftconvolve_axis_as<T, F, S, D>(
+    a: &ArrayBase<S, D>,
+    v: ArrayView1<T>,
+    mode: ConvolveMode,
+    axis: Axis,
+) -> Result<Array<T, D>>
+where
+    T: Copy + 'static,
+    F: FftNum,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    fn cast<X: Any, Y: Copy + 'static>(x: X) -> Y {
+        *(&x as &dyn Any)
+            .downcast_ref::<Y>()
+            .expect("types are checked to be the same")
+    }
+    let out = fftconvolve_axis(
+        &a.mapv(cast::<T, F>),
+        v.mapv(cast::<T, F>).view(),
+        mode,
+        axis,
+    )?;
+    Ok(out.mapv(cast::<F, T>))
+}
+
+/// Convolve each lane of an N-dimensional array `a` along `axis` with the 1-D kernel `v` through
+/// the FFT.
+///
+/// This is [convolve_axis](super::convolve_axis) by way of `scipy.signal.fftconvolve`. The FFTs
+/// are planned once, and the kernel transformed once, for all the lanes of `a`.
+///
+/// # Parameters
+/// * `a` : [[array_like]]([ndarray::ArrayBase])  
+///   N-dimensional signal to be (linearly) convolved.
+/// * `v` : (M,) [[array_like]]([ndarray::Array1])  
+///   One-dimensional convolution kernel.
+/// * `mode` : [ConvolveMode]  
+///   As per [convolve_axis](super::convolve_axis).
+/// * `axis` : [Axis]  
+///   The axis of `a` along which to convolve.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `axis` is out of bounds for `a`, or if `a` along `axis` or `v`
+/// is empty.
+///
+/// # Notes
+/// The direct method of [convolve_axis](super::convolve_axis) costs `O(N * M)` per lane against
+/// `O((N + M) log(N + M))` for the FFT method, which is faster for kernels of more than a few
+/// dozen taps. The results are subject to the rounding errors of the FFT, and so differ slightly
+/// from those of the direct method.
+///
+/// # Examples
+/// ```
+/// use ndarray::{array, Axis};
+/// use sci_rs_core::num_rs::{ConvolveMode, fftconvolve_axis};
+///
+/// let a = array![[1f64, 2., 3.], [4., 5., 6.]];
+/// let v = array![0., 1., 0.5];
+///
+/// let expected = array![[0., 1., 2.5, 4., 1.5], [0., 4., 7., 8.5, 3.]];
+/// let result = fftconvolve_axis(&a, (&v).into(), ConvolveMode::Full, Axis(1)).unwrap();
+/// assert!(result.iter().zip(&expected).all(|(r, e)| (r - e).abs() < 1e-12));
+/// ```
+pub fn fftconvolve_axis<T, S, D>(
+    a: &ArrayBase<S, D>,
+    v: ArrayView1<T>,
+    mode: ConvolveMode,
+    axis: Axis,
+) -> Result<Array<T, D>>
+where
+    T: FftNum,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    if axis.index() >= a.ndim() {
+        return Err(Error::InvalidArg {
+            arg: "axis".into(),
+            reason: "index out of range.".into(),
+        });
+    }
+    let (n, m) = (a.len_of(axis), v.len());
+    if n == 0 || m == 0 {
+        return Err(Error::InvalidArg {
+            arg: if n == 0 { "a" } else { "v" }.into(),
+            reason: "Cannot convolve an empty array.".into(),
+        });
+    }
+
+    let full_len = n + m - 1;
+    let (start, out_len) = match mode {
+        ConvolveMode::Full => (0, full_len),
+        ConvolveMode::Same => ((m - 1) / 2, n),
+        ConvolveMode::Valid => (n.min(m) - 1, n.max(m) - n.min(m) + 1),
+    };
+
+    let fft_len = full_len.next_power_of_two();
+    let mut planner = FftPlanner::new();
+    let fft = planner.plan_fft_forward(fft_len);
+    let ifft = planner.plan_fft_inverse(fft_len);
+    let zero = Complex::new(T::zero(), T::zero());
+    let mut scratch = vec![
+        zero;
+        fft.get_inplace_scratch_len()
+            .max(ifft.get_inplace_scratch_len())
+    ];
+
+    // The kernel spectrum, with the normalization of the inverse FFT folded in.
+    let scale = T::one() / T::from_usize(fft_len).unwrap();
+    let mut v_freq = vec![zero; fft_len];
+    v_freq
+        .iter_mut()
+        .zip(v)
+        .for_each(|(vf, &vi)| *vf = Complex::new(vi * scale, T::zero()));
+    fft.process_with_scratch(&mut v_freq, &mut scratch);
+
+    let mut out_dim = a.raw_dim();
+    out_dim[axis.index()] = out_len;
+    let mut out = Array::zeros(out_dim);
+    let mut buf = vec![zero; fft_len];
+    for (mut out_lane, lane) in out.lanes_mut(axis).into_iter().zip(a.lanes(axis)) {
+        buf.fill(zero);
+        buf.iter_mut()
+            .zip(lane)
+            .for_each(|(b, &xi)| *b = Complex::new(xi, T::zero()));
+        fft.process_with_scratch(&mut buf, &mut scratch);
+        buf.iter_mut().zip(&v_freq).for_each(|(b, vf)| *b = *b * vf);
+        ifft.process_with_scratch(&mut buf, &mut scratch);
+
+        out_lane
+            .iter_mut()
+            .zip(&buf[start..start + out_len])
+            .for_each(|(y, b)| *y = b.re);
+    }
+
+    Ok(out)
+}
@@ -1,11 +1,17 @@
+#[cfg(feature = "std")]
+mod fft;
 mod ndarray_conv_binds;
 
+#[cfg(feature = "std")]
+pub use fft::fftconvolve_axis;
+
 use crate::{Error, Result};
 use alloc::string::ToString;
-use ndarray::{Array1, ArrayView1};
-use ndarray_conv::{ConvExt, PaddingMode};
+use ndarray::{s, Array, Array1, Array2, ArrayBase, ArrayView1, ArrayView2, Axis, Data, Dimension};
+use ndarray_conv::ConvExt;
 
 /// Convolution mode determines behavior near edges and output size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConvolveMode {
     /// Full convolution, output size is `in1.len() + in2.len() - 1`
     Full,
@@ -15,6 +21,23 @@ pub enum ConvolveMode {
     Same,
 }
 
+/// Padding mode determines the values assumed beyond the edges of the signal, where the kernel
+/// overhangs it in [ConvolveMode::Full] and [ConvolveMode::Same].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum PaddingMode<T> {
+    /// Pad with zeros, as numpy and scipy do.
+    #[default]
+    Zeros,
+    /// Pad with a constant value, such as the edge value of a signal with a DC offset.
+    Const(T),
+    /// Pad with the signal reflected about its edge samples, which are not repeated, so that
+    /// `[a, b, c]` is padded as `[.., c, b, a, b, c, b, a, ..]`.
+    Reflect,
+    /// Pad by repeating the edge samples, so that `[a, b, c]` is padded as
+    /// `[.., a, a, a, b, c, c, c, ..]`.
+    Replicate,
+}
+
 /// Best effort parallel behaviour with numpy's convolve method. We take `v` as the convolution
 /// kernel.
 ///
@@ -42,6 +65,12 @@ pub enum ConvolveMode {
 /// # Panics
 /// We assume that `v` is shorter than `a`.
 ///
+/// # Notes
+/// With the `std` feature, `f32` and `f64` inputs are convolved through the FFT, as by
+/// [fftconvolve_axis], once `v` is 64 taps or longer and no longer than `a`, as for
+/// `scipy.signal.convolve` with `method='auto'`. The direct method is used otherwise, for which
+/// results are exact for integer types.
+///
 /// # Examples
 /// With [ConvolveMode::Full]:
 /// ```
@@ -67,7 +96,7 @@ pub enum ConvolveMode {
 /// let result = convolve((&a).into(), (&v).into(), ConvolveMode::Same).unwrap();
 /// assert_eq!(result, expected);
 /// ```
-/// With [ConvolveMode::Same]:
+/// With [ConvolveMode::Valid]:
 /// ```
 /// use ndarray::array;
 /// use sci_rs_core::num_rs::{ConvolveMode, convolve};
@@ -81,10 +110,57 @@ pub enum ConvolveMode {
 /// ```
 pub fn convolve<T>(a: ArrayView1<T>, v: ArrayView1<T>, mode: ConvolveMode) -> Result<Array1<T>>
 where
-    T: num_traits::NumAssign + core::marker::Copy,
+    T: num_traits::NumAssign + core::marker::Copy + 'static,
 {
+    convolve_with_padding(a, v, mode, PaddingMode::Zeros)
+}
+
+/// As [convolve], with the values of `a` beyond its edges given by `padding` rather than zeros.
+///
+/// # Parameters
+/// * `a` : (N,) [[array_like]]([ndarray::Array1])  
+///   Signal to be (linearly) convolved.
+/// * `v` : (M,) [[array_like]]([ndarray::Array1])  
+///   Second one-dimensional input array.
+/// * `mode` : [ConvolveMode]  
+///   As per [convolve].
+/// * `padding` : [PaddingMode]  
+///   Values of `a` beyond its edges. [PaddingMode::Zeros] gives the result of [convolve].
+///
+/// # Notes
+/// Only [PaddingMode::Zeros] switches to the FFT method as [convolve] does.
+///
+/// # Examples
+/// With [PaddingMode::Replicate], a constant signal is preserved up to its edges:
+/// ```
+/// use ndarray::array;
+/// use sci_rs_core::num_rs::{ConvolveMode, PaddingMode, convolve_with_padding};
+///
+/// let a = array![2., 2., 2., 2.];
+/// let v = array![0.5, 0.5];
+///
+/// let padding = PaddingMode::Replicate;
+/// let result = convolve_with_padding((&a).into(), (&v).into(), ConvolveMode::Same, padding);
+/// assert_eq!(result.unwrap(), a);
+/// ```
+pub fn convolve_with_padding<T>(
+    a: ArrayView1<T>,
+    v: ArrayView1<T>,
+    mode: ConvolveMode,
+    padding: PaddingMode<T>,
+) -> Result<Array1<T>>
+where
+    T: num_traits::NumAssign + core::marker::Copy + 'static,
+{
+    #[cfg(feature = "std")]
+    if padding == PaddingMode::Zeros && v.len() >= fft::FFT_MIN_KERNEL && v.len() <= a.len() {
+        if let Some(out) = fft::try_fftconvolve_axis(&a, v, mode, Axis(0)) {
+            return out;
+        }
+    }
+
     // Convolve
-    let result = a.conv(&v, mode.into(), PaddingMode::Zeros);
+    let result = a.conv(&v, mode.into(), padding.into());
     #[cfg(feature = "alloc")]
     {
         result.map_err(|e| Error::Conv {
@@ -97,6 +173,190 @@ where
     }
 }
 
+/// Convolve each lane of an N-dimensional array `a` along `axis` with the 1-D kernel `v`.
+///
+/// This is equivalent to `np.apply_along_axis(lambda y: np.convolve(y, v, mode), axis, a)`, and
+/// saves callers such as `lfilter` from iterating over the lanes themselves.
+///
+/// # Parameters
+/// * `a` : [[array_like]]([ndarray::ArrayBase])  
+///   N-dimensional signal to be (linearly) convolved.
+/// * `v` : (M,) [[array_like]]([ndarray::Array1])  
+///   One-dimensional convolution kernel.
+/// * `mode` : [ConvolveMode]  
+///   As per [convolve]. The output length along `axis` is that of the 1-D convolution of a
+///   single lane, whilst all other axes are left untouched. With [ConvolveMode::Same], every
+///   lane keeps its length and is centred with respect to the full output, as for
+///   `scipy.signal.convolve`, even when `v` is longer than the lane.
+/// * `axis` : [Axis]  
+///   The axis of `a` along which to convolve.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `axis` is out of bounds for `a`, and [Error::Conv] if the
+/// underlying convolution of any lane fails.
+///
+/// # Notes
+/// The direct and FFT methods are chosen as by [convolve]. The FFT method goes through
+/// [fftconvolve_axis], planning the FFTs once for all the lanes of `a`.
+///
+/// # Examples
+/// ```
+/// use ndarray::{array, Axis};
+/// use sci_rs_core::num_rs::{ConvolveMode, convolve_axis};
+///
+/// let a = array![[1., 2., 3.], [4., 5., 6.]];
+/// let v = array![0., 1., 0.5];
+///
+/// let expected = array![[0., 1., 2.5, 4., 1.5], [0., 4., 7., 8.5, 3.]];
+/// let result = convolve_axis(&a, (&v).into(), ConvolveMode::Full, Axis(1));
+/// assert_eq!(result.unwrap(), expected);
+/// ```
+pub fn convolve_axis<T, S, D>(
+    a: &ArrayBase<S, D>,
+    v: ArrayView1<T>,
+    mode: ConvolveMode,
+    axis: Axis,
+) -> Result<Array<T, D>>
+where
+    T: num_traits::NumAssign + core::marker::Copy + 'static,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    convolve_axis_with_padding(a, v, mode, PaddingMode::Zeros, axis)
+}
+
+/// As [convolve_axis], with the values of each lane beyond its edges given by `padding` rather
+/// than zeros, as [convolve_with_padding].
+///
+/// # Errors
+/// As per [convolve_axis].
+pub fn convolve_axis_with_padding<T, S, D>(
+    a: &ArrayBase<S, D>,
+    v: ArrayView1<T>,
+    mode: ConvolveMode,
+    padding: PaddingMode<T>,
+    axis: Axis,
+) -> Result<Array<T, D>>
+where
+    T: num_traits::NumAssign + core::marker::Copy + 'static,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    if axis.index() >= a.ndim() {
+        return Err(Error::InvalidArg {
+            arg: "axis".into(),
+            reason: "index out of range.".into(),
+        });
+    }
+
+    let (n, m) = (a.len_of(axis), v.len());
+    #[cfg(feature = "std")]
+    if padding == PaddingMode::Zeros && m >= fft::FFT_MIN_KERNEL && m <= n {
+        if let Some(out) = fft::try_fftconvolve_axis(a, v, mode, axis) {
+            return out;
+        }
+    }
+
+    let out_len = match mode {
+        ConvolveMode::Full => (n + m).saturating_sub(1),
+        ConvolveMode::Same => n,
+        ConvolveMode::Valid => (n.max(m) - n.min(m)) + 1,
+    };
+    let mut out_dim = a.raw_dim();
+    out_dim[axis.index()] = out_len;
+
+    let mut out = Array::zeros(out_dim);
+    out.lanes_mut(axis)
+        .into_iter()
+        .zip(a.lanes(axis))
+        .try_for_each(|(mut out_lane, lane)| {
+            let y = match mode {
+                ConvolveMode::Same if m > n => {
+                    let full = convolve_with_padding(lane, v.view(), ConvolveMode::Full, padding)?;
+                    let start = (m - 1) / 2;
+                    full.slice_move(s![start..start + n])
+                }
+                _ => convolve_with_padding(lane, v.view(), mode, padding)?,
+            };
+            y.assign_to(&mut out_lane);
+            Ok(())
+        })?;
+
+    Ok(out)
+}
+
+/// Cross-correlate two 2-dimensional arrays, as `scipy.signal.correlate2d`.
+///
+/// # Parameters
+/// * `in1` : (M, N) [[array_like]]([ndarray::Array2])  
+///   First input.
+/// * `in2` : (P, Q) [[array_like]]([ndarray::Array2])  
+///   Second input, the template or kernel which is slid over `in1`.
+/// * `mode` : [ConvolveMode]  
+///   [ConvolveMode::Full]:  
+///   The output is the full discrete linear cross-correlation of the inputs, with shape
+///   `(M + P - 1, N + Q - 1)`.
+///
+///   [ConvolveMode::Same]:  
+///   The output is the same size as `in1`, centered with respect to the full output.
+///
+///   [ConvolveMode::Valid]:  
+///   The output consists only of those elements that do not rely on the padding, with shape
+///   `(M - P + 1, N - Q + 1)`. Every element is the correlation of `in2` with a whole window of
+///   `in1`, as needed for an exhaustive template search.
+/// * `padding` : [PaddingMode]  
+///   Values of `in1` beyond its edges. [PaddingMode::Zeros] by default, as for scipy.
+///
+/// # Errors
+/// Returns [Error::ShapeMismatch] in [ConvolveMode::Valid] if `in2` is larger than `in1` in any
+/// dimension, and [Error::Conv] if the underlying convolution fails.
+///
+/// # Examples
+/// Locating a template within an image, where the valid part of the correlation peaks:
+/// ```
+/// use ndarray::array;
+/// use sci_rs_core::num_rs::{ConvolveMode, PaddingMode, correlate2d};
+///
+/// let image = array![
+///     [0., 0., 0., 0.],
+///     [0., 1., 2., 0.],
+///     [0., 3., 4., 0.],
+/// ];
+/// let template = array![[1., 2.], [3., 4.]];
+///
+/// let result = correlate2d(image.view(), template.view(), ConvolveMode::Valid, PaddingMode::Zeros)
+///     .unwrap();
+/// assert_eq!(result.dim(), (2, 3));
+/// assert_eq!(result[[1, 1]], 30.);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.correlate2d.html>
+pub fn correlate2d<T>(
+    in1: ArrayView2<T>,
+    in2: ArrayView2<T>,
+    mode: ConvolveMode,
+    padding: PaddingMode<T>,
+) -> Result<Array2<T>>
+where
+    T: num_traits::NumAssign + core::marker::Copy + 'static,
+{
+    let ((m, n), (p, q)) = (in1.dim(), in2.dim());
+    if mode == ConvolveMode::Valid && (p > m || q > n) {
+        return Err(Error::ShapeMismatch {
+            reason: alloc::format!(
+                "in2 of shape ({p}, {q}) is larger than in1 of shape ({m}, {n}) in valid mode."
+            ),
+        });
+    }
+
+    // Correlation is the convolution with `in2` flipped along both axes.
+    let flipped = Array2::from_shape_fn((p, q), |(i, j)| in2[[p - 1 - i, q - 1 - j]]);
+    let result = in1.conv(&flipped, mode.into(), padding.into());
+    result.map_err(|e| Error::Conv {
+        reason: e.to_string(),
+    })
+}
+
 #[cfg(test)]
 mod linear_convolve {
     use super::*;
@@ -109,7 +369,8 @@ mod linear_convolve {
         let v = array![0., 1., 0.5];
 
         let expected = array![0., 1., 2.5, 4., 1.5];
-        let result = convolve((&a).into(), (&v).into(), ConvolveMode::Full).unwrap();
+        let result = convolve((&a).into(), (&v).into(), ConvolveMode::Full);
+        let result = result.unwrap();
         assert_eq!(result, expected);
     }
 
@@ -119,7 +380,8 @@ mod linear_convolve {
         let v = array![0., 1., 0.5];
 
         let expected = array![1., 2.5, 4.];
-        let result = convolve((&a).into(), (&v).into(), ConvolveMode::Same).unwrap();
+        let result = convolve((&a).into(), (&v).into(), ConvolveMode::Same);
+        let result = result.unwrap();
         assert_eq!(result, expected);
     }
 
@@ -129,7 +391,289 @@ mod linear_convolve {
         let v = array![0., 1., 0.5];
 
         let expected = array![2.5];
-        let result = convolve((&a).into(), (&v).into(), ConvolveMode::Valid).unwrap();
+        let result = convolve((&a).into(), (&v).into(), ConvolveMode::Valid);
+        let result = result.unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn edge_padding_removes_roll_off() {
+        // A moving average of a signal with a large DC offset rolls off towards zero at the
+        // edges when zero padded.
+        let a = Array1::from_elem(16, 5f64);
+        let v = Array1::from_elem(5, 0.2);
+        let zeros = convolve(a.view(), v.view(), ConvolveMode::Same).unwrap();
+        assert!((zeros[0] - 3.).abs() < 1e-12);
+        assert!((zeros[15] - 3.).abs() < 1e-12);
+
+        // Padding with the edge value removes it.
+        for padding in [PaddingMode::Const(5.), PaddingMode::Replicate] {
+            for mode in [ConvolveMode::Full, ConvolveMode::Same] {
+                let result = convolve_with_padding(a.view(), v.view(), mode, padding).unwrap();
+                assert!(result.iter().all(|r| (r - 5.).abs() < 1e-12));
+            }
+        }
+
+        // Samples beyond the edges are not used in the valid part of the convolution.
+        let a = array![1., 4., 2., 8.];
+        let v = array![1., -1.];
+        let valid = convolve(a.view(), v.view(), ConvolveMode::Valid).unwrap();
+        for padding in [
+            PaddingMode::Const(7.),
+            PaddingMode::Reflect,
+            PaddingMode::Replicate,
+        ] {
+            let result =
+                convolve_with_padding(a.view(), v.view(), ConvolveMode::Valid, padding).unwrap();
+            assert_eq!(result, valid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod axis_convolve {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{array, Array3};
+
+    #[test]
+    fn matches_per_lane_loop() {
+        let a = Array3::from_shape_fn((4, 5, 6), |(i, j, k)| (i * 30 + j * 6 + k) as f64 - 7.);
+        let v = array![0.5, -1., 2., 0.25];
+
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            for ax in 0..3 {
+                let axis = Axis(ax);
+                let result = convolve_axis(&a, v.view(), mode, axis).unwrap();
+
+                assert_eq!(
+                    result.lanes(axis).into_iter().len(),
+                    a.lanes(axis).into_iter().len()
+                );
+                result
+                    .lanes(axis)
+                    .into_iter()
+                    .zip(a.lanes(axis))
+                    .for_each(|(r, lane)| {
+                        assert_eq!(r, convolve(lane, v.view(), mode).unwrap());
+                    });
+            }
+        }
+    }
+
+    #[test]
+    fn same_with_kernel_longer_than_lane() {
+        let a = array![[1., 2.], [3., 4.]];
+        let v = array![1., 2., 3., 4., 5.];
+
+        // >>> signal.convolve(a, v[None, :], mode='same')
+        let result = convolve_axis(&a, v.view(), ConvolveMode::Same, Axis(1)).unwrap();
+        assert_eq!(result, array![[7., 10.], [17., 24.]]);
+
+        // >>> signal.convolve(a, v[:, None], mode='same')
+        let result = convolve_axis(&a, v.view(), ConvolveMode::Same, Axis(0)).unwrap();
+        assert_eq!(result, array![[9., 14.], [13., 20.]]);
+
+        let result = convolve_axis_with_padding(
+            &a,
+            v.view(),
+            ConvolveMode::Same,
+            PaddingMode::Replicate,
+            Axis(1),
+        )
+        .unwrap();
+        assert_eq!(result, array![[18., 21.], [48., 51.]]);
+    }
+
+    #[test]
+    fn invalid_axis() {
+        let a = array![[1., 2., 3.], [4., 5., 6.]];
+        let v = array![1., 1.];
+
+        let result = convolve_axis(&a, v.view(), ConvolveMode::Full, Axis(2));
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod fft_convolve {
+    use super::*;
+
+    use alloc::vec;
+    use ndarray::{array, Array3};
+
+    fn assert_close<D: Dimension>(result: &Array<f64, D>, expected: &Array<f64, D>) {
+        assert_eq!(result.shape(), expected.shape());
+        for (r, e) in result.iter().zip(expected) {
+            assert!((r - e).abs() < 1e-12, "{r} != {e}");
+        }
+    }
+
+    #[test]
+    fn matches_direct_for_long_kernels() {
+        // Integer inputs are convolved exactly by the direct method.
+        let a = Array3::from_shape_fn((3, 400, 2), |(i, j, k)| {
+            ((i * 800 + j * 2 + k) * 37 % 101) as i64 - 50
+        });
+        let v: Array1<i64> = (0..77).map(|i| (i * 17 % 23) - 11).collect();
+        let (af, vf) = (a.mapv(|x| x as f64), v.mapv(|x| x as f64));
+
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            let direct = convolve_axis(&a, v.view(), mode, Axis(1)).unwrap();
+            let result = fftconvolve_axis(&af, vf.view(), mode, Axis(1)).unwrap();
+            assert_eq!(result.shape(), direct.shape());
+            for (r, d) in result.iter().zip(&direct) {
+                assert!((r - *d as f64).abs() < 1e-8, "{r} != {d}");
+            }
+
+            let result = fftconvolve_axis(
+                &af.mapv(|x| x as f32),
+                vf.mapv(|x| x as f32).view(),
+                mode,
+                Axis(1),
+            )
+            .unwrap();
+            assert_eq!(result.shape(), direct.shape());
+            // Outputs reach the order of 1e4, leaving few significant decimals in f32.
+            for (r, d) in result.iter().zip(&direct) {
+                assert!((r - *d as f32).abs() < 1e-1, "{r} != {d}");
+            }
+        }
+    }
+
+    #[test]
+    fn long_float_kernels_take_the_fft_path() {
+        let a = Array3::from_shape_fn((3, 400, 2), |(i, j, k)| {
+            ((i * 800 + j * 2 + k) * 37 % 101) as i64 - 50
+        });
+        let v: Array1<i64> = (0..fft::FFT_MIN_KERNEL as i64 + 13)
+            .map(|i| (i * 17 % 23) - 11)
+            .collect();
+        let (af, vf) = (a.mapv(|x| x as f64), v.mapv(|x| x as f64));
+
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            // Bit for bit the FFT method, and the direct method up to rounding.
+            let direct = convolve_axis(&a, v.view(), mode, Axis(1)).unwrap();
+            let result = convolve_axis(&af, vf.view(), mode, Axis(1)).unwrap();
+            assert_eq!(
+                result,
+                fftconvolve_axis(&af, vf.view(), mode, Axis(1)).unwrap()
+            );
+            assert_close(&result.mapv(|r| r.round()), &direct.mapv(|d| d as f64));
+            assert_ne!(result, direct.mapv(|d| d as f64));
+
+            let lane = af.slice(s![0, .., 0]);
+            let result = convolve(lane, vf.view(), mode).unwrap();
+            assert_eq!(
+                result,
+                fftconvolve_axis(&lane, vf.view(), mode, Axis(0)).unwrap()
+            );
+        }
+
+        // Shorter kernels are convolved directly, exactly for integer-valued floats.
+        let v = v.slice(s![..fft::FFT_MIN_KERNEL - 1]);
+        let direct = convolve_axis(&a, v, ConvolveMode::Full, Axis(1)).unwrap();
+        let result = convolve_axis(
+            &af,
+            v.mapv(|x| x as f64).view(),
+            ConvolveMode::Full,
+            Axis(1),
+        );
+        assert_eq!(result.unwrap(), direct.mapv(|d| d as f64));
+    }
+
+    #[test]
+    fn kernel_longer_than_lane() {
+        let a = array![[1., 2.], [3., 4.]];
+        let v = array![1., 2., 3., 4., 5.];
+
+        for mode in [ConvolveMode::Full, ConvolveMode::Same] {
+            let direct = convolve_axis(&a, v.view(), mode, Axis(1)).unwrap();
+            let result = fftconvolve_axis(&a, v.view(), mode, Axis(1)).unwrap();
+            assert_close(&result, &direct);
+        }
+
+        // >>> np.convolve([1, 2], [1, 2, 3, 4, 5], mode='valid')
+        let result = fftconvolve_axis(&a, v.view(), ConvolveMode::Valid, Axis(1)).unwrap();
+        assert_close(&result, &array![[4., 7., 10., 13.], [10., 17., 24., 31.]]);
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let a = array![[1., 2., 3.], [4., 5., 6.]];
+        let v = array![1., 1.];
+
+        assert!(fftconvolve_axis(&a, v.view(), ConvolveMode::Full, Axis(2)).is_err());
+        assert!(fftconvolve_axis(&a, array![].view(), ConvolveMode::Full, Axis(1)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod correlate_2d {
+    use super::*;
+    use alloc::vec;
+    use ndarray::array;
+
+    #[test]
+    fn valid_shape() {
+        let in1 = Array2::from_shape_fn((7, 5), |(i, j)| (i * 5 + j) as f64);
+        for (p, q) in [(1, 1), (3, 2), (7, 5), (2, 5), (7, 1)] {
+            let in2 = Array2::from_elem((p, q), 1.);
+            let result = correlate2d(
+                in1.view(),
+                in2.view(),
+                ConvolveMode::Valid,
+                PaddingMode::Zeros,
+            )
+            .unwrap();
+            assert_eq!(result.dim(), (7 - p + 1, 5 - q + 1));
+        }
+    }
+
+    #[test]
+    fn valid_and_full_values() {
+        // scipy.signal.correlate2d(in1, in2, mode='valid')
+        let in1 = array![[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]];
+        let in2 = array![[1., 0.], [-1., 2.]];
+        let expected = array![[8., 10., 12.], [16., 18., 20.]];
+        let result = correlate2d(
+            in1.view(),
+            in2.view(),
+            ConvolveMode::Valid,
+            PaddingMode::Zeros,
+        )
+        .unwrap();
         assert_eq!(result, expected);
+
+        // scipy.signal.correlate2d(in1, in2, mode='full')
+        let expected = array![
+            [2., 3., 4., 5., -4.],
+            [10., 8., 10., 12., -4.],
+            [18., 16., 18., 20., -4.],
+            [0., 9., 10., 11., 12.]
+        ];
+        let result = correlate2d(
+            in1.view(),
+            in2.view(),
+            ConvolveMode::Full,
+            PaddingMode::Zeros,
+        )
+        .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn valid_rejects_oversized_kernel() {
+        let in1 = Array2::<f64>::zeros((4, 4));
+        for shape in [(5, 1), (1, 5), (5, 5)] {
+            let in2 = Array2::<f64>::ones(shape);
+            let result = correlate2d(
+                in1.view(),
+                in2.view(),
+                ConvolveMode::Valid,
+                PaddingMode::Zeros,
+            );
+            assert!(matches!(result, Err(Error::ShapeMismatch { .. })));
+        }
     }
 }
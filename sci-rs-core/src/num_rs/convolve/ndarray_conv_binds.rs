@@ -1,4 +1,4 @@
-use super::ConvolveMode;
+use super::{ConvolveMode, PaddingMode};
 use ndarray_conv::ConvMode;
 
 impl<const N: usize> From<ConvolveMode> for ConvMode<N> {
@@ -10,3 +10,17 @@ impl<const N: usize> From<ConvolveMode> for ConvMode<N> {
         }
     }
 }
+
+impl<const N: usize, T> From<PaddingMode<T>> for ndarray_conv::PaddingMode<N, T>
+where
+    T: num_traits::NumAssign + Copy,
+{
+    fn from(value: PaddingMode<T>) -> Self {
+        match value {
+            PaddingMode::Zeros => ndarray_conv::PaddingMode::Zeros,
+            PaddingMode::Const(c) => ndarray_conv::PaddingMode::Const(c),
+            PaddingMode::Reflect => ndarray_conv::PaddingMode::Reflect,
+            PaddingMode::Replicate => ndarray_conv::PaddingMode::Replicate,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, Slice};
+
+/// Shift the zero-frequency component to the center of the spectrum.
+///
+/// This swaps half-spaces for all axes listed, or all axes if `axes` is `None`. For an axis of
+/// length `n`, the element at index 0 is moved to index `n / 2`, rounding down.
+///
+/// # Parameters
+/// * `x` : array_like  
+///   Input array.
+/// * `axes` : int or shape tuple, optional  
+///   Axes over which to shift. Default is None, which shifts all axes.
+///
+/// # Returns
+/// * `y` : ndarray  
+///   The shifted array.
+///
+/// # Panics
+/// If an axis in `axes` is out of range for `x`.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs_core::num_rs::fftshift;
+///
+/// // from numpy.fft import fftfreq
+/// // fftfreq(5, d=1/5)
+/// let freqs = array![0., 1., 2., -2., -1.];
+/// assert_eq!(fftshift(&freqs, None), array![-2., -1., 0., 1., 2.]);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.fft.fftshift.html>
+pub fn fftshift<F, S, D>(x: &ArrayBase<S, D>, axes: Option<&[usize]>) -> Array<F, D>
+where
+    F: Clone,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    roll_half(x, axes, |n| n - n / 2)
+}
+
+/// The inverse of [fftshift].
+///
+/// Although identical for even-length axes, the functions differ by one sample for odd-length
+/// axes. For an axis of length `n`, the element at index `n / 2`, rounding down, is moved to
+/// index 0.
+///
+/// # Parameters
+/// * `x` : array_like  
+///   Input array.
+/// * `axes` : int or shape tuple, optional  
+///   Axes over which to calculate. Defaults to None, which shifts all axes.
+///
+/// # Returns
+/// * `y` : ndarray  
+///   The shifted array.
+///
+/// # Panics
+/// If an axis in `axes` is out of range for `x`.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs_core::num_rs::{fftshift, ifftshift};
+///
+/// let freqs = array![[0., 1., 2.], [3., 4., -4.], [-3., -2., -1.]];
+/// assert_eq!(ifftshift(&fftshift(&freqs, None), None), freqs);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.fft.ifftshift.html>
+pub fn ifftshift<F, S, D>(x: &ArrayBase<S, D>, axes: Option<&[usize]>) -> Array<F, D>
+where
+    F: Clone,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    roll_half(x, axes, |n| n / 2)
+}
+
+/// Rotate each of `axes` of `x` so that the element at `split(n)` moves to index 0.
+fn roll_half<F, S, D>(
+    x: &ArrayBase<S, D>,
+    axes: Option<&[usize]>,
+    split: impl Fn(usize) -> usize,
+) -> Array<F, D>
+where
+    F: Clone,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let mut y = x.to_owned();
+    let mut roll = |axis: usize| {
+        let axis = Axis(axis);
+        let n = y.len_of(axis);
+        let k = split(n);
+        let x = y.clone();
+        y.slice_axis_mut(axis, Slice::from(..n - k))
+            .assign(&x.slice_axis(axis, Slice::from(k..)));
+        y.slice_axis_mut(axis, Slice::from(n - k..))
+            .assign(&x.slice_axis(axis, Slice::from(..k)));
+    };
+    match axes {
+        Some(axes) => axes.iter().for_each(|&axis| roll(axis)),
+        None => (0..x.ndim()).for_each(roll),
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{array, Array1};
+
+    #[test]
+    fn odd_and_even_1d() {
+        // >>> np.fft.fftshift(np.arange(5)), np.fft.ifftshift(np.arange(5))
+        // (array([3, 4, 0, 1, 2]), array([2, 3, 4, 0, 1]))
+        let x = Array1::from_iter(0..5);
+        assert_eq!(fftshift(&x, None), array![3, 4, 0, 1, 2]);
+        assert_eq!(ifftshift(&x, None), array![2, 3, 4, 0, 1]);
+
+        // >>> np.fft.fftshift(np.arange(6)), np.fft.ifftshift(np.arange(6))
+        // (array([3, 4, 5, 0, 1, 2]), array([3, 4, 5, 0, 1, 2]))
+        let x = Array1::from_iter(0..6);
+        assert_eq!(fftshift(&x, None), array![3, 4, 5, 0, 1, 2]);
+        assert_eq!(ifftshift(&x, None), array![3, 4, 5, 0, 1, 2]);
+
+        for n in 0..8 {
+            let x = Array1::from_iter(0..n);
+            assert_eq!(ifftshift(&fftshift(&x, None), None), x);
+            assert_eq!(fftshift(&ifftshift(&x.view(), None), None), x);
+        }
+    }
+
+    #[test]
+    fn odd_2d() {
+        // >>> freqs = np.fft.fftfreq(9, d=1./9).reshape(3, 3)
+        let freqs = array![[0, 1, 2], [3, 4, -4], [-3, -2, -1]];
+        // >>> np.fft.fftshift(freqs, axes=(1,))
+        assert_eq!(
+            fftshift(&freqs, Some(&[1])),
+            array![[2, 0, 1], [-4, 3, 4], [-1, -3, -2]]
+        );
+        // >>> np.fft.fftshift(freqs)
+        assert_eq!(
+            fftshift(&freqs, None),
+            array![[-1, -3, -2], [2, 0, 1], [-4, 3, 4]]
+        );
+        // >>> np.fft.ifftshift(freqs)
+        assert_eq!(
+            ifftshift(&freqs, None),
+            array![[4, -4, 3], [-2, -1, -3], [1, 2, 0]]
+        );
+    }
+
+    #[test]
+    fn even_2d() {
+        // >>> x = np.arange(8).reshape(2, 4)
+        let x = array![[0, 1, 2, 3], [4, 5, 6, 7]];
+        // >>> np.fft.fftshift(x)
+        assert_eq!(fftshift(&x, None), array![[6, 7, 4, 5], [2, 3, 0, 1]]);
+        // >>> np.fft.ifftshift(x, axes=0)
+        assert_eq!(
+            ifftshift(&x, Some(&[0])),
+            array![[4, 5, 6, 7], [0, 1, 2, 3]]
+        );
+        assert_eq!(fftshift(&x, Some(&[])), x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range_axis() {
+        fftshift(&array![[0, 1], [2, 3]], Some(&[2]));
+    }
+}
@@ -1,4 +1,24 @@
 #[cfg(feature = "alloc")]
+mod clip;
+#[cfg(feature = "alloc")]
+pub use clip::*;
+#[cfg(feature = "alloc")]
 mod convolve;
 #[cfg(feature = "alloc")]
 pub use convolve::*;
+#[cfg(feature = "alloc")]
+mod fftshift;
+#[cfg(feature = "alloc")]
+pub use fftshift::*;
+#[cfg(feature = "alloc")]
+mod pad;
+#[cfg(feature = "alloc")]
+pub use pad::*;
+#[cfg(feature = "alloc")]
+mod polynomial;
+#[cfg(feature = "alloc")]
+pub use polynomial::*;
+#[cfg(feature = "alloc")]
+mod range;
+#[cfg(feature = "alloc")]
+pub use range::*;
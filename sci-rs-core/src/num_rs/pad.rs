@@ -0,0 +1,431 @@
+use crate::{Error, Result};
+use alloc::vec::Vec;
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, Slice};
+use num_traits::Num;
+
+/// Pad mode determines how [pad] fills the values beyond the edges of an array, as numpy's `mode`
+/// and `reflect_type`.
+///
+/// The examples pad `[1, 2, 3, 4, 5]` by two on either side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadMode {
+    /// Pad with `cval`: `c c | 1 2 3 4 5 | c c`. numpy's `constant`.
+    Constant,
+    /// Pad with the edge values: `1 1 | 1 2 3 4 5 | 5 5`. numpy's `edge`, and the `constant`
+    /// extension of scipy.
+    Edge,
+    /// Pad with a linear ramp from `cval` at the ends of the padded array to the edge values:
+    /// `c (c + 1) / 2 | 1 2 3 4 5 | (c + 5) / 2 c`. numpy's `linear_ramp`.
+    LinearRamp,
+    /// Reflect about the edge values, which are not repeated: `3 2 | 1 2 3 4 5 | 4 3`. numpy's
+    /// `reflect`.
+    Reflect,
+    /// Reflect about the edges of the array, repeating the edge values: `2 1 | 1 2 3 4 5 | 5 4`.
+    /// numpy's `symmetric`.
+    Symmetric,
+    /// Wrap around to the opposite end: `4 5 | 1 2 3 4 5 | 1 2`. numpy's `wrap`.
+    Wrap,
+    /// Odd extension about the edge values, `2 * edge - reflect`: `-1 0 | 1 2 3 4 5 | 6 7`.
+    /// numpy's `reflect` with `reflect_type='odd'`, and the `odd` extension of scipy.
+    Odd,
+    /// Even extension about the edge values: `3 2 | 1 2 3 4 5 | 4 3`. The same as
+    /// [PadMode::Reflect], and the `even` extension of scipy.
+    Even,
+    /// Odd extension about the edges of the array, `2 * edge - symmetric`:
+    /// `0 1 | 1 2 3 4 5 | 5 6`. numpy's `symmetric` with `reflect_type='odd'`.
+    Antireflect,
+}
+
+/// Pad an array.
+///
+/// Each axis is padded in turn, so the corners are padded from the values padded along earlier
+/// axes, as in numpy. Padding by more than the length of an axis repeats the reflections or
+/// wraps.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The array to pad.
+/// * `pad_width` : sequence
+///   Number of values padded to the `(before, after)` edges of each axis.
+/// * `mode` : [PadMode]
+///   How to fill the padded values.
+/// * `cval` : scalar
+///   The padded value of [PadMode::Constant], and the end value of [PadMode::LinearRamp].
+///   Unused by the other modes.
+///
+/// # Returns
+/// * `pad` : ndarray
+///   Padded array with shape increased according to `pad_width`.
+///
+/// # Errors
+/// [Error::InvalidArg] if `pad_width` does not have a pair for each axis of `x`, or if an empty
+/// axis is padded with any mode but [PadMode::Constant].
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs_core::num_rs::{pad, PadMode};
+///
+/// let a = array![1., 2., 3., 4., 5.];
+/// let padded = pad(&a, &[(2, 3)], PadMode::Reflect, 0.).unwrap();
+/// assert_eq!(padded, array![3., 2., 1., 2., 3., 4., 5., 4., 3., 2.]);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.pad.html>
+pub fn pad<T, S, D>(
+    x: &ArrayBase<S, D>,
+    pad_width: &[(usize, usize)],
+    mode: PadMode,
+    cval: T,
+) -> Result<Array<T, D>>
+where
+    T: Copy + Num,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    if pad_width.len() != x.ndim() {
+        return Err(Error::InvalidArg {
+            arg: "pad_width".into(),
+            reason: "must have a pair of widths for each axis of x.".into(),
+        });
+    }
+
+    let mut shape = x.raw_dim();
+    for (n, &(before, after)) in shape.slice_mut().iter_mut().zip(pad_width) {
+        if *n == 0 && before + after > 0 && mode != PadMode::Constant {
+            return Err(Error::InvalidArg {
+                arg: "x".into(),
+                reason: "can't extend empty axis using modes other than Constant.".into(),
+            });
+        }
+        *n += before + after;
+    }
+
+    let mut padded = Array::from_elem(shape, cval);
+    padded
+        .slice_each_axis_mut(|ax| {
+            let before = pad_width[ax.axis.index()].0;
+            Slice::from(before..before + x.len_of(ax.axis))
+        })
+        .assign(x);
+    if mode == PadMode::Constant {
+        return Ok(padded);
+    }
+
+    let mut lane = Vec::new();
+    for (axis, &(before, after)) in pad_width.iter().enumerate() {
+        if before + after == 0 {
+            continue;
+        }
+        let n = x.len_of(Axis(axis));
+        for mut padded_lane in padded.lanes_mut(Axis(axis)) {
+            lane.clear();
+            lane.extend(padded_lane.iter().copied());
+            pad_lane(&mut lane, before, after, n, mode, cval);
+            padded_lane.iter_mut().zip(&lane).for_each(|(p, &v)| *p = v);
+        }
+    }
+    Ok(padded)
+}
+
+/// Fill the `before` and `after` values of `p` around the `n` values in its middle.
+fn pad_lane<T>(p: &mut [T], before: usize, after: usize, n: usize, mode: PadMode, cval: T)
+where
+    T: Copy + Num,
+{
+    let len = p.len();
+    let (first, last) = (p[before], p[len - after - 1]);
+    let (include_edge, odd) = match mode {
+        PadMode::Constant => return,
+        PadMode::Edge => {
+            p[..before].fill(first);
+            p[len - after..].fill(last);
+            return;
+        }
+        PadMode::LinearRamp => {
+            // As numpy's linspace from `cval` to the edge value, excluding the edge value. The
+            // steps are counted in `T`, which need not convert from `usize`.
+            let ramp = |ramp: &mut [T], edge: T| {
+                if ramp.is_empty() {
+                    return;
+                }
+                let width = ramp.iter().fold(T::zero(), |w, _| w + T::one());
+                let step = (edge - cval) / width;
+                let mut k = T::zero();
+                for v in ramp {
+                    *v = k * step + cval;
+                    k = k + T::one();
+                }
+            };
+            ramp(&mut p[..before], first);
+            ramp(&mut p[len - after..], last);
+            p[len - after..].reverse();
+            return;
+        }
+        PadMode::Wrap => {
+            for i in (0..before).chain(len - after..len) {
+                let j = (i as isize - before as isize).rem_euclid(n as isize) as usize;
+                p[i] = p[before + j];
+            }
+            return;
+        }
+        PadMode::Reflect | PadMode::Even => (false, false),
+        PadMode::Odd => (false, true),
+        PadMode::Symmetric => (true, false),
+        PadMode::Antireflect => (true, true),
+    };
+
+    // A single value can only be repeated
+    if n == 1 {
+        p[..before].fill(first);
+        p[len - after..].fill(last);
+        return;
+    }
+
+    // As numpy, reflect the values padded so far in chunks, each of at most a whole period, so
+    // that the odd extensions continue from the values padded by the previous chunk.
+    let two = T::one() + T::one();
+    let (mut before, mut after) = (before, after);
+    while before > 0 || after > 0 {
+        let old = len - before - after;
+        let (old, edge_offset) = if include_edge {
+            (old / n * n, 1)
+        } else {
+            ((old - 1) / (n - 1) * (n - 1), 0)
+        };
+
+        if before > 0 {
+            let chunk = old.min(before);
+            let start = before - edge_offset + chunk;
+            let edge = p[before];
+            for k in 0..chunk {
+                let v = p[start - k];
+                p[before - chunk + k] = if odd { two * edge - v } else { v };
+            }
+            before -= chunk;
+        }
+
+        if after > 0 {
+            let chunk = old.min(after);
+            let start = len - after + edge_offset - 2;
+            let edge = p[len - after - 1];
+            for k in 0..chunk {
+                let v = p[start - k];
+                p[len - after + k] = if odd { two * edge - v } else { v };
+            }
+            after -= chunk;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{array, Array1};
+
+    #[test]
+    fn pad_1d() {
+        // >>> a = [1, 2, 3, 4, 5]
+        let a = array![1., 2., 3., 4., 5.];
+        let cases = [
+            // >>> np.pad(a, (2, 3), 'constant', constant_values=-4)
+            (
+                PadMode::Constant,
+                vec![-4., -4., 1., 2., 3., 4., 5., -4., -4., -4.],
+            ),
+            // >>> np.pad(a, (2, 3), 'edge')
+            (PadMode::Edge, vec![1., 1., 1., 2., 3., 4., 5., 5., 5., 5.]),
+            // >>> np.pad(a, (2, 3), 'linear_ramp', end_values=-4)
+            (
+                PadMode::LinearRamp,
+                vec![-4., -1.5, 1., 2., 3., 4., 5., 2., -1., -4.],
+            ),
+            // >>> np.pad(a, (2, 3), 'reflect')
+            (
+                PadMode::Reflect,
+                vec![3., 2., 1., 2., 3., 4., 5., 4., 3., 2.],
+            ),
+            (PadMode::Even, vec![3., 2., 1., 2., 3., 4., 5., 4., 3., 2.]),
+            // >>> np.pad(a, (2, 3), 'reflect', reflect_type='odd')
+            (PadMode::Odd, vec![-1., 0., 1., 2., 3., 4., 5., 6., 7., 8.]),
+            // >>> np.pad(a, (2, 3), 'symmetric')
+            (
+                PadMode::Symmetric,
+                vec![2., 1., 1., 2., 3., 4., 5., 5., 4., 3.],
+            ),
+            // >>> np.pad(a, (2, 3), 'symmetric', reflect_type='odd')
+            (
+                PadMode::Antireflect,
+                vec![0., 1., 1., 2., 3., 4., 5., 5., 6., 7.],
+            ),
+            // >>> np.pad(a, (2, 3), 'wrap')
+            (PadMode::Wrap, vec![4., 5., 1., 2., 3., 4., 5., 1., 2., 3.]),
+        ];
+        for (mode, expected) in cases {
+            let result = pad(&a, &[(2, 3)], mode, -4.).unwrap();
+            assert_eq!(result, Array1::from(expected), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn pad_1d_longer_than_axis() {
+        // >>> a = [1, 2, 3]
+        let a = array![1., 2., 3.];
+        let cases = [
+            // >>> np.pad(a, (5, 4), 'reflect')
+            (
+                PadMode::Reflect,
+                vec![2., 1., 2., 3., 2., 1., 2., 3., 2., 1., 2., 3.],
+            ),
+            // >>> np.pad(a, (5, 4), 'reflect', reflect_type='odd')
+            (
+                PadMode::Odd,
+                vec![-4., -3., -2., -1., 0., 1., 2., 3., 4., 5., 6., 7.],
+            ),
+            // >>> np.pad(a, (5, 4), 'symmetric')
+            (
+                PadMode::Symmetric,
+                vec![2., 3., 3., 2., 1., 1., 2., 3., 3., 2., 1., 1.],
+            ),
+            // >>> np.pad(a, (5, 4), 'symmetric', reflect_type='odd')
+            (
+                PadMode::Antireflect,
+                vec![-2., -1., -1., 0., 1., 1., 2., 3., 3., 4., 5., 5.],
+            ),
+            // >>> np.pad(a, (5, 4), 'wrap')
+            (
+                PadMode::Wrap,
+                vec![2., 3., 1., 2., 3., 1., 2., 3., 1., 2., 3., 1.],
+            ),
+        ];
+        for (mode, expected) in cases {
+            let result = pad(&a, &[(5, 4)], mode, 0.).unwrap();
+            assert_eq!(result, Array1::from(expected), "{mode:?}");
+        }
+
+        // A single value is repeated.
+        let result = pad(&array![5.], &[(2, 1)], PadMode::Odd, 0.).unwrap();
+        assert_eq!(result, array![5., 5., 5., 5.]);
+    }
+
+    #[test]
+    fn pad_2d() {
+        // >>> b = [[1, 2, 3], [4, 5, 7]]
+        let b = array![[1., 2., 3.], [4., 5., 7.]];
+        let width = [(1, 2), (2, 1)];
+        let cases = [
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'constant', constant_values=-1)
+            (
+                PadMode::Constant,
+                array![
+                    [-1., -1., -1., -1., -1., -1.],
+                    [-1., -1., 1., 2., 3., -1.],
+                    [-1., -1., 4., 5., 7., -1.],
+                    [-1., -1., -1., -1., -1., -1.],
+                    [-1., -1., -1., -1., -1., -1.]
+                ],
+            ),
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'edge')
+            (
+                PadMode::Edge,
+                array![
+                    [1., 1., 1., 2., 3., 3.],
+                    [1., 1., 1., 2., 3., 3.],
+                    [4., 4., 4., 5., 7., 7.],
+                    [4., 4., 4., 5., 7., 7.],
+                    [4., 4., 4., 5., 7., 7.]
+                ],
+            ),
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'linear_ramp', end_values=-1)
+            (
+                PadMode::LinearRamp,
+                array![
+                    [-1., -1., -1., -1., -1., -1.],
+                    [-1., 0., 1., 2., 3., -1.],
+                    [-1., 1.5, 4., 5., 7., -1.],
+                    [-1., 0.25, 1.5, 2., 3., -1.],
+                    [-1., -1., -1., -1., -1., -1.]
+                ],
+            ),
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'reflect')
+            (
+                PadMode::Reflect,
+                array![
+                    [7., 5., 4., 5., 7., 5.],
+                    [3., 2., 1., 2., 3., 2.],
+                    [7., 5., 4., 5., 7., 5.],
+                    [3., 2., 1., 2., 3., 2.],
+                    [7., 5., 4., 5., 7., 5.]
+                ],
+            ),
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'reflect', reflect_type='odd')
+            (
+                PadMode::Odd,
+                array![
+                    [-3., -3., -2., -1., -1., -1.],
+                    [-1., 0., 1., 2., 3., 4.],
+                    [1., 3., 4., 5., 7., 9.],
+                    [3., 6., 7., 8., 11., 14.],
+                    [5., 9., 10., 11., 15., 19.]
+                ],
+            ),
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'symmetric')
+            (
+                PadMode::Symmetric,
+                array![
+                    [2., 1., 1., 2., 3., 3.],
+                    [2., 1., 1., 2., 3., 3.],
+                    [5., 4., 4., 5., 7., 7.],
+                    [5., 4., 4., 5., 7., 7.],
+                    [2., 1., 1., 2., 3., 3.]
+                ],
+            ),
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'symmetric', reflect_type='odd')
+            (
+                PadMode::Antireflect,
+                array![
+                    [0., 1., 1., 2., 3., 3.],
+                    [0., 1., 1., 2., 3., 3.],
+                    [3., 4., 4., 5., 7., 7.],
+                    [3., 4., 4., 5., 7., 7.],
+                    [6., 7., 7., 8., 11., 11.]
+                ],
+            ),
+            // >>> np.pad(b, ((1, 2), (2, 1)), 'wrap')
+            (
+                PadMode::Wrap,
+                array![
+                    [5., 7., 4., 5., 7., 4.],
+                    [2., 3., 1., 2., 3., 1.],
+                    [5., 7., 4., 5., 7., 4.],
+                    [2., 3., 1., 2., 3., 1.],
+                    [5., 7., 4., 5., 7., 4.]
+                ],
+            ),
+        ];
+        for (mode, expected) in cases {
+            let result = pad(&b, &width, mode, -1.).unwrap();
+            assert_eq!(result, expected, "{mode:?}");
+        }
+        assert_eq!(
+            pad(&b, &width, PadMode::Even, -1.).unwrap(),
+            pad(&b, &width, PadMode::Reflect, -1.).unwrap()
+        );
+    }
+
+    #[test]
+    fn pad_invalid() {
+        let b = array![[1., 2., 3.], [4., 5., 7.]];
+        assert!(matches!(
+            pad(&b, &[(1, 1)], PadMode::Edge, 0.),
+            Err(Error::InvalidArg { .. })
+        ));
+        let empty = Array1::<f64>::zeros(0);
+        assert!(pad(&empty, &[(1, 1)], PadMode::Reflect, 0.).is_err());
+        assert_eq!(
+            pad(&empty, &[(1, 1)], PadMode::Constant, 2.).unwrap(),
+            array![2., 2.]
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{Error, Result};
+use alloc::{vec, vec::Vec};
+use nalgebra::{Complex, ComplexField, DMatrix, RealField};
+
+/// Return the roots of a polynomial with coefficients given in `p`.
+///
+/// The values in the rank-1 array `p` are coefficients of a polynomial. If the length of `p` is
+/// n+1 then the polynomial is described by:
+///
+/// ```text
+/// p[0] * x**n + p[1] * x**(n-1) + ... + p[n-1]*x + p[n]
+/// ```
+///
+/// # Parameters
+/// * `p` : array_like  
+///   Rank-1 array of polynomial coefficients.
+///
+/// # Returns
+/// * `out` : ndarray  
+///   An array containing the roots of the polynomial.
+///
+/// # Notes
+/// As in numpy, the roots are the eigenvalues of the companion matrix of `p`. The companion
+/// matrix is balanced before the eigenvalue decomposition, which is what LAPACK does for numpy,
+/// as its rows may otherwise differ in scale by many orders of magnitude.
+///
+/// Leading zeros of `p` are ignored and trailing zeros give roots at the origin. The roots are not
+/// returned in any particular order.
+///
+/// # Examples
+/// ```
+/// use sci_rs_core::num_rs::roots;
+///
+/// let coeff = [3.2f64, 2., 1.];
+/// let mut r = roots(&coeff);
+/// r.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+/// assert!((r[0].re + 0.3125).abs() < 1e-12 && (r[0].im + 0.46351241).abs() < 1e-8);
+/// assert!((r[1].re + 0.3125).abs() < 1e-12 && (r[1].im - 0.46351241).abs() < 1e-8);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.roots.html>
+pub fn roots<F>(p: &[F]) -> Vec<Complex<F>>
+where
+    F: RealField + Copy,
+{
+    let Some(first) = p.iter().position(|pi| !pi.is_zero()) else {
+        return Vec::new();
+    };
+    let last = p.iter().rposition(|pi| !pi.is_zero()).unwrap();
+    let trailing_zeros = p.len() - 1 - last;
+    let p = &p[first..=last];
+
+    let n = p.len() - 1;
+    let mut out = Vec::with_capacity(n + trailing_zeros);
+    if n > 0 {
+        let mut a = DMatrix::<F>::zeros(n, n);
+        for (j, &pj) in p[1..].iter().enumerate() {
+            a[(0, j)] = -pj / p[0];
+        }
+        for i in 1..n {
+            a[(i, i - 1)] = F::one();
+        }
+        balance(&mut a);
+        out.extend(a.complex_eigenvalues().iter().copied());
+    }
+    out.extend((0..trailing_zeros).map(|_| Complex::new(F::zero(), F::zero())));
+    out
+}
+
+/// Find the coefficients of a polynomial with the given sequence of roots.
+///
+/// Returns the coefficients of the polynomial whose leading coefficient is one for the given
+/// sequence of zeros (multiple roots must be included in the sequence as many times as their
+/// multiplicity). This is the inverse of [roots] up to the scale of the coefficients.
+///
+/// # Parameters
+/// * `seq_of_zeros` : array_like  
+///   A sequence of polynomial roots.
+///
+/// # Returns
+/// * `c` : ndarray  
+///   1D array of polynomial coefficients from highest to lowest degree:
+///   `c[0] * x**(N) + c[1] * x**(N-1) + ... + c[N-1] * x + c[N]`
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if the roots do not come in complex conjugate pairs, within a
+/// tolerance relative to the size of the coefficients, as the coefficients would not be real.
+///
+/// # Examples
+/// ```
+/// use nalgebra::Complex;
+/// use sci_rs_core::num_rs::poly;
+///
+/// let r = [Complex::new(1., 2.), Complex::new(1., -2.), Complex::new(0.5, 0.)];
+/// assert_eq!(poly(&r).unwrap(), vec![1., -2.5, 6., -2.5]);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.poly.html>
+pub fn poly<F>(seq_of_zeros: &[Complex<F>]) -> Result<Vec<F>>
+where
+    F: RealField + Copy,
+{
+    let mut a = vec![Complex::new(F::one(), F::zero())];
+    for &z in seq_of_zeros {
+        a.push(Complex::new(F::zero(), F::zero()));
+        for i in (1..a.len()).rev() {
+            let prev = a[i - 1];
+            a[i] -= prev * z;
+        }
+    }
+
+    let scale = a.iter().fold(F::zero(), |m, ai| m.max(ai.modulus()));
+    let tol = F::default_epsilon().sqrt() * scale;
+    if a.iter().any(|ai| ai.im.abs() > tol) {
+        return Err(Error::InvalidArg {
+            arg: "seq_of_zeros".into(),
+            reason: "Roots must come in complex conjugate pairs for real coefficients.".into(),
+        });
+    }
+
+    Ok(a.into_iter().map(|ai| ai.re).collect())
+}
+
+/// Balance a square matrix in place by similarity transforms with powers of two, so that its
+/// rows and columns have comparable norms without changing its eigenvalues.
+///
+/// This is the Parlett and Reinsch algorithm, as performed by LAPACK's `gebal`.
+fn balance<F>(a: &mut DMatrix<F>)
+where
+    F: RealField + Copy,
+{
+    let radix = F::one() + F::one();
+    let sqrdx = radix * radix;
+    let threshold = nalgebra::convert::<f64, F>(0.95);
+    let n = a.nrows();
+
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let (mut c, mut r) = (F::zero(), F::zero());
+            for j in (0..n).filter(|&j| j != i) {
+                c += a[(j, i)].abs();
+                r += a[(i, j)].abs();
+            }
+            if c.is_zero() || r.is_zero() {
+                continue;
+            }
+
+            let s = c + r;
+            let mut f = F::one();
+            let mut g = r / radix;
+            while c < g {
+                f *= radix;
+                c *= sqrdx;
+            }
+            g = r * radix;
+            while c > g {
+                f /= radix;
+                c /= sqrdx;
+            }
+            if (c + r) / f < threshold * s {
+                done = false;
+                a.row_mut(i).scale_mut(F::one() / f);
+                a.column_mut(i).scale_mut(f);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn sorted(mut r: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+        r.sort_by(|a, b| {
+            a.re.partial_cmp(&b.re)
+                .unwrap()
+                .then(a.im.partial_cmp(&b.im).unwrap())
+        });
+        r
+    }
+
+    #[test]
+    fn degree_5_known_roots() {
+        // >>> np.poly([-1, 0.5, 2, 3, 40])
+        // array([   1. ,  -44.5,  183. , -114.5, -223. ,  120. ])
+        // >>> np.sort(np.roots(_))
+        // array([-1. ,  0.5,  2. ,  3. , 40. ])
+        let p = [1., -44.5, 183., -114.5, -223., 120.];
+        let r = sorted(roots(&p));
+        assert_eq!(r.len(), 5);
+        for (a, e) in r.iter().zip([-1., 0.5, 2., 3., 40.]) {
+            assert!((a.re - e).abs() < 1e-10, "{r:?}");
+            assert!(a.im.abs() < 1e-10, "{r:?}");
+        }
+    }
+
+    #[test]
+    fn complex_and_zero_roots() {
+        // >>> np.roots([0, 2, 0, 2, 0, 0])
+        // array([-0.+1.j, -0.-1.j,  0.+0.j,  0.+0.j])
+        let r = sorted(roots(&[0., 2., 0., 2., 0., 0.]));
+        let expected = [
+            Complex::new(0., -1.),
+            Complex::new(0., 0.),
+            Complex::new(0., 0.),
+            Complex::new(0., 1.),
+        ];
+        assert_eq!(r.len(), expected.len());
+        for (a, e) in r.iter().zip(expected) {
+            assert!((a - e).norm_sqr() < 1e-24, "{r:?}");
+        }
+
+        assert!(roots::<f64>(&[]).is_empty());
+        assert!(roots(&[0., 0.]).is_empty());
+        assert!(roots(&[3.]).is_empty());
+        assert_eq!(roots(&[2., -4.]), vec![Complex::new(2., 0.)]);
+    }
+
+    #[test]
+    fn poly_inverts_roots() {
+        // (2x^2 - 2x + 5)(3x^2 + x - 4), with a complex pair and two real roots.
+        let coeffs = [6., -4., 5., 13., -20.];
+        let c = poly(&roots(&coeffs)).unwrap();
+        assert_eq!(c.len(), coeffs.len());
+        for (a, e) in c.iter().zip(coeffs) {
+            assert!((a - e / coeffs[0]).abs() < 1e-12, "{c:?}");
+        }
+
+        assert_eq!(poly::<f64>(&[]).unwrap(), vec![1.]);
+        assert!(poly(&[Complex::new(1., 2.)]).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use ndarray::Array1;
+use num_traits::Float;
+
+/// Return evenly spaced numbers over a specified interval.
+///
+/// Returns `num` evenly spaced samples, calculated over the interval `[start, stop]`.
+///
+/// The endpoint of the interval can optionally be excluded.
+///
+/// # Parameters
+/// * `start` : float  
+///   The starting value of the sequence.
+/// * `stop` : float  
+///   The end value of the sequence, unless `endpoint` is set to false. In that case, the sequence
+///   consists of all but the last of `num + 1` evenly spaced samples, so that `stop` is excluded.
+/// * `num` : int  
+///   Number of samples to generate.
+/// * `endpoint` : bool  
+///   If true, `stop` is the last sample. Otherwise, it is not included.
+///
+/// # Returns
+/// * `samples` : ndarray  
+///   There are `num` equally spaced samples in the closed interval `[start, stop]` or the
+///   half-open interval `[start, stop)` (depending on whether `endpoint` is true or false).
+///
+/// # Examples
+/// ```
+/// use sci_rs_core::num_rs::linspace;
+///
+/// assert_eq!(linspace(2., 3., 5, true).to_vec(), [2., 2.25, 2.5, 2.75, 3.]);
+/// assert_eq!(linspace(2., 3., 5, false).to_vec(), [2., 2.2, 2.4, 2.6, 2.8]);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.linspace.html>
+pub fn linspace<F>(start: F, stop: F, num: usize, endpoint: bool) -> Array1<F>
+where
+    F: Float,
+{
+    let div = if endpoint { num.saturating_sub(1) } else { num };
+    if div == 0 {
+        return Array1::from_elem(num, start);
+    }
+    let step = (stop - start) / F::from(div).unwrap();
+    let mut y = Array1::from_shape_fn(num, |i| start + F::from(i).unwrap() * step);
+    if endpoint {
+        // Avoid rounding error in the last sample.
+        y[num - 1] = stop;
+    }
+    y
+}
+
+/// Return evenly spaced values within a given interval.
+///
+/// Values are generated within the half-open interval `[start, stop)`, the interval including
+/// `start` but excluding `stop`.
+///
+/// # Parameters
+/// * `start` : float  
+///   Start of interval. The interval includes this value.
+/// * `stop` : float  
+///   End of interval. The interval does not include this value, except in some cases where `step`
+///   is not an integer and floating point round-off affects the length of `out`.
+/// * `step` : float  
+///   Spacing between values, which may be negative.
+///
+/// # Returns
+/// * `arange` : ndarray  
+///   Array of evenly spaced values, of length `ceil((stop - start) / step)`, or empty if that is
+///   not positive.
+///
+/// # Panics
+/// If `step` is zero, as numpy raises `ZeroDivisionError`.
+///
+/// # Examples
+/// ```
+/// use sci_rs_core::num_rs::arange;
+///
+/// assert_eq!(arange(3., 7., 1.).to_vec(), [3., 4., 5., 6.]);
+/// assert_eq!(arange(1., 0., -0.25).to_vec(), [1., 0.75, 0.5, 0.25]);
+/// ```
+///
+/// <https://numpy.org/doc/stable/reference/generated/numpy.arange.html>
+pub fn arange<F>(start: F, stop: F, step: F) -> Array1<F>
+where
+    F: Float,
+{
+    assert!(!step.is_zero(), "Step of arange must not be zero");
+    let len = ((stop - start) / step).ceil().to_usize().unwrap_or(0);
+    Array1::from_shape_fn(len, |i| start + F::from(i).unwrap() * step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(y: &Array1<f64>, expected: &[f64]) {
+        assert_eq!(y.len(), expected.len());
+        for (a, e) in y.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-15, "{y:?}");
+        }
+    }
+
+    #[test]
+    fn linspace_matches_numpy() {
+        // >>> np.linspace(-1, 0.5, 7)
+        let y = linspace(-1f64, 0.5, 7, true);
+        let expected = [-1., -0.75, -0.5, -0.25, 0., 0.25, 0.5];
+        assert_close(&y, &expected);
+
+        // >>> np.linspace(0, 1, 3, endpoint=False)
+        let y = linspace(0f64, 1., 3, false);
+        let expected = [0., 1. / 3., 2. / 3.];
+        assert_close(&y, &expected);
+
+        // Decreasing, and the endpoint is exact.
+        // >>> np.linspace(1, 0.1, 4)
+        let y = linspace(1f64, 0.1, 4, true);
+        assert_close(&y, &[1., 0.7, 0.4, 0.1]);
+        assert_eq!(y[3], 0.1);
+
+        // >>> np.linspace(2, 3, 1), np.linspace(2, 3, 1, endpoint=False), np.linspace(2, 3, 0)
+        assert_eq!(linspace(2f64, 3., 1, true).to_vec(), [2.]);
+        assert_eq!(linspace(2f64, 3., 1, false).to_vec(), [2.]);
+        assert!(linspace(2f64, 3., 0, true).is_empty());
+        assert!(linspace(2f64, 3., 0, false).is_empty());
+    }
+
+    #[test]
+    fn arange_matches_numpy() {
+        // >>> np.arange(0, 1, 0.3)
+        let y = arange(0f64, 1., 0.3);
+        let expected = [0., 0.3, 0.6, 0.9];
+        assert_close(&y, &expected);
+
+        // Length is from the ceiling, so a step landing exactly on stop excludes it.
+        // >>> np.arange(0, 2, 0.5)
+        assert_eq!(arange(0f64, 2., 0.5).to_vec(), [0., 0.5, 1., 1.5]);
+
+        // >>> np.arange(5, 1, -1.5)
+        assert_eq!(arange(5f64, 1., -1.5).to_vec(), [5., 3.5, 2.]);
+
+        // >>> np.arange(1, 0, 1), np.arange(0, 1, -1)
+        assert!(arange(1f64, 0., 1.).is_empty());
+        assert!(arange(0f64, 1., -1.).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn arange_zero_step() {
+        arange(0f64, 1., 0.);
+    }
+}
@@ -46,11 +46,7 @@ fn randomized_signal(
 
     let mut result: Array1<f64> = Array1::zeros((n,)) + global_shift;
 
-    for ((a, freq), p) in ampl
-        .into_iter()
-        .zip(freqs.into_iter())
-        .zip(phases.into_iter())
-    {
+    for ((a, freq), p) in ampl.into_iter().zip(freqs).zip(phases) {
         let wave = t.mapv(|ti| a * (freq * ti + p).sin());
         result += &wave;
     }
@@ -46,11 +46,7 @@ fn randomized_signal(
 
     let mut result: Array1<f64> = Array1::zeros((n,)) + global_shift;
 
-    for ((a, freq), p) in ampl
-        .into_iter()
-        .zip(freqs.into_iter())
-        .zip(phases.into_iter())
-    {
+    for ((a, freq), p) in ampl.into_iter().zip(freqs).zip(phases) {
         let wave = t.mapv(|ti| a * (freq * ti + p).sin());
         result += &wave;
     }
@@ -104,5 +100,49 @@ fn lfilter_dyn(c: &mut Criterion) {
     );
 }
 
-criterion_group!(benches, lfilter_dyn);
+/// Long FIR filter, where the convolution in `lfilter` switches from the direct to the FFT method.
+///
+/// On a 10000-sample signal with a 500-tap filter, this took ~3.9 ms with the direct method and
+/// takes ~1.0 ms with the FFT method.
+fn lfilter_long_fir(c: &mut Criterion) {
+    const NUMTAPS: usize = 500;
+
+    let b: Array1<f64> = firwin_dyn(
+        NUMTAPS,
+        &[0.1],
+        None,
+        None::<&Hamming>,
+        &FilterBandType::Lowpass,
+        None,
+        None,
+    )
+    .unwrap()
+    .into();
+    let a = array![1.];
+
+    let (_, signal) = randomized_signal(
+        rand::rng(),
+        NonZeroUsize::new(14).unwrap(),
+        NonZeroUsize::new(10_000).unwrap(),
+        15.,
+    );
+
+    c.bench_with_input(
+        BenchmarkId::new("lfilter_long_fir", NUMTAPS),
+        &signal,
+        |bench, sig| {
+            bench.iter(|| {
+                ArrayView1::lfilter(
+                    black_box((&b).into()),
+                    black_box((&a).into()),
+                    black_box((sig).into()),
+                    None,
+                    None,
+                )
+            })
+        },
+    );
+}
+
+criterion_group!(benches, lfilter_dyn, lfilter_long_fir);
 criterion_main!(benches);
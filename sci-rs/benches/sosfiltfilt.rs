@@ -5,7 +5,7 @@ use sci_rs::signal::filter::{
     sosfiltfilt_dyn,
 };
 
-/// TLDR: 4.6x faster
+// TLDR: 4.6x faster
 
 ///
 /// 4th order Butterworth Bandpass Sosfilt 10 seconds of 1666Hz sine wave
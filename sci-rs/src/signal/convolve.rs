@@ -1,8 +1,13 @@
 use nalgebra::Complex;
+use ndarray::{
+    s, Array, Array2, ArrayBase, ArrayView, ArrayView1, Axis, Data, Dimension, Ix2, Slice,
+};
 use num_traits::{Float, FromPrimitive, Signed, Zero};
 use rustfft::{FftNum, FftPlanner};
+use sci_rs_core::num_rs::{pad, PadMode};
+use sci_rs_core::{Error, Result};
 
-pub use sci_rs_core::num_rs::ConvolveMode;
+pub use sci_rs_core::num_rs::{ConvolveMode, PaddingMode};
 
 /// Performs FFT-based convolution on two slices of floating point values.
 ///
@@ -66,11 +71,9 @@ pub fn fftconvolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode)
     match mode {
         ConvolveMode::Full => full_convolution,
         ConvolveMode::Valid => {
-            if n1 >= n2 {
-                full_convolution[(n2 - 1)..(n1)].to_vec()
-            } else {
-                Vec::new()
-            }
+            // Convolution commutes, so the shorter input always slides over the longer
+            let (lo, hi) = (n1.min(n2), n1.max(n2));
+            full_convolution[(lo - 1)..hi].to_vec()
         }
         ConvolveMode::Same => {
             let start = (n2 - 1) / 2;
@@ -80,11 +83,454 @@ pub fn fftconvolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode)
     }
 }
 
+/// Performs FFT-based convolution on two slices using the overlap-add method.
+///
+/// The longer input is split into blocks, each of which is convolved with the shorter input by
+/// FFTs of a fixed block size, and the results are added where they overlap. This is
+/// `scipy.signal.oaconvolve`, and is faster than [fftconvolve] when one input is much longer than
+/// the other, as for a long signal and a moderate FIR kernel. When the inputs are of similar
+/// length, it falls back to [fftconvolve].
+///
+/// The block size is chosen as scipy does, minimizing the FFT work per output sample.
+///
+/// # Arguments
+/// - `in1`: First input signal
+/// - `in2`: Second input signal
+/// - `mode`: Convolution mode
+///
+/// # Returns
+/// A Vec containing the discrete linear convolution of `in1` with `in2`, equal to the output of
+/// [fftconvolve] up to rounding.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::convolve::{fftconvolve, oaconvolve, ConvolveMode};
+///
+/// let x: Vec<f64> = (0..1000).map(|i| (0.1 * i as f64).sin()).collect();
+/// let h = [0.25; 16];
+/// let y = oaconvolve(&x, &h, ConvolveMode::Same);
+/// for (a, b) in y.iter().zip(fftconvolve(&x, &h, ConvolveMode::Same)) {
+///     assert!((a - b).abs() < 1e-12);
+/// }
+/// ```
+pub fn oaconvolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) -> Vec<F> {
+    let (n1, n2) = (in1.len(), in2.len());
+    if n1 == 0 || n2 == 0 {
+        return Vec::new();
+    }
+    // Convolution commutes, so the shorter input is always the one held whole
+    let (long, short) = if n1 >= n2 { (in1, in2) } else { (in2, in1) };
+    let Some(block_size) = oa_block_size(long.len(), short.len()) else {
+        return fftconvolve(in1, in2, mode);
+    };
+    let step = block_size - short.len() + 1;
+
+    let mut planner = FftPlanner::new();
+    let fft = planner.plan_fft_forward(block_size);
+    let ifft = planner.plan_fft_inverse(block_size);
+
+    let mut short_freq = vec![Complex::zero(); block_size];
+    short_freq
+        .iter_mut()
+        .zip(short)
+        .for_each(|(p, &v)| *p = Complex::new(v, F::zero()));
+    fft.process(&mut short_freq);
+
+    // Convolve each block and add it into the full convolution over its overlap with the next
+    let n = n1 + n2 - 1;
+    let scale = F::from(block_size).unwrap();
+    let mut full_convolution = vec![F::zero(); n];
+    let mut block = vec![Complex::zero(); block_size];
+    for (i, chunk) in long.chunks(step).enumerate() {
+        block.iter_mut().for_each(|b| *b = Complex::zero());
+        block
+            .iter_mut()
+            .zip(chunk)
+            .for_each(|(b, &v)| *b = Complex::new(v, F::zero()));
+        fft.process(&mut block);
+        block
+            .iter_mut()
+            .zip(&short_freq)
+            .for_each(|(b, s)| *b = *b * s);
+        ifft.process(&mut block);
+
+        let start = i * step;
+        full_convolution[start..]
+            .iter_mut()
+            .zip(&block[..chunk.len() + short.len() - 1])
+            .for_each(|(y, b)| *y = *y + b.re / scale);
+    }
+
+    // Extract the appropriate slice based on the mode, as fftconvolve
+    match mode {
+        ConvolveMode::Full => full_convolution,
+        ConvolveMode::Valid => {
+            let (lo, hi) = (n1.min(n2), n1.max(n2));
+            full_convolution[(lo - 1)..hi].to_vec()
+        }
+        ConvolveMode::Same => {
+            let start = (n2 - 1) / 2;
+            full_convolution[start..start + n1].to_vec()
+        }
+    }
+}
+
+/// The FFT block size for overlap-add convolution of inputs of lengths `long >= short`, or `None`
+/// if a single FFT of the whole convolution is at least as fast, as scipy's `_calc_oa_lens`.
+fn oa_block_size(long: usize, short: usize) -> Option<usize> {
+    if short == 1 || long == short || 2 * short >= long {
+        return None;
+    }
+    // Each block of size N yields N - overlap outputs for O(N log N) work, which is least at
+    // N = -overlap * W_{-1}(-1 / (2 e overlap)).
+    let overlap = (short - 1) as f64;
+    let opt_size = -overlap * lambert_w_m1(-1. / (2. * core::f64::consts::E * overlap));
+    let block_size = next_fast_len(opt_size.ceil() as usize);
+    (block_size < long).then_some(block_size)
+}
+
+/// The lower branch `W_{-1}` of the Lambert W function, for `-1/e < z < 0`.
+fn lambert_w_m1(z: f64) -> f64 {
+    // Start from the asymptotic expansion about 0 and refine by Newton's method
+    let l1 = (-z).ln();
+    let mut w = l1 - (-l1).ln();
+    for _ in 0..50 {
+        let ew = w.exp();
+        let step = (w * ew - z) / (ew * (w + 1.));
+        w -= step;
+        if step.abs() <= 1e-12 * w.abs() {
+            break;
+        }
+    }
+    w
+}
+
+/// The smallest length of at least `n` with no prime factors above 11, which rustfft and
+/// scipy's FFTs transform efficiently.
+fn next_fast_len(n: usize) -> usize {
+    (n.max(1)..)
+        .find(|&m| {
+            let m = [2, 3, 5, 7, 11].iter().fold(m, |mut m, &p| {
+                while m % p == 0 {
+                    m /= p;
+                }
+                m
+            });
+            m == 1
+        })
+        .unwrap()
+}
+
+/// Performs FFT-based convolution on two N-dimensional arrays.
+///
+/// This is the N-dimensional counterpart of [fftconvolve]: both inputs are zero-padded along
+/// every axis to (at least) `in1.shape() + in2.shape() - 1`, multiplied in the frequency domain
+/// and inverse-transformed.
+///
+/// # Arguments
+/// - `in1`: First input array
+/// - `in2`: Second input array, with the same dimensionality as `in1`
+/// - `mode`: Convolution mode
+///
+/// # Returns
+/// An array containing the discrete linear convolution of `in1` with `in2`.
+/// - Full mode: shape `in1.shape() + in2.shape() - 1`.
+/// - Same mode: shape `in1.shape()`, centered with respect to the full output.
+/// - Valid mode: shape `max - min + 1` per axis of the two input shapes. As with scipy, the
+///   inputs are swapped when `in2` is the larger one.
+///
+/// # Panics
+/// If the inputs differ in dimensionality, or in Valid mode when neither input is at least as
+/// large as the other along every axis.
+pub fn fftconvolve_nd<F, S1, S2, D>(
+    in1: &ArrayBase<S1, D>,
+    in2: &ArrayBase<S2, D>,
+    mode: ConvolveMode,
+) -> Array<F, D>
+where
+    F: Float + FftNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+    D: Dimension,
+{
+    assert_eq!(
+        in1.ndim(),
+        in2.ndim(),
+        "in1 and in2 should have the same dimensionality"
+    );
+    if in1.is_empty() || in2.is_empty() {
+        return Array::zeros(in1.raw_dim());
+    }
+    if mode == ConvolveMode::Valid {
+        let fits = |a: &[usize], b: &[usize]| a.iter().zip(b).all(|(m, n)| m >= n);
+        if !fits(in1.shape(), in2.shape()) {
+            assert!(
+                fits(in2.shape(), in1.shape()),
+                "for Valid mode, one input must be at least as large as the other in every dimension"
+            );
+            return fftconvolve_nd(in2, in1, mode);
+        }
+    }
+
+    let mut full_shape = in1.raw_dim();
+    let mut fft_shape = in1.raw_dim();
+    for ((full, fft), (&n1, &n2)) in full_shape
+        .slice_mut()
+        .iter_mut()
+        .zip(fft_shape.slice_mut().iter_mut())
+        .zip(in1.shape().iter().zip(in2.shape()))
+    {
+        *full = n1 + n2 - 1;
+        *fft = full.next_power_of_two();
+    }
+
+    // Zero-pad both inputs into complex buffers of the FFT shape
+    let pad = |x: ArrayView<F, D>| {
+        let mut padded = Array::from_elem(fft_shape.clone(), Complex::zero());
+        padded
+            .slice_each_axis_mut(|ax| Slice::from(0..x.len_of(ax.axis)))
+            .zip_mut_with(&x, |p, &v| *p = Complex::new(v, F::zero()));
+        padded
+    };
+    let mut padded_in1 = pad(in1.view());
+    let mut padded_in2 = pad(in2.view());
+
+    // Perform the FFT along every axis
+    let mut planner = FftPlanner::new();
+    fft_axes(&mut planner, &mut padded_in1, false);
+    fft_axes(&mut planner, &mut padded_in2, false);
+
+    // Multiply element-wise in the frequency domain and transform back
+    padded_in1.zip_mut_with(&padded_in2, |a, b| *a = *a * b);
+    fft_axes(&mut planner, &mut padded_in1, true);
+
+    // Take only the real part, normalize, and extract the slice for the requested mode
+    let scale = F::from(padded_in1.len()).unwrap();
+    padded_in1
+        .slice_each_axis(|ax| {
+            let i = ax.axis.index();
+            let (n1, n2) = (in1.shape()[i], in2.shape()[i]);
+            match mode {
+                ConvolveMode::Full => Slice::from(0..full_shape[i]),
+                ConvolveMode::Same => {
+                    let start = (n2 - 1) / 2;
+                    Slice::from(start..start + n1)
+                }
+                ConvolveMode::Valid => Slice::from((n2 - 1)..n1),
+            }
+        })
+        .mapv(|x| x.re / scale)
+}
+
+/// The values beyond the edges of the first input of [convolve2d], `boundary` in scipy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConvolveBoundary {
+    /// Pad with `fillvalue`.
+    #[default]
+    Fill,
+    /// Wrap around to the opposite edge, as a periodic signal.
+    Wrap,
+    /// Reflect about the edges, repeating the edge values.
+    Symm,
+}
+
+/// Convolve two 2-dimensional arrays, with the values beyond the edges of `in1` given by
+/// `boundary`.
+///
+/// This is `scipy.signal.convolve2d`, commonly applied to image-like data. With
+/// [ConvolveBoundary::Fill] and a `fillvalue` of zero, it is [fftconvolve_nd]. Otherwise, `in1`
+/// is padded as per `boundary` to cover the overhang of `in2` before being convolved.
+///
+/// # Arguments
+/// - `in1`: First input
+/// - `in2`: Second input
+/// - `mode`: Convolution mode, as [fftconvolve_nd]. [ConvolveMode::Valid] does not use the
+///   values beyond the edges, so ignores `boundary`.
+/// - `boundary`: How the values beyond the edges of `in1` are found
+/// - `fillvalue`: Value to pad `in1` with for [ConvolveBoundary::Fill]
+///
+/// # Returns
+/// An array containing a subset of the discrete linear convolution of `in1` with `in2`, with the
+/// shapes of [fftconvolve_nd].
+///
+/// # Panics
+/// In Valid mode, when neither input is at least as large as the other along both axes.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::convolve::{convolve2d, ConvolveBoundary, ConvolveMode};
+///
+/// // A box blur of a constant image is unchanged with symmetric boundaries.
+/// let image = array![[2f64, 2., 2.], [2., 2., 2.]];
+/// let kernel = array![[0.25, 0.25], [0.25, 0.25]];
+/// let y = convolve2d(&image, &kernel, ConvolveMode::Same, ConvolveBoundary::Symm, 0.);
+/// assert!(y.iter().all(|y| (y - 2.).abs() < 1e-12));
+/// ```
+pub fn convolve2d<F, S1, S2>(
+    in1: &ArrayBase<S1, Ix2>,
+    in2: &ArrayBase<S2, Ix2>,
+    mode: ConvolveMode,
+    boundary: ConvolveBoundary,
+    fillvalue: F,
+) -> Array2<F>
+where
+    F: Float + FftNum,
+    S1: Data<Elem = F>,
+    S2: Data<Elem = F>,
+{
+    let zero_fill = boundary == ConvolveBoundary::Fill && fillvalue == F::zero();
+    if zero_fill || mode == ConvolveMode::Valid || in1.is_empty() || in2.is_empty() {
+        return fftconvolve_nd(in1, in2, mode);
+    }
+
+    let pad_mode = match boundary {
+        ConvolveBoundary::Fill => PadMode::Constant,
+        ConvolveBoundary::Wrap => PadMode::Wrap,
+        ConvolveBoundary::Symm => PadMode::Symmetric,
+    };
+    let (m, n) = in2.dim();
+    let padded = pad(in1, &[(m - 1, m - 1), (n - 1, n - 1)], pad_mode, fillvalue)
+        .expect("a pair of widths for each non-empty axis");
+    let full = fftconvolve_nd(&padded, in2, ConvolveMode::Valid);
+    match mode {
+        ConvolveMode::Same => {
+            let (rows, cols) = in1.dim();
+            let (r, c) = ((m - 1) / 2, (n - 1) / 2);
+            full.slice(s![r..r + rows, c..c + cols]).to_owned()
+        }
+        _ => full,
+    }
+}
+
+/// Applies an in-place (inverse) FFT along every axis of `x`.
+fn fft_axes<F: FftNum, D: Dimension>(
+    planner: &mut FftPlanner<F>,
+    x: &mut Array<Complex<F>, D>,
+    inverse: bool,
+) {
+    for axis in 0..x.ndim() {
+        let len = x.len_of(Axis(axis));
+        let fft = if inverse {
+            planner.plan_fft_inverse(len)
+        } else {
+            planner.plan_fft_forward(len)
+        };
+        let mut buffer = vec![Complex::zero(); len];
+        for mut lane in x.lanes_mut(Axis(axis)) {
+            buffer
+                .iter_mut()
+                .zip(lane.iter())
+                .for_each(|(b, &v)| *b = v);
+            fft.process(&mut buffer);
+            lane.iter_mut().zip(&buffer).for_each(|(v, &b)| *v = b);
+        }
+    }
+}
+
+/// Performs direct (sum of products) convolution of two slices.
+///
+/// Unlike [fftconvolve], this is exact and so also applies to integer inputs, at the cost of
+/// `O(in1.len() * in2.len())` operations.
+///
+/// # Arguments
+/// - `in1`: First input signal
+/// - `in2`: Second input signal
+/// - `mode`: Convolution mode
+///
+/// # Returns
+/// A Vec containing the discrete linear convolution of `in1` with `in2`.
+/// - Full mode: length `in1.len() + in2.len() - 1`.
+/// - Same mode: length `in1.len()`, centered with respect to the full output.
+/// - Valid mode: length `max(in1.len(), in2.len()) - min(in1.len(), in2.len()) + 1`.
+pub fn convolve_direct<T>(in1: &[T], in2: &[T], mode: ConvolveMode) -> Vec<T>
+where
+    T: Copy + Zero + core::ops::Mul<Output = T>,
+{
+    let n1 = in1.len();
+    let n2 = in2.len();
+    if n1 == 0 || n2 == 0 {
+        return Vec::new();
+    }
+
+    let full = |k: usize| {
+        let lo = k.saturating_sub(n2 - 1);
+        let hi = k.min(n1 - 1);
+        (lo..=hi).fold(T::zero(), |acc, i| acc + in1[i] * in2[k - i])
+    };
+    let (start, len) = match mode {
+        ConvolveMode::Full => (0, n1 + n2 - 1),
+        ConvolveMode::Same => ((n2 - 1) / 2, n1),
+        ConvolveMode::Valid => (n1.min(n2) - 1, n1.max(n2) - n1.min(n2) + 1),
+    };
+    (start..start + len).map(full).collect()
+}
+
+/// Direct convolution of a signal with a short kernel of length fixed at compile time.
+///
+/// This is [convolve_direct] for FIR filters of a few taps, such as the 3, 5 or 7 tap smoothing
+/// and differencing filters applied to long signals in real time. With the kernel length known,
+/// the sum of products at each sample where the kernel fully overlaps the signal is unrolled and
+/// vectorized by the compiler, which is much faster than either [convolve_direct] or [convolve].
+/// The result is the same as [convolve_direct], to the bit.
+///
+/// # Arguments
+/// - `x`: Input signal
+/// - `kernel`: Filter taps
+/// - `mode`: Convolution mode
+///
+/// # Returns
+/// A Vec containing the discrete linear convolution of `x` with `kernel`, with the lengths of
+/// [convolve_direct].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::convolve::{convolve_fir, ConvolveMode};
+///
+/// let x = [1., 2., 3., 4., 5.];
+/// let y = convolve_fir(&x, [0.25, 0.5, 0.25], ConvolveMode::Same);
+/// assert_eq!(y, vec![1., 2., 3., 4., 3.5]);
+/// ```
+pub fn convolve_fir<T, const K: usize>(x: &[T], kernel: [T; K], mode: ConvolveMode) -> Vec<T>
+where
+    T: Copy + Zero + core::ops::Mul<Output = T>,
+{
+    let n = x.len();
+    if K == 0 || n < K {
+        return convolve_direct(x, &kernel, mode);
+    }
+
+    let edge = |k: usize| {
+        let lo = k.saturating_sub(K - 1);
+        let hi = k.min(n - 1);
+        (lo..=hi).fold(T::zero(), |acc, i| acc + x[i] * kernel[k - i])
+    };
+    let (start, len) = match mode {
+        ConvolveMode::Full => (0, n + K - 1),
+        ConvolveMode::Same => ((K - 1) / 2, n),
+        ConvolveMode::Valid => (K - 1, n - K + 1),
+    };
+
+    // Every mode covers all the outputs where the kernel fully overlaps `x`, at `K - 1..n`.
+    let reversed: [T; K] = core::array::from_fn(|j| kernel[K - 1 - j]);
+    let mut out = Vec::with_capacity(len);
+    out.extend((start..K - 1).map(edge));
+    out.extend(x.windows(K).map(|w| {
+        let w: &[T; K] = w.try_into().unwrap();
+        w.iter()
+            .zip(&reversed)
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }));
+    out.extend((n..start + len).map(edge));
+    out
+}
+
 /// Compute the convolution of two signals using FFT.
 ///
 /// # Arguments
 /// * `in1` - First input array
 /// * `in2` - Second input array
+/// * `mode` - Which part of the full convolution to return
 ///
 /// # Returns
 /// A Vec containing the convolution of `in1` with `in2`.
@@ -93,6 +539,258 @@ pub fn convolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) ->
     fftconvolve(in1, in2, mode)
 }
 
+/// Compute the convolution of two signals using FFT, with `in1` padded beyond its edges.
+///
+/// This is [convolve] with the values beyond the edges of `in1` given by `padding` rather than
+/// zeros, which [PaddingMode::Zeros] keeps.
+///
+/// # Arguments
+/// * `in1` - First input array
+/// * `in2` - Second input array
+/// * `mode` - Which part of the full convolution to return
+/// * `padding` - Values of `in1` beyond its edges
+///
+/// # Returns
+/// A Vec containing the convolution of the padded `in1` with `in2`, of the same length as that
+/// of [convolve]. In Valid mode with `in2` longer than `in1`, the padding fills the part of `in2`
+/// overhanging `in1`.
+///
+/// # Examples
+/// Padding with the edge value avoids the roll-off at the ends of a smoothed signal with an
+/// offset:
+/// ```
+/// use sci_rs::signal::convolve::{convolve_with_padding, ConvolveMode, PaddingMode};
+///
+/// let x = [10f64, 10., 11., 12.];
+/// let padding = PaddingMode::Const(10.);
+/// let smoothed = convolve_with_padding(&x, &[0.5, 0.5], ConvolveMode::Same, padding);
+/// assert!((smoothed[0] - 10.).abs() < 1e-12);
+/// ```
+pub fn convolve_with_padding<F: Float + FftNum>(
+    in1: &[F],
+    in2: &[F],
+    mode: ConvolveMode,
+    padding: PaddingMode<F>,
+) -> Vec<F> {
+    if padding != PaddingMode::Zeros && !in1.is_empty() && !in2.is_empty() {
+        let padded = pad_edges(in1, in2.len() - 1, padding);
+        let full = fftconvolve(&padded, in2, ConvolveMode::Valid);
+        return select_mode(full, in1.len(), in2.len(), mode);
+    }
+    fftconvolve(in1, in2, mode)
+}
+
+/// The method by which [convolve_method] and [correlate_method] compute their result, `method`
+/// in scipy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConvolveMethod {
+    /// Choose whichever of [ConvolveMethod::Direct] and [ConvolveMethod::Fft] is estimated to be
+    /// faster, by [choose_conv_method].
+    #[default]
+    Auto,
+    /// Sum of products, as [convolve_direct]. Fastest for short inputs and exact up to rounding.
+    Direct,
+    /// Multiplication in the frequency domain, as [fftconvolve]. Fastest for long inputs.
+    Fft,
+}
+
+/// Estimate whether direct or FFT convolution of signals of lengths `n1` and `n2` is faster.
+///
+/// This is scipy's `choose_conv_method` for 1-D floating point inputs, which weighs the number of
+/// multiplications of each method by constants scipy fitted to timings of each method.
+///
+/// # Returns
+/// [ConvolveMethod::Direct] or [ConvolveMethod::Fft], never [ConvolveMethod::Auto].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::convolve::{choose_conv_method, ConvolveMethod, ConvolveMode};
+///
+/// assert_eq!(choose_conv_method(1000, 5, ConvolveMode::Same), ConvolveMethod::Direct);
+/// assert_eq!(choose_conv_method(10000, 1000, ConvolveMode::Full), ConvolveMethod::Fft);
+/// ```
+pub fn choose_conv_method(n1: usize, n2: usize, mode: ConvolveMode) -> ConvolveMethod {
+    if n1 == 0 || n2 == 0 {
+        return ConvolveMethod::Direct;
+    }
+    let (s1, s2) = (n1 as f64, n2 as f64);
+    let direct_ops = match mode {
+        ConvolveMode::Full => s1 * s2,
+        ConvolveMode::Valid if n2 >= n1 => (s2 - s1 + 1.) * s1,
+        ConvolveMode::Valid => (s1 - s2 + 1.) * s2,
+        ConvolveMode::Same if n1 < n2 => s1 * s2,
+        ConvolveMode::Same => s1 * s2 - ((n2 / 2) * n2.div_ceil(2)) as f64,
+    };
+    // Three FFTs of the full output size
+    let full = s1 + s2 - 1.;
+    let fft_ops = 3. * full * full.ln();
+
+    let (o_fft, o_direct, o_offset) = match mode {
+        ConvolveMode::Valid => (1.89095737e-9, 2.1364985e-10, -1e-3),
+        ConvolveMode::Full => (1.7649070e-9, 2.1414831e-10, -1e-3),
+        ConvolveMode::Same if n2 <= n1 => (3.2646654e-9, 2.8478277e-10, -1e-3),
+        ConvolveMode::Same => (3.21635404e-9, 1.1773253e-8, -1e-5),
+    };
+    if o_fft * fft_ops < o_direct * direct_ops + o_offset {
+        ConvolveMethod::Fft
+    } else {
+        ConvolveMethod::Direct
+    }
+}
+
+/// Compute the convolution of two signals by the given method.
+///
+/// This is [convolve] with scipy's `method` argument: rather than always multiplying in the
+/// frequency domain, short kernels are convolved directly, which is both faster and exact up to
+/// rounding.
+///
+/// # Arguments
+/// * `in1` - First input array
+/// * `in2` - Second input array
+/// * `mode` - Which part of the full convolution to return
+/// * `method` - How to compute the convolution, [ConvolveMethod::Auto] to choose by
+///   [choose_conv_method].
+/// * `padding` - Values of `in1` beyond its edges, [PaddingMode::Zeros] for the same result as
+///   scipy.
+///
+/// # Returns
+/// A Vec containing the convolution of `in1` with `in2`, as [convolve].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::convolve::{convolve_method, ConvolveMethod, ConvolveMode, PaddingMode};
+///
+/// let x = [1f64, 2., 3.];
+/// let method = ConvolveMethod::Direct;
+/// let y = convolve_method(&x, &[4., 5., 6.], ConvolveMode::Full, method, PaddingMode::Zeros);
+/// assert_eq!(y, vec![4., 13., 28., 27., 18.]);
+/// ```
+pub fn convolve_method<F: Float + FftNum>(
+    in1: &[F],
+    in2: &[F],
+    mode: ConvolveMode,
+    method: ConvolveMethod,
+    padding: PaddingMode<F>,
+) -> Vec<F> {
+    if padding != PaddingMode::Zeros && !in1.is_empty() && !in2.is_empty() {
+        let padded = pad_edges(in1, in2.len() - 1, padding);
+        let full = convolve_method(
+            &padded,
+            in2,
+            ConvolveMode::Valid,
+            method,
+            PaddingMode::Zeros,
+        );
+        return select_mode(full, in1.len(), in2.len(), mode);
+    }
+
+    let method = match method {
+        ConvolveMethod::Auto => choose_conv_method(in1.len(), in2.len(), mode),
+        method => method,
+    };
+    match method {
+        ConvolveMethod::Direct => convolve_direct(in1, in2, mode),
+        _ => fftconvolve(in1, in2, mode),
+    }
+}
+
+/// Compute the cross-correlation of two signals by the given method.
+///
+/// This is [correlate] with scipy's `method` argument, as [convolve_method] is to [convolve].
+/// The normalized cross-correlation is always computed directly.
+///
+/// # Arguments
+/// * `in1` - First input array
+/// * `in2` - Second input array
+/// * `mode` - Which part of the full cross-correlation to return
+/// * `normalize` - Divide each lag by the product of the norms of the overlapping parts of `in1`
+///   and `in2`, as [correlate].
+/// * `method` - How to compute the cross-correlation, [ConvolveMethod::Auto] to choose by
+///   [choose_conv_method].
+/// * `padding` - Values of `in1` beyond its edges, [PaddingMode::Zeros] for the same result as
+///   scipy.
+///
+/// # Returns
+/// A Vec containing the cross-correlation of `in1` with `in2`, as [correlate].
+pub fn correlate_method<F: Float + FftNum>(
+    in1: &[F],
+    in2: &[F],
+    mode: ConvolveMode,
+    normalize: bool,
+    method: ConvolveMethod,
+    padding: PaddingMode<F>,
+) -> Vec<F> {
+    if normalize {
+        return correlate_with_padding(in1, in2, mode, normalize, padding);
+    }
+    let mut in2_rev = in2.to_vec();
+    in2_rev.reverse();
+    convolve_method(in1, &in2_rev, mode, method, padding)
+}
+
+/// The floating point type of an operation on `Self` and `Rhs`, as numpy's type promotion.
+///
+/// This is the wider of the two types, which can represent every value of both.
+pub trait Promote<Rhs = Self>: Sized {
+    /// The promoted type.
+    type Output: Float + FftNum + From<Self> + From<Rhs>;
+}
+
+macro_rules! promote {
+    ($($lhs:ty, $rhs:ty => $out:ty;)*) => {
+        $(
+            impl Promote<$rhs> for $lhs {
+                type Output = $out;
+            }
+        )*
+    };
+}
+
+promote! {
+    f32, f32 => f32;
+    f32, f64 => f64;
+    f64, f32 => f64;
+    f64, f64 => f64;
+}
+
+/// Compute the convolution of two signals of different floating point types using FFT.
+///
+/// Both inputs are promoted to the wider of their types, as by numpy, before being convolved by
+/// [convolve]. This avoids casting an `f32` signal to convolve it with an `f64` kernel, or vice
+/// versa.
+///
+/// # Arguments
+/// * `in1` - First input array
+/// * `in2` - Second input array
+/// * `mode` - Which part of the full convolution to return
+/// * `padding` - Values of `in1` beyond its edges, in the promoted type.
+///
+/// # Returns
+/// A Vec of the promoted type containing the convolution of `in1` with `in2`.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::convolve::{convolve_promoted, ConvolveMode, PaddingMode};
+///
+/// let x = [1f32, 2., 3.];
+/// let y: Vec<f64> = convolve_promoted(&x, &[0.5f64, 0.5], ConvolveMode::Full, PaddingMode::Zeros);
+/// assert_eq!(y.len(), 4);
+/// ```
+pub fn convolve_promoted<A, B>(
+    in1: &[A],
+    in2: &[B],
+    mode: ConvolveMode,
+    padding: PaddingMode<<A as Promote<B>>::Output>,
+) -> Vec<<A as Promote<B>>::Output>
+where
+    A: Promote<B> + Copy,
+    B: Copy,
+{
+    let in1: Vec<A::Output> = in1.iter().map(|&x| x.into()).collect();
+    let in2: Vec<A::Output> = in2.iter().map(|&x| x.into()).collect();
+    convolve_with_padding(&in1, &in2, mode, padding)
+}
+
 /// Compute the cross-correlation of two signals using FFT.
 ///
 /// Cross-correlation is similar to convolution but with flipping one of the signals.
@@ -101,15 +799,259 @@ pub fn convolve<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) ->
 /// # Arguments
 /// * `in1` - First input array
 /// * `in2` - Second input array
+/// * `mode` - Which part of the full cross-correlation to return
+/// * `normalize` - Divide each lag by the product of the norms of the overlapping parts of `in1`
+///   and `in2`, giving the normalized cross-correlation in `[-1, 1]`.
 ///
 /// # Returns
 /// A Vec containing the cross-correlation of `in1` with `in2`.
 /// With Full mode, the output length will be `in1.len() + in2.len() - 1`.
-pub fn correlate<F: Float + FftNum>(in1: &[F], in2: &[F], mode: ConvolveMode) -> Vec<F> {
+///
+/// # Notes
+/// Each lag of the normalized cross-correlation only covers the samples where `in1` and `in2`
+/// overlap, so the lags near the ends of [ConvolveMode::Full] and [ConvolveMode::Same] are
+/// normalized by shorter segments. Lags with zero energy in either segment are 0.
+///
+/// Such edge lags have small norms, which would amplify the rounding error of the FFT, so the
+/// normalized cross-correlation is computed directly. As a result, the autocorrelation at zero lag
+/// is exactly 1.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::convolve::{correlate, ConvolveMode};
+///
+/// let x: [f64; 4] = [1., -2., 3., 0.5];
+/// let autocorr = correlate(&x, &x, ConvolveMode::Full, true);
+/// assert_eq!(autocorr[3], 1.);
+/// assert!(autocorr.iter().all(|r| r.abs() <= 1.));
+/// ```
+pub fn correlate<F: Float + FftNum>(
+    in1: &[F],
+    in2: &[F],
+    mode: ConvolveMode,
+    normalize: bool,
+) -> Vec<F> {
     // For correlation, we need to reverse in2
     let mut in2_rev = in2.to_vec();
     in2_rev.reverse();
-    fftconvolve(in1, &in2_rev, mode)
+    if !normalize {
+        return fftconvolve(in1, &in2_rev, mode);
+    }
+
+    let n1 = in1.len();
+    let n2 = in2.len();
+    if n1 == 0 || n2 == 0 {
+        return Vec::new();
+    }
+
+    let normalized = |k: usize| {
+        let lo = k.saturating_sub(n2 - 1);
+        let hi = k.min(n1 - 1);
+        let (xy, xx, yy) = (lo..=hi).fold((F::zero(), F::zero(), F::zero()), |(xy, xx, yy), i| {
+            let (x, y) = (in1[i], in2_rev[k - i]);
+            (xy + x * y, xx + x * x, yy + y * y)
+        });
+        let norm = (xx * yy).sqrt();
+        if norm > F::zero() {
+            (xy / norm).max(-F::one()).min(F::one())
+        } else {
+            F::zero()
+        }
+    };
+    let (start, len) = match mode {
+        ConvolveMode::Full => (0, n1 + n2 - 1),
+        ConvolveMode::Same => ((n2 - 1) / 2, n1),
+        ConvolveMode::Valid => (n1.min(n2) - 1, n1.max(n2) - n1.min(n2) + 1),
+    };
+    (start..start + len).map(normalized).collect()
+}
+
+/// Compute the cross-correlation of two signals using FFT, with `in1` padded beyond its edges.
+///
+/// This is [correlate] with the values beyond the edges of `in1` given by `padding` rather than
+/// zeros, which [PaddingMode::Zeros] keeps.
+///
+/// # Arguments
+/// * `in1` - First input array
+/// * `in2` - Second input array
+/// * `mode` - Which part of the full cross-correlation to return
+/// * `normalize` - Divide each lag by the product of the norms of the overlapping parts of the
+///   padded `in1` and `in2`, as [correlate].
+/// * `padding` - Values of `in1` beyond its edges
+///
+/// # Returns
+/// A Vec containing the cross-correlation of the padded `in1` with `in2`, of the same length as
+/// that of [correlate].
+///
+/// # Notes
+/// Unless `padding` is [PaddingMode::Zeros], the padded `in1` overlaps all of `in2` at every lag,
+/// so the normalized lags near the ends are not of shorter segments.
+pub fn correlate_with_padding<F: Float + FftNum>(
+    in1: &[F],
+    in2: &[F],
+    mode: ConvolveMode,
+    normalize: bool,
+    padding: PaddingMode<F>,
+) -> Vec<F> {
+    if padding != PaddingMode::Zeros && !in1.is_empty() && !in2.is_empty() {
+        let padded = pad_edges(in1, in2.len() - 1, padding);
+        let full = correlate(&padded, in2, ConvolveMode::Valid, normalize);
+        return select_mode(full, in1.len(), in2.len(), mode);
+    }
+    correlate(in1, in2, mode, normalize)
+}
+
+/// Calculates the lag of each sample of the cross-correlation of two signals.
+///
+/// This is `scipy.signal.correlation_lags`: `lags[i]` is the lag, in samples, of `in2` relative
+/// to `in1` at the `i`-th sample of the output of [correlate] with the same `mode`. The lag of
+/// the peak cross-correlation is then the lag at the index of the peak.
+///
+/// As in scipy, with [ConvolveMode::Same], an odd `in1_len` and an even `in2_len`, the lags are
+/// one greater than those of the samples of [correlate].
+///
+/// # Arguments
+/// * `in1_len` - Length of the first input
+/// * `in2_len` - Length of the second input
+/// * `mode` - Which part of the full cross-correlation the lags are of
+///
+/// # Returns
+/// A Vec of the lags, with the length of the output of [correlate].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::convolve::{correlate, correlation_lags, ConvolveMode};
+///
+/// // `y` is `x` delayed by two samples.
+/// let x = [0f64, 1., 3., -2., 0.5, 0., 0., 0.];
+/// let y = [0f64, 0., 0., 1., 3., -2., 0.5, 0.];
+/// let corr = correlate(&y, &x, ConvolveMode::Full, false);
+/// let lags = correlation_lags(y.len(), x.len(), ConvolveMode::Full);
+/// let peak = (0..corr.len()).max_by(|&i, &j| corr[i].total_cmp(&corr[j])).unwrap();
+/// assert_eq!(lags[peak], 2);
+/// ```
+pub fn correlation_lags(in1_len: usize, in2_len: usize, mode: ConvolveMode) -> Vec<isize> {
+    let (n1, n2) = (in1_len as isize, in2_len as isize);
+    match mode {
+        ConvolveMode::Full => (1 - n2..n1).collect(),
+        ConvolveMode::Same => {
+            // Centered on the middle of the full lags, as scipy
+            let full = 1 - n2..n1;
+            let mid = (n1 + n2 - 1) / 2;
+            let bound = n1 / 2;
+            let start = full.start + mid - bound;
+            let len = if n1 % 2 == 0 {
+                2 * bound
+            } else {
+                2 * bound + 1
+            };
+            (start..start + len).collect()
+        }
+        ConvolveMode::Valid if n1 >= n2 => (0..=n1 - n2).collect(),
+        ConvolveMode::Valid => (n1 - n2..=0).collect(),
+    }
+}
+
+/// Extend `x` by `width` samples on either side, as per `padding`.
+fn pad_edges<F: Float>(x: &[F], width: usize, padding: PaddingMode<F>) -> Vec<F> {
+    let (mode, cval) = match padding {
+        PaddingMode::Zeros => (PadMode::Constant, F::zero()),
+        PaddingMode::Const(c) => (PadMode::Constant, c),
+        PaddingMode::Reflect => (PadMode::Reflect, F::zero()),
+        PaddingMode::Replicate => (PadMode::Edge, F::zero()),
+    };
+    pad(&ArrayView1::from(x), &[(width, width)], mode, cval)
+        .expect("x is not empty")
+        .into_raw_vec_and_offset()
+        .0
+}
+
+/// Select the `mode` part of a full convolution of inputs of lengths `n1` and `n2`.
+fn select_mode<F>(full: Vec<F>, n1: usize, n2: usize, mode: ConvolveMode) -> Vec<F> {
+    match mode {
+        ConvolveMode::Full => full,
+        ConvolveMode::Same => {
+            let start = (n2 - 1) / 2;
+            full.into_iter().skip(start).take(n1).collect()
+        }
+        ConvolveMode::Valid => {
+            let (short, long) = (n1.min(n2), n1.max(n2));
+            full.into_iter()
+                .skip(short - 1)
+                .take(long - short + 1)
+                .collect()
+        }
+    }
+}
+
+/// Estimate the peak memory allocated by [fftconvolve_nd], in bytes.
+///
+/// FFT convolution zero-pads both inputs to a common transform shape, which may be much larger
+/// than either input. The estimate can be used to choose between FFT and direct convolution, or to
+/// decide to process the data in chunks, before committing to the allocation.
+///
+/// The shapes are given per axis and must have the same number of dimensions. Each axis is padded
+/// to the next power of two of its full convolution length, and the estimate counts the two padded
+/// complex inputs, whose product is formed in place, and the real full and `mode` outputs. FFT plans
+/// and per-lane scratch are comparatively small and are left out.
+///
+/// # Arguments
+/// - `in1_shape`: Shape of the first input
+/// - `in2_shape`: Shape of the second input
+/// - `mode`: Convolution mode, which determines the size of the final output
+///
+/// # Returns
+/// The approximate peak allocation in bytes for inputs of element type `F`.
+///
+/// # Errors
+/// [Error::ShapeMismatch] if the shapes differ in dimensionality, or in Valid mode when neither
+/// input is at least as large as the other along every axis, as [fftconvolve_nd] would panic.
+pub fn fftconvolve_mem_estimate<F: Float>(
+    in1_shape: &[usize],
+    in2_shape: &[usize],
+    mode: ConvolveMode,
+) -> Result<usize> {
+    if in1_shape.len() != in2_shape.len() {
+        return Err(Error::ShapeMismatch {
+            reason: "in1 and in2 should have the same dimensionality.".into(),
+        });
+    }
+    let fits = |a: &[usize], b: &[usize]| a.iter().zip(b).all(|(m, n)| m >= n);
+    if mode == ConvolveMode::Valid && !fits(in1_shape, in2_shape) && !fits(in2_shape, in1_shape) {
+        return Err(Error::ShapeMismatch {
+            reason: "For Valid mode, one input must be at least as large as the other in every \
+                     dimension."
+                .into(),
+        });
+    }
+    if in1_shape.iter().chain(in2_shape).any(|&n| n == 0) {
+        return Ok(0);
+    }
+
+    let (fft_len, full_len, out_len) = in1_shape.iter().zip(in2_shape).fold(
+        (1usize, 1usize, 1usize),
+        |(fft_len, full_len, out_len), (&n1, &n2)| {
+            let full = n1 + n2 - 1;
+            let out = match mode {
+                ConvolveMode::Full => full,
+                ConvolveMode::Same => n1,
+                // The inputs are swapped if in2 is the larger one.
+                ConvolveMode::Valid => n1.max(n2) - n1.min(n2) + 1,
+            };
+            (
+                fft_len * full.next_power_of_two(),
+                full_len * full,
+                out_len * out,
+            )
+        },
+    );
+
+    // Padded in1 and padded in2, the product overwriting padded in1.
+    let complex_buffers = 2;
+    Ok(
+        complex_buffers * fft_len * core::mem::size_of::<Complex<F>>()
+            + (full_len + out_len) * core::mem::size_of::<F>(),
+    )
 }
 
 #[cfg(test)]
@@ -122,19 +1064,376 @@ mod tests {
         let in1 = vec![1.0, 2.0, 3.0];
         let in2 = vec![4.0, 5.0, 6.0];
         let result = convolve(&in1, &in2, ConvolveMode::Full);
-        let expected = vec![4.0, 13.0, 28.0, 27.0, 18.0];
+        let expected = [4.0, 13.0, 28.0, 27.0, 18.0];
+
+        for (a, b) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_fftconvolve_nd_full() {
+        let in1 = ndarray::arr1(&[1.0, 2.0, 3.0]);
+        let in2 = ndarray::arr1(&[4.0, 5.0, 6.0]);
+        let result = fftconvolve_nd(&in1, &in2, ConvolveMode::Full);
+        let expected = [4.0, 13.0, 28.0, 27.0, 18.0];
+        assert_eq!(result.len(), expected.len());
+        for (a, b) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
 
+        // scipy.signal.fftconvolve(in1, in2, mode="full")
+        let in1 = ndarray::array![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.], [0., -1., 2.]];
+        let in2 = ndarray::array![[1., -1.], [2., 0.], [0.5, 1.]];
+        let result = fftconvolve_nd(&in1, &in2, ConvolveMode::Full);
+        let expected = ndarray::array![
+            [1., 1., 1., -3.],
+            [6., 5., 7., -6.],
+            [15.5, 13., 16.5, -6.],
+            [16., 21.5, 29., 4.],
+            [3.5, 9., 16.5, 9.],
+            [0., -0.5, 0., 2.]
+        ];
+        assert_eq!(result.shape(), expected.shape());
         for (a, b) in result.iter().zip(expected.iter()) {
             assert_relative_eq!(a, b, epsilon = 1e-10);
         }
+
+        // scipy.signal.fftconvolve(in1, in2, mode="same")
+        let result = fftconvolve_nd(&in1, &in2, ConvolveMode::Same);
+        let expected = ndarray::array![
+            [6., 5., 7.],
+            [15.5, 13., 16.5],
+            [16., 21.5, 29.],
+            [3.5, 9., 16.5]
+        ];
+        assert_eq!(result.shape(), expected.shape());
+        for (a, b) in result.iter().zip(expected.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_fftconvolve_valid() {
+        // Matches the direct path, including when in2 is the longer input.
+        let in1 = [1.0, 2.0, 3.0, 4.0];
+        let in2 = [1.0, 2.0];
+        let expected = [4.0, 7.0, 10.0];
+        for result in [
+            fftconvolve(&in1, &in2, ConvolveMode::Valid),
+            fftconvolve(&in2, &in1, ConvolveMode::Valid),
+            fftconvolve_nd(
+                &ndarray::arr1(&in2),
+                &ndarray::arr1(&in1),
+                ConvolveMode::Valid,
+            )
+            .to_vec(),
+        ] {
+            assert_eq!(result.len(), expected.len());
+            for (a, b) in result.iter().zip(expected.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-10);
+            }
+        }
+
+        // scipy.signal.fftconvolve(in1, in2, mode="valid")
+        let in1 = ndarray::array![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.], [0., -1., 2.]];
+        let in2 = ndarray::array![[1., -1.], [2., 0.], [0.5, 1.]];
+        let expected = ndarray::array![[13., 16.5], [21.5, 29.]];
+        for result in [
+            fftconvolve_nd(&in1, &in2, ConvolveMode::Valid),
+            fftconvolve_nd(&in2, &in1, ConvolveMode::Valid),
+        ] {
+            assert_eq!(result.shape(), expected.shape());
+            for (a, b) in result.iter().zip(expected.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fftconvolve_valid_no_larger_input() {
+        let in1 = ndarray::Array2::<f64>::ones((4, 2));
+        let in2 = ndarray::Array2::<f64>::ones((2, 4));
+        fftconvolve_nd(&in1, &in2, ConvolveMode::Valid);
+    }
+
+    #[test]
+    fn test_convolve_method() {
+        let x: Vec<f64> = (0..40).map(|i| (0.4 * i as f64).sin() + 0.5).collect();
+        let cases: [(&[f64], &[f64]); 5] = [
+            (&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]),
+            (&[1.0, 2.0, 3.0, 4.0], &[1.0, 2.0]),
+            (&[1.0, 2.0, 3.0, 4.0], &[1.0, 2.0, 1.0]),
+            (&[1.0, 2.0], &[1.0, 2.0, 3.0, 4.0]),
+            (&x, &[0.1, 0.2, 0.4, 0.2, 0.1]),
+        ];
+        for (in1, in2) in cases {
+            for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+                for padding in [
+                    PaddingMode::Zeros,
+                    PaddingMode::Const(2.),
+                    PaddingMode::Reflect,
+                ] {
+                    let fft = convolve_method(in1, in2, mode, ConvolveMethod::Fft, padding);
+                    assert_eq!(fft, convolve_with_padding(in1, in2, mode, padding));
+                    for method in [ConvolveMethod::Direct, ConvolveMethod::Auto] {
+                        let result = convolve_method(in1, in2, mode, method, padding);
+                        assert_eq!(result.len(), fft.len());
+                        for (a, b) in result.iter().zip(fft.iter()) {
+                            assert_relative_eq!(a, b, epsilon = 1e-10);
+                        }
+                    }
+
+                    let fft = correlate_with_padding(in1, in2, mode, false, padding);
+                    for method in [ConvolveMethod::Direct, ConvolveMethod::Fft] {
+                        let result = correlate_method(in1, in2, mode, false, method, padding);
+                        assert_eq!(result.len(), fft.len());
+                        for (a, b) in result.iter().zip(fft.iter()) {
+                            assert_relative_eq!(a, b, epsilon = 1e-10);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Short kernels are convolved directly, exactly.
+        let direct = convolve_method(
+            &[1.0, 2.0, 3.0],
+            &[4.0, 5.0, 6.0],
+            ConvolveMode::Full,
+            ConvolveMethod::Auto,
+            PaddingMode::Zeros,
+        );
+        assert_eq!(direct, vec![4.0, 13.0, 28.0, 27.0, 18.0]);
+    }
+
+    #[test]
+    fn test_choose_conv_method() {
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            assert_eq!(choose_conv_method(3, 3, mode), ConvolveMethod::Direct);
+            assert_eq!(choose_conv_method(100_000, 5, mode), ConvolveMethod::Direct);
+            assert_eq!(choose_conv_method(100_000, 5000, mode), ConvolveMethod::Fft);
+        }
+        assert_eq!(
+            choose_conv_method(0, 5000, ConvolveMode::Full),
+            ConvolveMethod::Direct
+        );
+    }
+
+    #[test]
+    fn test_convolve_fir() {
+        let x: Vec<f64> = (0..200)
+            .map(|i| (0.05 * i as f64).sin() + 0.3 * (1.3 * i as f64).cos())
+            .collect();
+        let k3 = [0.25, 0.5, 0.25];
+        let k5 = [0.1, 0.2, 0.4, 0.2, 0.1];
+        let k7 = [-1., 0.5, 2., 3., 2., 0.5, -1.];
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            // Bit for bit the direct method, and the FFT method up to rounding, including for
+            // signals no longer than the kernel.
+            for (x, fir, kernel) in [
+                (&x[..], convolve_fir(&x, k3, mode), &k3[..]),
+                (&x[..], convolve_fir(&x, k5, mode), &k5[..]),
+                (&x[..], convolve_fir(&x, k7, mode), &k7[..]),
+                (&x[..5], convolve_fir(&x[..5], k5, mode), &k5[..]),
+                (&x[..4], convolve_fir(&x[..4], k5, mode), &k5[..]),
+            ] {
+                assert_eq!(fir, convolve_direct(x, kernel, mode));
+                let fft = convolve(x, kernel, mode);
+                assert_eq!(fir.len(), fft.len());
+                for (a, b) in fir.iter().zip(fft.iter()) {
+                    assert_relative_eq!(a, b, epsilon = 1e-10);
+                }
+            }
+        }
+
+        // Integer kernels, as convolve_direct.
+        assert_eq!(
+            convolve_fir(&[1, 2, 3, 4, 5], [1, 2, 3], ConvolveMode::Same),
+            vec![4, 10, 16, 22, 22]
+        );
+    }
+
+    #[test]
+    fn test_oaconvolve() {
+        let x: Vec<f64> = (0..5000)
+            .map(|i| (0.013 * i as f64).sin() + 0.2 * (0.9 * i as f64).cos())
+            .collect();
+        let h: Vec<f64> = (0..64).map(|i| (0.2 * i as f64 - 6.3).sin()).collect();
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            for (in1, in2) in [(&x, &h), (&h, &x)] {
+                let result = oaconvolve(in1, in2, mode);
+                let expected = convolve(in1, in2, mode);
+                assert_eq!(result.len(), expected.len());
+                for (a, b) in result.iter().zip(expected.iter()) {
+                    assert_relative_eq!(a, b, epsilon = 1e-9);
+                }
+            }
+        }
+
+        // Inputs of similar length fall back to a single FFT.
+        assert_eq!(oa_block_size(5000, 64), Some(500));
+        assert_eq!(oa_block_size(100, 60), None);
+        assert_eq!(oa_block_size(100, 1), None);
+        let result = oaconvolve(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], ConvolveMode::Full);
+        for (a, b) in result.iter().zip([4.0, 13.0, 28.0, 27.0, 18.0].iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_oa_block_size() {
+        // >>> from scipy.signal._signaltools import _calc_oa_lens
+        // >>> [_calc_oa_lens(s1, s2)[0] for s1, s2 in [(5000, 3), (100000, 100), (100000, 1000)]]
+        // [8, 840, 11000]
+        assert_eq!(oa_block_size(5000, 3), Some(8));
+        assert_eq!(oa_block_size(100_000, 100), Some(840));
+        assert_eq!(oa_block_size(100_000, 1000), Some(11000));
+        assert_eq!(oa_block_size(10_000, 1000), None);
+        assert_relative_eq!(
+            lambert_w_m1(-1. / (2. * core::f64::consts::E * 63.)),
+            -497.926818668216 / 63.,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_correlation_lags() {
+        // >>> correlation_lags(5, 3, mode)
+        assert_eq!(
+            correlation_lags(5, 3, ConvolveMode::Full),
+            vec![-2, -1, 0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            correlation_lags(5, 3, ConvolveMode::Same),
+            vec![-1, 0, 1, 2, 3]
+        );
+        assert_eq!(correlation_lags(5, 3, ConvolveMode::Valid), vec![0, 1, 2]);
+        // >>> correlation_lags(3, 5, mode)
+        assert_eq!(
+            correlation_lags(3, 5, ConvolveMode::Full),
+            vec![-4, -3, -2, -1, 0, 1, 2]
+        );
+        assert_eq!(correlation_lags(3, 5, ConvolveMode::Same), vec![-2, -1, 0]);
+        assert_eq!(correlation_lags(3, 5, ConvolveMode::Valid), vec![-2, -1, 0]);
+        // >>> correlation_lags(4, 3, 'same'), correlation_lags(5, 2, 'same')
+        assert_eq!(
+            correlation_lags(4, 3, ConvolveMode::Same),
+            vec![-1, 0, 1, 2]
+        );
+        assert_eq!(
+            correlation_lags(5, 2, ConvolveMode::Same),
+            vec![0, 1, 2, 3, 4]
+        );
+
+        // There is a lag for each sample of the cross-correlation.
+        for (n1, n2) in [(5, 3), (3, 5), (4, 4), (7, 2), (1, 6)] {
+            let (x, y) = (vec![1.; n1], vec![1.; n2]);
+            for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+                let corr = correlate(&x, &y, mode, false);
+                assert_eq!(correlation_lags(n1, n2, mode).len(), corr.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_convolve2d() {
+        let in1 = ndarray::array![
+            [1., 2., 0., 3., 1.],
+            [4., -1., 2., 2., 0.],
+            [0., 3., 5., 1., 2.],
+            [2., 1., 0., -2., 4.]
+        ];
+        let in2 = ndarray::array![[1., 0., -1.], [2., 1., 0.], [0., 1., 3.]];
+        let cases = [
+            // >>> convolve2d(in1, in2, 'full', boundary='fill')
+            (
+                ConvolveMode::Full,
+                ConvolveBoundary::Fill,
+                0.,
+                ndarray::array![
+                    [1., 2., -1., 1., 1., -3., -1.],
+                    [6., 4., 0., 9., 3., -1., 0.],
+                    [8., 6., 13., 10., 2., 9., 1.],
+                    [2., 11., 22., 3., 17., 10., -4.],
+                    [4., 4., 4., 10., 22., 9., 6.],
+                    [0., 2., 7., 3., -2., -2., 12.]
+                ],
+            ),
+            // >>> convolve2d(in1, in2, 'same', boundary='fill', fillvalue=2)
+            (
+                ConvolveMode::Same,
+                ConvolveBoundary::Fill,
+                2.,
+                ndarray::array![
+                    [10., 8., 17., 11., 13.],
+                    [10., 13., 10., 2., 15.],
+                    [15., 22., 3., 17., 16.],
+                    [10., 4., 10., 22., 13.]
+                ],
+            ),
+            // >>> convolve2d(in1, in2, 'full', boundary='symm')
+            (
+                ConvolveMode::Full,
+                ConvolveBoundary::Symm,
+                0.,
+                ndarray::array![
+                    [3., 22., 12., 6., 14., 7., 9.],
+                    [15., 4., 5., 15., 6., 11., 13.],
+                    [16., 9., 13., 10., 2., 11., 7.],
+                    [2., 21., 22., 3., 17., 18., -2.],
+                    [16., 3., 2., 7., 26., 23., 2.],
+                    [8., 15., 13., -3., 1., 11., 15.]
+                ],
+            ),
+            // >>> convolve2d(in1, in2, 'same', boundary='symm')
+            (
+                ConvolveMode::Same,
+                ConvolveBoundary::Symm,
+                0.,
+                ndarray::array![
+                    [4., 5., 15., 6., 11.],
+                    [9., 13., 10., 2., 11.],
+                    [21., 22., 3., 17., 18.],
+                    [3., 2., 7., 26., 23.]
+                ],
+            ),
+            // >>> convolve2d(in1, in2, 'same', boundary='wrap')
+            (
+                ConvolveMode::Same,
+                ConvolveBoundary::Wrap,
+                0.,
+                ndarray::array![
+                    [18., 7., 12., 1., 3.],
+                    [7., 13., 10., 2., 17.],
+                    [7., 22., 3., 17., 12.],
+                    [11., 3., 11., 23., 11.]
+                ],
+            ),
+            // >>> convolve2d(in1, in2, 'valid', boundary='symm')
+            (
+                ConvolveMode::Valid,
+                ConvolveBoundary::Symm,
+                0.,
+                ndarray::array![[13., 10., 2.], [22., 3., 17.]],
+            ),
+        ];
+        for (mode, boundary, fillvalue, expected) in cases {
+            let result = convolve2d(&in1, &in2, mode, boundary, fillvalue);
+            assert_eq!(result.shape(), expected.shape());
+            for (a, b) in result.iter().zip(expected.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-10);
+            }
+        }
     }
 
     #[test]
     fn test_correlate() {
         let in1 = vec![1.0, 2.0, 3.0];
         let in2 = vec![4.0, 5.0, 6.0];
-        let result = correlate(&in1, &in2, ConvolveMode::Full);
-        let expected = vec![6.0, 17.0, 32.0, 23.0, 12.0];
+        let result = correlate(&in1, &in2, ConvolveMode::Full, false);
+        let expected = [6.0, 17.0, 32.0, 23.0, 12.0];
         for (a, b) in result.iter().zip(expected.iter()) {
             assert_relative_eq!(a, b, epsilon = 1e-10);
         }
@@ -145,7 +1444,7 @@ mod tests {
         let in1 = vec![1.0, 2.0, 3.0, 4.0];
         let in2 = vec![1.0, 2.0];
         let result = convolve(&in1, &in2, ConvolveMode::Valid);
-        let expected = vec![4.0, 7.0, 10.0];
+        let expected = [4.0, 7.0, 10.0];
         for (a, b) in result.iter().zip(expected.iter()) {
             assert_relative_eq!(a, b, epsilon = 1e-10);
         }
@@ -156,12 +1455,201 @@ mod tests {
         let in1 = vec![1.0, 2.0, 3.0, 4.0];
         let in2 = vec![1.0, 2.0, 1.0];
         let result = convolve(&in1, &in2, ConvolveMode::Same);
-        let expected = vec![4.0, 8.0, 12.0, 11.0];
+        let expected = [4.0, 8.0, 12.0, 11.0];
         for (a, b) in result.iter().zip(expected.iter()) {
             assert_relative_eq!(a, b, epsilon = 1e-10);
         }
     }
 
+    #[test]
+    fn test_edge_padding() {
+        // A signal with a DC offset rolls off towards zero at the edges when zero padded.
+        let x = [4.; 12];
+        let v = [0.25; 4];
+        let zeros = convolve(&x, &v, ConvolveMode::Same);
+        assert_relative_eq!(zeros[0], 2., epsilon = 1e-10);
+        assert_relative_eq!(zeros[11], 3., epsilon = 1e-10);
+
+        // Padding with the edge value removes the roll-off, in either function and mode.
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            for padding in [PaddingMode::Const(4.), PaddingMode::Replicate] {
+                let result = convolve_with_padding(&x, &v, mode, padding);
+                assert_eq!(result.len(), convolve_direct(&x, &v, mode).len());
+                assert!(result.iter().all(|r| (r - 4.).abs() < 1e-10));
+                let result = correlate_with_padding(&x, &v, mode, false, padding);
+                assert!(result.iter().all(|r| (r - 4.).abs() < 1e-10));
+            }
+        }
+
+        // Valid outputs never use the padding.
+        let x = [1., 4., 2., 8., -3.];
+        let v = [1., -2., 0.5];
+        let valid = convolve(&x, &v, ConvolveMode::Valid);
+        for padding in [
+            PaddingMode::Const(7.),
+            PaddingMode::Reflect,
+            PaddingMode::Replicate,
+        ] {
+            let result = convolve_with_padding(&x, &v, ConvolveMode::Valid, padding);
+            for (r, e) in result.iter().zip(&valid) {
+                assert_relative_eq!(r, e, epsilon = 1e-10);
+            }
+        }
+
+        // With in2 longer than in1, the padding fills the overhang of in2 in Valid mode, and
+        // gives as many samples as zero padding.
+        let long = [1., -1., 2., 0.5, 3., 1.5, -2.];
+        let zeros = convolve(&v, &long, ConvolveMode::Valid);
+        assert_eq!(zeros.len(), 5);
+        let result = convolve_with_padding(&v, &long, ConvolveMode::Valid, PaddingMode::Const(0.));
+        for (r, e) in result.iter().zip(&zeros) {
+            assert_relative_eq!(r, e, epsilon = 1e-10);
+        }
+        for padding in [PaddingMode::Const(7.), PaddingMode::Replicate] {
+            let result = convolve_with_padding(&v, &long, ConvolveMode::Valid, padding);
+            let padded = pad_edges(&v, long.len() - 1, padding);
+            let expected = convolve_direct(&padded, &long, ConvolveMode::Full);
+            assert_eq!(result.len(), zeros.len());
+            for (r, e) in result.iter().zip(&expected[8..13]) {
+                assert_relative_eq!(r, e, epsilon = 1e-10);
+            }
+            let result = correlate_with_padding(&v, &long, ConvolveMode::Valid, false, padding);
+            assert_eq!(result.len(), zeros.len());
+        }
+
+        // Reflection about the edge samples, repeated for kernels longer than the signal.
+        assert_eq!(
+            pad_edges(&[1., 2., 3.], 5, PaddingMode::Reflect),
+            vec![2., 1., 2., 3., 2., 1., 2., 3., 2., 1., 2., 3., 2.]
+        );
+        let result = convolve_with_padding(&x, &v, ConvolveMode::Same, PaddingMode::Reflect);
+        let expected = convolve_direct(&[4., 1., 4., 2., 8., -3., 8.], &v, ConvolveMode::Valid);
+        for (r, e) in result.iter().zip(&expected) {
+            assert_relative_eq!(r, e, epsilon = 1e-10);
+        }
+
+        // Normalized lags all cover the whole of in2.
+        let result =
+            correlate_with_padding(&x, &v, ConvolveMode::Full, true, PaddingMode::Replicate);
+        let padded = pad_edges(&x, 2, PaddingMode::Replicate);
+        let expected = correlate(&padded, &v, ConvolveMode::Valid, true);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_correlate_normalized() {
+        let x: Vec<f64> = (0..50)
+            .map(|i| (0.3 * i as f64 + 0.5).sin() + 0.1 * i as f64)
+            .collect();
+        let y: Vec<f64> = (0..17).map(|i| (0.7 * i as f64).cos() - 0.2).collect();
+
+        // Autocorrelation at zero lag is exactly 1, in either mode.
+        let full = correlate(&x, &x, ConvolveMode::Full, true);
+        assert_eq!(full.len(), 99);
+        assert_eq!(full[49], 1.);
+        let same = correlate(&x, &x, ConvolveMode::Same, true);
+        assert_eq!(same.len(), 50);
+        assert_eq!(same[25], 1.);
+
+        // Each lag is normalized by the overlapping parts only, so the single-sample overlaps at
+        // either end are +-1.
+        let full = correlate(&x, &y, ConvolveMode::Full, true);
+        assert_eq!(full.len(), 66);
+        assert_eq!(full[0], (x[0] * y[16]).signum());
+        assert_eq!(full[65], (x[49] * y[0]).signum());
+        assert!(full.iter().all(|r| r.abs() <= 1.));
+
+        let raw = correlate(&x, &y, ConvolveMode::Full, false);
+        for (k, (r, c)) in full.iter().zip(&raw).enumerate() {
+            let lo = k.saturating_sub(16);
+            let hi = k.min(49);
+            let xx: f64 = (lo..=hi).map(|i| x[i] * x[i]).sum();
+            let yy: f64 = (lo..=hi).map(|i| y[16 + i - k].powi(2)).sum();
+            assert_relative_eq!(*r, c / (xx * yy).sqrt(), epsilon = 1e-10);
+        }
+
+        let same = correlate(&x, &y, ConvolveMode::Same, true);
+        assert_eq!(same, full[8..58]);
+        let valid = correlate(&x, &y, ConvolveMode::Valid, true);
+        assert_eq!(valid, full[16..50]);
+
+        // Zero energy in the overlap gives 0 rather than NaN.
+        let result = correlate(&[0., 0., 1.], &[1., 1.], ConvolveMode::Full, true);
+        assert_eq!(result, vec![0., 0., 1. / 2f64.sqrt(), 1.]);
+    }
+
+    #[test]
+    fn test_convolve_direct_same_centering() {
+        // >>> convolve([1, 2, 3, 4, 5], [1, 2, 3], mode='same')
+        // array([ 4, 10, 16, 22, 22])
+        // >>> convolve([1, 2, 3, 4, 5], [1, 2, 3, 4], mode='same')
+        // array([ 4, 10, 20, 30, 34])
+        let in1 = [1, 2, 3, 4, 5];
+        assert_eq!(
+            convolve_direct(&in1, &[1, 2, 3], ConvolveMode::Same),
+            vec![4, 10, 16, 22, 22]
+        );
+        assert_eq!(
+            convolve_direct(&in1, &[1, 2, 3, 4], ConvolveMode::Same),
+            vec![4, 10, 20, 30, 34]
+        );
+
+        // The FFT path takes the same slice of the full convolution.
+        let in1f: Vec<f64> = in1.iter().map(|&x| x as f64).collect();
+        for in2 in [
+            &[1, 2, 3][..],
+            &[1, 2, 3, 4][..],
+            &[1][..],
+            &[2, -1, 3, 1, 7, 5][..],
+        ] {
+            let direct = convolve_direct(&in1, in2, ConvolveMode::Same);
+            let in2f: Vec<f64> = in2.iter().map(|&x| x as f64).collect();
+            let fft = fftconvolve(&in1f, &in2f, ConvolveMode::Same);
+            assert_eq!(direct.len(), in1.len());
+            assert_eq!(fft.len(), in1.len());
+            for (d, f) in direct.iter().zip(&fft) {
+                assert_relative_eq!(*d as f64, f, epsilon = 1e-10);
+            }
+        }
+
+        // >>> convolve([1, 2, 3, 4], [1, 2], mode='valid')
+        // array([ 4,  7, 10])
+        assert_eq!(
+            convolve_direct(&[1, 2, 3, 4], &[1, 2], ConvolveMode::Valid),
+            vec![4, 7, 10]
+        );
+        assert_eq!(
+            convolve_direct(&[1, 2, 3], &[4, 5, 6], ConvolveMode::Full),
+            vec![4, 13, 28, 27, 18]
+        );
+    }
+
+    #[test]
+    fn test_fftconvolve_mem_estimate_shapes() {
+        assert_eq!(
+            fftconvolve_mem_estimate::<f32>(&[4, 0], &[2, 3], ConvolveMode::Full),
+            Ok(0)
+        );
+        // Each axis is padded independently: (8 * 8) complex f32 values per buffer.
+        assert_eq!(
+            fftconvolve_mem_estimate::<f32>(&[4, 4], &[2, 3], ConvolveMode::Full),
+            Ok(2 * 64 * 8 + 2 * 30 * 4)
+        );
+        // Valid mode swaps the inputs when in2 is the larger one.
+        assert_eq!(
+            fftconvolve_mem_estimate::<f32>(&[2, 3], &[4, 4], ConvolveMode::Valid),
+            Ok(2 * 64 * 8 + (30 + 3 * 2) * 4)
+        );
+        assert!(matches!(
+            fftconvolve_mem_estimate::<f32>(&[4, 2], &[2, 4], ConvolveMode::Valid),
+            Err(Error::ShapeMismatch { .. })
+        ));
+        assert!(matches!(
+            fftconvolve_mem_estimate::<f32>(&[4], &[2, 4], ConvolveMode::Full),
+            Err(Error::ShapeMismatch { .. })
+        ));
+    }
+
     #[test]
     #[cfg(feature = "plot")]
     fn test_scipy_example() {
@@ -173,7 +1661,7 @@ mod tests {
         let sig: Vec<f64> = StandardUniform.sample_iter(&mut rng).take(1000).collect();
 
         // Compute autocorrelation using correlate directly
-        let autocorr = correlate(&sig, &sig, ConvolveMode::Full);
+        let autocorr = correlate(&sig, &sig, ConvolveMode::Full, false);
 
         // Basic sanity checks
         assert_eq!(autocorr.len(), 1999); // Full convolution length should be 2N-1
@@ -192,4 +1680,40 @@ mod tests {
         let autocorr: Vec<f32> = autocorr.iter().map(|x| *x as f32).collect();
         crate::plot::python_plot(vec![&sig, &autocorr]);
     }
+
+    #[test]
+    fn test_convolve_promoted() {
+        let signal: Vec<f32> = (0..64).map(|i| (i as f32 * 0.3).sin()).collect();
+        let kernel = [0.1, 0.2, 0.4, 0.2, 0.1];
+
+        // The f32 signal is promoted exactly to f64.
+        let signal_f64: Vec<f64> = signal.iter().map(|&x| x.into()).collect();
+        for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+            let expected = convolve(&signal_f64, &kernel, mode);
+            let result: Vec<f64> = convolve_promoted(&signal, &kernel, mode, PaddingMode::Zeros);
+            assert_eq!(result, expected);
+
+            // The promotion is symmetric.
+            let expected = convolve(&kernel, &signal_f64, mode);
+            let result: Vec<f64> = convolve_promoted(&kernel, &signal, mode, PaddingMode::Zeros);
+            assert_eq!(result, expected);
+        }
+
+        let kernel_f32 = [0.25f32, 0.5, 0.25];
+        let result: Vec<f32> = convolve_promoted(
+            &signal,
+            &kernel_f32,
+            ConvolveMode::Same,
+            PaddingMode::Replicate,
+        );
+        assert_eq!(
+            result,
+            convolve_with_padding(
+                &signal,
+                &kernel_f32,
+                ConvolveMode::Same,
+                PaddingMode::Replicate
+            )
+        );
+    }
 }
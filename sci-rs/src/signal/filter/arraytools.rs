@@ -28,17 +28,9 @@ where
     // Before we convert into the appropriate axis object, we have to check at runtime that the
     // axis value specified is within -N <= axis < N.
     match axis {
-        None => (),
-        Some(axis) if axis.is_negative() => {
-            if axis.unsigned_abs() > N {
-                return Err(());
-            }
-        }
-        Some(axis) => {
-            if axis.unsigned_abs() >= N {
-                return Err(());
-            }
-        }
+        Some(axis) if axis.is_negative() && axis.unsigned_abs() > N => return Err(()),
+        Some(axis) if !axis.is_negative() && axis.unsigned_abs() >= N => return Err(()),
+        _ => (),
     }
 
     // We make a best effort to convert into appropriate axis object.
@@ -0,0 +1,184 @@
+use super::lfilter::{lfilter_df2t_mut, normalize_ba, validate_a};
+use alloc::{vec, vec::Vec};
+use ndarray::ArrayView1;
+use num_traits::{FromPrimitive, NumAssign};
+use sci_rs_core::{Error, Result};
+
+/// IIR or FIR filter of a signal fed in chunks, for signals too large to hold in memory.
+///
+/// Each chunk is filtered as by [lfilter][super::lfilter::LFilter], starting from the final
+/// filter delay values `zf` of the previous chunk, so that the concatenated output equals the
+/// output of filtering the whole signal at once. Only the filter delays are kept between chunks.
+///
+/// Zero-phase filtering, as by [filtfilt][super::FiltFilt], needs the end of the signal before
+/// its start can be filtered backwards, and so cannot be done in chunks.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::ChunkedFilter;
+///
+/// let mut filter = ChunkedFilter::new(&[0.5, 0.5], &[1., -0.5], None).unwrap();
+/// let mut y = filter.process(&[1., 2.]);
+/// y.extend(filter.process(&[3.]));
+/// assert_eq!(y, vec![0.5, 1.75, 3.375]);
+/// assert_eq!(filter.processed(), 3);
+///
+/// let zf = filter.finalize();
+/// assert_eq!(zf, vec![3.1875]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunkedFilter<T> {
+    /// Numerator, normalized by `a[0]` and padded to the length of `a`.
+    b: Vec<T>,
+    /// Denominator, normalized by `a[0]` and padded to the length of `b`.
+    a: Vec<T>,
+    /// Filter delay values.
+    z: Vec<T>,
+    processed: usize,
+}
+
+impl<T> ChunkedFilter<T>
+where
+    T: NumAssign + FromPrimitive + PartialOrd + Copy,
+{
+    /// Returns a filter with numerator `b` and denominator `a`, which are normalized by `a[0]`.
+    ///
+    /// # Parameters
+    /// * `b` : array_like
+    ///   The numerator coefficient vector in a 1-D sequence.
+    /// * `a` : array_like
+    ///   The denominator coefficient vector in a 1-D sequence.
+    /// * `zi` : array_like, optional
+    ///   Initial conditions for the filter delays, of length `max(len(a), len(b)) - 1`. If
+    ///   `None`, initial rest is assumed. See [super::lfilter_zi_dyn] to start in the steady
+    ///   state of a step response instead.
+    ///
+    /// # Errors
+    /// [Error::InvalidArg] if `a` cannot be normalized by `a[0]`, or if `zi` is of the wrong
+    /// length.
+    pub fn new(b: &[T], a: &[T], zi: Option<&[T]>) -> Result<Self> {
+        validate_a(ArrayView1::from(a))?;
+        let (b, a) = normalize_ba(b, a);
+
+        let z = match zi {
+            Some(zi) if zi.len() != a.len() - 1 => {
+                return Err(Error::InvalidArg {
+                    arg: "zi".into(),
+                    reason: "Expected zi of length max(len(a), len(b)) - 1.".into(),
+                })
+            }
+            Some(zi) => zi.to_vec(),
+            None => vec![T::zero(); a.len() - 1],
+        };
+
+        Ok(ChunkedFilter {
+            b,
+            a,
+            z,
+            processed: 0,
+        })
+    }
+
+    /// Filter the next `chunk` of the signal, continuing from the filter delays left by the
+    /// previous chunk.
+    pub fn process(&mut self, chunk: &[T]) -> Vec<T> {
+        self.processed += chunk.len();
+        lfilter_df2t_mut(&self.b, &self.a, chunk.iter().copied(), &mut self.z)
+    }
+
+    /// The number of samples filtered so far.
+    pub fn processed(&self) -> usize {
+        self.processed
+    }
+
+    /// Finish filtering, returning the final filter delay values `zf`.
+    ///
+    /// These can be passed as `zi` to resume filtering the signal later.
+    pub fn finalize(self) -> Vec<T> {
+        self.z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::lfilter;
+    use approx::assert_relative_eq;
+    use ndarray::{Array1, ArrayView1};
+
+    #[test]
+    fn chunks_match_whole_signal() {
+        // from scipy.signal import butter
+        // butter(3, 0.2)
+        let b = [
+            0.01809893300751445,
+            0.05429679902254335,
+            0.05429679902254335,
+            0.01809893300751445,
+        ];
+        let a = [
+            1.,
+            -1.760041880343169,
+            1.1828932620378303,
+            -0.2780599176345463,
+        ];
+        let x: Vec<f64> = (0..100).map(|i| (i as f64 / 5.).sin() + 0.5).collect();
+
+        // Filtering the whole signal at once, from zero initial delays to get their final values.
+        let (expected, zf) = lfilter(
+            ArrayView1::from(&b),
+            ArrayView1::from(&a),
+            ArrayView1::from(&x),
+            None,
+            Some(Array1::zeros(3).view()),
+        )
+        .unwrap();
+        let zf = zf.unwrap();
+
+        let mut filter = ChunkedFilter::new(&b, &a, None).unwrap();
+        let y: Vec<f64> = x.chunks(7).flat_map(|c| filter.process(c)).collect();
+        assert_eq!(filter.processed(), x.len());
+        assert_eq!(y.len(), expected.len());
+        for (yi, ei) in y.iter().zip(&expected) {
+            assert_relative_eq!(yi, ei, max_relative = 1e-12);
+        }
+        for (zi, ei) in filter.finalize().iter().zip(&zf) {
+            assert_relative_eq!(zi, ei, max_relative = 1e-12);
+        }
+
+        // Resuming from zf continues the signal.
+        let mut filter = ChunkedFilter::new(&b, &a, None).unwrap();
+        filter.process(&x[..50]);
+        let zf = filter.finalize();
+        let mut resumed = ChunkedFilter::new(&b, &a, Some(&zf)).unwrap();
+        for (yi, ei) in resumed
+            .process(&x[50..])
+            .iter()
+            .zip(expected.iter().skip(50))
+        {
+            assert_relative_eq!(yi, ei, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn unnormalized_coefficients() {
+        let mut filter = ChunkedFilter::new(&[1., 1.], &[2., -1.], None).unwrap();
+        let y = filter.process(&[1., 2., 3.]);
+        let (expected, _) = lfilter(
+            ArrayView1::from(&[0.5, 0.5]),
+            ArrayView1::from(&[1., -0.5]),
+            ArrayView1::from(&[1., 2., 3.]),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(y, expected.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(ChunkedFilter::new(&[1.], &[0., 1.], None).is_err());
+        assert!(ChunkedFilter::<f64>::new(&[1.], &[], None).is_err());
+        assert!(ChunkedFilter::new(&[1., 1.], &[1.], Some(&[0., 0.][..])).is_err());
+    }
+}
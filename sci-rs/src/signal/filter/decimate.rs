@@ -0,0 +1,598 @@
+use core::iter::Sum;
+use nalgebra::RealField;
+use ndarray::ArrayView1;
+use num_traits::{Float, MulAdd, Pow};
+use sci_rs_core::{Error, Result};
+
+use super::design::{
+    firwin_dyn, freqz_dyn, sosfreqz_dyn, try_iirfilter_dyn, zpk2sos_dyn, zpk2tf_dyn, DigitalFilter,
+    FilterBandType, FilterOutputType, FilterType, Sos,
+};
+use super::{lfilter, sosfilt_dyn, sosfiltfilt_dyn, upfirdn, FiltFilt, FiltFiltPad};
+use crate::signal::windows::{get_window, GetWindowBuilder};
+use crate::special::Bessel;
+
+use alloc::{vec, vec::Vec};
+
+/// Number of frequencies at which the anti-aliasing filter response is checked.
+const VALIDATION_WORN: usize = 512;
+
+/// The type of anti-aliasing filter designed by [decimate].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DecimateFtype {
+    /// An order `n` Chebyshev type I filter with 0.05 dB of passband ripple and a cutoff of
+    /// `0.8 / q`, applied in second-order sections.
+    #[default]
+    Iir,
+    /// A Hamming windowed FIR filter with `n + 1` taps and a cutoff of `1 / q`.
+    Fir,
+}
+
+/// Downsample the signal after applying an anti-aliasing filter.
+///
+/// This is `scipy.signal.decimate(x, q, n, ftype, zero_phase=zero_phase)`. The filter is
+/// designed as by scipy, with [try_iirfilter_dyn] for [DecimateFtype::Iir] and [firwin_dyn] for
+/// [DecimateFtype::Fir], then applied as by [decimate_with_filter_dyn]. As in scipy, the designed
+/// filter is not checked for its suitability as an anti-aliasing filter.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The signal to be downsampled.
+/// * `q` : int
+///   The downsampling factor. When using IIR downsampling, it is recommended to call
+///   `decimate` multiple times for downsampling factors higher than 13.
+/// * `n` : int, optional
+///   The order of the filter (1 less than the length for 'fir'). Defaults to 8 for 'iir' and
+///   20 times the downsampling factor for 'fir'.
+/// * `ftype` : [DecimateFtype]
+///   The type of the lowpass filter.
+/// * `zero_phase` : bool
+///   Prevent phase shift by filtering with `sosfiltfilt` instead of `sosfilt` for IIR filters,
+///   and by shifting the outputs back by the filter's group delay for FIR filters.
+///
+/// # Returns
+/// * `y` : ndarray
+///   The down-sampled signal, of length `ceil(x.len() / q)`.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `q` is zero, or if `q` is one with [DecimateFtype::Fir], as
+/// `firwin` rejects a cutoff at the Nyquist frequency.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::{decimate, DecimateFtype};
+///
+/// let x: Vec<f64> = (0..100).map(|i| (0.05 * i as f64).sin()).collect();
+/// let y = decimate(&x, 4, None, DecimateFtype::Iir, true).unwrap();
+/// assert_eq!(y.len(), 25);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.decimate.html>
+pub fn decimate<T>(
+    x: &[T],
+    q: usize,
+    n: Option<usize>,
+    ftype: DecimateFtype,
+    zero_phase: bool,
+) -> Result<Vec<T>>
+where
+    T: RealField + Float + Sum + MulAdd<Output = T> + Pow<T, Output = T> + Bessel,
+{
+    if q == 0 {
+        return Err(Error::InvalidArg {
+            arg: "q".into(),
+            reason: "Downsampling factor must be a positive integer.".into(),
+        });
+    }
+    let q_t = T::from(q).unwrap();
+    let system = match ftype {
+        DecimateFtype::Iir => {
+            let filter = try_iirfilter_dyn(
+                n.unwrap_or(8),
+                vec![T::from(0.8).unwrap() / q_t],
+                Some(T::from(0.05).unwrap()),
+                None,
+                Some(FilterBandType::Lowpass),
+                Some(FilterType::ChebyshevI),
+                None,
+                Some(FilterOutputType::Sos),
+                None,
+            )?;
+            let DigitalFilter::Sos(sos) = filter else {
+                unreachable!("try_iirfilter_dyn returns the requested sos output")
+            };
+            DecimateSystem::Sos(sos.sos)
+        }
+        DecimateFtype::Fir => {
+            let numtaps = n.unwrap_or(20 * q) + 1;
+            let b = firwin_dyn(
+                numtaps,
+                &[T::one() / q_t],
+                None,
+                Some(&get_window(
+                    GetWindowBuilder::<T>::Hamming,
+                    numtaps,
+                    Some(false),
+                )),
+                &FilterBandType::Lowpass,
+                None,
+                None,
+            )
+            .map_err(|_| Error::InvalidArg {
+                arg: "q".into(),
+                reason: "FIR downsampling needs a factor of at least 2.".into(),
+            })?;
+            DecimateSystem::Fir(b)
+        }
+    };
+
+    system.apply(x, q, zero_phase)
+}
+
+/// Downsample the signal after applying a user-supplied anti-aliasing filter.
+///
+/// This is `scipy.signal.decimate(x, q, ftype=system, zero_phase=zero_phase)` where `system` is a
+/// pre-built filter, allowing a specifically tuned filter to be reused for decimation.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The signal to be downsampled.
+/// * `q` : int
+///   The downsampling factor.
+/// * `filter` : [DigitalFilter]
+///   The anti-aliasing lowpass filter. As in scipy, a filter without poles is applied as an FIR
+///   filter and an IIR filter in [DigitalFilter::Ba] form with `lfilter`, whilst an IIR filter in
+///   [DigitalFilter::Zpk] form is converted into second-order sections for filtering.
+/// * `zero_phase` : bool
+///   Prevent phase shift by filtering with `sosfiltfilt` or `filtfilt` instead of `sosfilt` or
+///   `lfilter` for IIR filters, and by shifting the outputs back by the filter's group delay for
+///   FIR filters.
+///
+/// # Returns
+/// * `y` : ndarray
+///   The down-sampled signal, of length `ceil(x.len() / q)`.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if
+/// * `q` is zero,
+/// * `filter` is not suitable as an anti-aliasing filter for `q`, that is, it does not pass DC
+///   or is not at least 3 dB below its DC gain at and above the new Nyquist frequency `pi / q`,
+/// * `x` is too short to be padded by `filtfilt` when `filter` is an IIR filter in
+///   [DigitalFilter::Ba] form and `zero_phase` is true.
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.decimate.html>
+pub fn decimate_with_filter_dyn<F>(
+    x: &[F],
+    q: usize,
+    filter: DigitalFilter<F>,
+    zero_phase: bool,
+) -> Result<Vec<F>>
+where
+    F: RealField + Float + Sum,
+{
+    if q == 0 {
+        return Err(Error::InvalidArg {
+            arg: "q".into(),
+            reason: "Downsampling factor must be a positive integer.".into(),
+        });
+    }
+
+    let system = match filter {
+        DigitalFilter::Zpk(zpk) if zpk.p.is_empty() => {
+            let ba = zpk2tf_dyn(zpk.z.len(), &zpk.z, &zpk.p, zpk.k);
+            DecimateSystem::Fir(ba.b)
+        }
+        DigitalFilter::Zpk(zpk) => {
+            let order = zpk.p.len();
+            DecimateSystem::Sos(zpk2sos_dyn(order, zpk, None, None).sos)
+        }
+        DigitalFilter::Ba(ba) if ba.b.is_empty() => {
+            return Err(Error::InvalidArg {
+                arg: "filter".into(),
+                reason: "Filter numerator must not be empty.".into(),
+            });
+        }
+        DigitalFilter::Ba(ba) if ba.a.len() == 1 => {
+            let a0 = ba.a[0];
+            DecimateSystem::Fir(ba.b.into_iter().map(|bi| bi / a0).collect())
+        }
+        DigitalFilter::Ba(ba) => DecimateSystem::Ba(ba.b, ba.a),
+        DigitalFilter::Sos(sos) => DecimateSystem::Sos(sos.sos),
+    };
+    system.validate(q)?;
+
+    system.apply(x, q, zero_phase)
+}
+
+/// Anti-aliasing filter in the form it is applied.
+enum DecimateSystem<F: RealField + Copy> {
+    Fir(Vec<F>),
+    Sos(Vec<Sos<F>>),
+    Ba(Vec<F>, Vec<F>),
+}
+
+impl<F> DecimateSystem<F>
+where
+    F: RealField + Float,
+{
+    /// Check that the filter passes DC and attenuates everything that would alias after
+    /// downsampling by `q`.
+    fn validate(&self, q: usize) -> Result<()> {
+        if q == 1 {
+            return Ok(());
+        }
+        let (w, h) = match self {
+            DecimateSystem::Fir(b) => freqz_dyn(b, &[F::one()], VALIDATION_WORN, false, None),
+            DecimateSystem::Sos(sos) => sosfreqz_dyn(sos, VALIDATION_WORN, false, None),
+            DecimateSystem::Ba(b, a) => freqz_dyn(b, a, VALIDATION_WORN, false, None),
+        };
+
+        // Compare squared magnitudes, -3 dB being half the power at DC.
+        let dc = h[0].norm_sqr();
+        let nyquist = F::pi() / F::from(q).unwrap();
+        let stop = w
+            .iter()
+            .zip(&h)
+            .filter(|(wi, _)| **wi >= nyquist)
+            .map(|(_, hi)| hi.norm_sqr())
+            .fold(F::zero(), Float::max);
+
+        if dc <= F::zero() || stop + stop > dc {
+            return Err(Error::InvalidArg {
+                arg: "filter".into(),
+                reason: "Filter is not a lowpass filter suitable for the downsampling factor."
+                    .into(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Filter `x` and keep every `q`-th sample.
+    fn apply(self, x: &[F], q: usize, zero_phase: bool) -> Result<Vec<F>>
+    where
+        F: Sum,
+    {
+        let n_out = x.len().div_ceil(q);
+        match self {
+            DecimateSystem::Fir(b) => {
+                // As scipy, `upfirdn(b, x, 1, q)` when `zero_phase` is false, and otherwise
+                // `resample_poly(x, 1, q, window=b)`, which delays the filter by zeros in front so
+                // that the output samples fall at its centre.
+                let (h, n_pre_remove) = if zero_phase {
+                    let half_len = (b.len() - 1) / 2;
+                    let n_pre_pad = q - half_len % q;
+                    let h: Vec<F> = core::iter::repeat_n(F::zero(), n_pre_pad)
+                        .chain(b)
+                        .collect();
+                    (h, (half_len + n_pre_pad) / q)
+                } else {
+                    (b, 0)
+                };
+                Ok(upfirdn(&h, x, 1, q)
+                    .into_iter()
+                    .skip(n_pre_remove)
+                    .take(n_out)
+                    .collect())
+            }
+            DecimateSystem::Sos(sos) => {
+                let y = if zero_phase {
+                    sosfiltfilt_dyn(x.iter(), &sos)
+                } else {
+                    let mut sos = sos;
+                    sosfilt_dyn(x.iter(), &mut sos)
+                };
+                Ok(y.into_iter().step_by(q).collect())
+            }
+            DecimateSystem::Ba(b, a) => {
+                let (b, a, x) = (
+                    ArrayView1::from(&b),
+                    ArrayView1::from(&a),
+                    ArrayView1::from(x),
+                );
+                let y = if zero_phase {
+                    ArrayView1::filtfilt(b, a, x, None, Some(FiltFiltPad::default()))?.into_dyn()
+                } else {
+                    lfilter(b, a, x, None, None)?.0
+                };
+                Ok(y.into_iter().step_by(q).collect())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{ellip_dyn, BaFormatFilter, SosFormatFilter};
+    use approx::assert_relative_eq;
+
+    fn chirp_like(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                (40. * t * t).sin() + 0.3 * (1.1 * i as f64).cos()
+            })
+            .collect()
+    }
+
+    fn custom_sos(wn: f64, btype: FilterBandType) -> Vec<Sos<f64>> {
+        let DigitalFilter::Sos(SosFormatFilter { sos }) = ellip_dyn(
+            6,
+            0.5,
+            40.,
+            vec![wn],
+            Some(btype),
+            None,
+            Some(FilterOutputType::Sos),
+            None,
+        ) else {
+            panic!("Expected sos output")
+        };
+        sos
+    }
+
+    #[test]
+    fn custom_sos_matches_filter_then_downsample() {
+        let x = chirp_like(203);
+        let q = 4;
+        let sos = custom_sos(0.2, FilterBandType::Lowpass);
+
+        let y = decimate_with_filter_dyn(
+            &x,
+            q,
+            DigitalFilter::Sos(SosFormatFilter { sos: sos.clone() }),
+            false,
+        )
+        .unwrap();
+        let mut manual_sos = sos.clone();
+        let expected: Vec<f64> = sosfilt_dyn(x.iter(), &mut manual_sos)
+            .into_iter()
+            .step_by(q)
+            .collect();
+        assert_eq!(y.len(), 51);
+        for (a, e) in y.iter().zip(&expected) {
+            assert_relative_eq!(a, e, max_relative = 1e-12);
+        }
+
+        let y = decimate_with_filter_dyn(
+            &x,
+            q,
+            DigitalFilter::Sos(SosFormatFilter { sos: sos.clone() }),
+            true,
+        )
+        .unwrap();
+        let expected: Vec<f64> = sosfiltfilt_dyn(x.iter(), &sos)
+            .into_iter()
+            .step_by(q)
+            .collect();
+        assert_eq!(y.len(), 51);
+        for (a, e) in y.iter().zip(&expected) {
+            assert_relative_eq!(a, e, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn custom_ba_matches_filter_then_downsample() {
+        let x = chirp_like(203);
+        let q = 4;
+        let DigitalFilter::Ba(BaFormatFilter { b, a }) = ellip_dyn(
+            4,
+            0.5,
+            40.,
+            vec![0.2],
+            Some(FilterBandType::Lowpass),
+            None,
+            Some(FilterOutputType::Ba),
+            None,
+        ) else {
+            panic!("Expected ba output")
+        };
+        let filter = || {
+            DigitalFilter::Ba(BaFormatFilter {
+                b: b.clone(),
+                a: a.clone(),
+            })
+        };
+        let (b, a) = (ArrayView1::from(&b), ArrayView1::from(&a));
+
+        let y = decimate_with_filter_dyn(&x, q, filter(), false).unwrap();
+        let (expected, _) = lfilter(b, a, ArrayView1::from(&x), None, None).unwrap();
+        assert_eq!(y.len(), 51);
+        for (a, e) in y.iter().zip(expected.iter().step_by(q)) {
+            assert_relative_eq!(a, e, max_relative = 1e-12);
+        }
+
+        let y = decimate_with_filter_dyn(&x, q, filter(), true).unwrap();
+        let expected = ArrayView1::filtfilt(
+            b,
+            a,
+            ArrayView1::from(&x),
+            None,
+            Some(FiltFiltPad::default()),
+        )
+        .unwrap();
+        assert_eq!(y.len(), 51);
+        for (a, e) in y.iter().zip(expected.iter().step_by(q)) {
+            assert_relative_eq!(a, e, max_relative = 1e-12);
+        }
+
+        // Too short for the default padding of filtfilt.
+        let result = decimate_with_filter_dyn(&x[..10], q, filter(), true);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fir_zero_phase_is_centred() {
+        let x = chirp_like(100);
+        let q = 3;
+        let b: Vec<f64> = firwin_dyn::<f64, f64>(
+            31,
+            &[1. / q as f64],
+            None,
+            None::<&crate::signal::windows::Hamming>,
+            &FilterBandType::Lowpass,
+            None,
+            None,
+        )
+        .unwrap();
+        let filter = || {
+            DigitalFilter::Ba(BaFormatFilter {
+                b: b.clone(),
+                a: vec![1.],
+            })
+        };
+
+        let full: Vec<f64> = (0..x.len() + b.len() - 1)
+            .map(|n| {
+                (0..b.len())
+                    .filter(|k| *k <= n && n - k < x.len())
+                    .map(|k| b[k] * x[n - k])
+                    .sum()
+            })
+            .collect();
+
+        let y = decimate_with_filter_dyn(&x, q, filter(), false).unwrap();
+        assert_eq!(y.len(), 34);
+        for (m, a) in y.iter().enumerate() {
+            assert_relative_eq!(*a, full[m * q], max_relative = 1e-12);
+        }
+
+        let y = decimate_with_filter_dyn(&x, q, filter(), true).unwrap();
+        assert_eq!(y.len(), 34);
+        for (m, a) in y.iter().enumerate() {
+            assert_relative_eq!(*a, full[m * q + 15], max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn rejects_unsuitable_filters() {
+        let x = chirp_like(64);
+
+        let highpass = custom_sos(0.2, FilterBandType::Highpass);
+        let result = decimate_with_filter_dyn(
+            &x,
+            4,
+            DigitalFilter::Sos(SosFormatFilter { sos: highpass }),
+            false,
+        );
+        assert!(result.is_err());
+
+        // Cutoff too high for a factor of 8.
+        let wide = custom_sos(0.5, FilterBandType::Lowpass);
+        let result = decimate_with_filter_dyn(
+            &x,
+            8,
+            DigitalFilter::Sos(SosFormatFilter { sos: wide }),
+            false,
+        );
+        assert!(result.is_err());
+
+        let lowpass = custom_sos(0.2, FilterBandType::Lowpass);
+        let result = decimate_with_filter_dyn(
+            &x,
+            0,
+            DigitalFilter::Sos(SosFormatFilter { sos: lowpass }),
+            false,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decimate_chirp_by_4() {
+        let x = chirp_like(64);
+        let q = 4;
+
+        // >>> signal.decimate(x, 4, ftype='fir', zero_phase=True)
+        let expected = [
+            0.038050659809359434,
+            0.13480383499468304,
+            0.5934218277591747,
+            0.9928660001759515,
+            0.5800790072674561,
+            -0.6561881510709434,
+            -0.6727032978256219,
+            1.0677079608234141,
+            -0.6530016624769421,
+            0.19900496956866898,
+            0.040275350566954145,
+            -0.1191994824220972,
+            0.12552806095569208,
+            -0.11270571393821795,
+            0.10049758124437168,
+            -0.11388307997195726,
+        ];
+        let y = decimate(&x, q, None, DecimateFtype::Fir, true).unwrap();
+        assert_eq!(y.len(), expected.len());
+        for (a, e) in y.iter().zip(expected) {
+            assert_relative_eq!(*a, e, epsilon = 1e-12);
+        }
+
+        // >>> signal.decimate(x, 4, zero_phase=False)
+        let expected = [
+            1.2107787868043501e-06,
+            0.0019503941612910912,
+            0.032750970323976114,
+            0.09801784590230332,
+            0.34947998486476683,
+            0.8407100677933856,
+            0.8590174287688035,
+            -0.037883129445354824,
+            -0.8690131298976804,
+            0.04795212076066055,
+            0.7210620536096641,
+            -0.6080742064976428,
+            0.09251358918574054,
+            0.2919881324543188,
+            -0.4150532079585897,
+            0.3503690446010066,
+        ];
+        let y = decimate(&x, q, None, DecimateFtype::Iir, false).unwrap();
+        assert_eq!(y.len(), expected.len());
+        for (a, e) in y.iter().zip(expected) {
+            assert_relative_eq!(*a, e, epsilon = 1e-10);
+        }
+
+        // >>> signal.decimate(x, 4)
+        let expected = [
+            0.28336385566591915,
+            0.052333461264589,
+            0.667527509134539,
+            0.8720490977539188,
+            0.7376097490160937,
+            -0.8571632880349874,
+            -0.43159978038625296,
+            0.8082797333434876,
+            -0.40975223964252544,
+            -0.0019564653501779284,
+            0.1596627912088721,
+            -0.1703823768973248,
+            0.08514923428871304,
+            -0.01867905579837821,
+            -0.02683166533215414,
+            -0.08937904861079903,
+        ];
+        let y = decimate(&x, q, None, DecimateFtype::Iir, true).unwrap();
+        assert_eq!(y.len(), expected.len());
+        for (a, e) in y.iter().zip(expected) {
+            assert_relative_eq!(*a, e, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn decimate_rejects_invalid_factors() {
+        let x = chirp_like(64);
+        for ftype in [DecimateFtype::Iir, DecimateFtype::Fir] {
+            let result = decimate(&x, 0, None, ftype, true);
+            assert!(matches!(result, Err(Error::InvalidArg { .. })), "{ftype:?}");
+        }
+        // firwin rejects a cutoff at the Nyquist frequency.
+        let result = decimate(&x, 1, None, DecimateFtype::Fir, true);
+        assert!(matches!(result, Err(Error::InvalidArg { .. })));
+        assert_eq!(
+            decimate(&x, 1, None, DecimateFtype::Iir, false)
+                .unwrap()
+                .len(),
+            64
+        );
+    }
+}
@@ -8,14 +8,85 @@ use alloc::vec::Vec;
 
 #[cfg(feature = "alloc")]
 use super::{iirfilter_dyn, DigitalFilter, FilterBandType, FilterOutputType, FilterType, Sos};
-///
+
 /// Butterworth digital and analog filter design.
 ///
 /// Design an Nth-order digital or analog Butterworth filter and return
 /// the filter coefficients.
 ///
-/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.butter.html>
+/// Parameters
+/// ----------
+/// * `order` : int  
+///   The order of the filter. For 'bandpass' and 'bandstop' filters, the resulting order of the
+///   final second-order sections ('sos') matrix is `2*order`.
+/// * `Wn` : array_like  
+///   A scalar or length-2 sequence giving the critical frequencies.
+///   For a Butterworth filter, this is the point at which the gain
+///   drops to 1/sqrt(2) that of the passband (the "-3 dB point").
+///
+///   For digital filters, `Wn` are in the same units as `fs`. By default,
+///   `fs` is 2 half-cycles/sample, so these are normalized from 0 to 1,
+///   where 1 is the Nyquist frequency. (`Wn` is thus in
+///   half-cycles / sample.)
+///
+///   For analog filters, `Wn` is an angular frequency (e.g. rad/s).
+/// * `btype` : {'lowpass', 'highpass', 'bandpass', 'bandstop'}, optional  
+///   The type of filter.  Default is 'lowpass'.
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned.
+/// * `output` : {'ba', 'zpk', 'sos'}, optional  
+///   Type of output:  numerator/denominator ('ba'), pole-zero ('zpk'), or
+///   second-order sections ('sos'). Default is 'ba' for backwards
+///   compatibility, but 'sos' should be used for general-purpose filtering.
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
 ///
+/// Returns
+/// -------
+/// The filter, as [iirfilter_dyn] with `ftype` [FilterType::Butterworth].
+///
+/// # Example
+/// A 4th-order bandpass filter from 10 to 50 Hz, sampled at 1666 Hz:
+///
+/// ```custom,{class=language-python}
+/// >>> from scipy.signal import butter
+/// >>> butter(4, [10, 50], btype='bandpass', output='sos', fs=1666)
+/// array([[ 2.67757674e-05,  5.35515348e-05,  2.67757674e-05,
+///          1.00000000e+00, -1.79912022e+00,  8.16257861e-01],
+///        [ 1.00000000e+00,  2.00000000e+00,  1.00000000e+00,
+///          1.00000000e+00, -1.87747699e+00,  9.09430241e-01],
+///        [ 1.00000000e+00, -2.00000000e+00,  1.00000000e+00,
+///          1.00000000e+00, -1.92379599e+00,  9.26379467e-01],
+///        [ 1.00000000e+00, -2.00000000e+00,  1.00000000e+00,
+///          1.00000000e+00, -1.97849731e+00,  9.79989489e-01]])
+/// ```
+/// Sci-rs:
+/// ```
+/// use sci_rs::signal::filter::design::{
+///     butter_dyn, DigitalFilter, FilterBandType, FilterOutputType, Sos, SosFormatFilter,
+/// };
+///
+/// let filter = butter_dyn(
+///     4,
+///     vec![10., 50.],
+///     Some(FilterBandType::Bandpass),
+///     None,
+///     Some(FilterOutputType::Sos),
+///     Some(1666.),
+/// );
+/// let expected = DigitalFilter::Sos(SosFormatFilter {
+///     sos: vec![
+///         Sos::new([2.67757674e-05, 5.35515348e-05, 2.67757674e-05], [1., -1.79912022, 0.816257861]),
+///         Sos::new([1., 2., 1.], [1., -1.87747699, 0.909430241]),
+///         Sos::new([1., -2., 1.], [1., -1.92379599, 0.926379467]),
+///         Sos::new([1., -2., 1.], [1., -1.97849731, 0.979989489]),
+///     ],
+/// });
+/// assert!(filter.approx_eq(&expected, 1e-7));
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.butter.html>
 #[cfg(feature = "alloc")]
 pub fn butter_dyn<F>(
     order: usize,
@@ -142,9 +213,7 @@ mod tests {
                 ];
 
                 assert_eq!(expected_sos.len(), sos.sos.len());
-                for i in 0..sos.sos.len() {
-                    let actual = sos.sos[i];
-                    let expected = expected_sos[i];
+                for (actual, expected) in sos.sos.iter().zip(&expected_sos) {
                     assert_relative_eq!(actual.b[0], expected.b[0], max_relative = 1e-7);
                     assert_relative_eq!(actual.b[1], expected.b[1], max_relative = 1e-7);
                     assert_relative_eq!(actual.b[2], expected.b[2], max_relative = 1e-7);
@@ -183,9 +252,7 @@ mod tests {
                 ];
 
                 assert_eq!(expected_sos.len(), sos.sos.len());
-                for i in 0..sos.sos.len() {
-                    let actual = sos.sos[i];
-                    let expected = expected_sos[i];
+                for (actual, expected) in sos.sos.iter().zip(&expected_sos) {
                     assert_relative_eq!(actual.b[0], expected.b[0], max_relative = 1e-6);
                     assert_relative_eq!(actual.b[1], expected.b[1], max_relative = 1e-6);
                     assert_relative_eq!(actual.b[2], expected.b[2], max_relative = 1e-6);
@@ -206,7 +206,7 @@ mod tests {
         ];
 
         let expected_zc: Vec<Complex<f64>> = Vec::new();
-        let expected_zr = vec![
+        let expected_zr = [
             Complex::new(1., 0.),
             Complex::new(1., 0.),
             Complex::new(1., 0.),
@@ -236,7 +236,7 @@ mod tests {
             Complex::new(0.93873849, -0.16792939),
         ];
 
-        let expected_zc = vec![
+        let expected_zc = [
             Complex::new(0.89956011, 0.08396115),
             Complex::new(0.93873849, 0.16792939),
             Complex::new(0.96189799, 0.03364097),
@@ -0,0 +1,398 @@
+use core::iter::Sum;
+
+use nalgebra::RealField;
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use super::{
+    iirfilter_dyn, try_iirfilter_dyn, BesselThomsonNorm, DesignedFilter, FilterBandType,
+    FilterOutputType, FilterType,
+};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
+/// Builder for IIR filters, designed with [try_iirfilter_dyn].
+///
+/// Each option is set by a named method instead of a positional argument, and inconsistent
+/// options are reported as errors by [FilterBuilder::design] rather than panicking. Options
+/// which are not set take the same defaults as `scipy.signal.iirfilter`, except that the band
+/// type is lowpass unless otherwise specified, as in `scipy.signal.butter`.
+///
+/// # Example
+/// ```
+/// use sci_rs::signal::filter::design::{iirfilter_dyn, FilterBuilder, FilterBandType,
+///     FilterOutputType, FilterType};
+///
+/// let filter = FilterBuilder::<f64>::butterworth()
+///     .order(4)
+///     .bandpass(10., 50.)
+///     .fs(1666.)
+///     .sos()
+///     .design()
+///     .unwrap();
+///
+/// let expected = iirfilter_dyn(
+///     4,
+///     vec![10., 50.],
+///     None,
+///     None,
+///     Some(FilterBandType::Bandpass),
+///     Some(FilterType::Butterworth),
+///     Some(false),
+///     Some(FilterOutputType::Sos),
+///     Some(1666.),
+/// );
+/// assert!(filter.filter().approx_eq(&expected, 1e-12));
+/// assert_eq!(filter.btype(), FilterBandType::Bandpass);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterBuilder<F> {
+    ftype: FilterType,
+    rp: Option<F>,
+    rs: Option<F>,
+    order: Option<usize>,
+    btype: Option<FilterBandType>,
+    wn: Option<Vec<F>>,
+    analog: bool,
+    output: Option<FilterOutputType>,
+    fs: Option<F>,
+    /// The first option that was set more than once, reported on design.
+    conflict: Option<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F> FilterBuilder<F>
+where
+    F: RealField + Float + Sum,
+{
+    fn new(ftype: FilterType, rp: Option<F>, rs: Option<F>) -> Self {
+        FilterBuilder {
+            ftype,
+            rp,
+            rs,
+            order: None,
+            btype: None,
+            wn: None,
+            analog: false,
+            output: None,
+            fs: None,
+            conflict: None,
+        }
+    }
+
+    /// Design a Butterworth filter.
+    pub fn butterworth() -> Self {
+        Self::new(FilterType::Butterworth, None, None)
+    }
+
+    /// Design a Chebyshev type I filter with `rp` decibels of ripple in the passband.
+    pub fn chebyshev1(rp: F) -> Self {
+        Self::new(FilterType::ChebyshevI, Some(rp), None)
+    }
+
+    /// Design a Chebyshev type II filter with at least `rs` decibels of attenuation in the
+    /// stopband.
+    pub fn chebyshev2(rs: F) -> Self {
+        Self::new(FilterType::ChebyshevII, None, Some(rs))
+    }
+
+    /// Design an elliptic (Cauer) filter with `rp` decibels of ripple in the passband and at
+    /// least `rs` decibels of attenuation in the stopband.
+    pub fn elliptic(rp: F, rs: F) -> Self {
+        Self::new(FilterType::CauerElliptic, Some(rp), Some(rs))
+    }
+
+    /// Design a Bessel/Thomson filter with the given critical frequency normalization.
+    pub fn bessel(norm: BesselThomsonNorm) -> Self {
+        Self::new(FilterType::BesselThomson(norm), None, None)
+    }
+
+    /// Set the order of the filter.
+    pub fn order(mut self, order: usize) -> Self {
+        self.order = self.set_once(self.order, order, "order");
+        self
+    }
+
+    /// Design a lowpass filter with critical frequency `wn`.
+    pub fn lowpass(self, wn: F) -> Self {
+        self.btype(FilterBandType::Lowpass).cutoff(vec![wn])
+    }
+
+    /// Design a highpass filter with critical frequency `wn`.
+    pub fn highpass(self, wn: F) -> Self {
+        self.btype(FilterBandType::Highpass).cutoff(vec![wn])
+    }
+
+    /// Design a bandpass filter passing frequencies between `low` and `high`.
+    pub fn bandpass(self, low: F, high: F) -> Self {
+        self.btype(FilterBandType::Bandpass).cutoff(vec![low, high])
+    }
+
+    /// Design a bandstop filter rejecting frequencies between `low` and `high`.
+    pub fn bandstop(self, low: F, high: F) -> Self {
+        self.btype(FilterBandType::Bandstop).cutoff(vec![low, high])
+    }
+
+    /// Set the band type of the filter, for use with [FilterBuilder::cutoff].
+    pub fn btype(mut self, btype: FilterBandType) -> Self {
+        self.btype = self.set_once(self.btype, btype, "band type");
+        self
+    }
+
+    /// Set the critical frequencies of the filter, one for lowpass and highpass filters and two
+    /// for bandpass and bandstop filters.
+    ///
+    /// For digital filters, these are in the same units as [FilterBuilder::fs], or normalized
+    /// from 0 to 1 where 1 is the Nyquist frequency if no sampling frequency is set. For analog
+    /// filters, these are angular frequencies (e.g. rad/s).
+    pub fn cutoff(mut self, wn: Vec<F>) -> Self {
+        let current = self.wn.take();
+        self.wn = self.set_once(current, wn, "cutoff frequencies");
+        self
+    }
+
+    /// Design an analog filter instead of a digital one.
+    pub fn analog(mut self) -> Self {
+        self.analog = true;
+        self
+    }
+
+    /// Set the sampling frequency of the digital filter.
+    pub fn fs(mut self, fs: F) -> Self {
+        self.fs = self.set_once(self.fs, fs, "sampling frequency");
+        self
+    }
+
+    /// Set the representation of the designed filter.
+    pub fn output(mut self, output: FilterOutputType) -> Self {
+        self.output = self.set_once(self.output, output, "output type");
+        self
+    }
+
+    /// Return the filter as numerator and denominator polynomials.
+    pub fn ba(self) -> Self {
+        self.output(FilterOutputType::Ba)
+    }
+
+    /// Return the filter as zeros, poles and gain.
+    pub fn zpk(self) -> Self {
+        self.output(FilterOutputType::Zpk)
+    }
+
+    /// Return the filter as second order sections.
+    pub fn sos(self) -> Self {
+        self.output(FilterOutputType::Sos)
+    }
+
+    /// Design the filter, which is returned along with its [FilterType] and [FilterBandType].
+    ///
+    /// # Errors
+    /// - [Error::ConflictArg] if an option was set more than once, or if a sampling frequency
+    ///   was given for an analog filter.
+    /// - [Error::InvalidArg] if the order or cutoff frequencies are missing, if the number of
+    ///   cutoff frequencies does not suit the band type, if the cutoff frequencies are not
+    ///   increasing and within `(0, fs/2)` (or positive, for analog filters), or if the ripple
+    ///   or attenuation is negative, as checked by [try_iirfilter_dyn].
+    pub fn design(self) -> Result<DesignedFilter<F>> {
+        if let Some(reason) = self.conflict {
+            return Err(Error::ConflictArg { reason });
+        }
+        let order = self.order.ok_or_else(|| Error::InvalidArg {
+            arg: "order".into(),
+            reason: "The order of the filter must be set.".into(),
+        })?;
+        let wn = self.wn.ok_or_else(|| Error::InvalidArg {
+            arg: "wn".into(),
+            reason: "The cutoff frequencies of the filter must be set.".into(),
+        })?;
+
+        let btype = self.btype.unwrap_or(FilterBandType::Lowpass);
+        let expected = match btype {
+            FilterBandType::Lowpass | FilterBandType::Highpass => 1,
+            FilterBandType::Bandpass | FilterBandType::Bandstop => 2,
+        };
+        if wn.len() != expected {
+            return Err(Error::InvalidArg {
+                arg: "wn".into(),
+                reason: format!(
+                    "A {btype:?} filter needs {expected} cutoff frequencies, but {} were given.",
+                    wn.len()
+                ),
+            });
+        }
+
+        let filter = try_iirfilter_dyn(
+            order,
+            wn,
+            self.rp,
+            self.rs,
+            Some(btype),
+            Some(self.ftype),
+            Some(self.analog),
+            self.output,
+            self.fs,
+        )?;
+        Ok(DesignedFilter::new(filter, self.ftype, btype))
+    }
+
+    /// Returns `value` to store for an option currently holding `current`, noting a conflict if
+    /// the option was already set.
+    fn set_once<T>(&mut self, current: Option<T>, value: T, name: &str) -> Option<T> {
+        if current.is_some() && self.conflict.is_none() {
+            self.conflict = Some(format!("The {name} of the filter was set more than once."));
+        }
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{cheby1_dyn, ellip_dyn, DigitalFilter};
+
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    #[test]
+    fn matches_iirfilter() {
+        let filter = FilterBuilder::<f64>::butterworth()
+            .order(4)
+            .bandpass(10., 50.)
+            .fs(1666.)
+            .sos()
+            .design()
+            .unwrap();
+        let expected = iirfilter_dyn(
+            4,
+            vec![10., 50.],
+            None,
+            None,
+            Some(FilterBandType::Bandpass),
+            Some(FilterType::Butterworth),
+            Some(false),
+            Some(FilterOutputType::Sos),
+            Some(1666.),
+        );
+        assert!(filter.filter().approx_eq(&expected, 0.), "{filter:?}");
+
+        let filter = FilterBuilder::chebyshev1(2.)
+            .order(5)
+            .cutoff(vec![0.3])
+            .btype(FilterBandType::Highpass)
+            .zpk()
+            .design()
+            .unwrap();
+        let expected = cheby1_dyn(
+            5,
+            2.,
+            vec![0.3],
+            Some(FilterBandType::Highpass),
+            None,
+            Some(FilterOutputType::Zpk),
+            None,
+        );
+        assert!(filter.filter().approx_eq(&expected, 0.), "{filter:?}");
+    }
+
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    #[test]
+    fn defaults_to_lowpass_ba() {
+        let filter = FilterBuilder::<f64>::bessel(BesselThomsonNorm::Mag)
+            .order(3)
+            .cutoff(vec![2.])
+            .analog()
+            .design()
+            .unwrap();
+        let expected = iirfilter_dyn(
+            3,
+            vec![2.],
+            None,
+            None,
+            Some(FilterBandType::Lowpass),
+            Some(FilterType::BesselThomson(BesselThomsonNorm::Mag)),
+            Some(true),
+            Some(FilterOutputType::Ba),
+            None,
+        );
+        assert!(matches!(filter.filter(), DigitalFilter::Ba(_)));
+        assert!(filter.filter().approx_eq(&expected, 0.), "{filter:?}");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rejects_conflicting_options() {
+        let conflict = |builder: FilterBuilder<f64>| {
+            matches!(builder.design(), Err(Error::ConflictArg { .. }))
+        };
+        let butter = || FilterBuilder::<f64>::butterworth().order(4);
+
+        assert!(conflict(butter().order(5).lowpass(0.2)));
+        assert!(conflict(butter().lowpass(0.2).highpass(0.3)));
+        assert!(conflict(butter().bandpass(0.2, 0.3).cutoff(vec![0.2, 0.4])));
+        assert!(conflict(butter().lowpass(0.2).ba().sos()));
+        assert!(conflict(butter().lowpass(0.2).fs(10.).fs(20.)));
+        assert!(conflict(butter().lowpass(2.).analog().fs(10.)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rejects_invalid_options() {
+        let invalid = |builder: FilterBuilder<f64>, expected_arg: &str| match builder.design() {
+            Err(Error::InvalidArg { arg, .. }) => arg == expected_arg,
+            _ => false,
+        };
+        let butter = || FilterBuilder::<f64>::butterworth();
+
+        assert!(invalid(butter().lowpass(0.2), "order"));
+        assert!(invalid(butter().order(4), "wn"));
+        assert!(invalid(
+            butter()
+                .order(4)
+                .btype(FilterBandType::Bandpass)
+                .cutoff(vec![0.2]),
+            "wn"
+        ));
+        assert!(invalid(butter().order(4).cutoff(vec![0.2, 0.3]), "wn"));
+        assert!(invalid(butter().order(4).bandstop(0.3, 0.2), "wn"));
+        assert!(invalid(butter().order(4).lowpass(0.), "wn"));
+        assert!(invalid(butter().order(4).lowpass(1.), "wn"));
+        assert!(invalid(butter().order(4).lowpass(500.).fs(1000.), "wn"));
+
+        // Errors of the design itself are returned rather than panicking.
+        assert!(invalid(
+            FilterBuilder::chebyshev1(-1.).order(4).lowpass(0.2),
+            "rp"
+        ));
+        assert!(invalid(
+            FilterBuilder::chebyshev2(-1.).order(4).lowpass(0.2),
+            "rs"
+        ));
+        assert!(invalid(
+            FilterBuilder::elliptic(0., 40.).order(4).lowpass(0.2),
+            "rp"
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn designs_elliptic() {
+        let filter = FilterBuilder::elliptic(1., 40.)
+            .order(4)
+            .lowpass(0.2)
+            .sos()
+            .design()
+            .unwrap();
+        let expected = ellip_dyn(
+            4,
+            1.,
+            40.,
+            vec![0.2],
+            Some(FilterBandType::Lowpass),
+            None,
+            Some(FilterOutputType::Sos),
+            None,
+        );
+        assert!(filter.filter().approx_eq(&expected, 0.), "{filter:?}");
+    }
+}
@@ -1,6 +1,15 @@
-use nalgebra::{Complex, RealField};
+use nalgebra::{Complex, ComplexField, RealField};
+use num_traits::Float;
 
-use super::Sos;
+use super::{FilterBandType, FilterType, Sos};
+
+#[cfg(feature = "alloc")]
+use super::{
+    freqz::{freqz_w_dyn, rad_per_sample_to_fs},
+    freqz_dyn,
+    sos::approx_eq_real,
+    sosfreqz_dyn,
+};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -61,6 +70,18 @@ pub enum DigitalFilter<F: RealField + Copy + Sized> {
     Sos(SosFormatFilter<F>),
 }
 
+/// A [DigitalFilter] together with the filter and band types it was designed with.
+///
+/// Returned by [FilterBuilder::design](super::FilterBuilder::design), so that the design can be
+/// queried after the fact, e.g. for logging.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct DesignedFilter<F: RealField + Copy> {
+    filter: DigitalFilter<F>,
+    ftype: FilterType,
+    btype: FilterBandType,
+}
+
 #[cfg(feature = "alloc")]
 impl<F: RealField + Copy> ZpkFormatFilter<F> {
     /// Create a ZpkFormatFilter
@@ -72,6 +93,185 @@ impl<F: RealField + Copy> ZpkFormatFilter<F> {
     pub fn new(z: Vec<Complex<F>>, p: Vec<Complex<F>>, k: F) -> Self {
         ZpkFormatFilter { z, p, k }
     }
+
+    /// Whether the zeros, poles and gain of `self` and `other` agree to within `tol`.
+    ///
+    /// Zeros and poles are compared in order, so both filters must list them in the same order,
+    /// as scipy's design functions do. Each pair of roots `x`, `y` must satisfy
+    /// `|x - y| <= tol * max(1, |x|, |y|)`. The gains, which commonly span many orders of
+    /// magnitude, are compared relatively, with `|k1 - k2| <= tol * max(|k1|, |k2|)`.
+    pub fn approx_eq(&self, other: &ZpkFormatFilter<F>, tol: F) -> bool {
+        let roots_eq = |x: &[Complex<F>], y: &[Complex<F>]| {
+            x.len() == y.len()
+                && x.iter().zip(y).all(|(xi, yi)| {
+                    let scale = F::one().max(xi.modulus()).max(yi.modulus());
+                    (xi - yi).modulus() <= tol * scale
+                })
+        };
+        roots_eq(&self.z, &other.z)
+            && roots_eq(&self.p, &other.p)
+            && (self.k - other.k).abs() <= tol * self.k.abs().max(other.k.abs())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: RealField + Copy> BaFormatFilter<F> {
+    /// Whether the coefficients of `self` and `other` agree to within `tol`.
+    ///
+    /// Each pair of coefficients `x`, `y` must satisfy `|x - y| <= tol * max(1, |x|, |y|)`.
+    pub fn approx_eq(&self, other: &BaFormatFilter<F>, tol: F) -> bool {
+        let coeffs_eq = |x: &[F], y: &[F]| {
+            x.len() == y.len()
+                && x.iter()
+                    .zip(y)
+                    .all(|(&xi, &yi)| approx_eq_real(xi, yi, tol))
+        };
+        coeffs_eq(&self.b, &other.b) && coeffs_eq(&self.a, &other.a)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: RealField + Copy> SosFormatFilter<F> {
+    /// Whether each section of `self` and `other` agree to within `tol`, as per [Sos::approx_eq].
+    pub fn approx_eq(&self, other: &SosFormatFilter<F>, tol: F) -> bool {
+        self.sos.len() == other.sos.len()
+            && self
+                .sos
+                .iter()
+                .zip(&other.sos)
+                .all(|(x, y)| x.approx_eq(y, tol))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: RealField + Copy> DigitalFilter<F> {
+    /// Whether `self` and `other` are the same filter to within `tol`.
+    ///
+    /// Both filters must be in the same representation, which are then compared coefficient by
+    /// coefficient as per [BaFormatFilter::approx_eq], [ZpkFormatFilter::approx_eq] and
+    /// [SosFormatFilter::approx_eq]. Filters in different representations are never equal, even
+    /// if their responses are.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::signal::filter::design::{
+    ///     butter_dyn, DigitalFilter, FilterBandType, FilterOutputType, Sos, SosFormatFilter,
+    /// };
+    ///
+    /// // from scipy.signal import butter
+    /// // butter(2, 0.5, output='sos')
+    /// let expected = DigitalFilter::Sos(SosFormatFilter {
+    ///     sos: vec![Sos::new([0.29289322, 0.58578644, 0.29289322], [1., 0., 0.17157288])],
+    /// });
+    /// let filter = butter_dyn(
+    ///     2,
+    ///     vec![0.5],
+    ///     Some(FilterBandType::Lowpass),
+    ///     None,
+    ///     Some(FilterOutputType::Sos),
+    ///     None,
+    /// );
+    /// assert!(filter.approx_eq(&expected, 1e-7));
+    /// ```
+    pub fn approx_eq(&self, other: &DigitalFilter<F>, tol: F) -> bool {
+        match (self, other) {
+            (DigitalFilter::Ba(x), DigitalFilter::Ba(y)) => x.approx_eq(y, tol),
+            (DigitalFilter::Zpk(x), DigitalFilter::Zpk(y)) => x.approx_eq(y, tol),
+            (DigitalFilter::Sos(x), DigitalFilter::Sos(y)) => x.approx_eq(y, tol),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: RealField + Float> DigitalFilter<F> {
+    /// The order of the filter, being the number of poles or zeros, whichever is greater.
+    ///
+    /// Note that band-pass and band-stop designs have twice the order that was requested from
+    /// [super::iirfilter_dyn], as with scipy.
+    pub fn order(&self) -> usize {
+        let degree = |c: &[F]| c.iter().rposition(|ci| !ci.is_zero()).unwrap_or(0);
+        match self {
+            DigitalFilter::Ba(ba) => degree(&ba.b).max(degree(&ba.a)),
+            DigitalFilter::Zpk(zpk) => zpk.z.len().max(zpk.p.len()),
+            DigitalFilter::Sos(sos) => {
+                // Zeros and poles need not be paired within each section.
+                let zeros: usize = sos.sos.iter().map(|s| degree(&s.b)).sum();
+                let poles: usize = sos.sos.iter().map(|s| degree(&s.a)).sum();
+                zeros.max(poles)
+            }
+        }
+    }
+
+    /// The frequency response of the filter, as per [freqz_dyn] and [sosfreqz_dyn] in the
+    /// respective representations.
+    pub(crate) fn response_dyn(
+        &self,
+        wor_n: usize,
+        whole: bool,
+        fs: Option<F>,
+    ) -> (Vec<F>, Vec<Complex<F>>) {
+        match self {
+            DigitalFilter::Ba(ba) => freqz_dyn(&ba.b, &ba.a, wor_n, whole, fs),
+            DigitalFilter::Sos(sos) => sosfreqz_dyn(&sos.sos, wor_n, whole, fs),
+            DigitalFilter::Zpk(zpk) => {
+                // Evaluate the factored form directly rather than expanding it into b/a.
+                let w = freqz_w_dyn::<F>(wor_n, whole);
+                let h = w
+                    .iter()
+                    .map(|&wi| {
+                        let z = Complex::new(Float::cos(wi), Float::sin(wi));
+                        let num = zpk
+                            .z
+                            .iter()
+                            .fold(Complex::from(zpk.k), |acc, zi| acc * (z - zi));
+                        zpk.p.iter().fold(num, |acc, pi| acc / (z - pi))
+                    })
+                    .collect();
+                (rad_per_sample_to_fs(w, fs), h)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: RealField + Copy> DesignedFilter<F> {
+    /// Wrap `filter`, which was designed as a `ftype` filter of band type `btype`.
+    pub(crate) fn new(filter: DigitalFilter<F>, ftype: FilterType, btype: FilterBandType) -> Self {
+        DesignedFilter {
+            filter,
+            ftype,
+            btype,
+        }
+    }
+
+    /// The designed filter.
+    pub fn filter(&self) -> &DigitalFilter<F> {
+        &self.filter
+    }
+
+    /// Unwrap the designed filter.
+    pub fn into_filter(self) -> DigitalFilter<F> {
+        self.filter
+    }
+
+    /// The type of IIR filter that was designed.
+    pub fn ftype(&self) -> FilterType {
+        self.ftype
+    }
+
+    /// The band type of the filter that was designed.
+    pub fn btype(&self) -> FilterBandType {
+        self.btype
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: RealField + Float> DesignedFilter<F> {
+    /// The order of the designed filter, as per [DigitalFilter::order].
+    pub fn order(&self) -> usize {
+        self.filter.order()
+    }
 }
 
 #[cfg(test)]
@@ -97,4 +297,113 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reports_order() {
+        use crate::signal::filter::design::{butter_dyn, FilterOutputType};
+        use alloc::vec;
+
+        for order in [1, 4, 5] {
+            for output in [
+                FilterOutputType::Ba,
+                FilterOutputType::Zpk,
+                FilterOutputType::Sos,
+            ] {
+                let filter = butter_dyn(
+                    order,
+                    vec![0.3],
+                    Some(FilterBandType::Highpass),
+                    None,
+                    Some(output),
+                    None,
+                );
+                assert_eq!(filter.order(), order, "{output:?}");
+            }
+        }
+
+        let allpass = DigitalFilter::Ba(BaFormatFilter {
+            b: vec![-0.5, 1.],
+            a: vec![1., -0.5],
+        });
+        assert_eq!(allpass.order(), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reports_design_types() {
+        use crate::signal::filter::design::{BesselThomsonNorm, FilterBuilder};
+
+        // The types are returned as designed, even where the response would suggest otherwise,
+        // such as for a Chebyshev type I filter whose ripple exceeds 3 dB.
+        let filter = FilterBuilder::<f64>::chebyshev1(6.)
+            .order(4)
+            .highpass(0.3)
+            .sos()
+            .design()
+            .unwrap();
+        assert_eq!(filter.ftype(), FilterType::ChebyshevI);
+        assert_eq!(filter.btype(), FilterBandType::Highpass);
+        assert_eq!(filter.order(), 4);
+
+        let filter = FilterBuilder::<f64>::bessel(BesselThomsonNorm::Delay)
+            .order(3)
+            .bandstop(0.2, 0.4)
+            .ba()
+            .design()
+            .unwrap();
+        assert_eq!(
+            filter.ftype(),
+            FilterType::BesselThomson(BesselThomsonNorm::Delay)
+        );
+        assert_eq!(filter.btype(), FilterBandType::Bandstop);
+        assert_eq!(filter.order(), 6);
+        assert!(matches!(filter.into_filter(), DigitalFilter::Ba(_)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn approx_eq_distinguishes_filters() {
+        use alloc::vec;
+
+        let zpk = |k: f64, p1: Complex<f64>| {
+            DigitalFilter::Zpk(ZpkFormatFilter::new(
+                vec![Complex::new(-1., 0.), Complex::new(-1., 0.)],
+                vec![p1, p1.conj()],
+                k,
+            ))
+        };
+        let p = Complex::new(0.3, 0.4);
+        let filter = zpk(0.2, p);
+        assert!(filter.approx_eq(&zpk(0.2, p), 0.));
+        assert!(filter.approx_eq(&zpk(0.2 + 1e-9, p + Complex::new(0., 1e-9)), 1e-8));
+        assert!(!filter.approx_eq(&zpk(0.2 + 1e-6, p), 1e-8));
+        // Small gains are compared relatively.
+        assert!(!zpk(1e-9, p).approx_eq(&zpk(2e-9, p), 1e-8));
+        assert!(!filter.approx_eq(&zpk(0.2, p + Complex::new(1e-6, 0.)), 1e-8));
+
+        // Differing numbers of roots or coefficients.
+        let fewer = DigitalFilter::Zpk(ZpkFormatFilter::new(vec![], vec![p, p.conj()], 0.2));
+        assert!(!filter.approx_eq(&fewer, 1.));
+        let ba = |b: Vec<f64>| {
+            DigitalFilter::Ba(BaFormatFilter {
+                b,
+                a: vec![1., -0.5],
+            })
+        };
+        assert!(ba(vec![0.25, 0.25]).approx_eq(&ba(vec![0.25, 0.25 + 1e-12]), 1e-10));
+        assert!(!ba(vec![0.25, 0.25]).approx_eq(&ba(vec![0.25, 0.25, 0.]), 1e-10));
+
+        // Differing representations.
+        let sos = DigitalFilter::Sos(SosFormatFilter {
+            sos: vec![Sos::new([0.2, 0.4, 0.2], [1., -0.6, 0.25])],
+        });
+        assert!(sos.approx_eq(&sos, 0.));
+        assert!(!sos.approx_eq(&filter, 1.));
+        let other = DigitalFilter::Sos(SosFormatFilter {
+            sos: vec![Sos::new([0.2, 0.4, 0.2], [1., -0.6, 0.26])],
+        });
+        assert!(!sos.approx_eq(&other, 1e-3));
+        assert!(sos.approx_eq(&other, 1e-1));
+    }
 }
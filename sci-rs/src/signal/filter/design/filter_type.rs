@@ -1,4 +1,8 @@
+use core::{fmt, str::FromStr};
+use sci_rs_core::Error;
+
 /// Type of IIR filter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FilterType {
     /// Butterworth
     /// <https://en.wikipedia.org/wiki/Butterworth_filter>
@@ -17,9 +21,21 @@ pub enum FilterType {
     BesselThomson(BesselThomsonNorm),
 }
 
+impl FilterType {
+    /// Bessel/Thomson filter with the default [BesselThomsonNorm::Phase] normalization, as per
+    /// `scipy.signal.bessel(..., norm='phase')`.
+    pub fn bessel() -> Self {
+        FilterType::BesselThomson(BesselThomsonNorm::default())
+    }
+}
+
 /// Bessel-Thomson filter normalization
+///
+/// Defaults to [BesselThomsonNorm::Phase], matching scipy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum BesselThomsonNorm {
     /// Phase
+    #[default]
     Phase,
     /// Delay
     Delay,
@@ -43,3 +59,167 @@ pub enum FilterBandType {
     /// (double-sided)
     Bandstop,
 }
+
+/// Error for a filter or band type name that is not known to scipy.
+fn unknown_name(arg: &str, name: &str) -> Error {
+    #[cfg(feature = "alloc")]
+    {
+        Error::InvalidArg {
+            arg: arg.into(),
+            reason: alloc::format!("{name:?} is not a known {arg}."),
+        }
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        let _ = (arg, name);
+        Error::InvalidArg
+    }
+}
+
+/// Parse the `ftype` names of `scipy.signal.iirfilter`, such as `"butter"` or `"ellip"`.
+///
+/// `"bessel"` has the default [BesselThomsonNorm::Phase] normalization, whilst
+/// `"bessel_phase"`, `"bessel_delay"` and `"bessel_mag"` select it explicitly.
+impl FromStr for FilterType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "butter" | "butterworth" => Ok(FilterType::Butterworth),
+            "cheby1" | "chebyshev1" | "chebyI" => Ok(FilterType::ChebyshevI),
+            "cheby2" | "chebyshev2" | "chebyII" => Ok(FilterType::ChebyshevII),
+            "ellip" | "elliptic" | "cauer" => Ok(FilterType::CauerElliptic),
+            "bessel" | "bessel_phase" => Ok(FilterType::BesselThomson(BesselThomsonNorm::Phase)),
+            "bessel_delay" => Ok(FilterType::BesselThomson(BesselThomsonNorm::Delay)),
+            "bessel_mag" => Ok(FilterType::BesselThomson(BesselThomsonNorm::Mag)),
+            _ => Err(unknown_name("ftype", s)),
+        }
+    }
+}
+
+/// Format as the scipy `ftype` name, which parses back into the same [FilterType].
+impl fmt::Display for FilterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FilterType::Butterworth => "butter",
+            FilterType::ChebyshevI => "cheby1",
+            FilterType::ChebyshevII => "cheby2",
+            FilterType::CauerElliptic => "ellip",
+            FilterType::BesselThomson(BesselThomsonNorm::Phase) => "bessel",
+            FilterType::BesselThomson(BesselThomsonNorm::Delay) => "bessel_delay",
+            FilterType::BesselThomson(BesselThomsonNorm::Mag) => "bessel_mag",
+        })
+    }
+}
+
+/// Parse the `btype` names of `scipy.signal.iirfilter`, such as `"bandpass"` or `"lp"`.
+impl FromStr for FilterBandType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lowpass" | "low" | "lp" | "l" => Ok(FilterBandType::Lowpass),
+            "highpass" | "high" | "hp" | "h" => Ok(FilterBandType::Highpass),
+            "bandpass" | "band" | "pass" | "bp" => Ok(FilterBandType::Bandpass),
+            "bandstop" | "stop" | "bs" | "bands" => Ok(FilterBandType::Bandstop),
+            _ => Err(unknown_name("btype", s)),
+        }
+    }
+}
+
+/// Format as the scipy `btype` name, which parses back into the same [FilterBandType].
+impl fmt::Display for FilterBandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FilterBandType::Lowpass => "lowpass",
+            FilterBandType::Highpass => "highpass",
+            FilterBandType::Bandpass => "bandpass",
+            FilterBandType::Bandstop => "bandstop",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn bessel_defaults_to_phase_norm() {
+        assert_eq!(BesselThomsonNorm::default(), BesselThomsonNorm::Phase);
+        assert_eq!(
+            FilterType::bessel(),
+            FilterType::BesselThomson(BesselThomsonNorm::Phase)
+        );
+    }
+
+    #[test]
+    fn filter_type_from_str() {
+        for (name, ftype) in [
+            ("butter", FilterType::Butterworth),
+            ("cheby1", FilterType::ChebyshevI),
+            ("cheby2", FilterType::ChebyshevII),
+            ("ellip", FilterType::CauerElliptic),
+            ("bessel", FilterType::bessel()),
+            (
+                "bessel_delay",
+                FilterType::BesselThomson(BesselThomsonNorm::Delay),
+            ),
+            (
+                "bessel_mag",
+                FilterType::BesselThomson(BesselThomsonNorm::Mag),
+            ),
+        ] {
+            assert_eq!(name.parse::<FilterType>(), Ok(ftype));
+            assert_eq!(ftype.to_string(), name);
+        }
+        assert_eq!("cauer".parse(), Ok(FilterType::CauerElliptic));
+        assert!(matches!(
+            "chebyshev".parse::<FilterType>(),
+            Err(Error::InvalidArg { .. })
+        ));
+        assert!("Butter".parse::<FilterType>().is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn every_parsed_filter_type_designs() {
+        use crate::signal::filter::design::try_iirfilter_dyn;
+        use alloc::vec;
+
+        // Config-driven design, with each family reachable from its name.
+        for name in ["butter", "cheby1", "cheby2", "ellip", "bessel"] {
+            let filter = try_iirfilter_dyn::<f64>(
+                4,
+                vec![0.2, 0.4],
+                Some(1.),
+                Some(40.),
+                Some("bandpass".parse().unwrap()),
+                Some(name.parse().unwrap()),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+            assert_eq!(filter.order(), 8, "{name}");
+        }
+    }
+
+    #[test]
+    fn filter_band_type_from_str() {
+        for (name, btype) in [
+            ("lowpass", FilterBandType::Lowpass),
+            ("highpass", FilterBandType::Highpass),
+            ("bandpass", FilterBandType::Bandpass),
+            ("bandstop", FilterBandType::Bandstop),
+        ] {
+            assert_eq!(name.parse::<FilterBandType>(), Ok(btype));
+            assert_eq!(btype.to_string(), name);
+        }
+        assert_eq!("bp".parse(), Ok(FilterBandType::Bandpass));
+        assert!(matches!(
+            "notch".parse::<FilterBandType>(),
+            Err(Error::InvalidArg { .. })
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+use nalgebra::RealField;
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use super::freqz_dyn;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
+/// Passband ripple, stopband attenuation and transition width of an FIR filter, as measured by
+/// [fir_response_metrics].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FirMetrics<F> {
+    /// Largest deviation of the passband magnitude from unit gain, as `-20 log10(delta)` in dB.
+    /// This is in the same convention as the `ripple` of [super::kaiserord].
+    pub passband_ripple: F,
+    /// Smallest attenuation of the stopband, as `-20 log10(delta)` in dB.
+    pub stopband_attenuation: F,
+    /// Width of the widest transition band, in the same units as `fs`.
+    pub transition_width: F,
+}
+
+/// Measure the passband ripple, stopband attenuation and transition width of an FIR filter.
+///
+/// The magnitude response is computed on a dense grid from DC to Nyquist, and compared against
+/// an ideal response with unit gain in the passbands and zero gain in the stopbands, which are
+/// where the magnitude is above and below one half (-6 dB) respectively. These crossings are the
+/// cutoff frequencies of filters designed by the window method, such as with [super::firwin_dyn].
+///
+/// Around each cutoff, the passband extends up to its first ripple peak and the stopband from
+/// its first null; the largest deviations `delta_p` and `delta_s` from the ideal response are
+/// measured beyond these. The transition band then lies between the passband edge, where the
+/// magnitude is `1 - delta_p`, and the stopband edge, where it is `delta_s`. This is the
+/// definition of the transition width in [super::kaiserord].
+///
+/// # Parameters
+/// * `taps` : array_like
+///   Coefficients of the FIR filter.
+/// * `fs` : `Option<F>`
+///   The sampling frequency of the digital system. Defaults to 2 half-cycles/sample, so that the
+///   transition width is a fraction of the Nyquist frequency, as in [super::kaiserord].
+///
+/// # Returns
+/// * `metrics` : [FirMetrics]
+///
+/// # Errors
+/// [Error::InvalidArg] if the response does not have both a passband and a stopband.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::design::{firwin_dyn, fir_response_metrics, FilterBandType};
+/// use sci_rs::signal::windows::Hamming;
+///
+/// let taps: Vec<f64> = firwin_dyn(
+///     51,
+///     &[0.3f64],
+///     None,
+///     None::<&Hamming>,
+///     &FilterBandType::Lowpass,
+///     None,
+///     None,
+/// )
+/// .unwrap();
+/// let metrics = fir_response_metrics(&taps, None).unwrap();
+/// // A Hamming window has around 53 dB of attenuation.
+/// assert!(metrics.stopband_attenuation > 50.);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn fir_response_metrics<F>(taps: &[F], fs: Option<F>) -> Result<FirMetrics<F>>
+where
+    F: RealField + Float,
+{
+    const WORN: usize = 8192;
+    let fs = fs.unwrap_or_else(|| F::from(2).unwrap());
+    let (w, h) = freqz_dyn(taps, &[F::one()], 2 * WORN, true, Some(fs));
+    // Only the upper half of the unit circle, including Nyquist.
+    let amp: Vec<F> = h.iter().take(WORN + 1).map(|hi| hi.norm()).collect();
+
+    let half = F::from(0.5).unwrap();
+    let passed: Vec<bool> = amp.iter().map(|&a| a >= half).collect();
+    if passed.iter().all(|&p| p) || passed.iter().all(|&p| !p) {
+        return Err(Error::InvalidArg {
+            arg: "taps".into(),
+            reason: "The response must have both a passband and a stopband.".into(),
+        });
+    }
+
+    // For each cutoff between `i` and `i + 1`, find the first ripple peak of the passband and the
+    // first null of the stopband, moving away from the cutoff.
+    let walk = |start: usize, step: isize, rising: bool| {
+        let mut j = start;
+        loop {
+            let Some(next) = j.checked_add_signed(step).filter(|&n| n <= WORN) else {
+                break j;
+            };
+            let moving_on = if rising {
+                amp[next] >= amp[j]
+            } else {
+                amp[next] <= amp[j]
+            };
+            if passed[next] != passed[start] || !moving_on {
+                break j;
+            }
+            j = next;
+        }
+    };
+    let transitions: Vec<(usize, usize)> = (0..WORN)
+        .filter(|&i| passed[i] != passed[i + 1])
+        .map(|i| {
+            if passed[i] {
+                (walk(i, -1, true), walk(i + 1, 1, false))
+            } else {
+                (walk(i + 1, 1, true), walk(i, -1, false))
+            }
+        })
+        .collect();
+
+    let mut excluded = vec![false; WORN + 1];
+    for &(peak, null) in &transitions {
+        let (lo, hi) = (peak.min(null), peak.max(null));
+        excluded[lo + 1..hi].iter_mut().for_each(|e| *e = true);
+    }
+    let (delta_p, delta_s) =
+        (0..=WORN)
+            .filter(|&i| !excluded[i])
+            .fold((F::zero(), F::zero()), |(dp, ds), i| {
+                if passed[i] {
+                    (Float::max(dp, Float::abs(F::one() - amp[i])), ds)
+                } else {
+                    (dp, Float::max(ds, amp[i]))
+                }
+            });
+
+    // The frequency at which the magnitude crosses `level`, moving from `from` towards `to`.
+    let crossing = |from: usize, to: usize, level: F| {
+        let step: isize = if to > from { 1 } else { -1 };
+        let above = amp[from] >= level;
+        let mut j = from;
+        while j != to && (amp[j.wrapping_add_signed(step)] >= level) == above {
+            j = j.wrapping_add_signed(step);
+        }
+        if j == to {
+            return w[to];
+        }
+        let k = j.wrapping_add_signed(step);
+        let t = (amp[j] - level) / (amp[j] - amp[k]);
+        w[j] + t * (w[k] - w[j])
+    };
+    let transition_width = transitions
+        .iter()
+        .map(|&(peak, null)| {
+            let wp = crossing(peak, null, F::one() - delta_p);
+            let ws = crossing(null, peak, delta_s);
+            Float::abs(ws - wp)
+        })
+        .fold(F::zero(), Float::max);
+
+    let db = |delta: F| -F::from(20).unwrap() * Float::log10(delta);
+    Ok(FirMetrics {
+        passband_ripple: db(delta_p),
+        stopband_attenuation: db(delta_s),
+        transition_width,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{firwin_dyn, kaiserord, FilterBandType};
+    use crate::signal::windows::{Hamming, Kaiser};
+    use approx::assert_relative_eq;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn kaiser_lowpass_meets_spec() {
+        // Example of scipy.signal.kaiserord: 65 dB of attenuation, with a transition width of
+        // 24 Hz around a cutoff of 175 Hz, sampled at 1000 Hz.
+        let (fs, cutoff, width, ripple) = (1000., 175., 24., 65.);
+        let (numtaps, beta) = kaiserord(ripple, width / (0.5 * fs));
+        let numtaps = numtaps as usize;
+        let taps: Vec<f64> = firwin_dyn(
+            numtaps,
+            &[cutoff],
+            None,
+            Some(&Kaiser::new(numtaps, beta, true)),
+            &FilterBandType::Lowpass,
+            Some(false),
+            Some(fs),
+        )
+        .unwrap();
+
+        let metrics = fir_response_metrics(&taps, Some(fs)).unwrap();
+        // The first sidelobe violates the spec by about 0.125 dB, as noted by scipy.
+        assert_relative_eq!(metrics.stopband_attenuation, ripple, epsilon = 0.2);
+        assert_relative_eq!(metrics.passband_ripple, ripple, epsilon = 0.2);
+        assert_relative_eq!(metrics.transition_width, width, epsilon = 0.5);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bandpass_and_degenerate_responses() {
+        let taps: Vec<f64> = firwin_dyn(
+            101,
+            &[0.3, 0.5],
+            None,
+            None::<&Hamming>,
+            &FilterBandType::Bandpass,
+            None,
+            None,
+        )
+        .unwrap();
+        let metrics = fir_response_metrics(&taps, None).unwrap();
+        assert!(metrics.stopband_attenuation > 50.);
+        // The ripple of both transitions overlaps in the narrow passband.
+        assert!(metrics.passband_ripple > 45.);
+        // The Hamming window has a transition width of about 3.3 / numtaps of the sample rate.
+        assert_relative_eq!(metrics.transition_width, 2. * 3.3 / 101., epsilon = 0.01);
+
+        assert!(fir_response_metrics(&[1.], None).is_err());
+        assert!(fir_response_metrics(&[0.], None).is_err());
+    }
+}
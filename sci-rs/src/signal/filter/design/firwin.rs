@@ -146,10 +146,14 @@ fn firwin_dyn_validate<F: Real + PartialOrd, W: Real>(
 ///   Defaults to Hamming.
 ///   Please set `sym=True` if you wish to have similar behaviour to scipy.
 /// * `pass_zero` : [FilterBandType], optional  
-///   If True, the gain at the frequency 0 (i.e., the "DC gain") is 1.  
-///   If False, the DC gain is
-///   0. Can also be a string argument for the desired filter type (equivalent to ``btype`` in
-///   [IIR design functions](iirfilter_dyn)).
+///   The desired filter type, as for ``btype`` in [IIR design functions](iirfilter_dyn). This is
+///   the string form of scipy's `pass_zero`, which also accepts booleans:
+///     * [FilterBandType::Lowpass] and [FilterBandType::Bandstop] are `pass_zero=True`, so the
+///       gain at the frequency 0 (i.e., the "DC gain") is 1. `cutoff` must have exactly one
+///       element for `Lowpass`, and at least two for `Bandstop`.
+///     * [FilterBandType::Highpass] and [FilterBandType::Bandpass] are `pass_zero=False`, so the
+///       DC gain is 0. `cutoff` must have exactly one element for `Highpass`, and at least two
+///       for `Bandpass`.
 ///
 /// * `scale` : bool, optional  
 ///   Set to True to scale the coefficients so that the frequency response is exactly unity at a
@@ -431,7 +435,7 @@ pub fn firwin_dyn<F, W>(
     cutoff: &[F],
     width: Option<F>,
     window: Option<&impl GetWindow<W>>,
-    pass_zero: &FilterBandType, // Union with bools to follow scipy?
+    pass_zero: &FilterBandType,
     scale: Option<bool>,
     fs: Option<F>,
 ) -> Result<Vec<W>, Error>
@@ -0,0 +1,706 @@
+use nalgebra::{Complex, RealField};
+use num_traits::{Float, Zero};
+
+#[cfg(feature = "alloc")]
+use super::{DigitalFilter, Sos};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Compute the frequency response of a digital filter.
+///
+/// Given the M-order numerator `b` and N-order denominator `a` of a digital filter, compute its
+/// frequency response:
+///
+/// ```text
+///             jw                 -jw              -jwM
+///    jw    B(e  )    b[0] + b[1]e    + ... + b[M]e
+/// H(e  ) = ------ = -----------------------------------
+///             jw                 -jw              -jwN
+///          A(e  )    a[0] + a[1]e    + ... + a[N]e
+/// ```
+///
+/// # Parameters
+/// * `b` : array_like
+///   Numerator of a linear filter.
+/// * `a` : array_like
+///   Denominator of a linear filter.
+/// * `wor_n` : int
+///   Compute at that many frequencies, equally spaced around the unit circle (`worN` in scipy).
+/// * `whole` : bool
+///   Normally, frequencies are computed from 0 to the Nyquist frequency, fs/2 (upper-half of
+///   unit-circle). If `whole` is true, compute frequencies from 0 to fs.
+/// * `fs` : `Option<F>`
+///   The sampling frequency of the digital system. Defaults to `2*pi` radians/sample (so `w` is
+///   from 0 to pi).
+///
+/// # Returns
+/// * `w` : ndarray
+///   The frequencies at which `h` was computed, **in the same units as `fs`**. That is, when
+///   `fs` is `None`, `w` is in radians/sample and spans `[0, pi)` (or `[0, 2*pi)` if `whole`).
+///   When `fs` is given, `w` is in Hz (or whatever unit `fs` carries) and spans `[0, fs/2)` (or
+///   `[0, fs)` if `whole`).
+/// * `h` : ndarray
+///   The frequency response, as complex numbers.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::design::freqz_dyn;
+///
+/// let b = [0.5, 0.5];
+/// let a = [1.];
+///
+/// // Radians/sample
+/// let (w, h) = freqz_dyn(&b, &a, 4, false, None);
+/// assert!((w[2] - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+///
+/// // Hz
+/// let (w, h) = freqz_dyn(&b, &a, 4, false, Some(1000.));
+/// assert!((w[2] - 250.).abs() < 1e-12);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.freqz.html>
+#[cfg(feature = "alloc")]
+pub fn freqz_dyn<F>(
+    b: &[F],
+    a: &[F],
+    wor_n: usize,
+    whole: bool,
+    fs: Option<F>,
+) -> (Vec<F>, Vec<Complex<F>>)
+where
+    F: RealField + Float,
+{
+    let w = freqz_w_dyn::<F>(wor_n, whole);
+    let h = w
+        .iter()
+        .map(|&wi| {
+            let zm1 = Complex::new(Float::cos(wi), -Float::sin(wi));
+            polyval_zm1(b, zm1) / polyval_zm1(a, zm1)
+        })
+        .collect();
+
+    (rad_per_sample_to_fs(w, fs), h)
+}
+
+/// Compute the frequency response of a digital filter in SOS format.
+///
+/// The response of the cascade is the product of the response of each second order section,
+/// which avoids the numerical issues of expanding the sections into a single transfer function
+/// first.
+///
+/// # Parameters
+/// * `sos` : array_like
+///   Array of second-order filter coefficients.
+/// * `wor_n` : int
+///   Compute at that many frequencies, equally spaced around the unit circle (`worN` in scipy).
+/// * `whole` : bool
+///   Normally, frequencies are computed from 0 to the Nyquist frequency, fs/2 (upper-half of
+///   unit-circle). If `whole` is true, compute frequencies from 0 to fs.
+/// * `fs` : `Option<F>`
+///   The sampling frequency of the digital system. Defaults to `2*pi` radians/sample (so `w` is
+///   from 0 to pi).
+///
+/// # Returns
+/// * `w` : ndarray
+///   The frequencies at which `h` was computed, in the same units as `fs`. As per [freqz_dyn],
+///   this is radians/sample when `fs` is `None`.
+/// * `h` : ndarray
+///   The frequency response, as complex numbers.
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.sosfreqz.html>
+#[cfg(feature = "alloc")]
+pub fn sosfreqz_dyn<F>(
+    sos: &[Sos<F>],
+    wor_n: usize,
+    whole: bool,
+    fs: Option<F>,
+) -> (Vec<F>, Vec<Complex<F>>)
+where
+    F: RealField + Float,
+{
+    let w = freqz_w_dyn::<F>(wor_n, whole);
+    let h = w
+        .iter()
+        .map(|&wi| {
+            let zm1 = Complex::new(Float::cos(wi), -Float::sin(wi));
+            sos.iter()
+                .map(|s| polyval_zm1(&s.b, zm1) / polyval_zm1(&s.a, zm1))
+                .fold(Complex::new(F::one(), F::zero()), |acc, hi| acc * hi)
+        })
+        .collect();
+
+    (rad_per_sample_to_fs(w, fs), h)
+}
+
+/// Compute the group delay of a digital filter.
+///
+/// The group delay measures by how many samples amplitude envelopes of various spectral
+/// components of a signal are delayed by a filter. It is formally defined as the derivative of
+/// continuous (unwrapped) phase:
+///
+/// ```text
+///           d        jw
+/// D(w) = - -- arg H(e)
+///          dw
+/// ```
+///
+/// # Parameters
+/// * `b` : array_like
+///   Numerator of a linear filter.
+/// * `a` : array_like
+///   Denominator of a linear filter.
+/// * `wor_n` : int
+///   Compute at that many frequencies, equally spaced around the unit circle (`w` in scipy).
+/// * `whole` : bool
+///   Normally, frequencies are computed from 0 to the Nyquist frequency, fs/2 (upper-half of
+///   unit-circle). If `whole` is true, compute frequencies from 0 to fs.
+/// * `fs` : `Option<F>`
+///   The sampling frequency of the digital system. Defaults to `2*pi` radians/sample (so `w` is
+///   from 0 to pi).
+///
+/// # Returns
+/// * `w` : ndarray
+///   The frequencies at which group delay was computed, in the same units as `fs`.
+/// * `gd` : ndarray
+///   The group delay, in samples.
+///
+/// # Notes
+/// The group delay is computed as the ratio of two polynomials in `e^-jw`, which are both zero
+/// where the filter has a zero on the unit circle, such as at the null of a notch filter. Unlike
+/// scipy, which sets these points to zero, the group delay there is interpolated from the
+/// nearest well-defined neighbours, which is the limit of the group delay for a smooth response.
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.group_delay.html>
+#[cfg(feature = "alloc")]
+pub fn group_delay_dyn<F>(
+    b: &[F],
+    a: &[F],
+    wor_n: usize,
+    whole: bool,
+    fs: Option<F>,
+) -> (Vec<F>, Vec<F>)
+where
+    F: RealField + Float,
+{
+    // c = convolve(b, a[::-1]), with cr its derivative with respect to z^-1 scaled by z^-1.
+    let mut c = alloc::vec![F::zero(); b.len() + a.len() - 1];
+    for (i, &bi) in b.iter().enumerate() {
+        for (j, &aj) in a.iter().rev().enumerate() {
+            c[i + j] += bi * aj;
+        }
+    }
+    let cr: Vec<F> = c
+        .iter()
+        .enumerate()
+        .map(|(k, &ck)| ck * F::from(k).unwrap())
+        .collect();
+
+    let w = freqz_w_dyn::<F>(wor_n, whole);
+    let offset = F::from(a.len() - 1).unwrap();
+    let threshold = F::from(10.).unwrap() * F::epsilon();
+    let gd: Vec<Option<F>> = w
+        .iter()
+        .map(|&wi| {
+            let zm1 = Complex::new(Float::cos(wi), -Float::sin(wi));
+            let den = polyval_zm1(&c, zm1);
+            if den.norm_sqr() < threshold * threshold {
+                return None;
+            }
+            let gd = (polyval_zm1(&cr, zm1) / den).re - offset;
+            Float::is_finite(gd).then_some(gd)
+        })
+        .collect();
+
+    let gd = (0..gd.len())
+        .map(|i| {
+            gd[i].unwrap_or_else(|| {
+                let prev = gd[..i].iter().rev().find_map(|g| *g);
+                let next = gd[i + 1..].iter().find_map(|g| *g);
+                match (prev, next) {
+                    (Some(p), Some(n)) => (p + n) / (F::one() + F::one()),
+                    (Some(g), None) | (None, Some(g)) => g,
+                    (None, None) => F::zero(),
+                }
+            })
+        })
+        .collect();
+
+    (rad_per_sample_to_fs(w, fs), gd)
+}
+
+/// Measure the -3 dB cutoff frequencies of a digital filter.
+///
+/// The magnitude response is computed on a dense grid from DC to Nyquist, and the frequencies
+/// at which it crosses half of the peak power (-3 dB) are found by linear interpolation of the
+/// gain in dB between neighbouring grid points. This is useful to verify the band edges of a
+/// designed filter, which for a Butterworth filter are the critical frequencies `Wn`.
+///
+/// # Parameters
+/// * `filter` : [DigitalFilter]
+///   The filter, in any representation.
+/// * `fs` : `Option<F>`
+///   The sampling frequency of the digital system. Defaults to 2 half-cycles/sample, so that the
+///   cutoff frequencies are in the same units as `Wn` in the design functions.
+///
+/// # Returns
+/// * `cutoffs` : ndarray
+///   The -3 dB crossings in increasing order, in the same units as `fs`. This is one frequency
+///   for lowpass and highpass filters, and two for bandpass and bandstop filters. It is empty
+///   when the response never drops 3 dB below its peak.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::design::{butter_dyn, cutoff_frequencies_dyn, FilterOutputType};
+///
+/// let filter = butter_dyn(4, vec![0.2f64], None, None, Some(FilterOutputType::Sos), None);
+/// let cutoffs = cutoff_frequencies_dyn(&filter, None);
+/// assert_eq!(cutoffs.len(), 1);
+/// assert!((cutoffs[0] - 0.2).abs() < 1e-4);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn cutoff_frequencies_dyn<F>(filter: &DigitalFilter<F>, fs: Option<F>) -> Vec<F>
+where
+    F: RealField + Float,
+{
+    const WORN: usize = 8192;
+    let fs = fs.unwrap_or_else(|| F::from(2).unwrap());
+    let (w, h) = filter.response_dyn(2 * WORN, true, Some(fs));
+    // Only the upper half of the unit circle, including Nyquist.
+    let power: Vec<F> = h.iter().take(WORN + 1).map(|hi| hi.norm_sqr()).collect();
+
+    let peak = power.iter().copied().fold(F::zero(), Float::max);
+    if peak <= F::zero() || Float::is_nan(peak) {
+        return Vec::new();
+    }
+    let ten = F::from(10).unwrap();
+    // Floor exact nulls, so that they are a finite number of dB below the peak.
+    let gain_db: Vec<F> = power
+        .iter()
+        .map(|&p| ten * Float::log10(Float::max(p / peak, F::min_positive_value())))
+        .collect();
+    let level = ten * Float::log10(F::from(0.5).unwrap());
+
+    (0..WORN)
+        .filter_map(|i| {
+            let (g0, g1) = (gain_db[i] - level, gain_db[i + 1] - level);
+            // A crossing exactly on a grid point is counted once, as being above the level.
+            if (g0 >= F::zero()) == (g1 >= F::zero()) {
+                return None;
+            }
+            let t = g0 / (g0 - g1);
+            Some(w[i] + t * (w[i + 1] - w[i]))
+        })
+        .collect()
+}
+
+/// Compute the frequency response of an analog filter.
+///
+/// Given the M-order numerator `b` and N-order denominator `a` of an analog filter, compute its
+/// frequency response:
+///
+/// ```text
+///         b[0]*(jw)**M + b[1]*(jw)**(M-1) + ... + b[M]
+/// H(w) = ----------------------------------------------
+///         a[0]*(jw)**N + a[1]*(jw)**(N-1) + ... + a[N]
+/// ```
+///
+/// # Parameters
+/// * `b` : array_like
+///   Numerator of a linear filter, in descending powers of `s`.
+/// * `a` : array_like
+///   Denominator of a linear filter, in descending powers of `s`.
+/// * `wor_n` : array_like
+///   The angular frequencies at which to compute the response, in rad/s (`worN` in scipy).
+///
+/// # Returns
+/// * `w` : ndarray
+///   The angular frequencies at which `h` was computed.
+/// * `h` : ndarray
+///   The frequency response, as complex numbers.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::design::freqs_dyn;
+///
+/// // H(s) = 1 / (s + 1)
+/// let (w, h) = freqs_dyn(&[1.], &[1., 1.], &[0., 1.]);
+/// assert_eq!(w, vec![0., 1.]);
+/// assert!((h[1].norm() - core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.freqs.html>
+#[cfg(feature = "alloc")]
+pub fn freqs_dyn<F>(b: &[F], a: &[F], wor_n: &[F]) -> (Vec<F>, Vec<Complex<F>>)
+where
+    F: RealField + Float,
+{
+    let h = wor_n
+        .iter()
+        .map(|&wi| {
+            let s = Complex::new(F::zero(), wi);
+            polyval_s(b, s) / polyval_s(a, s)
+        })
+        .collect();
+
+    (wor_n.to_vec(), h)
+}
+
+/// Angular frequencies in radians/sample, as `np.linspace(0, lastpoint, N, endpoint=False)`.
+#[cfg(feature = "alloc")]
+pub(super) fn freqz_w_dyn<F>(wor_n: usize, whole: bool) -> Vec<F>
+where
+    F: RealField + Float,
+{
+    let lastpoint = if whole { F::two_pi() } else { F::pi() };
+    let n = F::from(wor_n).unwrap();
+    (0..wor_n)
+        .map(|i| lastpoint * F::from(i).unwrap() / n)
+        .collect()
+}
+
+/// Rescale radians/sample into the units of `fs`, if given.
+#[cfg(feature = "alloc")]
+pub(super) fn rad_per_sample_to_fs<F>(w: Vec<F>, fs: Option<F>) -> Vec<F>
+where
+    F: RealField + Float,
+{
+    match fs {
+        Some(fs) => w.into_iter().map(|wi| wi * fs / F::two_pi()).collect(),
+        None => w,
+    }
+}
+
+/// Evaluate `c[0] + c[1] z^-1 + ... + c[n] z^-n` by Horner's method, given `zm1 = z^-1`.
+fn polyval_zm1<F>(c: &[F], zm1: Complex<F>) -> Complex<F>
+where
+    F: RealField + Float,
+{
+    c.iter()
+        .rev()
+        .fold(Complex::zero(), |acc: Complex<F>, &ci| acc * zm1 + ci)
+}
+
+/// Evaluate `c[0] s^n + c[1] s^(n-1) + ... + c[n]` by Horner's method.
+fn polyval_s<F>(c: &[F], s: Complex<F>) -> Complex<F>
+where
+    F: RealField + Float,
+{
+    c.iter()
+        .fold(Complex::zero(), |acc: Complex<F>, &ci| acc * s + ci)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    #[track_caller]
+    fn assert_cplx_eq(a: &[Complex<f64>], e: &[Complex<f64>]) {
+        assert_eq!(a.len(), e.len());
+        for (a, e) in a.iter().zip(e) {
+            assert_relative_eq!(a.re, e.re, epsilon = 1e-10);
+            assert_relative_eq!(a.im, e.im, epsilon = 1e-10);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn freqz_rad_per_sample() {
+        // >>> w, h = freqz([0.5, 0.5], [1], worN=4)
+        // >>> w
+        // array([0.        , 0.78539816, 1.57079633, 2.35619449])
+        // >>> h
+        // array([1.        +0.j        , 0.85355339-0.35355339j,
+        //        0.5       -0.5j       , 0.14644661-0.35355339j])
+        let (w, h) = freqz_dyn(&[0.5, 0.5], &[1.], 4, false, None);
+        for (a, e) in w.iter().zip([0., PI / 4., PI / 2., 3. * PI / 4.]) {
+            assert_relative_eq!(*a, e, epsilon = 1e-12);
+        }
+        assert_cplx_eq(
+            &h,
+            &[
+                Complex::new(1., 0.),
+                Complex::new(0.8535533905932737, -0.35355339059327373),
+                Complex::new(0.5, -0.5),
+                Complex::new(0.14644660940672627, -0.35355339059327373),
+            ],
+        );
+
+        // >>> w, h = freqz([0.5, 0.5], [1], worN=4, whole=True)
+        // >>> w
+        // array([0.        , 1.57079633, 3.14159265, 4.71238898])
+        let (w, _) = freqz_dyn(&[0.5, 0.5], &[1.], 4, true, None);
+        for (a, e) in w.iter().zip([0., PI / 2., PI, 3. * PI / 2.]) {
+            assert_relative_eq!(*a, e, epsilon = 1e-12);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn freqz_hz() {
+        // >>> w, h = freqz([1], [1, -0.5], worN=4, fs=1000)
+        // >>> w
+        // array([  0., 125., 250., 375.])
+        // >>> h
+        // array([2.        +0.j        , 1.19074357-0.65123928j,
+        //        0.8       -0.4j       , 0.69160937-0.18065105j])
+        let (w, h) = freqz_dyn(&[1.], &[1., -0.5], 4, false, Some(1000.));
+        for (a, e) in w.iter().zip([0., 125., 250., 375.]) {
+            assert_relative_eq!(*a, e, epsilon = 1e-10);
+        }
+        assert_cplx_eq(
+            &h,
+            &[
+                Complex::new(2., 0.),
+                Complex::new(1.1907435698305462, -0.6512392830509103),
+                Complex::new(0.8, -0.4),
+                Complex::new(0.6916093713459244, -0.18065104775679267),
+            ],
+        );
+
+        // >>> w, h = freqz([1], [1, -0.5], worN=4, whole=True, fs=1000)
+        // >>> w
+        // array([  0., 250., 500., 750.])
+        let (w, _) = freqz_dyn(&[1.], &[1., -0.5], 4, true, Some(1000.));
+        for (a, e) in w.iter().zip([0., 250., 500., 750.]) {
+            assert_relative_eq!(*a, e, epsilon = 1e-10);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn freqz_of_butterworth_bandpass() {
+        use crate::signal::filter::design::{
+            butter_dyn, DigitalFilter, FilterBandType, FilterOutputType,
+        };
+        use alloc::vec;
+
+        // The filter of `matches_scipy_iirfilter_butter_ba`:
+        // >>> b, a = butter(4, [10, 50], btype='bandpass', fs=1666)
+        // >>> w, h = freqz(b, a, worN=833, fs=1666)
+        let fs = 1666.;
+        let design = |output| {
+            butter_dyn::<f64>(
+                4,
+                vec![10., 50.],
+                Some(FilterBandType::Bandpass),
+                None,
+                Some(output),
+                Some(fs),
+            )
+        };
+        let DigitalFilter::Ba(ba) = design(FilterOutputType::Ba) else {
+            panic!("Expected ba output");
+        };
+        let DigitalFilter::Zpk(zpk) = design(FilterOutputType::Zpk) else {
+            panic!("Expected zpk output");
+        };
+        let (w, h) = freqz_dyn(&ba.b, &ba.a, 833, false, Some(fs));
+        assert_eq!(w.len(), 833);
+        assert_relative_eq!(w[1], 1., epsilon = 1e-10);
+
+        // The bilinear transform of an analog Butterworth bandpass, whose band edges were
+        // pre-warped to tan(pi * f / fs).
+        let warp = |f: f64| (PI * f / fs).tan();
+        let (lo, hi) = (warp(10.), warp(50.));
+        let magnitude = |f: f64| {
+            let x = warp(f);
+            let ratio = (x * x - lo * hi) / (x * (hi - lo));
+            1. / (1. + ratio.powi(8)).sqrt()
+        };
+        // Expanding the poles, which are close to the unit circle, into `a` costs some precision,
+        // which second order sections avoid.
+        for (&wi, hi_) in w.iter().zip(&h).skip(1) {
+            assert_relative_eq!(hi_.norm(), magnitude(wi), epsilon = 1e-5);
+        }
+        let DigitalFilter::Sos(sos) = design(FilterOutputType::Sos) else {
+            panic!("Expected sos output");
+        };
+        let (_, h_sos) = sosfreqz_dyn(&sos.sos, 833, false, Some(fs));
+        for (&wi, hi_) in w.iter().zip(&h_sos).skip(1) {
+            assert_relative_eq!(hi_.norm(), magnitude(wi), epsilon = 1e-12);
+        }
+        // Half power at the band edges.
+        assert_relative_eq!(
+            h[10].norm(),
+            core::f64::consts::FRAC_1_SQRT_2,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            h[50].norm(),
+            core::f64::consts::FRAC_1_SQRT_2,
+            epsilon = 1e-5
+        );
+
+        // The phase agrees with the factored zeros, poles and gain.
+        for (&wi, hi_) in w.iter().zip(&h) {
+            let z = Complex::new(0., 2. * PI * wi / fs).exp();
+            let num = zpk
+                .z
+                .iter()
+                .fold(Complex::from(zpk.k), |acc, zi| acc * (z - zi));
+            let expected = zpk.p.iter().fold(num, |acc, pi| acc / (z - pi));
+            assert_relative_eq!(hi_.re, expected.re, epsilon = 1e-5);
+            assert_relative_eq!(hi_.im, expected.im, epsilon = 1e-5);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfreqz_matches_cascade() {
+        let sos = [
+            Sos::new([0.5, 0.5, 0.], [1., 0., 0.]),
+            Sos::new([1., 0., 0.], [1., -0.5, 0.]),
+        ];
+        let (w, h) = sosfreqz_dyn(&sos, 4, false, None);
+        let (wb, hb) = freqz_dyn(&[0.5, 0.5], &[1., -0.5], 4, false, None);
+        assert_eq!(w, wb);
+        assert_cplx_eq(&h, &hb);
+        assert_relative_eq!(h[2].re, 0.2, epsilon = 1e-12);
+        assert_relative_eq!(h[2].im, -0.6, epsilon = 1e-12);
+
+        let (w, _) = sosfreqz_dyn(&sos, 4, false, Some(1000.));
+        for (a, e) in w.iter().zip([0., 125., 250., 375.]) {
+            assert_relative_eq!(*a, e, epsilon = 1e-10);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfreqz_of_butterworth_sections() {
+        use crate::signal::filter::design::{
+            butter_dyn, DigitalFilter, FilterBandType, FilterOutputType,
+        };
+        use alloc::vec;
+        use core::f64::consts::FRAC_1_SQRT_2;
+
+        // The 4 sections of `matches_scipy_iirfilter_butter_sos`:
+        // >>> sos = butter(4, [10, 50], btype='bandpass', output='sos', fs=1666)
+        // >>> w, h = sosfreqz(sos, worN=833, fs=1666)
+        // The magnitudes at 5, 10, 22, 50 and 100 Hz are those of the Butterworth response,
+        // 1 / sqrt(1 + r**8) with r = (x**2 - x_lo*x_hi) / (x*(x_hi - x_lo)), x = tan(pi*f/fs).
+        let DigitalFilter::Sos(sos) = butter_dyn::<f64>(
+            4,
+            vec![10., 50.],
+            Some(FilterBandType::Bandpass),
+            None,
+            Some(FilterOutputType::Sos),
+            Some(1666.),
+        ) else {
+            panic!("Expected sos output");
+        };
+        assert_eq!(sos.sos.len(), 4);
+        let (w, h) = sosfreqz_dyn(&sos.sos, 833, false, Some(1666.));
+        for (i, e) in [
+            (5, 0.03147280),
+            (10, FRAC_1_SQRT_2),
+            (22, 1.),
+            (50, FRAC_1_SQRT_2),
+            (100, 0.03026181),
+        ] {
+            assert_relative_eq!(w[i], i as f64, epsilon = 1e-10);
+            assert_relative_eq!(h[i].norm(), e, epsilon = 1e-7);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn group_delay_of_delay_and_notch() {
+        // >>> w, gd = group_delay(([0, 0, 1], [1]), w=4)
+        // >>> gd
+        // array([2., 2., 2., 2.])
+        let (w, gd) = group_delay_dyn(&[0., 0., 1.], &[1.], 4, false, Some(1000.));
+        for (a, e) in w.iter().zip([0., 125., 250., 375.]) {
+            assert_relative_eq!(*a, e, epsilon = 1e-10);
+        }
+        for g in gd {
+            assert_relative_eq!(g, 2., epsilon = 1e-12);
+        }
+
+        // >>> b, a = iirnotch(0.25, 5)
+        // The notch is at w = pi/4, the third of 8 frequencies.
+        let w0 = PI / 4.;
+        let gain = 1. / (1. + (w0 / 5. / 2.).tan());
+        let b = [gain, -2. * gain * w0.cos(), gain];
+        let a = [1., -2. * gain * w0.cos(), 2. * gain - 1.];
+        let (w, gd) = group_delay_dyn(&b, &a, 8, false, None);
+        assert_relative_eq!(w[2], w0, epsilon = 1e-12);
+        assert!(gd.iter().all(|g| g.is_finite()));
+        assert_relative_eq!(gd[2], (gd[1] + gd[3]) / 2., epsilon = 1e-12);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cutoff_frequencies_of_butterworth() {
+        use crate::signal::filter::design::{
+            butter_dyn, BaFormatFilter, FilterBandType, FilterOutputType,
+        };
+        use alloc::vec;
+
+        for output in [
+            FilterOutputType::Ba,
+            FilterOutputType::Zpk,
+            FilterOutputType::Sos,
+        ] {
+            let filter = butter_dyn(4, vec![0.2], None, None, Some(output), None);
+            let cutoffs = cutoff_frequencies_dyn(&filter, None);
+            assert_eq!(cutoffs.len(), 1, "{output:?}");
+            assert_relative_eq!(cutoffs[0], 0.2, epsilon = 1e-5);
+        }
+
+        let filter = butter_dyn(
+            3,
+            vec![100., 200.],
+            Some(FilterBandType::Bandstop),
+            None,
+            Some(FilterOutputType::Sos),
+            Some(1000.),
+        );
+        let cutoffs = cutoff_frequencies_dyn(&filter, Some(1000.));
+        assert_eq!(cutoffs.len(), 2);
+        assert_relative_eq!(cutoffs[0], 100., epsilon = 1e-2);
+        assert_relative_eq!(cutoffs[1], 200., epsilon = 1e-2);
+
+        // An all-pass filter has no cutoff.
+        let allpass = DigitalFilter::Ba(BaFormatFilter {
+            b: vec![-0.5, 1.],
+            a: vec![1., -0.5],
+        });
+        assert!(cutoff_frequencies_dyn(&allpass, None).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn freqs_of_analog_lowpass() {
+        use core::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+        // H(s) = 1 / (s + 1), for which H(jw) = (1 - jw) / (1 + w**2).
+        let w = [0., 0.1, 1., 10.];
+        let (wo, h) = freqs_dyn(&[1.], &[1., 1.], &w);
+        assert_eq!(wo, w);
+        assert_cplx_eq(
+            &h,
+            &[
+                Complex::new(1., 0.),
+                Complex::new(0.9900990099009901, -0.09900990099009901),
+                Complex::new(0.5, -0.5),
+                Complex::new(0.009900990099009901, -0.09900990099009901),
+            ],
+        );
+
+        // The analog Butterworth lowpass of order 2 with a cutoff of 1 rad/s is
+        // H(s) = 1 / (s**2 + sqrt(2) s + 1), which has a gain of 1/sqrt(2) at the cutoff.
+        let (_, h) = freqs_dyn(&[1.], &[1., SQRT_2, 1.], &w[1..]);
+        assert_cplx_eq(
+            &h,
+            &[
+                Complex::new(0.9899010098990102, -0.141407215515758),
+                Complex::new(0., -FRAC_1_SQRT_2),
+                Complex::new(-0.0098990100989901, -0.0014140721551575794),
+            ],
+        );
+        assert_relative_eq!(h[1].norm(), FRAC_1_SQRT_2, epsilon = 1e-12);
+    }
+}
@@ -6,13 +6,16 @@ use num_traits::Float;
 #[cfg(feature = "alloc")]
 use super::{
     bilinear_zpk_dyn, lp2bp_zpk_dyn, lp2bs_zpk_dyn, lp2hp_zpk_dyn, lp2lp_zpk_dyn, zpk2sos_dyn,
-    DigitalFilter, FilterBandType, FilterOutputType, FilterType, Sos,
+    BesselThomsonNorm, DigitalFilter, FilterBandType, FilterOutputType, FilterType, Sos,
 };
 #[cfg(feature = "alloc")]
 use crate::signal::filter::design::{zpk2tf_dyn, ZpkFormatFilter};
+use crate::special::xsf::{ellipj, ellipk, ellipkm1};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use sci_rs_core::{num_rs::roots, Error, Result};
 
 ///
 ///
@@ -39,6 +42,7 @@ use alloc::vec::Vec;
 /// buttord : Find order and critical points from passband and stopband spec
 /// cheb1ord, cheb2ord, ellipord
 /// iirdesign : General filter design using passband and stopband spec
+/// [try_iirfilter_dyn] : As this function, returning an error instead of panicking
 ///
 #[allow(clippy::too_many_arguments)]
 #[cfg(feature = "alloc")]
@@ -106,7 +110,7 @@ where
             cheb1ap_dyn(order, rp.unwrap())
         }
         FilterType::ChebyshevII => {
-            if rp.is_none() {
+            if rs.is_none() {
                 panic!(
                     "stopband attenuation (rs) must be provided to design an Chebyshev II filter."
                 );
@@ -117,13 +121,9 @@ where
             if rs.is_none() || rp.is_none() {
                 panic!("Both rp and rs must be provided to design an elliptic filter.");
             }
-            // ellipap::<N>(rp, rs)
-            todo!()
-        }
-        FilterType::BesselThomson(norm) => {
-            // besselap::<N>(norm = norm),
-            todo!()
+            ellipap_dyn(order, rp.unwrap(), rs.unwrap())
         }
+        FilterType::BesselThomson(norm) => besselap_dyn(order, norm),
     };
 
     // Pre-warp frequencies for digital filter design
@@ -203,11 +203,139 @@ where
     let output = output.unwrap_or(FilterOutputType::Ba);
     match output {
         FilterOutputType::Zpk => DigitalFilter::Zpk(zpk),
-        FilterOutputType::Ba => DigitalFilter::Ba(zpk2tf_dyn(2 * order, &zpk.z, &zpk.p, zpk.k)),
+        FilterOutputType::Ba => {
+            let n = zpk.z.len().max(zpk.p.len());
+            DigitalFilter::Ba(zpk2tf_dyn(n, &zpk.z, &zpk.p, zpk.k))
+        }
         FilterOutputType::Sos => DigitalFilter::Sos(zpk2sos_dyn(order, zpk, None, Some(analog))),
     }
 }
 
+/// IIR digital and analog filter design given order and critical points, checking the arguments.
+///
+/// As [iirfilter_dyn], but returning an error for the arguments on which [iirfilter_dyn] panics.
+///
+/// Errors
+/// ------
+/// * [Error::ConflictArg] if `fs` is given for an `analog` filter.
+/// * [Error::InvalidArg] if the number of critical frequencies `wn` does not suit `btype`, if
+///   they are not increasing and within `(0, fs/2)` (or positive, for analog filters), if `rp`
+///   or `rs` is negative, or if `ftype` needs `rp` or `rs` but it is not given.
+///
+/// Examples
+/// --------
+/// ```
+/// use sci_rs::signal::filter::design::{try_iirfilter_dyn, FilterBandType};
+/// use sci_rs_core::Error;
+///
+/// let filter = try_iirfilter_dyn::<f64>(
+///     4,
+///     vec![1.],
+///     None,
+///     None,
+///     Some(FilterBandType::Lowpass),
+///     None,
+///     Some(true),
+///     None,
+///     Some(100.),
+/// );
+/// assert!(matches!(filter, Err(Error::ConflictArg { .. })));
+/// ```
+#[allow(clippy::too_many_arguments)]
+#[cfg(feature = "alloc")]
+pub fn try_iirfilter_dyn<F>(
+    order: usize,
+    wn: Vec<F>,
+    rp: Option<F>,
+    rs: Option<F>,
+    btype: Option<FilterBandType>,
+    ftype: Option<FilterType>,
+    analog: Option<bool>,
+    output: Option<FilterOutputType>,
+    fs: Option<F>,
+) -> Result<DigitalFilter<F>>
+where
+    F: RealField + Float + Sum,
+{
+    let invalid = |arg: &str, reason: &str| {
+        Err(Error::InvalidArg {
+            arg: arg.into(),
+            reason: reason.into(),
+        })
+    };
+
+    let is_analog = analog.unwrap_or(false);
+    if is_analog && fs.is_some() {
+        return Err(Error::ConflictArg {
+            reason: "fs cannot be specified for an analog filter.".into(),
+        });
+    }
+
+    let expected = match btype.unwrap_or(FilterBandType::Bandpass) {
+        FilterBandType::Lowpass | FilterBandType::Highpass => 1,
+        FilterBandType::Bandpass | FilterBandType::Bandstop => 2,
+    };
+    if wn.len() != expected {
+        return invalid(
+            "wn",
+            "Must specify a single critical frequency for a lowpass or highpass filter, or \
+             start and stop frequencies for a bandpass or bandstop filter.",
+        );
+    }
+    if wn.iter().any(|&wi| wi <= F::zero()) {
+        return invalid("wn", "Filter critical frequencies must be greater than 0.");
+    }
+    if wn.len() > 1 && wn[0] >= wn[1] {
+        return invalid("wn", "Wn[0] must be less than Wn[1].");
+    }
+    let nyquist = fs.map_or(F::one(), |fs| fs / F::from(2).unwrap());
+    if !is_analog && wn.iter().any(|&wi| wi >= nyquist) {
+        return invalid(
+            "wn",
+            "Digital filter critical frequencies must be less than fs/2.",
+        );
+    }
+
+    if rp.is_some_and(|rp| rp < F::zero()) {
+        return invalid("rp", "Passband ripple (rp) must be positive.");
+    }
+    if rs.is_some_and(|rs| rs < F::zero()) {
+        return invalid("rs", "Stopband attenuation (rs) must be positive.");
+    }
+    match ftype.unwrap_or(FilterType::Butterworth) {
+        FilterType::ChebyshevI if rp.is_none() => {
+            return invalid(
+                "rp",
+                "Passband ripple (rp) must be provided to design a Chebyshev I filter.",
+            );
+        }
+        FilterType::ChebyshevII if rs.is_none() => {
+            return invalid(
+                "rs",
+                "Stopband attenuation (rs) must be provided to design a Chebyshev II filter.",
+            );
+        }
+        FilterType::CauerElliptic if rp.is_none() || rs.is_none() => {
+            return invalid(
+                "rp",
+                "Both rp and rs must be provided to design an elliptic filter.",
+            );
+        }
+        FilterType::CauerElliptic if rp == Some(F::zero()) || rs == Some(F::zero()) => {
+            return invalid(
+                "rp",
+                "Cannot design an elliptic filter with zero passband ripple or stopband \
+                 attenuation.",
+            );
+        }
+        _ => {}
+    }
+
+    Ok(iirfilter_dyn(
+        order, wn, rp, rs, btype, ftype, analog, output, fs,
+    ))
+}
+
 /// """Return (z,p,k) for analog prototype of Nth-order Butterworth filter.
 ///
 /// The filter will have an angular (e.g., rad/s) cutoff frequency of 1.
@@ -524,8 +652,469 @@ where
     )
 }
 
+/// Return (z,p,k) of an Nth-order elliptic analog lowpass filter.
+///
+/// The filter is a normalized prototype that has `rp` decibels of ripple in the passband and a
+/// stopband `rs` decibels down.
+///
+/// The filter's angular (e.g., rad/s) cutoff frequency is normalized to 1, defined as the point
+/// at which the gain first drops below `-rp`.
+///
+/// Notes
+/// -----
+/// The selectivity of the prototype is found from the degree equation with the nome series, and
+/// the poles from the inverse Jacobian elliptic function by Landen transformations, as in scipy.
+///
+/// Panics
+/// ------
+/// If `rp` and `rs` are so close that no elliptic filter meets them.
+///
+/// See Also
+/// --------
+/// ellip : Filter design function using this prototype
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.ellipap.html>
+#[cfg(feature = "alloc")]
+pub fn ellipap_dyn<F>(n: usize, rp: F, rs: F) -> ZpkFormatFilter<F>
+where
+    F: Float + RealField,
+{
+    let (one, tenth) = (F::one(), F::from(0.1).unwrap());
+    if n == 0 {
+        // Even order filters have DC gain of -rp dB
+        let k = Float::powf(F::from(10).unwrap(), -rp / F::from(20).unwrap());
+        return ZpkFormatFilter::new(Vec::new(), Vec::new(), k);
+    }
+    if n == 1 {
+        let p = -Float::sqrt(one / pow10m1(tenth * rp));
+        return ZpkFormatFilter::new(Vec::new(), alloc::vec![Complex::new(p, F::zero())], -p);
+    }
+
+    let eps_sq = pow10m1(tenth * rp);
+    let eps = Float::sqrt(eps_sq);
+    let ck1_sq = eps_sq / pow10m1(tenth * rs);
+    if ck1_sq == F::zero() {
+        panic!("Cannot design a filter with given rp and rs specifications.");
+    }
+
+    let m = ellipdeg(n, ck1_sq);
+    let capk = ellipk(m);
+    let n_f = F::from(n).unwrap();
+    let sncndn: Vec<(F, F, F)> = ((1 - n % 2)..n)
+        .step_by(2)
+        .map(|j| {
+            let (sn, cn, dn, _) = ellipj(F::from(j).unwrap() * capk / n_f, m);
+            (sn, cn, dn)
+        })
+        .collect();
+
+    let epsilon = F::from(2e-16).unwrap();
+    let mut z: Vec<Complex<F>> = sncndn
+        .iter()
+        .filter(|(sn, _, _)| Float::abs(*sn) > epsilon)
+        .map(|(sn, _, _)| Complex::new(F::zero(), one / (Float::sqrt(m) * *sn)))
+        .collect();
+    z.extend(z.clone().iter().map(|zi| zi.conj()));
+
+    let r = arc_jac_sc1(one / eps, ck1_sq);
+    let v0 = capk * r / (n_f * ellipk(ck1_sq));
+    let (sv, cv, dv, _) = ellipj(v0, one - m);
+    let mut p: Vec<Complex<F>> = sncndn
+        .iter()
+        .map(|&(s, c, d)| -Complex::new(c * d * sv * cv, s * dv) / (one - Float::powi(d * sv, 2)))
+        .collect();
+    let conj: Vec<Complex<F>> = if n % 2 == 1 {
+        let norm = Float::sqrt(
+            p.iter()
+                .map(|pi| pi.norm_sqr())
+                .fold(F::zero(), |a, b| a + b),
+        );
+        p.iter()
+            .filter(|pi| Float::abs(pi.im) > epsilon * norm)
+            .map(|pi| pi.conj())
+            .collect()
+    } else {
+        p.iter().map(|pi| pi.conj()).collect()
+    };
+    p.extend(conj);
+
+    let prod = |x: &[Complex<F>]| {
+        x.iter()
+            .fold(Complex::new(one, F::zero()), |acc, xi| acc * -xi)
+    };
+    let mut k = (prod(&p) / prod(&z)).re;
+    if n.is_multiple_of(2) {
+        k /= Float::sqrt(one + eps_sq);
+    }
+
+    ZpkFormatFilter::new(z, p, k)
+}
+
+/// `10^x - 1`, accurate for small `x`.
+fn pow10m1<F: Float>(x: F) -> F {
+    Float::exp_m1(x * Float::ln(F::from(10).unwrap()))
+}
+
+/// Solve the degree equation of an order `n` elliptic filter for the selectivity parameter, given
+/// the discrimination parameter `m1`, using the nome series.
+fn ellipdeg<F: Float>(n: usize, m1: F) -> F {
+    // Terms of the series, beyond which the nome is too small to contribute.
+    const ELLIPDEG_MMAX: i32 = 7;
+
+    let q1 = Float::exp(-F::from(PI).unwrap() * ellipkm1(m1) / ellipk(m1));
+    let q = Float::powf(q1, F::one() / F::from(n).unwrap());
+    let num = (0..=ELLIPDEG_MMAX).fold(F::zero(), |acc, m| acc + Float::powi(q, m * (m + 1)));
+    let den = (1..=ELLIPDEG_MMAX + 1).fold(F::zero(), |acc, m| acc + Float::powi(q, m * m));
+    let den = F::one() + F::from(2).unwrap() * den;
+    F::from(16).unwrap() * q * Float::powi(num / den, 4)
+}
+
+/// Inverse Jacobian elliptic `sn` of parameter `m`, `0 <= m <= 1`, for complex `w`, by the
+/// descending Landen transformation.
+#[cfg(feature = "alloc")]
+fn arc_jac_sn<F: Float + RealField>(w: Complex<F>, m: F) -> Complex<F> {
+    // Landen transformations converge quadratically, so this is plenty for any float.
+    const ARC_JAC_SN_MAXITER: usize = 10;
+
+    let one = F::one();
+    let k = Float::sqrt(m);
+    if k > one {
+        return Complex::new(F::nan(), F::nan());
+    } else if k == one {
+        return w.atanh();
+    }
+
+    let mut ks = alloc::vec![k];
+    while let Some(&k) = ks.last().filter(|k| **k != F::zero()) {
+        if ks.len() > ARC_JAC_SN_MAXITER {
+            panic!("Landen transformation not converging");
+        }
+        let kp = Float::sqrt((one - k) * (one + k));
+        ks.push((one - kp) / (one + kp));
+    }
+
+    let capk = ks[1..].iter().fold(one, |acc, k| acc * (one + *k)) * F::frac_pi_2();
+    let wn = ks.windows(2).fold(w, |wn, k| {
+        let kwn = wn.scale(k[0]);
+        let complement = ((-kwn + one) * (kwn + one)).sqrt();
+        (wn + wn) / ((complement + one).scale(one + k[1]))
+    });
+    wn.asin().scale(capk / F::frac_pi_2())
+}
+
+/// Real inverse Jacobian elliptic `sc` of parameter `m` at `w`, with `sc(z, 1 - m) = -i sn(iz, m)`.
+#[cfg(feature = "alloc")]
+fn arc_jac_sc1<F: Float + RealField>(w: F, m: F) -> F {
+    arc_jac_sn(Complex::new(F::zero(), w), m).im
+}
+
+/// Elliptic (Cauer) digital and analog filter design.
+///
+/// Design an Nth-order digital or analog elliptic filter and return the filter coefficients.
+///
+/// Parameters
+/// ----------
+/// * `N` : int  
+///   The order of the filter.
+/// * `rp` : float  
+///   The maximum ripple allowed below unity gain in the passband.
+///   Specified in decibels, as a positive number.
+/// * `rs` : float  
+///   The minimum attenuation required in the stop band.
+///   Specified in decibels, as a positive number.
+/// * `Wn` : array_like  
+///   A scalar or length-2 sequence giving the critical frequencies.
+///   For elliptic filters, this is the point in the transition band at which
+///   the gain first drops below -`rp`.
+///
+///   For digital filters, `Wn` are in the same units as `fs`. By default,
+///   `fs` is 2 half-cycles/sample, so these are normalized from 0 to 1,
+///   where 1 is the Nyquist frequency. (`Wn` is thus in
+///   half-cycles / sample.)
+///
+///   For analog filters, `Wn` is an angular frequency (e.g., rad/s).
+/// * `btype` : {'lowpass', 'highpass', 'bandpass', 'bandstop'}, optional  
+///   The type of filter.  Default is 'lowpass'.
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned.
+/// * `output` : {'ba', 'zpk', 'sos'}, optional  
+///   Type of output:  numerator/denominator ('ba'), pole-zero ('zpk'), or
+///   second-order sections ('sos'). Default is 'ba' for backwards
+///   compatibility, but 'sos' should be used for general-purpose filtering.
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
+///
+/// Returns
+/// -------
+/// b, a : ndarray, ndarray
+///   Numerator (`b`) and denominator (`a`) polynomials of the IIR filter.
+///   Only returned if ``output='ba'``.
+/// z, p, k : ndarray, ndarray, float
+///   Zeros, poles, and system gain of the IIR filter transfer
+///   function.  Only returned if ``output='zpk'``.
+/// sos : ndarray
+///   Second-order sections representation of the IIR filter.
+///   Only returned if ``output='sos'``.
+///
+/// See Also
+/// --------
+/// ellipord, [ellipap_dyn]
+///
+/// Notes
+/// -----
+/// Also known as Cauer or Zolotarev filters, the elliptical filter maximizes
+/// the rate of transition between the frequency response's passband and
+/// stopband, at the expense of ripple in both, and increased ringing in the
+/// step response.
+///
+/// As `rp` approaches 0, the elliptical filter becomes a Chebyshev
+/// type II filter ([cheby2_dyn]). As `rs` approaches 0, it becomes a Chebyshev
+/// type I filter ([cheby1_dyn]). As both approach 0, it becomes a Butterworth
+/// filter.
+///
+/// The equiripple passband has N maxima or minima (for example, a
+/// 5th-order filter has 3 maxima and 2 minima). Consequently, the DC gain is
+/// unity for odd-order filters, or -rp dB for even-order filters.
+#[allow(clippy::too_many_arguments)]
+#[cfg(feature = "alloc")]
+pub fn ellip_dyn<F>(
+    n: usize,
+    rp: F,
+    rs: F,
+    wn: Vec<F>,
+    btype: Option<FilterBandType>,
+    analog: Option<bool>,
+    output: Option<FilterOutputType>,
+    fs: Option<F>,
+) -> DigitalFilter<F>
+where
+    F: RealField + Float + Sum,
+{
+    iirfilter_dyn(
+        n,
+        wn,
+        Some(rp),
+        Some(rs),
+        btype,
+        Some(FilterType::CauerElliptic),
+        analog,
+        output,
+        fs,
+    )
+}
+
+/// Return (z,p,k) for analog prototype of an Nth-order Bessel filter.
+///
+/// Parameters
+/// ----------
+/// * `N` : int  
+///   The order of the filter.
+/// * `norm` : {'phase', 'delay', 'mag'}  
+///   Frequency normalization:
+///
+///   ``phase``  
+///   The filter is normalized such that the phase response reaches its midpoint at an angular
+///   (e.g. rad/s) cutoff frequency of 1. This happens for both low-pass and high-pass filters,
+///   so this is the "phase-matched" case. The magnitude response asymptotes are the same as a
+///   Butterworth filter of the same order with a cutoff of `Wn`.
+///
+///   ``delay``  
+///   The filter is normalized such that the group delay in the passband is 1 (e.g., 1 second).
+///   This is the "natural" type obtained by solving Bessel polynomials.
+///
+///   ``mag``  
+///   The filter is normalized such that the gain magnitude is -3 dB at angular frequency 1.
+///
+/// Returns
+/// -------
+/// z, p, k  
+///   Zeros (always empty), poles and gain of the prototype. The poles are ordered by decreasing
+///   imaginary part.
+///
+/// Notes
+/// -----
+/// The poles are the roots of the reverse Bessel polynomial
+/// $$\theta_N(s) = \sum_{k=0}^N \frac{(2N - k)!}{2^{N-k} k! (N-k)!} s^k,$$
+/// found as the eigenvalues of its companion matrix with [roots].
+///
+/// See Also
+/// --------
+/// bessel : Filter design function using this prototype
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.besselap.html>
+#[cfg(feature = "alloc")]
+pub fn besselap_dyn<F>(n: usize, norm: BesselThomsonNorm) -> ZpkFormatFilter<F>
+where
+    F: Float + RealField,
+{
+    if n == 0 {
+        return ZpkFormatFilter::new(Vec::new(), Vec::new(), F::one());
+    }
+
+    // Coefficients of the reverse Bessel polynomial, from a_n = 1 down to a_0.
+    let mut a = alloc::vec![F::one(); n + 1];
+    for k in (1..=n).rev() {
+        let ratio = F::from((2 * n - k + 1) * k).unwrap() / F::from(2 * (n - k + 1)).unwrap();
+        a[k - 1] = a[k] * ratio;
+    }
+    let a_last = a[0];
+
+    let p = reverse_bessel_roots(&a);
+    match norm {
+        BesselThomsonNorm::Delay => ZpkFormatFilter::new(Vec::new(), p, a_last),
+        BesselThomsonNorm::Phase => {
+            // Match the high frequency asymptote of a Butterworth filter.
+            let scale = Float::powf(a_last, -F::one() / F::from(n).unwrap());
+            let p = p.into_iter().map(|pi| pi.scale(scale)).collect();
+            ZpkFormatFilter::new(Vec::new(), p, F::one())
+        }
+        BesselThomsonNorm::Mag => {
+            let wc = bessel_cutoff(&p, a_last);
+            let p = p.into_iter().map(|pi| pi.unscale(wc)).collect();
+            let k = a_last / Float::powi(wc, n as i32);
+            ZpkFormatFilter::new(Vec::new(), p, k)
+        }
+    }
+}
+
+/// Roots of the monic polynomial with coefficients `a`, lowest order first. The roots are
+/// returned as conjugate pairs, ordered by decreasing imaginary part.
+#[cfg(feature = "alloc")]
+fn reverse_bessel_roots<F>(a: &[F]) -> Vec<Complex<F>>
+where
+    F: Float + RealField,
+{
+    let n = a.len() - 1;
+    let coeffs: Vec<F> = a.iter().rev().copied().collect();
+    let mut z = roots(&coeffs);
+
+    // Enforce exact conjugate symmetry, which leaves a middle root real for odd orders.
+    let im = |zi: &Complex<F>| zi.im.to_f64().unwrap();
+    z.sort_by(|x, y| im(y).total_cmp(&im(x)));
+    let half = F::from(0.5).unwrap();
+    (0..n)
+        .map(|i| (z[i] + z[n - 1 - i].conj()).scale(half))
+        .collect()
+}
+
+/// Angular frequency at which the gain `k / prod(jw - p)` falls to -3 dB.
+#[cfg(feature = "alloc")]
+fn bessel_cutoff<F>(p: &[Complex<F>], k: F) -> F
+where
+    F: Float + RealField,
+{
+    let gain = |w: F| {
+        let denom = p
+            .iter()
+            .fold(Complex::new(F::one(), F::zero()), |acc, &pi| {
+                acc * (Complex::new(F::zero(), w) - pi)
+            });
+        k / denom.modulus()
+    };
+    let target = F::one() / Float::sqrt(F::from(2).unwrap());
+
+    // The gain of a Bessel filter decreases monotonically, so the cutoff can be bisected.
+    let (mut lo, mut hi) = (F::zero(), F::one());
+    while gain(hi) > target {
+        lo = hi;
+        hi *= F::from(2).unwrap();
+    }
+    for _ in 0..200 {
+        let mid = (lo + hi) / F::from(2).unwrap();
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if gain(mid) > target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / F::from(2).unwrap()
+}
+
+/// Bessel/Thomson digital and analog filter design.
+///
+/// Design an Nth-order digital or analog Bessel filter and return the
+/// filter coefficients.
+///
+/// Parameters
+/// ----------
+/// * `N` : int  
+///   The order of the filter.
+/// * `Wn` : array_like  
+///   A scalar or length-2 sequence giving the critical frequencies (defined by the `norm`
+///   parameter). For analog filters, `Wn` is an angular frequency (e.g., rad/s).
+///
+///   For digital filters, `Wn` are in the same units as `fs`. By default,
+///   `fs` is 2 half-cycles/sample, so these are normalized from 0 to 1,
+///   where 1 is the Nyquist frequency. (`Wn` is thus in
+///   half-cycles / sample.)
+/// * `btype` : {'lowpass', 'highpass', 'bandpass', 'bandstop'}, optional  
+///   The type of filter.  Default is 'lowpass'.
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned. (See Notes.)
+/// * `output` : {'ba', 'zpk', 'sos'}, optional  
+///   Type of output:  numerator/denominator ('ba'), pole-zero ('zpk'), or
+///   second-order sections ('sos'). Default is 'ba'.
+/// * `norm` : {'phase', 'delay', 'mag'}, optional  
+///   Critical frequency normalization, see [besselap_dyn]. Default is 'phase'.
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
+///
+/// Returns
+/// -------
+/// b, a : ndarray, ndarray  
+///     Numerator (`b`) and denominator (`a`) polynomials of the IIR filter.
+///     Only returned if ``output='ba'``.
+/// z, p, k : ndarray, ndarray, float  
+///     Zeros, poles, and system gain of the IIR filter transfer
+///     function.  Only returned if ``output='zpk'``.
+/// sos : ndarray  
+///     Second-order sections representation of the IIR filter.
+///     Only returned if ``output='sos'``.
+///
+/// Notes
+/// -----
+/// Also known as a Thomson filter, the analog Bessel filter has maximally
+/// flat group delay and maximally linear phase response, with very little
+/// ringing in the step response. The Bessel is inherently an analog filter,
+/// and the digital version produced by the bilinear transform loses the
+/// maximally flat delay.
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.bessel.html>
+#[cfg(feature = "alloc")]
+pub fn bessel_dyn<F>(
+    n: usize,
+    wn: Vec<F>,
+    btype: Option<FilterBandType>,
+    analog: Option<bool>,
+    output: Option<FilterOutputType>,
+    norm: Option<BesselThomsonNorm>,
+    fs: Option<F>,
+) -> DigitalFilter<F>
+where
+    F: RealField + Float + Sum,
+{
+    iirfilter_dyn(
+        n,
+        wn,
+        None, // rp
+        None, // rs
+        Some(btype.unwrap_or(FilterBandType::Lowpass)),
+        Some(FilterType::BesselThomson(norm.unwrap_or_default())),
+        analog,
+        output,
+        fs,
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
     use approx::assert_relative_eq;
 
     use super::*;
@@ -565,11 +1154,9 @@ mod tests {
             ];
             let k = 0.1634450339473848;
 
+            let expected = ZpkFormatFilter::new(Vec::new(), p.to_vec(), k);
             let zpk = cheb1ap_dyn::<f64>(4, 2.);
-            for (expected, actual) in p.into_iter().zip(zpk.p) {
-                assert_relative_eq!(expected.re, actual.re, max_relative = 1e-7);
-                assert_relative_eq!(expected.im, actual.im, max_relative = 1e-7);
-            }
+            assert!(zpk.approx_eq(&expected, 1e-8), "{zpk:?}");
             assert_relative_eq!(zpk.k, k);
         }
         {
@@ -590,11 +1177,9 @@ mod tests {
             ];
             let k = 0.08172251697369243;
 
+            let expected = ZpkFormatFilter::new(Vec::new(), p.to_vec(), k);
             let zpk = cheb1ap_dyn::<f64>(5, 2.);
-            for (expected, actual) in p.into_iter().zip(zpk.p) {
-                assert_relative_eq!(expected.re, actual.re, max_relative = 1e-7);
-                assert_relative_eq!(expected.im, actual.im, max_relative = 1e-7);
-            }
+            assert!(zpk.approx_eq(&expected, 1e-8), "{zpk:?}");
             assert_relative_eq!(zpk.k, k);
         }
     }
@@ -624,15 +1209,9 @@ mod tests {
             ];
             let k = 0.7943282347242814;
 
+            let expected = ZpkFormatFilter::new(z.to_vec(), p.to_vec(), k);
             let zpk = cheb2ap_dyn::<f64>(4, 2.);
-            for (expected, actual) in z.into_iter().zip(zpk.z) {
-                assert_relative_eq!(expected.re, actual.re, max_relative = 1e-7);
-                assert_relative_eq!(expected.im, actual.im, max_relative = 1e-7);
-            }
-            for (expected, actual) in p.into_iter().zip(zpk.p) {
-                assert_relative_eq!(expected.re, actual.re, max_relative = 1e-7);
-                assert_relative_eq!(expected.im, actual.im, max_relative = 1e-7);
-            }
+            assert!(zpk.approx_eq(&expected, 1e-8), "{zpk:?}");
             assert_relative_eq!(zpk.k, k);
         }
         {
@@ -659,17 +1238,337 @@ mod tests {
             ];
             let k = 6.537801357895397;
 
+            let expected = ZpkFormatFilter::new(z.to_vec(), p.to_vec(), k);
             let zpk = cheb2ap_dyn::<f64>(5, 2.);
-            for (expected, actual) in z.into_iter().zip(zpk.z) {
-                assert_relative_eq!(expected.re, actual.re, max_relative = 1e-7);
-                assert_relative_eq!(expected.im, actual.im, max_relative = 1e-7);
+            assert!(zpk.approx_eq(&expected, 1e-8), "{zpk:?}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_scipy_ellipap() {
+        {
+            // from scipy.signal import ellipap
+            // ellipap(N=4, rp=1, rs=40)
+            let z: [Complex<f64>; 4] = [
+                Complex::new(0., 3.5252874329960022),
+                Complex::new(0., 1.6095504012251538),
+                Complex::new(0., -3.5252874329960022),
+                Complex::new(0., -1.6095504012251538),
+            ];
+            let p: [Complex<f64>; 4] = [
+                Complex::new(-0.3642905958734215, -0.4786027676406498),
+                Complex::new(-0.10528126462117149, -0.9937108112087721),
+                Complex::new(-0.3642905958734215, 0.4786027676406498),
+                Complex::new(-0.10528126462117149, 0.9937108112087721),
+            ];
+            let k = 0.01;
+
+            let expected = ZpkFormatFilter::new(z.to_vec(), p.to_vec(), k);
+            let zpk = ellipap_dyn::<f64>(4, 1., 40.);
+            assert!(zpk.approx_eq(&expected, 1e-12), "{zpk:?}");
+            assert_relative_eq!(zpk.k, k, max_relative = 1e-12);
+        }
+        {
+            // ellipap(N=3, rp=0.5, rs=60)
+            let z: [Complex<f64>; 2] = [
+                Complex::new(0., 6.545111159844347),
+                Complex::new(0., -6.545111159844347),
+            ];
+            let p: [Complex<f64>; 3] = [
+                Complex::new(-0.6333593366910755, 0.),
+                Complex::new(-0.3082273249424122, -1.023902830821039),
+                Complex::new(-0.3082273249424122, 1.023902830821039),
+            ];
+            let k = 0.01690468680625121;
+
+            let expected = ZpkFormatFilter::new(z.to_vec(), p.to_vec(), k);
+            let zpk = ellipap_dyn::<f64>(3, 0.5, 60.);
+            assert!(zpk.approx_eq(&expected, 1e-12), "{zpk:?}");
+            assert_relative_eq!(zpk.k, k, max_relative = 1e-12);
+        }
+        {
+            // ellipap(N=1, rp=1, rs=40)
+            let p = [Complex::new(-1.9652267283602718, 0.)];
+            let expected = ZpkFormatFilter::new(Vec::new(), p.to_vec(), 1.9652267283602718);
+            let zpk = ellipap_dyn::<f64>(1, 1., 40.);
+            assert!(zpk.approx_eq(&expected, 1e-14), "{zpk:?}");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ellip_meets_ripple_and_attenuation() {
+        use crate::signal::filter::design::{ellipord_dyn, sosfreqz_dyn, SosFormatFilter};
+
+        // An elliptic lowpass of the order chosen by ellipord is within rp dB below unity up to
+        // Wn, and at least rs dB down from the stopband edge.
+        let (n, wn) = ellipord_dyn(vec![0.3], vec![0.45], 0.5, 60., None, None);
+        assert_eq!(n, 6);
+        let DigitalFilter::Sos(SosFormatFilter { sos }) = ellip_dyn(
+            n,
+            0.5,
+            60.,
+            wn,
+            Some(FilterBandType::Lowpass),
+            None,
+            Some(FilterOutputType::Sos),
+            None,
+        ) else {
+            panic!("Expected sos output")
+        };
+        let (w, h) = sosfreqz_dyn(&sos, 512, false, None);
+        for (wi, hi) in w.iter().zip(&h) {
+            let db = 10. * f64::log10(hi.norm_sqr());
+            if *wi <= 0.3 * core::f64::consts::PI {
+                assert!((-0.5 - 1e-6..=1e-6).contains(&db), "{wi}: {db}");
+            } else if *wi >= 0.45 * core::f64::consts::PI {
+                assert!(db <= -60. + 1e-6, "{wi}: {db}");
             }
-            for (expected, actual) in p.into_iter().zip(zpk.p) {
-                assert_relative_eq!(expected.re, actual.re, max_relative = 1e-7);
-                assert_relative_eq!(expected.im, actual.im, max_relative = 1e-7);
+        }
+    }
+
+    #[test]
+    fn matches_scipy_besselap() {
+        // from scipy.signal import besselap
+        // besselap(4, norm='phase') = (array([], dtype=float64), array(
+        //    [-0.65721117+0.83016144j,
+        //     -0.9047588 +0.27091873j,
+        //     -0.9047588 -0.27091873j,
+        //     -0.65721117-0.83016144j]),
+        //   1.0)
+        let p: [Complex<f64>; 4] = [
+            Complex::new(-0.6572111716718829, 0.8301614350048734),
+            Complex::new(-0.904758796788245, 0.27091873300387465),
+            Complex::new(-0.904758796788245, -0.27091873300387465),
+            Complex::new(-0.6572111716718829, -0.8301614350048734),
+        ];
+        let expected = ZpkFormatFilter::new(Vec::new(), p.to_vec(), 1.);
+        let zpk = besselap_dyn::<f64>(4, BesselThomsonNorm::Phase);
+        assert!(zpk.approx_eq(&expected, 1e-12), "{zpk:?}");
+
+        // besselap(5, norm='mag')
+        let p: [Complex<f64>; 5] = [
+            Complex::new(-0.9576765485626819, 1.471124320730395),
+            Complex::new(-1.3808773258604397, 0.7179095876267685),
+            Complex::new(-1.502316271447479, 0.),
+            Complex::new(-1.3808773258604397, -0.7179095876267685),
+            Complex::new(-0.9576765485626819, -1.471124320730395),
+        ];
+        let expected = ZpkFormatFilter::new(Vec::new(), p.to_vec(), 11.212836685370533);
+        let zpk = besselap_dyn::<f64>(5, BesselThomsonNorm::Mag);
+        assert!(zpk.approx_eq(&expected, 1e-10), "{zpk:?}");
+
+        // The gain is -3 dB at the cutoff.
+        let denom = zpk.p.iter().fold(Complex::new(1., 0.), |acc, p| {
+            acc * (Complex::<f64>::i() - p)
+        });
+        assert_relative_eq!(zpk.k / denom.modulus(), 0.5f64.sqrt(), max_relative = 1e-12);
+
+        let zpk = besselap_dyn::<f64>(0, BesselThomsonNorm::Phase);
+        assert!(zpk.p.is_empty());
+        assert_eq!(zpk.k, 1.);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn besselap_delay_norm_has_unit_group_delay() {
+        for n in 1..=10 {
+            let zpk = besselap_dyn::<f64>(n, BesselThomsonNorm::Delay);
+            assert_eq!(zpk.p.len(), n);
+            assert!(zpk.z.is_empty());
+
+            // The group delay of k / prod(s - p) is sum(re(1 / (jw - p))), which is 1 at DC and
+            // maximally flat around it.
+            let group_delay = |w: f64| -> f64 {
+                zpk.p
+                    .iter()
+                    .map(|p| (Complex::new(0., w) - p).recip().re)
+                    .sum()
+            };
+            assert_relative_eq!(group_delay(0.), 1., max_relative = 1e-10);
+            if n >= 3 {
+                assert_relative_eq!(group_delay(0.2), 1., max_relative = 1e-4);
             }
-            assert_relative_eq!(zpk.k, k, max_relative = 1e-7);
+
+            // The DC gain is unity.
+            let dc = zpk.p.iter().fold(Complex::new(1., 0.), |acc, p| acc * -p);
+            assert_relative_eq!(zpk.k / dc.re, 1., max_relative = 1e-10);
+        }
+
+        // besselap(4, norm='delay')[1:]
+        let p: [Complex<f64>; 4] = [
+            Complex::new(-2.1037893971796278, 2.6574180418567526),
+            Complex::new(-2.8962106028203722, 0.8672341289345038),
+            Complex::new(-2.8962106028203722, -0.8672341289345038),
+            Complex::new(-2.1037893971796278, -2.6574180418567526),
+        ];
+        let expected = ZpkFormatFilter::new(Vec::new(), p.to_vec(), 105.);
+        let zpk = besselap_dyn::<f64>(4, BesselThomsonNorm::Delay);
+        assert!(zpk.approx_eq(&expected, 1e-10), "{zpk:?}");
+    }
+
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    #[test]
+    fn bessel_analog_lowpass_scales_prototype() {
+        // bessel(3, 2., analog=True, output='zpk') has the phase normalized poles scaled by 2.
+        let filter = bessel_dyn::<f64>(
+            3,
+            vec![2.],
+            None,
+            Some(true),
+            Some(FilterOutputType::Zpk),
+            None,
+            None,
+        );
+        let DigitalFilter::Zpk(zpk) = filter else {
+            panic!("Expected zpk output")
+        };
+        let p: [Complex<f64>; 3] = [
+            Complex::new(-1.4912807716961534, 1.4227332499456706),
+            Complex::new(-1.8832000530664134, 0.),
+            Complex::new(-1.4912807716961534, -1.4227332499456706),
+        ];
+        let expected = ZpkFormatFilter::new(Vec::new(), p.to_vec(), 8.);
+        assert!(zpk.approx_eq(&expected, 1e-10), "{zpk:?}");
+    }
+
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    #[test]
+    fn bessel_default_norm_matches_scipy() {
+        use crate::signal::filter::design::BaFormatFilter;
+
+        // from scipy.signal import bessel
+        // bessel(4, 0.2)
+        let expected = DigitalFilter::Ba(BaFormatFilter {
+            b: vec![
+                0.004287420292916852,
+                0.01714968117166741,
+                0.025724521757501114,
+                0.01714968117166741,
+                0.004287420292916852,
+            ],
+            a: vec![
+                1.,
+                -2.217973641334813,
+                1.977072841708559,
+                -0.8251124174637818,
+                0.1346119417767054,
+            ],
+        });
+        let filter = bessel_dyn::<f64>(4, vec![0.2], None, None, None, None, None);
+        assert!(filter.approx_eq(&expected, 1e-12), "{filter:?}");
+
+        // bessel(5, 0.3, btype='highpass')
+        let expected = DigitalFilter::Ba(BaFormatFilter {
+            b: vec![
+                0.16719443505111595,
+                -0.8359721752555797,
+                1.6719443505111593,
+                -1.6719443505111593,
+                0.8359721752555797,
+                -0.16719443505111595,
+            ],
+            a: vec![
+                1.,
+                -1.8334633663871707,
+                1.5883315761095609,
+                -0.7316790810338336,
+                0.1786488534009921,
+                -0.01809904470415278,
+            ],
+        });
+        let filter = bessel_dyn::<f64>(
+            5,
+            vec![0.3],
+            Some(FilterBandType::Highpass),
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(filter.approx_eq(&expected, 1e-12), "{filter:?}");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn try_iirfilter_distinguishes_conflicting_and_invalid_args() {
+        let design = |wn: Vec<f64>, btype, ftype, analog, fs| {
+            try_iirfilter_dyn(4, wn, None, None, Some(btype), ftype, analog, None, fs)
+        };
+
+        // fs and analog=true conflict with each other, even for otherwise valid arguments.
+        assert!(matches!(
+            design(
+                vec![10.],
+                FilterBandType::Lowpass,
+                None,
+                Some(true),
+                Some(100.)
+            ),
+            Err(Error::ConflictArg { .. })
+        ));
+
+        let bad_wn = [
+            (vec![10., 20.], FilterBandType::Lowpass, Some(100.)),
+            (vec![10.], FilterBandType::Bandpass, Some(100.)),
+            (vec![0.], FilterBandType::Highpass, None),
+            (vec![20., 10.], FilterBandType::Bandstop, Some(100.)),
+            (vec![50.], FilterBandType::Lowpass, Some(100.)),
+            (vec![1.5], FilterBandType::Lowpass, None),
+        ];
+        for (wn, btype, fs) in bad_wn {
+            assert!(matches!(
+                design(wn, btype, None, None, fs),
+                Err(Error::InvalidArg { arg, .. }) if arg == "wn"
+            ));
         }
+
+        assert!(matches!(
+            design(vec![0.2], FilterBandType::Lowpass, Some(FilterType::ChebyshevI), None, None),
+            Err(Error::InvalidArg { arg, .. }) if arg == "rp"
+        ));
+        // Elliptic filters need both rp and rs.
+        assert!(matches!(
+            design(vec![0.2], FilterBandType::Lowpass, Some(FilterType::CauerElliptic), None, None),
+            Err(Error::InvalidArg { arg, .. }) if arg == "rp"
+        ));
+        assert!(try_iirfilter_dyn(
+            4,
+            vec![0.2],
+            Some(1.),
+            Some(40.),
+            Some(FilterBandType::Lowpass),
+            Some(FilterType::CauerElliptic),
+            None,
+            None,
+            None
+        )
+        .is_ok());
+
+        // Analog critical frequencies are not bounded by the Nyquist frequency.
+        assert!(design(vec![1.5], FilterBandType::Lowpass, None, Some(true), None).is_ok());
+
+        let zpk = |filter| match filter {
+            DigitalFilter::Zpk(zpk) => zpk,
+            _ => panic!("Expected a filter in ZPK format."),
+        };
+        let args = || {
+            (
+                vec![10., 50.],
+                Some(FilterBandType::Bandpass),
+                Some(FilterOutputType::Zpk),
+                Some(1666.),
+            )
+        };
+        let (wn, btype, output, fs) = args();
+        let expected = zpk(iirfilter_dyn::<f64>(
+            4, wn, None, None, btype, None, None, output, fs,
+        ));
+        let (wn, btype, output, fs) = args();
+        let actual = try_iirfilter_dyn(4, wn, None, None, btype, None, None, output, fs).unwrap();
+        assert!(zpk(actual).approx_eq(&expected, 0.));
     }
 
     #[cfg(all(feature = "alloc", feature = "std"))]
@@ -710,24 +1609,10 @@ mod tests {
             Some(1666.),
         );
 
-        match filter {
-            DigitalFilter::Zpk(zpk) => {
-                assert_eq!(zpk.z.len(), expected_zpk.z.len());
-                for (a, e) in zpk.z.iter().zip(expected_zpk.z.iter()) {
-                    assert_relative_eq!(a.re, e.re, max_relative = 1e-6);
-                    assert_relative_eq!(a.im, e.im, max_relative = 1e-6);
-                }
-
-                assert_eq!(zpk.p.len(), expected_zpk.p.len());
-                for (a, e) in zpk.p.iter().zip(expected_zpk.p.iter()) {
-                    assert_relative_eq!(a.re, e.re, max_relative = 1e-6);
-                    assert_relative_eq!(a.im, e.im, max_relative = 1e-6);
-                }
-
-                assert_relative_eq!(zpk.k, expected_zpk.k, max_relative = 1e-8);
-            }
-            _ => panic!(),
-        }
+        assert!(
+            filter.approx_eq(&DigitalFilter::Zpk(expected_zpk), 1e-8),
+            "{filter:?}"
+        );
     }
 
     #[cfg(all(feature = "alloc", feature = "std"))]
@@ -769,9 +1654,7 @@ mod tests {
                 ];
 
                 assert_eq!(expected_sos.len(), sos.sos.len());
-                for i in 0..sos.sos.len() {
-                    let actual = sos.sos[i];
-                    let expected = expected_sos[i];
+                for (actual, expected) in sos.sos.iter().zip(&expected_sos) {
                     assert_relative_eq!(actual.b[0], expected.b[0], max_relative = 1e-7);
                     assert_relative_eq!(actual.b[1], expected.b[1], max_relative = 1e-7);
                     assert_relative_eq!(actual.b[2], expected.b[2], max_relative = 1e-7);
@@ -875,24 +1758,10 @@ mod tests {
             Some(2003.),
         );
 
-        match filter {
-            DigitalFilter::Zpk(zpk) => {
-                assert_eq!(zpk.z.len(), expected_zpk.z.len());
-                for (a, e) in zpk.z.iter().zip(expected_zpk.z.iter()) {
-                    assert_relative_eq!(a.re, e.re, max_relative = 1e-6);
-                    assert_relative_eq!(a.im, e.im, max_relative = 1e-6);
-                }
-
-                assert_eq!(zpk.p.len(), expected_zpk.p.len());
-                for (a, e) in zpk.p.iter().zip(expected_zpk.p.iter()) {
-                    assert_relative_eq!(a.re, e.re, max_relative = 1e-6);
-                    assert_relative_eq!(a.im, e.im, max_relative = 1e-6);
-                }
-
-                assert_relative_eq!(zpk.k, expected_zpk.k, max_relative = 1e-8);
-            }
-            _ => panic!(),
-        }
+        assert!(
+            filter.approx_eq(&DigitalFilter::Zpk(expected_zpk), 1e-8),
+            "{filter:?}"
+        );
     }
 
     #[cfg(all(feature = "alloc", feature = "std"))]
@@ -910,7 +1779,7 @@ mod tests {
             ],
             vec![
                 Complex::new(0.86788666, 0.23258286),
-                Complex::new(0.76382075, 0.0847872),
+                Complex::new(0.76382075, 0.08478723),
                 Complex::new(0.76382075, -0.08478723),
                 Complex::new(0.86788666, -0.23258286),
             ],
@@ -928,23 +1797,61 @@ mod tests {
             Some(2003.),
         );
 
-        match filter {
-            DigitalFilter::Zpk(zpk) => {
-                assert_eq!(zpk.z.len(), expected_zpk.z.len());
-                for (a, e) in zpk.z.iter().zip(expected_zpk.z.iter()) {
-                    assert_relative_eq!(a.re, e.re, max_relative = 1e-6);
-                    assert_relative_eq!(a.im, e.im, max_relative = 1e-6);
-                }
+        assert!(
+            filter.approx_eq(&DigitalFilter::Zpk(expected_zpk), 1e-8),
+            "{filter:?}"
+        );
+    }
 
-                assert_eq!(zpk.p.len(), expected_zpk.p.len());
-                for (a, e) in zpk.p.iter().zip(expected_zpk.p.iter()) {
-                    assert_relative_eq!(a.re, e.re, max_relative = 1e-6);
-                    assert_relative_eq!(a.im, e.im, max_relative = 1e-6);
-                }
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    #[test]
+    fn matches_scipy_cheby2_sos_bandstop() {
+        // Unlike Butterworth, the zeros are finite and must each be paired with the nearest poles.
+        // >>> signal.cheby2(4, 40, [0.2, 0.5], 'bandstop', output='sos')
+        let filter = cheby2_dyn::<f64>(
+            4,
+            40.,
+            vec![0.2, 0.5],
+            Some(FilterBandType::Bandstop),
+            Some(false),
+            Some(FilterOutputType::Sos),
+            None,
+        );
+
+        let DigitalFilter::Sos(sos) = filter else {
+            panic!("Expected sos output")
+        };
+        let expected_sos = [
+            Sos::new(
+                [
+                    0.13533791665221215,
+                    -0.08800549313177074,
+                    0.13533791665221215,
+                ],
+                [1., 0.12207268458233939, 0.12961950145192377],
+            ),
+            Sos::new(
+                [1., -1.3131866745501823, 1.],
+                [1., -1.1907687640307096, 0.41194929526024876],
+            ),
+            Sos::new(
+                [1., -0.07829227910913225, 1.],
+                [1., 0.6341402357831647, 0.6129411034957161],
+            ),
+            Sos::new(
+                [1., -1.5901000053395518, 1.],
+                [1., -1.6478049130270265, 0.8066627946214087],
+            ),
+        ];
 
-                assert_relative_eq!(zpk.k, expected_zpk.k, max_relative = 1e-8);
+        assert_eq!(expected_sos.len(), sos.sos.len());
+        for (actual, expected) in sos.sos.iter().zip(expected_sos.iter()) {
+            for (a, e) in actual.b.iter().zip(expected.b.iter()) {
+                assert_relative_eq!(a, e, max_relative = 1e-9);
+            }
+            for (a, e) in actual.a.iter().zip(expected.a.iter()) {
+                assert_relative_eq!(a, e, max_relative = 1e-9);
             }
-            _ => panic!(),
         }
     }
 }
@@ -0,0 +1,1011 @@
+use core::{f64::consts::PI, iter::Sum};
+
+use nalgebra::RealField;
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use super::{iirfilter_dyn, DigitalFilter, FilterBandType, FilterOutputType, FilterType};
+use crate::special::xsf::ellipk;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Butterworth filter order selection.
+///
+/// Return the order of the lowest order digital or analog Butterworth filter that loses no more
+/// than `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband.
+///
+/// Parameters
+/// ----------
+/// * `wp`, `ws` : array_like  
+///   Passband and stopband edge frequencies, each of length 1 or 2.
+///
+///   For digital filters, these are in the same units as `fs`. By default,
+///   `fs` is 2 half-cycles/sample, so these are normalized from 0 to 1,
+///   where 1 is the Nyquist frequency. (`wp` and `ws` are thus in
+///   half-cycles / sample.) For example:
+///
+///   - Lowpass:   wp = [0.2],        ws = [0.3]
+///   - Highpass:  wp = [0.3],        ws = [0.2]
+///   - Bandpass:  wp = [0.2, 0.5],   ws = [0.1, 0.6]
+///   - Bandstop:  wp = [0.1, 0.6],   ws = [0.2, 0.5]
+///
+///   For analog filters, `wp` and `ws` are angular frequencies (e.g., rad/s).
+/// * `gpass` : float  
+///   The maximum loss in the passband (dB).
+/// * `gstop` : float  
+///   The minimum attenuation in the stopband (dB).
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned.
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
+///
+/// Returns
+/// -------
+/// * `ord` : int  
+///   The lowest order for a Butterworth filter which meets specs.
+/// * `wn` : ndarray or float  
+///   The Butterworth natural frequency (i.e. the "3dB frequency"). Should
+///   be used with [butter_dyn](super::butter_dyn) or [iirfilter_dyn](super::iirfilter_dyn) to
+///   give filter results. If `fs` is specified, this is in the same units, and `fs` must also be
+///   passed to the design function.
+///
+/// Panics
+/// ------
+/// If `gpass` or `gstop` are not positive, or `gpass > gstop`, or if `wp` and `ws` do not
+/// describe one of the four band types above.
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.buttord.html>
+#[cfg(feature = "alloc")]
+pub fn buttord_dyn<F>(
+    wp: Vec<F>,
+    ws: Vec<F>,
+    gpass: F,
+    gstop: F,
+    analog: Option<bool>,
+    fs: Option<F>,
+) -> (usize, Vec<F>)
+where
+    F: Float + RealField,
+{
+    let analog = analog.unwrap_or(false);
+    let (btype, nat, passb) =
+        lowpass_prototype_spec(wp, ws, gpass, gstop, analog, fs, butter_order);
+
+    let ord = Float::ceil(butter_order(nat, gpass, gstop));
+    let ord_usize = ord.to_usize().unwrap();
+
+    // Find the Butterworth natural frequency W0 (or the "3dB" frequency) to give exactly gpass at
+    // passb.
+    let one = F::one();
+    let two = F::from(2).unwrap();
+    let gpass_lin = Float::powf(
+        F::from(10).unwrap(),
+        F::from(0.1).unwrap() * Float::abs(gpass),
+    );
+    let w0 = Float::powf(gpass_lin - one, -one / (two * ord));
+
+    // Convert this frequency back from the lowpass prototype to the original analog filter.
+    let wn = match btype {
+        FilterBandType::Lowpass => vec![w0 * passb[0]],
+        FilterBandType::Highpass => vec![passb[0] / w0],
+        FilterBandType::Bandstop => {
+            let bw = passb[1] - passb[0];
+            let discr = Float::sqrt(bw * bw + F::from(4).unwrap() * w0 * w0 * passb[0] * passb[1]);
+            sorted_abs(vec![(bw + discr) / (two * w0), (bw - discr) / (two * w0)])
+        }
+        FilterBandType::Bandpass => {
+            let bw = passb[1] - passb[0];
+            let wn = [-w0, w0]
+                .iter()
+                .map(|&w0| {
+                    -w0 * bw / two
+                        + Float::sqrt(w0 * w0 / F::from(4).unwrap() * bw * bw + passb[0] * passb[1])
+                })
+                .collect();
+            sorted_abs(wn)
+        }
+    };
+
+    (ord_usize, post_warp(wn, analog, fs))
+}
+
+/// Chebyshev type I filter order selection.
+///
+/// Return the order of the lowest order digital or analog Chebyshev Type I filter that loses no
+/// more than `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband.
+///
+/// Parameters
+/// ----------
+/// * `wp`, `ws` : array_like  
+///   Passband and stopband edge frequencies, each of length 1 or 2, which select the band type as
+///   described in [buttord_dyn]. For digital filters, these are in the same units as `fs`, which
+///   is 2 half-cycles/sample by default. For analog filters, these are angular frequencies
+///   (e.g., rad/s).
+/// * `gpass` : float  
+///   The maximum loss in the passband (dB).
+/// * `gstop` : float  
+///   The minimum attenuation in the stopband (dB).
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned.
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
+///
+/// Returns
+/// -------
+/// * `ord` : int  
+///   The lowest order for a Chebyshev type I filter that meets specs.
+/// * `wn` : ndarray or float  
+///   The Chebyshev natural frequency (the "3dB frequency") for use with
+///   [cheby1_dyn](super::cheby1_dyn) to give filter results. If `fs` is specified, this is in
+///   the same units, and `fs` must also be passed to [cheby1_dyn](super::cheby1_dyn).
+///
+/// Panics
+/// ------
+/// As [buttord_dyn].
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.cheb1ord.html>
+#[cfg(feature = "alloc")]
+pub fn cheb1ord_dyn<F>(
+    wp: Vec<F>,
+    ws: Vec<F>,
+    gpass: F,
+    gstop: F,
+    analog: Option<bool>,
+    fs: Option<F>,
+) -> (usize, Vec<F>)
+where
+    F: Float + RealField,
+{
+    let analog = analog.unwrap_or(false);
+    let (btype, nat, passb) = lowpass_prototype_spec(wp, ws, gpass, gstop, analog, fs, cheby_order);
+
+    let ord = Float::ceil(cheby_order(nat, gpass, gstop))
+        .to_usize()
+        .unwrap();
+
+    // Natural frequencies are just the passband edges
+    (ord, post_warp(passb, analog, fs))
+}
+
+/// Chebyshev type II filter order selection.
+///
+/// Return the order of the lowest order digital or analog Chebyshev Type II filter that loses no
+/// more than `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband.
+///
+/// Parameters
+/// ----------
+/// * `wp`, `ws` : array_like  
+///   Passband and stopband edge frequencies, each of length 1 or 2, which select the band type as
+///   described in [buttord_dyn]. For digital filters, these are in the same units as `fs`, which
+///   is 2 half-cycles/sample by default. For analog filters, these are angular frequencies
+///   (e.g., rad/s).
+/// * `gpass` : float  
+///   The maximum loss in the passband (dB).
+/// * `gstop` : float  
+///   The minimum attenuation in the stopband (dB).
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned.
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
+///
+/// Returns
+/// -------
+/// * `ord` : int  
+///   The lowest order for a Chebyshev type II filter that meets specs.
+/// * `wn` : ndarray or float  
+///   The Chebyshev natural frequency (the "3dB frequency") for use with
+///   [cheby2_dyn](super::cheby2_dyn) to give filter results. If `fs` is specified, this is in
+///   the same units, and `fs` must also be passed to [cheby2_dyn](super::cheby2_dyn).
+///
+/// Panics
+/// ------
+/// As [buttord_dyn].
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.cheb2ord.html>
+#[cfg(feature = "alloc")]
+pub fn cheb2ord_dyn<F>(
+    wp: Vec<F>,
+    ws: Vec<F>,
+    gpass: F,
+    gstop: F,
+    analog: Option<bool>,
+    fs: Option<F>,
+) -> (usize, Vec<F>)
+where
+    F: Float + RealField,
+{
+    let analog = analog.unwrap_or(false);
+    let (btype, nat, passb) = lowpass_prototype_spec(wp, ws, gpass, gstop, analog, fs, cheby_order);
+
+    let one = F::one();
+    let two = F::from(2).unwrap();
+    let ord = Float::ceil(cheby_order(nat, gpass, gstop));
+
+    // Find frequency where analog response is -gpass dB, then convert back from the lowpass
+    // prototype to the original filter.
+    let new_freq = one / Float::cosh(Float::acosh(cheby_gain_ratio(gpass, gstop)) / ord);
+    let nat = match btype {
+        FilterBandType::Lowpass => vec![passb[0] / new_freq],
+        FilterBandType::Highpass => vec![passb[0] * new_freq],
+        FilterBandType::Bandstop => {
+            let bw = passb[1] - passb[0];
+            let product = passb[0] * passb[1];
+            let nat0 = -new_freq / two * bw
+                + Float::sqrt(new_freq * new_freq * bw * bw / F::from(4).unwrap() + product);
+            vec![nat0, product / nat0]
+        }
+        FilterBandType::Bandpass => {
+            let bw = passb[1] - passb[0];
+            let product = passb[0] * passb[1];
+            let nat0 = -bw / (two * new_freq)
+                + Float::sqrt(bw * bw / (F::from(4).unwrap() * new_freq * new_freq) + product);
+            vec![nat0, product / nat0]
+        }
+    };
+
+    (ord.to_usize().unwrap(), post_warp(nat, analog, fs))
+}
+
+/// Elliptic (Cauer) filter order selection.
+///
+/// Return the order of the lowest order digital or analog elliptic filter that loses no more than
+/// `gpass` dB in the passband and has at least `gstop` dB attenuation in the stopband.
+///
+/// Parameters
+/// ----------
+/// * `wp`, `ws` : array_like  
+///   Passband and stopband edge frequencies, each of length 1 or 2, which select the band type as
+///   described in [buttord_dyn]. For digital filters, these are in the same units as `fs`, which
+///   is 2 half-cycles/sample by default. For analog filters, these are angular frequencies
+///   (e.g., rad/s).
+/// * `gpass` : float  
+///   The maximum loss in the passband (dB).
+/// * `gstop` : float  
+///   The minimum attenuation in the stopband (dB).
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned.
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
+///
+/// Returns
+/// -------
+/// * `ord` : int  
+///   The lowest order for an elliptic filter that meets specs.
+/// * `wn` : ndarray or float  
+///   The natural frequency (the passband edges) for use with an elliptic filter design. If `fs`
+///   is specified, this is in the same units, and `fs` must also be passed to the design
+///   function.
+///
+/// Panics
+/// ------
+/// As [buttord_dyn].
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.ellipord.html>
+#[cfg(feature = "alloc")]
+pub fn ellipord_dyn<F>(
+    wp: Vec<F>,
+    ws: Vec<F>,
+    gpass: F,
+    gstop: F,
+    analog: Option<bool>,
+    fs: Option<F>,
+) -> (usize, Vec<F>)
+where
+    F: Float + RealField,
+{
+    let analog = analog.unwrap_or(false);
+    let (_, nat, passb) = lowpass_prototype_spec(wp, ws, gpass, gstop, analog, fs, ellip_order);
+
+    let ord = Float::ceil(ellip_order(nat, gpass, gstop))
+        .to_usize()
+        .unwrap();
+
+    (ord, post_warp(passb, analog, fs))
+}
+
+/// Complete IIR digital and analog filter design.
+///
+/// Given passband and stopband frequencies and gains, construct an analog or digital IIR filter
+/// of minimum order for a given basic type. The order and natural frequencies are selected by
+/// [buttord_dyn], [cheb1ord_dyn], [cheb2ord_dyn] or [ellipord_dyn], and the filter is then
+/// designed by [iirfilter_dyn] with `rp = gpass` and `rs = gstop`.
+///
+/// Parameters
+/// ----------
+/// * `wp`, `ws` : array_like  
+///   Passband and stopband edge frequencies, each of length 1 or 2, which select the band type as
+///   described in [buttord_dyn]. For digital filters, these are in the same units as `fs`, which
+///   is 2 half-cycles/sample by default. For analog filters, these are angular frequencies
+///   (e.g., rad/s).
+/// * `gpass` : float  
+///   The maximum loss in the passband (dB).
+/// * `gstop` : float  
+///   The minimum attenuation in the stopband (dB).
+/// * `analog` : bool, optional  
+///   When True, return an analog filter, otherwise a digital filter is
+///   returned.
+/// * `ftype` : [FilterType], optional  
+///   The type of IIR filter to design. Defaults to [FilterType::CauerElliptic], as in scipy.
+/// * `output` : [FilterOutputType], optional  
+///   Filter form of the output. Defaults to [FilterOutputType::Ba].
+/// * `fs` : float, optional  
+///   The sampling frequency of the digital system.
+///
+/// Returns
+/// -------
+/// * `filter` : [DigitalFilter]  
+///   The designed filter, in the `output` representation.
+///
+/// Panics
+/// ------
+/// As [buttord_dyn], and for [FilterType::BesselThomson], which has no order selection.
+///
+/// # Example
+/// ```
+/// use sci_rs::signal::filter::design::{buttord_dyn, iirdesign_dyn, FilterOutputType,
+///     FilterType};
+///
+/// let filter = iirdesign_dyn(
+///     vec![0.2, 0.5],
+///     vec![0.1, 0.6],
+///     1.,
+///     40.,
+///     None,
+///     Some(FilterType::Butterworth),
+///     Some(FilterOutputType::Sos),
+///     None,
+/// );
+/// // A bandpass design has twice the order of its lowpass prototype.
+/// let (order, _) = buttord_dyn(vec![0.2, 0.5], vec![0.1, 0.6], 1., 40., None, None);
+/// assert_eq!(filter.order(), 2 * order);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.iirdesign.html>
+#[allow(clippy::too_many_arguments)]
+#[cfg(feature = "alloc")]
+pub fn iirdesign_dyn<F>(
+    wp: Vec<F>,
+    ws: Vec<F>,
+    gpass: F,
+    gstop: F,
+    analog: Option<bool>,
+    ftype: Option<FilterType>,
+    output: Option<FilterOutputType>,
+    fs: Option<F>,
+) -> DigitalFilter<F>
+where
+    F: Float + RealField + Sum,
+{
+    let ftype = ftype.unwrap_or(FilterType::CauerElliptic);
+    let ordfunc = match ftype {
+        FilterType::Butterworth => buttord_dyn,
+        FilterType::ChebyshevI => cheb1ord_dyn,
+        FilterType::ChebyshevII => cheb2ord_dyn,
+        FilterType::CauerElliptic => ellipord_dyn,
+        FilterType::BesselThomson(_) => {
+            panic!("Bessel/Thomson does not have order selection. Use iirfilter_dyn function.")
+        }
+    };
+
+    let (_, _, btype) = validate_wp_ws(wp.clone(), ws.clone(), fs, analog.unwrap_or(false));
+    let (ord, wn) = ordfunc(wp, ws, gpass, gstop, analog, fs);
+
+    iirfilter_dyn(
+        ord,
+        wn,
+        Some(gpass),
+        Some(gstop),
+        Some(btype),
+        Some(ftype),
+        analog,
+        output,
+        fs,
+    )
+}
+
+/// Fractional order of a Butterworth filter with the given natural frequency ratio.
+fn butter_order<F: Float>(nat: F, gpass: F, gstop: F) -> F {
+    let ten = F::from(10).unwrap();
+    let tenth = F::from(0.1).unwrap();
+    let gstop = Float::powf(ten, tenth * Float::abs(gstop));
+    let gpass = Float::powf(ten, tenth * Float::abs(gpass));
+    Float::log10((gstop - F::one()) / (gpass - F::one()))
+        / (F::from(2).unwrap() * Float::log10(nat))
+}
+
+/// Ratio `sqrt((10^(gstop/10) - 1) / (10^(gpass/10) - 1))` of the stopband and passband gains.
+fn cheby_gain_ratio<F: Float>(gpass: F, gstop: F) -> F {
+    let ten = F::from(10).unwrap();
+    let tenth = F::from(0.1).unwrap();
+    let gstop = Float::powf(ten, tenth * Float::abs(gstop));
+    let gpass = Float::powf(ten, tenth * Float::abs(gpass));
+    Float::sqrt((gstop - F::one()) / (gpass - F::one()))
+}
+
+/// Fractional order of a Chebyshev filter with the given natural frequency ratio.
+fn cheby_order<F: Float>(nat: F, gpass: F, gstop: F) -> F {
+    Float::acosh(cheby_gain_ratio(gpass, gstop)) / Float::acosh(nat)
+}
+
+/// Fractional order of an elliptic filter with the given natural frequency ratio, from the
+/// degree equation `n = K(k^2) K(1 - k1^2) / (K(1 - k^2) K(k1^2))` with selectivity `k = 1 / nat`
+/// and discrimination `k1 = 1 / cheby_gain_ratio`.
+fn ellip_order<F: Float>(nat: F, gpass: F, gstop: F) -> F {
+    let arg0 = F::one() / nat;
+    let arg1 = F::one() / cheby_gain_ratio(gpass, gstop);
+    let (m0, m1) = (arg0 * arg0, arg1 * arg1);
+    ellipk(m0) * ellipk(F::one() - m1) / (ellipk(F::one() - m0) * ellipk(m1))
+}
+
+/// Validate a passband/stopband spec and reduce it to the analog lowpass prototype.
+///
+/// Returns the band type, the natural frequency ratio of the prototype, and the (pre-warped and,
+/// for bandstop filters, optimized) passband edges. `order` gives the fractional order of the
+/// filter family for a natural frequency ratio, with `gpass` and `gstop` fixed.
+#[cfg(feature = "alloc")]
+fn lowpass_prototype_spec<F>(
+    wp: Vec<F>,
+    ws: Vec<F>,
+    gpass: F,
+    gstop: F,
+    analog: bool,
+    fs: Option<F>,
+    order: fn(F, F, F) -> F,
+) -> (FilterBandType, F, Vec<F>)
+where
+    F: Float,
+{
+    validate_gpass_gstop(gpass, gstop);
+    let (wp, ws, btype) = validate_wp_ws(wp, ws, fs, analog);
+    let (passb, stopb) = pre_warp(&wp, &ws, analog);
+    let (nat, passb) = find_nat_freq(&stopb, passb, btype, |nat| order(nat, gpass, gstop));
+    (btype, nat, passb)
+}
+
+/// Check that the passband loss and stopband attenuation are consistent.
+fn validate_gpass_gstop<F: Float>(gpass: F, gstop: F) {
+    if gpass <= F::zero() {
+        panic!("gpass should be larger than 0.0");
+    } else if gstop <= F::zero() {
+        panic!("gstop should be larger than 0.0");
+    } else if gpass > gstop {
+        panic!("gpass should be smaller than gstop");
+    }
+}
+
+/// Normalize `wp` and `ws` by `fs`, check them, and deduce the band type from their lengths and
+/// relative order.
+#[cfg(feature = "alloc")]
+fn validate_wp_ws<F: Float>(
+    wp: Vec<F>,
+    ws: Vec<F>,
+    fs: Option<F>,
+    analog: bool,
+) -> (Vec<F>, Vec<F>, FilterBandType) {
+    let (wp, ws) = match fs {
+        Some(fs) => {
+            if analog {
+                panic!("fs cannot be specified for an analog filter");
+            }
+            let two = F::from(2).unwrap();
+            (
+                wp.into_iter().map(|w| two * w / fs).collect::<Vec<_>>(),
+                ws.into_iter().map(|w| two * w / fs).collect::<Vec<_>>(),
+            )
+        }
+        None => (wp, ws),
+    };
+
+    if wp.len() != ws.len() || !(1..=2).contains(&wp.len()) {
+        panic!("wp and ws must have one or two elements each, and the same shape");
+    }
+    if wp.iter().chain(&ws).any(|&w| w <= F::zero()) {
+        panic!("Values for wp, ws must be greater than 0");
+    }
+    if !analog && wp.iter().chain(&ws).any(|&w| w >= F::one()) {
+        panic!("Values for wp, ws must be less than fs/2");
+    }
+    if wp.len() == 2 && !((ws[0] < wp[0] && wp[1] < ws[1]) || (wp[0] < ws[0] && ws[1] < wp[1])) {
+        panic!("Passband must lie strictly inside stopband or vice versa");
+    }
+
+    let btype = match (wp.len(), wp[0] >= ws[0]) {
+        (1, false) => FilterBandType::Lowpass,
+        (1, true) => FilterBandType::Highpass,
+        (_, false) => FilterBandType::Bandstop,
+        (_, true) => FilterBandType::Bandpass,
+    };
+    (wp, ws, btype)
+}
+
+/// Pre-warp digital band edges to the analog frequencies of the bilinear transform.
+#[cfg(feature = "alloc")]
+fn pre_warp<F: Float>(wp: &[F], ws: &[F], analog: bool) -> (Vec<F>, Vec<F>) {
+    if analog {
+        return (wp.to_vec(), ws.to_vec());
+    }
+    let warp = |w: &F| Float::tan(F::from(PI / 2.).unwrap() * *w);
+    (wp.iter().map(warp).collect(), ws.iter().map(warp).collect())
+}
+
+/// Undo [pre_warp] for digital filters, and scale back to the units of `fs`.
+#[cfg(feature = "alloc")]
+fn post_warp<F: Float>(wn: Vec<F>, analog: bool, fs: Option<F>) -> Vec<F> {
+    let wn = if analog {
+        wn
+    } else {
+        let scale = F::from(2. / PI).unwrap();
+        wn.into_iter().map(|w| scale * Float::atan(w)).collect()
+    };
+    match fs {
+        Some(fs) => {
+            let half_fs = fs / F::from(2).unwrap();
+            wn.into_iter().map(|w| w * half_fs).collect()
+        }
+        None => wn,
+    }
+}
+
+/// Find the natural frequency ratio of the analog lowpass prototype for the given band edges.
+///
+/// For a bandstop filter the passband edges are first moved, within the gap to the stopband, to
+/// minimize the fractional filter order given by `order`. The possibly moved passband edges are
+/// returned alongside the ratio.
+#[cfg(feature = "alloc")]
+fn find_nat_freq<F, O>(
+    stopb: &[F],
+    mut passb: Vec<F>,
+    btype: FilterBandType,
+    order: O,
+) -> (F, Vec<F>)
+where
+    F: Float,
+    O: Fn(F) -> F,
+{
+    let bandstop_nat = |passb: &[F]| {
+        stopb
+            .iter()
+            .map(|&s| Float::abs(s * (passb[0] - passb[1]) / (s * s - passb[0] * passb[1])))
+            .fold(F::infinity(), Float::min)
+    };
+
+    let nat = match btype {
+        FilterBandType::Lowpass => Float::abs(stopb[0] / passb[0]),
+        FilterBandType::Highpass => Float::abs(passb[0] / stopb[0]),
+        FilterBandType::Bandstop => {
+            let tiny = F::from(1e-12).unwrap();
+            let xatol = F::from(1e-5).unwrap();
+            for (ind, lo, hi) in [
+                (0, passb[0], stopb[0] - tiny),
+                (1, stopb[1] + tiny, passb[1]),
+            ] {
+                let moved = fminbound(
+                    |w| {
+                        let mut p = passb.clone();
+                        p[ind] = w;
+                        order(bandstop_nat(&p))
+                    },
+                    lo,
+                    hi,
+                    xatol,
+                );
+                passb[ind] = moved;
+            }
+            bandstop_nat(&passb)
+        }
+        FilterBandType::Bandpass => stopb
+            .iter()
+            .map(|&s| Float::abs((s * s - passb[0] * passb[1]) / (s * (passb[0] - passb[1]))))
+            .fold(F::infinity(), Float::min),
+    };
+    (nat, passb)
+}
+
+/// Bounded scalar minimization of `f` on `[x1, x2]` by Brent's method, as `scipy.optimize.fminbound`
+/// with at most 500 function evaluations.
+fn fminbound<F, Func>(f: Func, x1: F, x2: F, xatol: F) -> F
+where
+    F: Float,
+    Func: Fn(F) -> F,
+{
+    let max_fun = 500;
+    let half = F::from(0.5).unwrap();
+    let two = F::from(2).unwrap();
+    let three = F::from(3).unwrap();
+    let sqrt_eps = Float::sqrt(F::from(2.2e-16).unwrap());
+    let golden_mean = half * (three - Float::sqrt(F::from(5).unwrap()));
+    // Sign of `v`, taking zero as positive.
+    let sign = |v: F| if v < F::zero() { -F::one() } else { F::one() };
+
+    let (mut a, mut b) = (x1, x2);
+    let mut fulc = a + golden_mean * (b - a);
+    let (mut nfc, mut xf) = (fulc, fulc);
+    let (mut rat, mut e) = (F::zero(), F::zero());
+    let mut fx = f(xf);
+    let mut num = 1;
+    let (mut ffulc, mut fnfc) = (fx, fx);
+    let mut xm = half * (a + b);
+    let mut tol1 = sqrt_eps * Float::abs(xf) + xatol / three;
+    let mut tol2 = two * tol1;
+
+    while Float::abs(xf - xm) > tol2 - half * (b - a) {
+        let mut golden = true;
+        // Check for parabolic fit
+        if Float::abs(e) > tol1 {
+            golden = false;
+            let r = (xf - nfc) * (fx - ffulc);
+            let q = (xf - fulc) * (fx - fnfc);
+            let mut p = (xf - fulc) * q - (xf - nfc) * r;
+            let mut q = two * (q - r);
+            if q > F::zero() {
+                p = -p;
+            }
+            q = Float::abs(q);
+            let r = e;
+            e = rat;
+
+            // Check for acceptability of parabola
+            if Float::abs(p) < Float::abs(half * q * r) && p > q * (a - xf) && p < q * (b - xf) {
+                rat = p / q;
+                let x = xf + rat;
+                if (x - a) < tol2 || (b - x) < tol2 {
+                    rat = tol1 * sign(xm - xf);
+                }
+            } else {
+                golden = true;
+            }
+        }
+        if golden {
+            e = if xf >= xm { a - xf } else { b - xf };
+            rat = golden_mean * e;
+        }
+
+        let x = xf + sign(rat) * Float::max(Float::abs(rat), tol1);
+        let fu = f(x);
+        num += 1;
+
+        if fu <= fx {
+            if x >= xf {
+                a = xf;
+            } else {
+                b = xf;
+            }
+            (fulc, ffulc) = (nfc, fnfc);
+            (nfc, fnfc) = (xf, fx);
+            (xf, fx) = (x, fu);
+        } else {
+            if x < xf {
+                a = x;
+            } else {
+                b = x;
+            }
+            if fu <= fnfc || nfc == xf {
+                (fulc, ffulc) = (nfc, fnfc);
+                (nfc, fnfc) = (x, fu);
+            } else if fu <= ffulc || fulc == xf || fulc == nfc {
+                (fulc, ffulc) = (x, fu);
+            }
+        }
+
+        xm = half * (a + b);
+        tol1 = sqrt_eps * Float::abs(xf) + xatol / three;
+        tol2 = two * tol1;
+
+        if num >= max_fun {
+            break;
+        }
+    }
+    xf
+}
+
+/// Absolute values of `w`, in increasing order.
+#[cfg(feature = "alloc")]
+fn sorted_abs<F: Float>(w: Vec<F>) -> Vec<F> {
+    let mut w: Vec<F> = w.into_iter().map(Float::abs).collect();
+    w.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    w
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[track_caller]
+    fn assert_ord_eq(actual: (usize, Vec<f64>), expected: (usize, &[f64])) {
+        assert_eq!(actual.0, expected.0);
+        assert_eq!(actual.1.len(), expected.1.len());
+        for (a, e) in actual.1.iter().zip(expected.1) {
+            assert_relative_eq!(a, e, max_relative = 1e-9);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_scipy_buttord() {
+        // from scipy.signal import buttord
+        // buttord(0.2, 0.3, 3, 40)
+        assert_ord_eq(
+            buttord_dyn(vec![0.2], vec![0.3], 3., 40., None, None),
+            (11, &[0.2000403906692605]),
+        );
+        // buttord(0.3, 0.2, 3, 40)
+        assert_ord_eq(
+            buttord_dyn(vec![0.3], vec![0.2], 3., 40., None, None),
+            (11, &[0.29994441539367495]),
+        );
+        // buttord([0.2, 0.5], [0.1, 0.6], 3, 40)
+        assert_ord_eq(
+            buttord_dyn(vec![0.2, 0.5], vec![0.1, 0.6], 3., 40., None, None),
+            (9, &[0.19997484768392573, 0.5000427940030838]),
+        );
+        // buttord([0.1, 0.6], [0.2, 0.5], 3, 40)
+        assert_ord_eq(
+            buttord_dyn(vec![0.1, 0.6], vec![0.2, 0.5], 3., 40., None, None),
+            (9, &[0.14760890650591516, 0.5999425956799482]),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn buttord_analog_and_fs() {
+        // from scipy.signal import buttord
+        // buttord([20, 50], [14, 60], 3, 40, True)
+        assert_ord_eq(
+            buttord_dyn(vec![20., 50.], vec![14., 60.], 3., 40., Some(true), None),
+            (13, &[19.998434358816233, 50.003914409387455]),
+        );
+        // buttord(100, 200, 1, 30, fs=1000)
+        assert_ord_eq(
+            buttord_dyn(vec![100.], vec![200.], 1., 30., None, Some(1000.)),
+            (6, &[111.01981080939436]),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_scipy_cheb1ord() {
+        // from scipy.signal import cheb1ord
+        // cheb1ord([0.2, 0.5], [0.1, 0.6], 3, 40)
+        assert_ord_eq(
+            cheb1ord_dyn(vec![0.2, 0.5], vec![0.1, 0.6], 3., 40., None, None),
+            (5, &[0.2, 0.5]),
+        );
+        // cheb1ord([0.1, 0.6], [0.2, 0.5], 3, 40)
+        assert_ord_eq(
+            cheb1ord_dyn(vec![0.1, 0.6], vec![0.2, 0.5], 3., 40., None, None),
+            (5, &[0.14758232569947782, 0.5999987080915622]),
+        );
+        // cheb1ord(0.2, 0.3, 3, 40)
+        assert_ord_eq(
+            cheb1ord_dyn(vec![0.2], vec![0.3], 3., 40., None, None),
+            (6, &[0.2]),
+        );
+        // cheb1ord([100, 200], [50, 250], 1, 60, fs=1000)
+        assert_ord_eq(
+            cheb1ord_dyn(
+                vec![100., 200.],
+                vec![50., 250.],
+                1.,
+                60.,
+                None,
+                Some(1000.),
+            ),
+            (7, &[100., 200.]),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_scipy_cheb2ord() {
+        // from scipy.signal import cheb2ord
+        // cheb2ord([0.2, 0.5], [0.1, 0.6], 3, 40)
+        assert_ord_eq(
+            cheb2ord_dyn(vec![0.2, 0.5], vec![0.1, 0.6], 3., 40., None, None),
+            (5, &[0.15201672851009923, 0.5906581070614803]),
+        );
+        // cheb2ord([0.1, 0.6], [0.2, 0.5], 3, 40)
+        assert_ord_eq(
+            cheb2ord_dyn(vec![0.1, 0.6], vec![0.2, 0.5], 3., 40., None, None),
+            (5, &[0.1957821996420536, 0.5072373364647154]),
+        );
+        // cheb2ord(0.3, 0.2, 3, 40)
+        assert_ord_eq(
+            cheb2ord_dyn(vec![0.3], vec![0.2], 3., 40., None, None),
+            (6, &[0.21985418170859403]),
+        );
+        // cheb2ord([20, 50], [14, 60], 3, 40, True)
+        assert_ord_eq(
+            cheb2ord_dyn(vec![20., 50.], vec![14., 60.], 3., 40., Some(true), None),
+            (6, &[16.851947661841816, 59.34032196553274]),
+        );
+        // cheb2ord([100, 200], [50, 250], 1, 60, fs=1000)
+        assert_ord_eq(
+            cheb2ord_dyn(
+                vec![100., 200.],
+                vec![50., 250.],
+                1.,
+                60.,
+                None,
+                Some(1000.),
+            ),
+            (7, &[76.59673525513486, 243.83722988763736]),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_scipy_ellipord() {
+        // from scipy.signal import ellipord
+        // ellipord(0.2, 0.3, 3, 60)
+        assert_ord_eq(
+            ellipord_dyn(vec![0.2], vec![0.3], 3., 60., None, None),
+            (5, &[0.2]),
+        );
+        // ellipord(0.3, 0.2, 1, 40)
+        assert_ord_eq(
+            ellipord_dyn(vec![0.3], vec![0.2], 1., 40., None, None),
+            (4, &[0.3]),
+        );
+        // ellipord([0.2, 0.5], [0.1, 0.6], 3, 40)
+        assert_ord_eq(
+            ellipord_dyn(vec![0.2, 0.5], vec![0.1, 0.6], 3., 40., None, None),
+            (4, &[0.2, 0.5]),
+        );
+        // ellipord([0.1, 0.6], [0.2, 0.5], 3, 40)
+        assert_ord_eq(
+            ellipord_dyn(vec![0.1, 0.6], vec![0.2, 0.5], 3., 40., None, None),
+            (4, &[0.14758232794342985, 0.5999987080915622]),
+        );
+        // ellipord([0.1, 0.6], [0.2, 0.5], 0.5, 80)
+        assert_ord_eq(
+            ellipord_dyn(vec![0.1, 0.6], vec![0.2, 0.5], 0.5, 80., None, None),
+            (7, &[0.14758232794342985, 0.5999987080915622]),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ellipord_analog_and_fs() {
+        // from scipy.signal import ellipord
+        // ellipord([20, 50], [14, 60], 3, 40, True)
+        assert_ord_eq(
+            ellipord_dyn(vec![20., 50.], vec![14., 60.], 3., 40., Some(true), None),
+            (4, &[20., 50.]),
+        );
+        // ellipord(100, 120, 0.1, 80, fs=1000)
+        assert_ord_eq(
+            ellipord_dyn(vec![100.], vec![120.], 0.1, 80., None, Some(1000.)),
+            (10, &[100.]),
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn buttord_rejects_overlapping_bands() {
+        buttord_dyn(vec![0.2, 0.5], vec![0.3, 0.6], 3., 40., None, None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iirdesign_meets_spec() {
+        use crate::signal::filter::design::sosfreqz_dyn;
+
+        #[track_caller]
+        fn assert_meets_spec(
+            filter: DigitalFilter<f64>,
+            passband: impl Fn(f64) -> bool,
+            stopband: impl Fn(f64) -> bool,
+            gpass: f64,
+            gstop: f64,
+            fs: f64,
+        ) {
+            let DigitalFilter::Sos(sos) = filter else {
+                panic!("expected second order sections");
+            };
+            let (w, h) = sosfreqz_dyn(&sos.sos, 2048, false, Some(fs));
+            for (wi, hi) in w.into_iter().zip(h) {
+                let db = 10. * hi.norm_sqr().log10();
+                if passband(wi) {
+                    assert!(db >= -gpass - 1e-6, "{db} dB at {wi}");
+                } else if stopband(wi) {
+                    assert!(db <= -gstop + 1e-6, "{db} dB at {wi}");
+                }
+            }
+        }
+
+        // from scipy.signal import iirdesign
+        // iirdesign([0.2, 0.5], [0.1, 0.6], 1, 40, ftype='butter', output='sos')
+        let filter = iirdesign_dyn(
+            vec![0.2, 0.5],
+            vec![0.1, 0.6],
+            1.,
+            40.,
+            None,
+            Some(FilterType::Butterworth),
+            Some(FilterOutputType::Sos),
+            None,
+        );
+        assert_eq!(
+            filter.order(),
+            2 * buttord_dyn(vec![0.2, 0.5], vec![0.1, 0.6], 1., 40., None, None).0
+        );
+        assert_meets_spec(
+            filter,
+            |w| (0.2..=0.5).contains(&w),
+            |w| w <= 0.1 || w >= 0.6,
+            1.,
+            40.,
+            2.,
+        );
+
+        // iirdesign(100, 150, 0.5, 60, ftype='cheby2', output='sos', fs=1000)
+        let filter = iirdesign_dyn(
+            vec![100.],
+            vec![150.],
+            0.5,
+            60.,
+            None,
+            Some(FilterType::ChebyshevII),
+            Some(FilterOutputType::Sos),
+            Some(1000.),
+        );
+        assert_meets_spec(filter, |w| w <= 100., |w| w >= 150., 0.5, 60., 1000.);
+
+        // The default elliptic design.
+        // iirdesign([0.1, 0.6], [0.2, 0.5], 1, 40, output='sos')
+        let filter = iirdesign_dyn(
+            vec![0.1, 0.6],
+            vec![0.2, 0.5],
+            1.,
+            40.,
+            None,
+            None,
+            Some(FilterOutputType::Sos),
+            None,
+        );
+        assert_eq!(
+            filter.order(),
+            2 * ellipord_dyn(vec![0.1, 0.6], vec![0.2, 0.5], 1., 40., None, None).0
+        );
+        assert_meets_spec(
+            filter,
+            |w| w <= 0.1 || w >= 0.6,
+            |w| (0.2..=0.5).contains(&w),
+            1.,
+            40.,
+            2.,
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn iirdesign_rejects_bessel() {
+        iirdesign_dyn(
+            vec![0.2],
+            vec![0.3],
+            3.,
+            40.,
+            None,
+            Some(FilterType::bessel()),
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    fn fminbound_finds_minimum() {
+        let x = fminbound(|x: f64| (x - 0.3) * (x - 0.3), 0., 1., 1e-8);
+        assert_relative_eq!(x, 0.3, max_relative = 1e-6);
+
+        // A minimum at the bound
+        let x = fminbound(|x: f64| x, 1., 2., 1e-5);
+        assert!((1. ..1. + 1e-4).contains(&x), "{x}");
+    }
+}
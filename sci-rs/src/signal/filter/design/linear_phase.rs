@@ -0,0 +1,109 @@
+use num_traits::Float;
+
+/// The four types of linear-phase FIR filters, by coefficient symmetry and length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinearPhaseType {
+    /// Symmetric coefficients, odd length. Suitable for any band type.
+    TypeI,
+    /// Symmetric coefficients, even length. Has a zero at Nyquist, so cannot be highpass.
+    TypeII,
+    /// Antisymmetric coefficients, odd length. Has zeros at DC and Nyquist, as for
+    /// differentiators and Hilbert transformers.
+    TypeIII,
+    /// Antisymmetric coefficients, even length. Has a zero at DC, so cannot be lowpass.
+    TypeIV,
+}
+
+/// Determine whether FIR filter coefficients have linear phase.
+///
+/// An FIR filter has (generalized) linear phase when its coefficients are symmetric,
+/// `h[n] == h[N-1-n]`, or antisymmetric, `h[n] == -h[N-1-n]`, about their center.
+///
+/// # Parameters
+/// * `taps` : array_like
+///   Coefficients of the FIR filter, such as from [super::firwin_dyn].
+/// * `tol` : float
+///   Absolute tolerance when comparing mirrored coefficients.
+///
+/// # Returns
+/// The [LinearPhaseType] the coefficients satisfy, or `None` if they are neither symmetric nor
+/// antisymmetric. Symmetry takes precedence for coefficients that are both, i.e. all zeros.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::design::{is_linear_phase, LinearPhaseType};
+///
+/// assert_eq!(is_linear_phase(&[0.25, 0.5, 0.25], 1e-12), Some(LinearPhaseType::TypeI));
+/// assert_eq!(is_linear_phase(&[0.25, 0.5, 0.3], 1e-12), None);
+/// ```
+pub fn is_linear_phase<F: Float>(taps: &[F], tol: F) -> Option<LinearPhaseType> {
+    let mirrored = || {
+        taps.iter()
+            .zip(taps.iter().rev())
+            .take(taps.len().div_ceil(2))
+    };
+    let odd = !taps.len().is_multiple_of(2);
+
+    if mirrored().all(|(&a, &b)| (a - b).abs() <= tol) {
+        Some(if odd {
+            LinearPhaseType::TypeI
+        } else {
+            LinearPhaseType::TypeII
+        })
+    } else if mirrored().all(|(&a, &b)| (a + b).abs() <= tol) {
+        Some(if odd {
+            LinearPhaseType::TypeIII
+        } else {
+            LinearPhaseType::TypeIV
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{firwin_dyn, FilterBandType};
+    use crate::signal::windows::Hamming;
+
+    #[test]
+    fn detects_each_type() {
+        let lowpass: alloc::vec::Vec<f64> = firwin_dyn::<f64, f64>(
+            5,
+            &[0.3],
+            None,
+            None::<&Hamming>,
+            &FilterBandType::Lowpass,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            is_linear_phase(&lowpass, 1e-12),
+            Some(LinearPhaseType::TypeI)
+        );
+        assert_eq!(
+            is_linear_phase(&[0.5, 0.5], 1e-12),
+            Some(LinearPhaseType::TypeII)
+        );
+        // Central difference differentiator.
+        assert_eq!(
+            is_linear_phase(&[0.5, 0., -0.5], 1e-12),
+            Some(LinearPhaseType::TypeIII)
+        );
+        // First difference.
+        assert_eq!(
+            is_linear_phase(&[1., -1.], 1e-12),
+            Some(LinearPhaseType::TypeIV)
+        );
+
+        // The center tap of an antisymmetric odd-length filter must vanish.
+        assert_eq!(is_linear_phase(&[0.5, 0.1, -0.5], 1e-12), None);
+        assert_eq!(is_linear_phase(&[1., 2., 3.], 1e-12), None);
+        assert_eq!(
+            is_linear_phase(&[1., 2., 1. + 1e-9], 1e-6),
+            Some(LinearPhaseType::TypeI)
+        );
+    }
+}
@@ -134,8 +134,8 @@ mod tests {
         let wo = 0.16892363165758506;
         let bw = 0.30282619318434084;
 
-        let expected_z = vec![Complex::new(0., 0.); 4];
-        let expected_p = vec![
+        let expected_z = [Complex::new(0., 0.); 4];
+        let expected_p = [
             Complex::new(-0.02022036, -0.07498294),
             Complex::new(-0.07648538, -0.06990013),
             Complex::new(-0.07648538, 0.06990013),
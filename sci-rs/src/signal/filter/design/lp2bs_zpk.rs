@@ -64,72 +64,32 @@ where
     let degree = relative_degree_dyn(&zpk.z, &zpk.p);
 
     //Invert to a highpass filter with desired bandwidth
-    let one_neg = unsafe { F::from(-1.).unwrap_unchecked() };
-    let two = unsafe { F::from(2.).unwrap_unchecked() };
-    let two_neg = unsafe { F::from(-2).unwrap_unchecked() };
-    let z_hp: Vec<_> = zpk
-        .z
-        .iter()
-        .map(|zi| (Complex::new(bw * one_neg, F::zero()) / *zi) * Complex::new(F::zero(), one_neg))
-        .collect();
-
-    let p_hp: Vec<_> = zpk
-        .p
-        .iter()
-        .map(|zi| (Complex::new(F::zero(), bw / two) / *zi) * Complex::new(F::zero(), one_neg))
-        .collect();
+    let bw_half = Complex::new(bw / F::from(2.).unwrap(), F::zero());
+    let z_hp: Vec<_> = zpk.z.iter().map(|zi| bw_half / *zi).collect();
+    let p_hp: Vec<_> = zpk.p.iter().map(|pi| bw_half / *pi).collect();
 
     //Duplicate poles and zeros and shift from baseband to +wo and -wo
     let wo2 = Complex::new(Float::powi(wo, 2), F::zero());
-
-    let mut z_bs_t = z_hp
-        .iter()
-        .map(|zi| {
-            (
-                *zi,
-                ((zi - Complex::new(F::zero(), zi.im)).powi(2) - wo2).sqrt(),
-            )
-        })
-        .collect::<Vec<(Complex<F>, Complex<F>)>>();
-
-    let z_bs_t = z_bs_t
-        .iter()
-        .map(|(zi, zi2)| (Complex::new(zi.re, zi2.im), Complex::new(zi.re, zi2.im)))
-        .collect::<Vec<(Complex<F>, Complex<F>)>>();
-
-    let mut z_bs = z_bs_t
-        .iter()
-        .map(|(a, b)| Complex::new(a.re, b.im))
-        .chain(
-            z_bs_t
-                .iter()
-                .map(|(a, b)| Complex::new(a.re, b.im * one_neg)),
-        )
-        .collect::<Vec<Complex<F>>>();
-
-    let p_bs_t = p_hp
-        .iter()
-        .map(|zi| (*zi, (zi.powi(2) - wo2).sqrt()))
-        .collect::<Vec<(Complex<F>, Complex<F>)>>();
-
-    let p_bs = p_bs_t
-        .iter()
-        .map(|(a, b)| a + b)
-        .chain(p_bs_t.iter().map(|(a, b)| a - b))
-        .collect::<Vec<Complex<F>>>();
+    let shift = |hp: &[Complex<F>]| {
+        let roots: Vec<_> = hp.iter().map(|hi| (hi.powi(2) - wo2).sqrt()).collect();
+        hp.iter()
+            .zip(roots.iter())
+            .map(|(hi, ri)| hi + ri)
+            .chain(hp.iter().zip(roots.iter()).map(|(hi, ri)| hi - ri))
+            .collect::<Vec<Complex<F>>>()
+    };
+    let mut z_bs = shift(&z_hp);
+    let p_bs = shift(&p_hp);
 
     //Move any zeros that were at infinity to the center of the stopband
-    z_bs.extend((0..degree).map(|_| Complex::new(F::zero(), F::one() * wo)));
-    z_bs.extend((0..degree).map(|_| Complex::new(F::zero(), one_neg * wo)));
+    z_bs.extend((0..degree).map(|_| Complex::new(F::zero(), wo)));
+    z_bs.extend((0..degree).map(|_| Complex::new(F::zero(), -wo)));
 
     //Cancel out gain change caused by inversion
-    let num = zpk.z.iter().copied().fold(F::one(), |acc, zi| acc * zi.re);
-    let denom = zpk
-        .p
-        .iter()
-        .map(|pi| Complex::new(one_neg, F::zero()) * *pi)
-        .fold(Complex::new(F::one(), F::zero()), |acc, pi| acc * pi);
-    let k_bs = zpk.k * (num / denom.real());
+    let c_unit = Complex::new(F::one(), F::zero());
+    let num = zpk.z.iter().fold(c_unit, |acc, zi| acc * -zi);
+    let denom = zpk.p.iter().fold(c_unit, |acc, pi| acc * -pi);
+    let k_bs = zpk.k * (num / denom).re;
 
     ZpkFormatFilter::new(z_bs, p_bs, k_bs)
 }
@@ -143,12 +103,13 @@ mod tests {
     #[cfg(all(feature = "alloc", feature = "std"))]
     #[test]
     fn matches_scipy_example_bandstop() {
+        // Real zeros at -1, signed so that sqrt takes the same branch as numpy did.
         let zpk: ZpkFormatFilter<_> = ZpkFormatFilter::new(
             vec![
-                Complex::new(-1., -1.),
-                Complex::new(-1., -1.),
-                Complex::new(-1., -1.),
-                Complex::new(-1., -1.),
+                Complex::new(-1., -0.),
+                Complex::new(-1., -0.),
+                Complex::new(-1., -0.),
+                Complex::new(-1., -0.),
             ],
             vec![
                 Complex::new(0.98765384, 0.02863265),
@@ -161,7 +122,7 @@ mod tests {
 
         let wo = 1.6;
         let bw = 1.2;
-        let expected_z = vec![
+        let expected_z = [
             Complex::new(-0.6, -1.4832397),
             Complex::new(-0.6, -1.4832397),
             Complex::new(-0.6, -1.4832397),
@@ -171,7 +132,7 @@ mod tests {
             Complex::new(-0.6, 1.4832397),
             Complex::new(-0.6, 1.4832397),
         ];
-        let expected_p = vec![
+        let expected_p = [
             Complex::new(0.61420466, -1.49811199),
             Complex::new(0.62070377, -1.48343601),
             Complex::new(0.62070377, 1.48343601),
@@ -204,10 +165,10 @@ mod tests {
     fn matches_scipy_example_bandstop_two() {
         let zpk: ZpkFormatFilter<_> = ZpkFormatFilter::new(
             vec![
-                Complex::new(1., 1.),
-                Complex::new(1., 1.),
-                Complex::new(1., 1.),
-                Complex::new(1., 1.),
+                Complex::new(1., 0.),
+                Complex::new(1., 0.),
+                Complex::new(1., 0.),
+                Complex::new(1., 0.),
             ],
             vec![
                 Complex::new(0.86788666, -0.23258286),
@@ -220,7 +181,7 @@ mod tests {
 
         let wo = 1.6;
         let bw = 1.2;
-        let expected_z = vec![
+        let expected_z = [
             Complex::new(0.6, 1.4832397),
             Complex::new(0.6, 1.4832397),
             Complex::new(0.6, 1.4832397),
@@ -230,7 +191,7 @@ mod tests {
             Complex::new(0.6, -1.4832397),
             Complex::new(0.6, -1.4832397),
         ];
-        let expected_p = vec![
+        let expected_p = [
             Complex::new(0.72053235, 1.64918244),
             Complex::new(0.82361252, 1.48883633),
             Complex::new(0.82361252, -1.48883633),
@@ -108,8 +108,8 @@ mod tests {
         // z1 = [-0.16892363 -0.16892363 -0.16892363 -0.16892363]
         // p1 = [0.16640246+0.00580077j 0.16312117+0.00235538j 0.16312117-0.00235538j 0.16640246-0.00580077j]
         // k1 = 1.204213960778651e-07
-        let expected_z = vec![Complex::new(-0.16892363, -0.16892363); 4];
-        let expected_p = vec![
+        let expected_z = [Complex::new(-0.16892363, -0.16892363); 4];
+        let expected_p = [
             Complex::new(0.16640246, 0.00580077),
             Complex::new(0.16312117, 0.00235538),
             Complex::new(0.16312117, -0.00235538),
@@ -1,15 +1,21 @@
 mod bilinear_zpk;
 mod butter;
 mod cplx;
+mod filter_builder;
 mod filter_output;
 mod filter_type;
+mod fir_metrics;
 mod firwin;
+mod freqz;
 mod iirfilter;
+mod iirord;
 mod kaiser;
+mod linear_phase;
 mod lp2bp_zpk;
 mod lp2bs_zpk;
 mod lp2hp_zpk;
 mod lp2lp_zpk;
+mod quantize_sos;
 mod relative_degree;
 mod sos;
 mod zpk2sos;
@@ -18,15 +24,21 @@ mod zpk2tf;
 pub use bilinear_zpk::*;
 pub use butter::*;
 use cplx::*;
+pub use filter_builder::*;
 pub use filter_output::*;
 pub use filter_type::*;
+pub use fir_metrics::*;
 pub use firwin::*;
+pub use freqz::*;
 pub use iirfilter::*;
+pub use iirord::*;
 pub use kaiser::*;
+pub use linear_phase::*;
 pub use lp2bp_zpk::*;
 pub use lp2bs_zpk::*;
 pub use lp2hp_zpk::*;
 pub use lp2lp_zpk::*;
+pub use quantize_sos::*;
 use relative_degree::*;
 pub use sos::*;
 pub use zpk2sos::*;
@@ -0,0 +1,106 @@
+use nalgebra::RealField;
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use super::{sosfreqz_dyn, Sos};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Number of frequencies at which the quantized response is compared.
+#[cfg(feature = "alloc")]
+const QUANTIZE_WORN: usize = 512;
+
+/// Quantize second-order section coefficients to fixed point.
+///
+/// Each coefficient is rounded to the nearest multiple of `2^-frac_bits`, as when exporting the
+/// filter to a fixed-point DSP or FPGA. The frequency responses of the original and quantized
+/// cascades are compared to help choose a word length.
+///
+/// # Parameters
+/// * `sos` : array_like
+///   Array of second-order filter coefficients.
+/// * `frac_bits` : int
+///   Number of fractional bits of the fixed-point representation.
+///
+/// # Returns
+/// * `sos` : ndarray
+///   The quantized second-order sections, with the filter state reset.
+/// * `deviation` : float
+///   The maximum deviation of the quantized frequency response from the original,
+///   `20 * log10(max|Hq(w) - H(w)| / max|H(w)|)`, in dB relative to the peak gain of the original
+///   filter. This is `-inf` if quantization does not change the response.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::design::{quantize_sos_dyn, Sos};
+///
+/// let sos = [Sos::new([0.1, 0.2, 0.1], [1., -1.1, 0.3])];
+/// let (quantized, deviation) = quantize_sos_dyn(&sos, 8);
+/// assert_eq!(quantized[0].b, [0.1015625, 0.19921875, 0.1015625]);
+/// assert!(deviation < -30.);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn quantize_sos_dyn<F>(sos: &[Sos<F>], frac_bits: u32) -> (Vec<Sos<F>>, F)
+where
+    F: RealField + Float,
+{
+    let scale = Float::powi(F::from(2).unwrap(), frac_bits as i32);
+    let quantize = |c: [F; 3]| c.map(|ci| Float::round(ci * scale) / scale);
+    let quantized: Vec<Sos<F>> = sos
+        .iter()
+        .map(|s| Sos::new(quantize(s.b), quantize(s.a)))
+        .collect();
+
+    let (_, h) = sosfreqz_dyn(sos, QUANTIZE_WORN, false, None);
+    let (_, hq) = sosfreqz_dyn(&quantized, QUANTIZE_WORN, false, None);
+    let peak = h.iter().map(|hi| hi.norm_sqr()).fold(F::zero(), Float::max);
+    let error = h
+        .iter()
+        .zip(&hq)
+        .map(|(hi, hqi)| (hqi - hi).norm_sqr())
+        .fold(F::zero(), Float::max);
+
+    // Ratio of squared magnitudes, hence 10 rather than 20.
+    let deviation = F::from(10).unwrap() * Float::log10(error / peak);
+    (quantized, deviation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{
+        butter_dyn, DigitalFilter, FilterBandType, FilterOutputType, SosFormatFilter,
+    };
+    use alloc::vec;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn deviation_decreases_with_frac_bits() {
+        let DigitalFilter::Sos(SosFormatFilter { sos }) = butter_dyn(
+            4,
+            vec![0.3],
+            Some(FilterBandType::Lowpass),
+            None,
+            Some(FilterOutputType::Sos),
+            None,
+        ) else {
+            panic!("Expected sos output")
+        };
+
+        let deviations: Vec<f64> = [8, 12, 16, 20, 24]
+            .into_iter()
+            .map(|frac_bits| quantize_sos_dyn(&sos, frac_bits).1)
+            .collect();
+        for pair in deviations.windows(2) {
+            assert!(pair[1] < pair[0], "{deviations:?}");
+        }
+
+        // Coefficients that are exactly representable are unchanged.
+        let exact = [Sos::new([0.5, 0.25, 0.], [1., -0.5, 0.125])];
+        let (quantized, deviation) = quantize_sos_dyn(&exact, 3);
+        assert_eq!(quantized[0].b, exact[0].b);
+        assert_eq!(quantized[0].a, exact[0].a);
+        assert_eq!(deviation, f64::NEG_INFINITY);
+    }
+}
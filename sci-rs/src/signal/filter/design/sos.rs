@@ -68,6 +68,25 @@ impl<F: RealField + Copy> Sos<F> {
             .map(|ba| Sos::new([*ba.0, *ba.1, *ba.2], [*ba.3, *ba.4, *ba.5]))
             .collect()
     }
+
+    /// Whether the coefficients of `self` and `other` agree to within `tol`.
+    ///
+    /// Each pair of coefficients `x`, `y` must satisfy `|x - y| <= tol * max(1, |x|, |y|)`, that
+    /// is, an absolute tolerance for coefficients smaller than 1 and a relative one otherwise. The
+    /// filter state is not compared.
+    pub fn approx_eq(&self, other: &Sos<F>, tol: F) -> bool {
+        self.b
+            .iter()
+            .chain(&self.a)
+            .zip(other.b.iter().chain(&other.a))
+            .all(|(&x, &y)| approx_eq_real(x, y, tol))
+    }
+}
+
+/// `|x - y| <= tol * max(1, |x|, |y|)`, as for [Sos::approx_eq].
+pub(super) fn approx_eq_real<F: RealField + Copy>(x: F, y: F, tol: F) -> bool {
+    let scale = F::one().max(x.abs()).max(y.abs());
+    (x - y).abs() <= tol * scale
 }
 
 #[cfg(test)]
@@ -113,4 +132,19 @@ print(buttersos)
         assert_eq!(sos.len(), 4);
         println!("{:?}", sos);
     }
+
+    #[test]
+    fn approx_eq_compares_coefficients() {
+        let sos = Sos::new([0.5, 1., 0.5], [1., -0.4, 0.2]);
+        let mut close = Sos::new([0.5 + 1e-9, 1., 0.5], [1., -0.4, 0.2 - 1e-9]);
+        close.zi0 = 3.;
+        assert!(sos.approx_eq(&close, 1e-8));
+        assert!(!sos.approx_eq(&close, 1e-10));
+
+        // Relative for large coefficients.
+        let large = Sos::new([2000., 0., 0.], [1., 0., 0.]);
+        let scaled = Sos::new([2000.001, 0., 0.], [1., 0., 0.]);
+        assert!(large.approx_eq(&scaled, 1e-6));
+        assert!(!large.approx_eq(&scaled, 1e-7));
+    }
 }
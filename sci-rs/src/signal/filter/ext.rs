@@ -2,6 +2,8 @@ use core::ops::Sub;
 
 use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, Dyn, OMatrix, Scalar};
 use num_traits::{One, Zero};
+#[cfg(feature = "alloc")]
+use sci_rs_core::num_rs::PadMode;
 
 /// Pad types.
 ///
@@ -21,6 +23,42 @@ pub enum Pad {
     Constant,
 }
 
+/// Boundary extension modes, as used by `scipy.signal.upfirdn`.
+///
+/// Describes how samples beyond either end of a signal are obtained, for an input `a b c d`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoundaryMode {
+    /// Pad with zeros: `0 0 | a b c d | 0 0`.
+    #[default]
+    Zero,
+
+    /// Reflect about the edge samples: `c b | a b c d | c b`.
+    Reflect,
+
+    /// Reflect about the edges of the signal: `b a | a b c d | d c`.
+    Symmetric,
+
+    /// Repeat the edge samples: `a a | a b c d | d d`.
+    Edge,
+
+    /// Wrap around to the opposite end: `c d | a b c d | a b`.
+    Wrap,
+}
+
+#[cfg(feature = "alloc")]
+impl From<BoundaryMode> for PadMode {
+    /// The [sci_rs_core::num_rs::pad] mode extending a signal as `mode`, with [PadMode::Constant] padding with zeros.
+    fn from(mode: BoundaryMode) -> Self {
+        match mode {
+            BoundaryMode::Zero => PadMode::Constant,
+            BoundaryMode::Reflect => PadMode::Reflect,
+            BoundaryMode::Symmetric => PadMode::Symmetric,
+            BoundaryMode::Edge => PadMode::Edge,
+            BoundaryMode::Wrap => PadMode::Wrap,
+        }
+    }
+}
+
 /// Pad an [nalgebra] array.
 pub fn pad<T, M, N>(
     padtype: Pad,
@@ -224,6 +262,22 @@ where
 mod tests {
     use super::*;
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boundary_mode_pad() {
+        use alloc::vec;
+        use sci_rs_core::num_rs::pad;
+
+        // Extend `a b c d` by two samples on either side, as in the variant docs.
+        let x = ndarray::array![1, 2, 3, 4];
+        let ext = |mode: BoundaryMode| pad(&x, &[(2, 2)], mode.into(), 0).unwrap().to_vec();
+        assert_eq!(ext(BoundaryMode::Zero), [0, 0, 1, 2, 3, 4, 0, 0]);
+        assert_eq!(ext(BoundaryMode::Reflect), [3, 2, 1, 2, 3, 4, 3, 2]);
+        assert_eq!(ext(BoundaryMode::Symmetric), [2, 1, 1, 2, 3, 4, 4, 3]);
+        assert_eq!(ext(BoundaryMode::Edge), [1, 1, 1, 2, 3, 4, 4, 4]);
+        assert_eq!(ext(BoundaryMode::Wrap), [3, 4, 1, 2, 3, 4, 1, 2]);
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn scipy_example_dyn() {
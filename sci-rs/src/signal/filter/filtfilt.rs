@@ -1,7 +1,7 @@
 use super::arraytools::{
     axis_reverse_unsafe, axis_slice_unsafe, check_and_get_axis_dyn, ndarray_shape_as_array_st,
 };
-use super::lfilter::LFilter;
+use super::lfilter::{lfilter_df2t, normalize_ba, LFilter};
 use super::lfilter_zi::lfilter_zi_dyn;
 use alloc::{vec, vec::Vec};
 use core::ops::{Add, Sub};
@@ -9,6 +9,7 @@ use ndarray::{
     Array, ArrayBase, ArrayView, ArrayView1, Axis, CowArray, Data, Dim, Dimension, Ix, RawData,
     RemoveAxis, SliceArg, SliceInfo, SliceInfoElem,
 };
+use sci_rs_core::num_rs::{pad, PadMode};
 use sci_rs_core::{Error, Result};
 
 /// Padding utilised in [FiltFilt::filtfilt].
@@ -42,7 +43,7 @@ impl FiltFiltPadType {
     ///   making an constant extension of `x` along the specified axis.
     fn ext<T, S, D>(&self, x: ArrayBase<S, D>, n: usize, axis: Option<isize>) -> Result<Array<T, D>>
     where
-        T: Clone + Add<T, Output = T> + Sub<T, Output = T> + num_traits::One,
+        T: Copy + num_traits::Num + num_traits::FromPrimitive,
         S: Data<Elem = T>,
         D: Dimension + RemoveAxis,
         SliceInfo<Vec<SliceInfoElem>, D, D>: SliceArg<D, OutDim = D>,
@@ -51,8 +52,6 @@ impl FiltFiltPadType {
             return Ok(x.to_owned());
         }
 
-        let ndim = D::NDIM.unwrap_or(x.ndim());
-
         let axis = check_and_get_axis_dyn(axis, &x).map_err(|_| Error::InvalidArg {
             arg: "axis".into(),
             reason: "index out of range.".into(),
@@ -69,73 +68,14 @@ impl FiltFiltPadType {
             }
         }
 
-        match self {
-            FiltFiltPadType::Odd => {
-                let left_end =
-                    unsafe { axis_slice_unsafe(&x, Some(0), Some(1), None, axis, ndim) }?;
-                let left_ext = unsafe {
-                    axis_slice_unsafe(&x, Some(n as isize), Some(0), Some(-1), axis, ndim)
-                }?;
-                let right_end = unsafe { axis_slice_unsafe(&x, Some(-1), None, None, axis, ndim) }?;
-                let right_ext = unsafe {
-                    axis_slice_unsafe(&x, Some(-2), Some(-2 - (n as isize)), Some(-1), axis, ndim)
-                }?;
-
-                let ll = left_end.to_owned().add(left_end).sub(left_ext);
-                let rr = right_end.to_owned().add(right_end).sub(right_ext);
-
-                ndarray::concatenate(Axis(axis), &[ll.view(), x.view(), rr.view()]).map_err(|_| {
-                    Error::InvalidArg {
-                        arg: "x".into(),
-                        reason: "Shape Error".into(),
-                    }
-                })
-            }
-            FiltFiltPadType::Even => {
-                let left_ext = unsafe {
-                    axis_slice_unsafe(&x, Some(n as isize), Some(0), Some(-1), axis, ndim)
-                }?;
-                let right_ext = unsafe {
-                    axis_slice_unsafe(&x, Some(-2), Some(-2 - (n as isize)), Some(-1), axis, ndim)
-                }?;
-
-                ndarray::concatenate(Axis(axis), &[left_ext.view(), x.view(), right_ext.view()])
-                    .map_err(|_| Error::InvalidArg {
-                        arg: "x".into(),
-                        reason: "Shape Error".into(),
-                    })
-            }
-            FiltFiltPadType::Const => {
-                let ones: Array<T, D> = Array::ones({
-                    let mut t = vec![1; ndim];
-                    t[axis] = n;
-                    ndarray::IxDyn(&t)
-                })
-                .into_dimensionality() // This is needed for IxDyn -> IxN
-                .map_err(|_| Error::InvalidArg {
-                    arg: "x".into(),
-                    reason: "Coercing into identical dimensionality had issue".into(),
-                })?;
-
-                let left_ext = {
-                    let left_end =
-                        unsafe { axis_slice_unsafe(&x, Some(0), Some(1), None, axis, ndim) }?;
-                    ones.clone() * left_end
-                };
-
-                let right_ext = {
-                    let right_end =
-                        unsafe { axis_slice_unsafe(&x, Some(-1), None, None, axis, ndim) }?;
-                    ones * right_end
-                };
-
-                ndarray::concatenate(Axis(axis), &[left_ext.view(), x.view(), right_ext.view()])
-                    .map_err(|_| Error::InvalidArg {
-                        arg: "x".into(),
-                        reason: "Shape Error".into(),
-                    })
-            }
-        }
+        let mode = match self {
+            FiltFiltPadType::Odd => PadMode::Odd,
+            FiltFiltPadType::Even => PadMode::Even,
+            FiltFiltPadType::Const => PadMode::Edge,
+        };
+        let mut pad_width = vec![(0, 0); x.ndim()];
+        pad_width[axis] = (n, n);
+        pad(&x, &pad_width, mode, T::zero())
     }
 }
 
@@ -148,6 +88,34 @@ pub struct FiltFiltPad {
     pub len: Option<usize>,
 }
 
+/// Edge handling method for [FiltFilt::filtfilt_method].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FiltFiltMethod {
+    /// Pad the signal as in [FiltFilt::filtfilt].
+    #[default]
+    Pad,
+    /// Gustafsson's method as in [FiltFilt::filtfilt_gust], with the given `irlen`.
+    Gust {
+        /// Length of the nonnegligible part of the impulse response.
+        irlen: Option<usize>,
+    },
+}
+
+/// Default padding length of [FiltFilt::filtfilt], ``3 * max(len(a), len(b))``.
+///
+/// The signal must be longer than this along the filtered axis to be filtered with the default
+/// padding, which can be checked before calling [FiltFilt::filtfilt].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::default_padlen_ba;
+///
+/// assert_eq!(default_padlen_ba(&[0.5, 0.5], &[1.]), 6);
+/// ```
+pub fn default_padlen_ba<T>(b: &[T], a: &[T]) -> usize {
+    3 * a.len().max(b.len())
+}
+
 /// Helper for validating padding of filtfilt.
 ///
 /// # Parameters
@@ -158,26 +126,25 @@ pub struct FiltFiltPad {
 ///   Array that is being filtered.
 /// `axis`: usize
 ///   Axis of `x` which is being filtered.
-/// `ntaps`: usize
-///   This simply is `max(a.len(), b.len())`.
-//   In an ideal world, this would be shoe-horned into FiltFiltPad's len parameter.
+/// `default_len`: usize
+///   The pad len when the user does not specify one, such as [default_padlen_ba].
 ///
 /// # Panics
 /// `axis` is as acting on `x` is assumed to be valid, otherwise panics.
-fn validate_pad<T, D>(
+pub(super) fn validate_pad<T, D>(
     pad: Option<FiltFiltPad>,
     x: ArrayView<T, D>,
     axis: usize,
-    ntaps: usize,
+    default_len: usize,
 ) -> Result<(usize, CowArray<T, D>)>
 where
-    T: Clone + Add<T, Output = T> + Sub<T, Output = T> + num_traits::One,
+    T: Copy + num_traits::Num + num_traits::FromPrimitive,
     D: Dimension + RemoveAxis,
     SliceInfo<Vec<SliceInfoElem>, D, D>: SliceArg<D, OutDim = D>,
 {
     let edge = match pad {
         None => 0,
-        Some(FiltFiltPad { len, .. }) => len.unwrap_or(ntaps * 3),
+        Some(FiltFiltPad { len, .. }) => len.unwrap_or(default_len),
     };
 
     {
@@ -200,7 +167,7 @@ where
 ///
 /// Valid only from 1 to 6 dimensional arrays.
 ///
-/// Note: FiltFilt gust is a separate function not yet implemented.
+/// Gustafsson's method is provided separately by [FiltFilt::filtfilt_gust].
 // Note: Usage of trait and macro for implementation is an inherited from LFilter.
 // LFilter for supertrait?
 pub trait FiltFilt<S, T, const N: usize>
@@ -304,7 +271,38 @@ where
 
     /// Forward-back IIR filter that uses Gustafsson's method.
     ///
-    /// Not yet implemented.
+    /// Apply the IIR filter defined by `(b, a)` to `x` twice, first forward then backward, using
+    /// Gustafsson's initial conditions. Instead of padding, the initial conditions of both
+    /// passes are chosen so that the result is the same as filtering backward then forward.
+    ///
+    /// # Parameters
+    /// * `b`: (N,) array_like  
+    ///   The numerator coefficient vector of the filter.
+    /// * `a`: (N,) array_like  
+    ///   The denominator coefficient vector of the filter.  If ``a[0]``
+    ///   is not 1, then both `a` and `b` are normalized by ``a[0]``.
+    /// * `x`: array_like  
+    ///   The array of data to be filtered.
+    /// * `axis`: int, optional  
+    ///   The axis of `x` to which the filter is applied.  
+    ///   Default is -1.
+    /// * `irlen`: int or None, optional  
+    ///   The length of the nonnegligible part of the impulse response. If `irlen` is None, or if
+    ///   the length of the signal is less than ``2 * irlen``, then no part of the impulse response
+    ///   is ignored. Otherwise, only the first and last `irlen` samples are corrected, which is
+    ///   much faster for long signals.
+    ///
+    /// # Returns
+    /// * y : `Array`
+    ///   The filtered output with the same shape as `x`.
+    ///
+    /// # Notes
+    /// The least squares problem for the initial conditions depends only on the filter and the
+    /// signal length, so it is factorized once and reused for every lane along `axis`.
+    ///
+    /// # References
+    /// F. Gustaffson, "Determining the initial states in forward-backward filtering", Transactions
+    /// on Signal Processing, Vol. 46, pp. 988-992, 1996.
     fn filtfilt_gust<'a>(
         b: ArrayView1<'a, T>,
         a: ArrayView1<'a, T>,
@@ -312,10 +310,170 @@ where
         axis: Option<isize>,
         irlen: Option<usize>,
     ) -> Result<Array<T, Dim<[Ix; N]>>>
+    where
+        Dim<[Ix; N]>: Dimension,
+        T: nalgebra::RealField + Copy + core::iter::Sum;
+
+    /// Apply a digital filter forward and backward to a signal, selecting how the edges are
+    /// handled with `method`.
+    ///
+    /// [FiltFiltMethod::Pad] forwards `padding` to [FiltFilt::filtfilt], whilst
+    /// [FiltFiltMethod::Gust] uses [FiltFilt::filtfilt_gust] and does not pad the signal.
+    ///
+    /// # Errors
+    /// [Error::ConflictArg] if `padding` is given together with [FiltFiltMethod::Gust].
+    ///
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.filtfilt.html>
+    fn filtfilt_method<'a>(
+        b: ArrayView1<'a, T>,
+        a: ArrayView1<'a, T>,
+        x: Self,
+        axis: Option<isize>,
+        padding: Option<FiltFiltPad>,
+        method: FiltFiltMethod,
+    ) -> Result<Array<T, Dim<[Ix; N]>>>
     where
         Self: Sized,
+        T: Clone + Add<T, Output = T> + Sub<T, Output = T> + num_traits::One,
+        Dim<[Ix; N]>: Dimension,
+        T: nalgebra::RealField + Copy + core::iter::Sum,
     {
-        todo!("Gust method of FiltFilt is not yet implemented.");
+        match method {
+            FiltFiltMethod::Pad => Self::filtfilt(b, a, x, axis, padding),
+            FiltFiltMethod::Gust { .. } if padding.is_some() => Err(Error::ConflictArg {
+                reason: "Padding is not used by Gustafsson's method.".into(),
+            }),
+            FiltFiltMethod::Gust { irlen } => Self::filtfilt_gust(b, a, x, axis, irlen),
+        }
+    }
+}
+
+/// Gustafsson's initial conditions for signals of a given length, see [FiltFilt::filtfilt_gust].
+struct Gust<T: nalgebra::RealField> {
+    b: Vec<T>,
+    a: Vec<T>,
+    /// Signal length.
+    n: usize,
+    /// Length of the corrected ends of the signal, `n` if the whole signal is corrected.
+    m: usize,
+    /// `[S^R, O^R]`, mapping initial conditions onto the forward-backward output.
+    w: nalgebra::DMatrix<T>,
+    /// Factorization of `[(S^R - O), (O^R - S)]`.
+    svd: nalgebra::linalg::SVD<T, nalgebra::Dyn, nalgebra::Dyn>,
+}
+
+impl<T> Gust<T>
+where
+    T: nalgebra::RealField + Copy,
+{
+    /// Prepare the least squares problem for `(b, a)` of order at least 1, with `a[0]` non-zero.
+    fn new(b: &[T], a: &[T], n: usize, irlen: Option<usize>) -> Self {
+        let order = a.len().max(b.len()) - 1;
+        let (b, a) = normalize_ba(b, a);
+        let m = match irlen {
+            Some(irlen) if n > 2 * irlen => irlen,
+            _ => n,
+        };
+
+        // Obs, the observability matrix, is the response to each unit initial condition.
+        let mut zi = vec![T::zero(); order];
+        zi[0] = T::one();
+        let impulse = lfilter_df2t(&b, &a, core::iter::repeat_n(T::zero(), m), &zi);
+        let obs =
+            nalgebra::DMatrix::from_fn(
+                m,
+                order,
+                |i, k| {
+                    if i >= k {
+                        impulse[i - k]
+                    } else {
+                        T::zero()
+                    }
+                },
+            );
+        // S applies the filter to the reversed propagated initial conditions.
+        let mut s = nalgebra::DMatrix::zeros(m, order);
+        for k in 0..order {
+            let col = lfilter_df2t(
+                &b,
+                &a,
+                obs.column(k).iter().rev().copied(),
+                &vec![T::zero(); order],
+            );
+            s.column_mut(k).copy_from_slice(&col);
+        }
+        let reversed = |mat: &nalgebra::DMatrix<T>| {
+            nalgebra::DMatrix::from_fn(m, order, |i, k| mat[(m - 1 - i, k)])
+        };
+        let (obsr, sr) = (reversed(&obs), reversed(&s));
+
+        // With truncation, the two halves correct opposite ends of the signal.
+        let rows = if m == n { m } else { 2 * m };
+        let lower = rows - m;
+        let mut mat = nalgebra::DMatrix::zeros(rows, 2 * order);
+        let mut w = nalgebra::DMatrix::zeros(rows, 2 * order);
+        mat.view_mut((0, 0), (m, order)).copy_from(&(&sr - &obs));
+        mat.view_mut((lower, order), (m, order))
+            .copy_from(&(&obsr - &s));
+        w.view_mut((0, 0), (m, order)).copy_from(&sr);
+        w.view_mut((lower, order), (m, order)).copy_from(&obsr);
+
+        Gust {
+            b,
+            a,
+            n,
+            m,
+            w,
+            svd: mat.svd(true, true),
+        }
+    }
+
+    /// Filter a single lane of length `n` forward and backward.
+    fn filtfilt(&self, x: &[T]) -> Result<Vec<T>> {
+        let (b, a, n, m) = (&self.b, &self.a, self.n, self.m);
+        let zi = vec![T::zero(); b.len() - 1];
+
+        // Naive forward-backward and backward-forward filters, with zero initial conditions.
+        let y_f = lfilter_df2t(b, a, x.iter().copied(), &zi);
+        let mut y_fb = lfilter_df2t(b, a, y_f.into_iter().rev(), &zi);
+        y_fb.reverse();
+        let y_b = lfilter_df2t(b, a, x.iter().rev().copied(), &zi);
+        let y_bf = lfilter_df2t(b, a, y_b.into_iter().rev(), &zi);
+
+        let delta = |i: usize| y_bf[i] - y_fb[i];
+        let delta = if m == n {
+            nalgebra::DVector::from_fn(n, |i, _| delta(i))
+        } else {
+            nalgebra::DVector::from_fn(2 * m, |i, _| {
+                if i < m {
+                    delta(i)
+                } else {
+                    delta(n - 2 * m + i)
+                }
+            })
+        };
+        let ic_opt = self
+            .svd
+            .solve(&delta, T::default_epsilon())
+            .map_err(|reason| Error::InvalidArg {
+                arg: "b/a".into(),
+                reason: reason.into(),
+            })?;
+
+        let wic = &self.w * ic_opt;
+        if m == n {
+            y_fb.iter_mut().zip(wic.iter()).for_each(|(y, w)| *y += *w);
+        } else {
+            y_fb[..m]
+                .iter_mut()
+                .zip(wic.iter())
+                .for_each(|(y, w)| *y += *w);
+            y_fb[n - m..]
+                .iter_mut()
+                .zip(wic.iter().skip(m))
+                .for_each(|(y, w)| *y += *w);
+        }
+        Ok(y_fb)
     }
 }
 
@@ -341,7 +499,12 @@ macro_rules! filtfilt_for_dim {
                     arg: "axis".into(),
                     reason: "index out of range.".into(),
                 })?;
-                let (edge, ext) = validate_pad(padding, x.view(), axis, a.len().max(b.len()))?;
+                let (edge, ext) = validate_pad(
+                    padding,
+                    x.view(),
+                    axis,
+                    default_padlen_ba(b.as_slice().unwrap(), a.as_slice().unwrap()),
+                )?;
 
                 let zi: Array<T, Dim<[Ix; $N]>> = {
                     let mut zi = lfilter_zi_dyn(b.as_slice().unwrap(), a.as_slice().unwrap());
@@ -397,6 +560,49 @@ macro_rules! filtfilt_for_dim {
                     Ok(y.to_owned())
                 }
             }
+
+            fn filtfilt_gust<'a>(
+                b: ArrayView1<'a, T>,
+                a: ArrayView1<'a, T>,
+                x: Self,
+                axis: Option<isize>,
+                irlen: Option<usize>,
+            ) -> Result<Array<T, Dim<[Ix; $N]>>>
+            where
+                Dim<[Ix; $N]>: Dimension,
+                T: nalgebra::RealField + Copy + core::iter::Sum,
+            {
+                let axis = check_and_get_axis_dyn(axis, &x).map_err(|_| Error::InvalidArg {
+                    arg: "axis".into(),
+                    reason: "index out of range.".into(),
+                })?;
+                let b: Vec<T> = b.iter().copied().collect();
+                let a: Vec<T> = a.iter().copied().collect();
+
+                if a.first().is_none_or(|a0| a0.is_zero()) {
+                    return Err(Error::InvalidArg {
+                        arg: "a".into(),
+                        reason: "First coefficient of the denominator must be non-zero.".into(),
+                    });
+                }
+
+                let mut y = x.to_owned();
+                if y.is_empty() {
+                    return Ok(y);
+                }
+                if a.len().max(b.len()) == 1 {
+                    let gain = b.first().copied().unwrap_or_else(T::zero) / a[0];
+                    y.mapv_inplace(|yi| gain * gain * yi);
+                    return Ok(y);
+                }
+
+                let gust = Gust::new(&b, &a, x.shape()[axis], irlen);
+                for mut lane in y.lanes_mut(Axis(axis)) {
+                    let filtered = gust.filtfilt(&lane.to_vec())?;
+                    lane.iter_mut().zip(filtered).for_each(|(l, f)| *l = f);
+                }
+                Ok(y)
+            }
         }
     };
 }
@@ -413,7 +619,7 @@ mod test {
     use super::*;
     use alloc::vec;
     use approx::assert_relative_eq;
-    use ndarray::{array, Zip};
+    use ndarray::{array, Array1, ArrayView1, Zip};
 
     /// Test odd_ext as from documentation.
     #[test]
@@ -656,6 +862,271 @@ mod test {
             .for_each(|&r, &e| assert_relative_eq!(r, e, max_relative = 1e-5, epsilon = 1e-10));
     }
 
+    /// Tests that filtfilt with default padding is a zero-phase IIR filter.
+    #[test]
+    fn filtfilt_1d_iir_default_pad() {
+        // b, a = butter(3, 0.2)
+        let b = array![
+            0.01809893300751445,
+            0.05429679902254335,
+            0.05429679902254335,
+            0.01809893300751445
+        ];
+        let a = array![
+            1.,
+            -1.760041880343169,
+            1.1828932620378303,
+            -0.2780599176345463
+        ];
+        // A sine well inside the passband, with noise of deterministic sines in the stopband.
+        let (w0, n) = (0.05 * core::f64::consts::PI, 200);
+        let x = Array::from_iter((0..n).map(|i| {
+            let i = i as f64;
+            (w0 * i).sin() + 0.2 * (2.1 * i).sin() + 0.1 * (2.7 * i + 1.).cos()
+        }));
+        let y =
+            Array1::filtfilt(b.view(), a.view(), x, None, Some(FiltFiltPad::default())).unwrap();
+        assert_eq!(y.len(), n);
+
+        // Away from the edges, the sine is scaled by |H(w0)|^2 without any phase shift, and
+        // the noise is removed.
+        let h = |w: f64| {
+            let e = |c: &Array1<f64>| {
+                c.iter().enumerate().fold((0., 0.), |(re, im), (k, ck)| {
+                    (
+                        re + ck * (w * k as f64).cos(),
+                        im - ck * (w * k as f64).sin(),
+                    )
+                })
+            };
+            let ((br, bi), (ar, ai)) = (e(&b), e(&a));
+            (br * br + bi * bi) / (ar * ar + ai * ai)
+        };
+        let gain = h(w0);
+        for i in 40..160 {
+            assert_relative_eq!(y[i], gain * (w0 * i as f64).sin(), epsilon = 1e-3);
+        }
+
+        // The initial conditions from lfilter_zi leave a constant untouched, even at the edges.
+        let y = Array1::filtfilt(
+            b.view(),
+            a.view(),
+            Array1::from_elem(30, 2.5),
+            None,
+            Some(FiltFiltPad::default()),
+        )
+        .unwrap();
+        y.iter()
+            .for_each(|&yi| assert_relative_eq!(yi, 2.5, max_relative = 1e-10));
+    }
+
+    /// Tests filtfilt with a denominator longer than the numerator.
+    #[test]
+    fn filtfilt_1d_iir_short_numerator() {
+        let b = array![0.5];
+        let a = array![1., -0.5];
+        let x = Array1::from_elem(10, -1.);
+        for pad_type in [
+            FiltFiltPadType::Odd,
+            FiltFiltPadType::Even,
+            FiltFiltPadType::Const,
+        ] {
+            let pad = FiltFiltPad {
+                pad_type,
+                len: None,
+            };
+            let y = ArrayView1::filtfilt(b.view(), a.view(), x.view(), None, Some(pad)).unwrap();
+            y.iter()
+                .for_each(|&yi| assert_relative_eq!(yi, -1., max_relative = 1e-12));
+        }
+    }
+
+    /// Tests Gustafsson's method with an IIR filter against scipy.
+    #[test]
+    fn filtfilt_gust_1d_iir() {
+        // x = np.sin(0.3 * np.arange(40)) + 0.5 * np.cos(1.7 * np.arange(40)) + 0.05 * np.arange(40)
+        // b, a = butter(2, 0.2)
+        // expected = filtfilt(b, a, x, method='gust')
+        let x = Array::from_iter((0..40).map(|i| {
+            let i = i as f64;
+            (0.3 * i).sin() + 0.5 * (1.7 * i).cos() + 0.05 * i
+        }));
+        let b = array![0.0674552738890719, 0.1349105477781438, 0.0674552738890719];
+        let a = array![1., -1.142980502539901, 0.41280159809618866];
+        let result = Array::<_, Dim<[_; 1]>>::filtfilt_gust(b.view(), a.view(), x, None, None)
+            .expect("Could not filtfilt_gust");
+        let expected = array![
+            0.46032612721167443,
+            0.5479772285326894,
+            0.6945372466361959,
+            0.8770113319151158,
+            1.0433921128945989,
+            1.1532458060908766,
+            1.194043272993695,
+            1.1581254736714084,
+            1.0391374249163894,
+            0.8541921004327453,
+            0.6359251649171055,
+            0.40531370311773995,
+            0.1796367029753654,
+            -0.008792639948880588,
+            -0.13106612037774276,
+            -0.18049370676250714,
+            -0.15321944542667784,
+            -0.037217648732274504,
+            0.16392932495524173,
+            0.42597357060837804,
+            0.7303085032476851,
+            1.064793195503711,
+            1.400892378005698,
+            1.7032049505479256,
+            1.9547484118510157,
+            2.14579119563813,
+            2.255902106419285,
+            2.273403356011929,
+            2.2108620196542197,
+            2.0828592383403777,
+            1.8959358468348797,
+            1.6714948765466255,
+            1.4463084413820473,
+            1.2440472620172764,
+            1.074940027781001,
+            0.9558329222192776,
+            0.8966741414844037,
+            0.8774646365715743,
+            0.8676145750615479,
+            0.8562641769130822
+        ];
+
+        Zip::from(&result)
+            .and(&expected)
+            .for_each(|&r, &e| assert_relative_eq!(r, e, max_relative = 1e-9));
+    }
+
+    /// Tests the default padlen against scipy's `3 * max(len(a), len(b))`.
+    #[test]
+    fn default_padlen_matches_scipy() {
+        // b, a = butter(3, 0.2)
+        let b = [
+            0.01809893300751445,
+            0.05429679902254335,
+            0.05429679902254335,
+            0.01809893300751445,
+        ];
+        let a = [
+            1.,
+            -1.760041880343169,
+            1.1828932620378303,
+            -0.2780599176345463,
+        ];
+        assert_eq!(default_padlen_ba(&b, &a), 12);
+        // An FIR filter has a = [1].
+        assert_eq!(default_padlen_ba(&[0.5, 0.4, 0.1], &[1.]), 9);
+        // A shorter numerator uses the length of the denominator.
+        assert_eq!(default_padlen_ba(&[0.5], &[1., -0.5]), 6);
+
+        // Signals must be longer than the default padlen.
+        let pad = Some(FiltFiltPad::default());
+        let (b, a) = (Array1::from(b.to_vec()), Array1::from(a.to_vec()));
+        let x = Array1::from_elem(12, 1.);
+        assert!(Array1::filtfilt(b.view(), a.view(), x, None, pad).is_err());
+        let x = Array1::from_elem(13, 1.);
+        assert!(Array1::filtfilt(b.view(), a.view(), x, None, pad).is_ok());
+    }
+
+    /// Tests selecting Gustafsson's method on a short signal, where padding changes the edges.
+    #[test]
+    fn filtfilt_method_gust() {
+        // Same signal and filter as filtfilt_gust_1d_iir, whose output is checked against scipy.
+        let x = Array::from_iter((0..40).map(|i| {
+            let i = i as f64;
+            (0.3 * i).sin() + 0.5 * (1.7 * i).cos() + 0.05 * i
+        }));
+        let b = array![0.0674552738890719, 0.1349105477781438, 0.0674552738890719];
+        let a = array![1., -1.142980502539901, 0.41280159809618866];
+
+        let gust = ArrayView1::filtfilt_method(
+            b.view(),
+            a.view(),
+            x.view(),
+            None,
+            None,
+            FiltFiltMethod::Gust { irlen: None },
+        )
+        .unwrap();
+        let expected = ArrayView1::filtfilt_gust(b.view(), a.view(), x.view(), None, None).unwrap();
+        assert_eq!(gust, expected);
+        assert_relative_eq!(gust[0], 0.46032612721167443, max_relative = 1e-9);
+        assert_relative_eq!(gust[39], 0.8562641769130822, max_relative = 1e-9);
+
+        let pad = ArrayView1::filtfilt_method(
+            b.view(),
+            a.view(),
+            x.view(),
+            None,
+            Some(FiltFiltPad::default()),
+            FiltFiltMethod::default(),
+        )
+        .unwrap();
+        let padded = ArrayView1::filtfilt(
+            b.view(),
+            a.view(),
+            x.view(),
+            None,
+            Some(FiltFiltPad::default()),
+        )
+        .unwrap();
+        assert_eq!(pad, padded);
+        assert!((pad[0] - gust[0]).abs() > 1e-2);
+
+        let conflict = ArrayView1::filtfilt_method(
+            b.view(),
+            a.view(),
+            x.view(),
+            None,
+            Some(FiltFiltPad::default()),
+            FiltFiltMethod::Gust { irlen: None },
+        );
+        assert!(matches!(conflict, Err(Error::ConflictArg { .. })));
+    }
+
+    /// Tests that truncating the impulse response with `irlen` barely changes a long signal.
+    #[test]
+    fn filtfilt_gust_2d_irlen() {
+        let b = array![0.0674552738890719, 0.1349105477781438, 0.0674552738890719];
+        let a = array![1., -1.142980502539901, 0.41280159809618866];
+        let x = Array::from_shape_fn((2, 2000), |(r, i)| {
+            let i = i as f64;
+            (0.01 * i * (r + 1) as f64).sin() + 0.3 * (2.1 * i).cos() + 1.5
+        });
+
+        let full =
+            ArrayView::<_, Dim<[_; 2]>>::filtfilt_gust(b.view(), a.view(), x.view(), Some(1), None)
+                .expect("Could not filtfilt_gust");
+        // The impulse response decays by a factor of ~0.64 per sample.
+        let truncated = ArrayView::<_, Dim<[_; 2]>>::filtfilt_gust(
+            b.view(),
+            a.view(),
+            x.view(),
+            Some(1),
+            Some(100),
+        )
+        .expect("Could not filtfilt_gust irlen");
+        Zip::from(&truncated)
+            .and(&full)
+            .for_each(|&t, &f| assert_relative_eq!(t, f, epsilon = 1e-12));
+
+        // Each lane is filtered independently.
+        for (row, full_row) in x.outer_iter().zip(full.outer_iter()) {
+            let expected =
+                ArrayView::<_, Dim<[_; 1]>>::filtfilt_gust(b.view(), a.view(), row, None, None)
+                    .expect("Could not filtfilt_gust row");
+            Zip::from(&full_row)
+                .and(&expected)
+                .for_each(|&r, &e| assert_relative_eq!(r, e, max_relative = 1e-12));
+        }
+    }
+
     /// Tests that filtfilt works with no padding with a FIR filter.
     #[test]
     fn filtfilt_2d_fir_none_pad() {
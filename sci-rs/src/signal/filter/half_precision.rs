@@ -0,0 +1,211 @@
+use core::borrow::Borrow;
+use ndarray::{
+    Array, ArrayBase, ArrayView, ArrayView1, Data, Dimension, IxDyn, SliceArg, SliceInfo,
+    SliceInfoElem,
+};
+use num_traits::AsPrimitive;
+use sci_rs_core::Result;
+
+use super::design::Sos;
+use super::{lfilter, sosfilt_dyn};
+
+use alloc::vec::Vec;
+
+/// Second Order Sections filter an iterator of half-precision samples.
+///
+/// This is [sosfilt_dyn] for inputs such as [half::f16] or [half::bf16]. Each sample is widened to
+/// `f32`, filtered with `f32` coefficients and state, and rounded back to `H`. Keeping the state
+/// in `f32` avoids accumulating half-precision rounding errors through the recursion, so only the
+/// output is limited to the precision of `H`.
+///
+/// As for [sosfilt_dyn], `sos` holds the final filter state, so reusing it continues filtering
+/// from where the previous call left off.
+///
+/// # Examples
+/// ```
+/// use half::f16;
+/// use sci_rs::signal::filter::{design::Sos, sosfilt_half_dyn};
+///
+/// let mut sos = [Sos::new([0.25f32, 0.5, 0.25], [1., 0., 0.])];
+/// let x = [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)];
+/// let y: Vec<f16> = sosfilt_half_dyn(&x, &mut sos);
+/// assert_eq!(y, [0.25, 1., 2.].map(f16::from_f32));
+/// ```
+pub fn sosfilt_half_dyn<YI, H>(y: YI, sos: &mut [Sos<f32>]) -> Vec<H>
+where
+    H: AsPrimitive<f32>,
+    f32: AsPrimitive<H>,
+    YI: IntoIterator,
+    YI::Item: Borrow<H>,
+{
+    sosfilt_dyn(y.into_iter().map(|yi| yi.borrow().as_()), sos)
+        .into_iter()
+        .map(|yi| yi.as_())
+        .collect()
+}
+
+/// Filter half-precision data `x` along one-dimension with an IIR or FIR filter.
+///
+/// This is [lfilter] for inputs such as [half::f16] or [half::bf16], computing in `f32` and
+/// rounding the output back to `H`. The filter coefficients, `zi` and the returned `zf` are kept
+/// in `f32`, so that chunks of a signal can be filtered without losing the filter state to
+/// half-precision rounding.
+///
+/// ## Parameters
+/// * `b` : array_like
+///   The numerator coefficient vector in a 1-D sequence.
+/// * `a` : array_like
+///   The denominator coefficient vector in a 1-D sequence.
+/// * `x` : array_like
+///   An N-dimensional half-precision input array.
+/// * `axis`: `Option<isize>`
+///   Default to `-1` if `None`.
+/// * `zi`: array_like
+///   Initial conditions for filter delays, as per [lfilter].
+///
+/// ## Returns
+/// * `y` : array
+///   The output of the digital filter, rounded to `H`.
+/// * `zf` : array, optional
+///   The final filter delay values if `zi` is given.
+///
+/// # Examples
+/// ```
+/// use half::f16;
+/// use ndarray::array;
+/// use sci_rs::signal::filter::lfilter_half;
+///
+/// let b = array![0.5f32, 0.5];
+/// let a = array![1.];
+/// let x = array![1., 2., 3., 4.].mapv(f16::from_f32);
+/// let (y, _) = lfilter_half(b.view(), a.view(), x.view(), None, None).unwrap();
+/// assert_eq!(y.into_raw_vec_and_offset().0, [0.5, 1.5, 2.5, 3.5].map(f16::from_f32));
+/// ```
+///
+/// # Errors
+/// As for [lfilter], [Error::InvalidArg](sci_rs_core::Error::InvalidArg) if `a` is empty, if
+/// `a[0]` is zero or too close to zero relative to the rest of `a`, or if `zi` is not of the
+/// expected shape.
+///
+/// # Panics
+/// If `axis` is out of bounds for `x`, as for [lfilter].
+#[allow(clippy::type_complexity)]
+pub fn lfilter_half<'a, H, S, D>(
+    b: ArrayView1<'a, f32>,
+    a: ArrayView1<'a, f32>,
+    x: ArrayBase<S, D>,
+    axis: Option<isize>,
+    zi: Option<ArrayView<f32, D>>,
+) -> Result<(Array<H, IxDyn>, Option<Array<f32, IxDyn>>)>
+where
+    H: AsPrimitive<f32>,
+    f32: AsPrimitive<H>,
+    S: Data<Elem = H>,
+    D: Dimension,
+    SliceInfo<Vec<SliceInfoElem>, D, D>: SliceArg<D, OutDim = D>,
+{
+    let x: Array<f32, D> = x.mapv(|xi| xi.as_());
+    let (y, zf) = lfilter(b, a, x, axis, zi)?;
+    Ok((y.mapv(|yi| yi.as_()), zf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{
+        butter_dyn, DigitalFilter, FilterBandType, FilterOutputType, SosFormatFilter,
+    };
+    use alloc::vec;
+    use half::{bf16, f16};
+    use ndarray::{array, Array1, Array2, Axis};
+
+    fn signal(n: usize) -> Vec<f32> {
+        (0..n)
+            .map(|i| {
+                let t = i as f32 / 100.;
+                (2. * t).sin() + 0.5 * (37. * t).cos()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sosfilt_half_matches_f32() {
+        let DigitalFilter::Sos(SosFormatFilter { sos }) = butter_dyn(
+            4,
+            vec![0.1f32],
+            Some(FilterBandType::Lowpass),
+            None,
+            Some(FilterOutputType::Sos),
+            None,
+        ) else {
+            panic!("Expected sos output")
+        };
+        let x = signal(500);
+        let expected = sosfilt_dyn(x.iter(), &mut sos.clone());
+
+        let x16: Vec<f16> = x.iter().map(|&xi| f16::from_f32(xi)).collect();
+        let y16: Vec<f16> = sosfilt_half_dyn(&x16, &mut sos.clone());
+        assert_eq!(y16.len(), expected.len());
+        for (y, e) in y16.iter().zip(&expected) {
+            // Rounding of the input and the output, at 11 bits of precision each.
+            assert!(
+                (y.to_f32() - e).abs() <= 2e-3 * (1. + e.abs()),
+                "{y} != {e}"
+            );
+        }
+
+        let xb16: Vec<bf16> = x.iter().map(|&xi| bf16::from_f32(xi)).collect();
+        let yb16: Vec<bf16> = sosfilt_half_dyn(&xb16, &mut sos.clone());
+        for (y, e) in yb16.iter().zip(&expected) {
+            // bf16 only has 8 bits of precision.
+            assert!(
+                (y.to_f32() - e).abs() <= 1.6e-2 * (1. + e.abs()),
+                "{y} != {e}"
+            );
+        }
+    }
+
+    #[test]
+    fn lfilter_half_matches_f32() {
+        let b = array![0.1f32, 0.2, 0.4, 0.2, 0.1];
+        let a = array![1f32];
+        let x = Array2::from_shape_vec((2, 300), signal(600)).unwrap();
+        let (expected, _) = lfilter(b.view(), a.view(), x.view(), Some(1), None).unwrap();
+
+        let x16 = x.mapv(f16::from_f32);
+        let (y16, zf) = lfilter_half(b.view(), a.view(), x16.view(), Some(1), None).unwrap();
+        assert!(zf.is_none());
+        assert_eq!(y16.shape(), expected.shape());
+        for (y, e) in y16.iter().zip(&expected) {
+            assert!(
+                (y.to_f32() - e).abs() <= 2e-3 * (1. + e.abs()),
+                "{y} != {e}"
+            );
+        }
+
+        // The state carried between chunks stays in f32.
+        let x16: Array1<f16> = x16.index_axis(Axis(0), 0).to_owned();
+        let zi = Array1::<f32>::zeros(b.len() - 1);
+        let (head, zf) = lfilter_half(
+            b.view(),
+            a.view(),
+            x16.slice(ndarray::s![..100]),
+            None,
+            Some(zi.view()),
+        )
+        .unwrap();
+        let zf = zf.unwrap().into_dimensionality().unwrap();
+        let (tail, _) = lfilter_half(
+            b.view(),
+            a.view(),
+            x16.slice(ndarray::s![100..]),
+            None,
+            Some(zf.view()),
+        )
+        .unwrap();
+        let (whole, _) =
+            lfilter_half::<f16, _, _>(b.view(), a.view(), x16.view(), None, None).unwrap();
+        let chunked: Vec<f16> = head.iter().chain(tail.iter()).copied().collect();
+        assert_eq!(chunked, whole.into_raw_vec_and_offset().0);
+    }
+}
@@ -26,8 +26,6 @@ pub fn lfilter_zi_dyn<F>(b: &[F], a: &[F]) -> Array1<F>
 where
     F: RealField + Copy + PartialEq + Scalar + Zero + One + Sum + SubAssign,
 {
-    let m = b.len();
-
     let ai0 = a
         .iter()
         .enumerate()
@@ -45,9 +43,9 @@ where
     }
 
     // Pad with zeros to match length
-    while a.len() < m {
-        a.push(F::zero());
-    }
+    let m = a.len().max(b.len());
+    a.resize(m, F::zero());
+    b.resize(m, F::zero());
 
     // Solve zi = A*zi + B
     let mut compa: DMatrix<_> = companion_dyn(a.iter(), m);
@@ -79,6 +77,80 @@ where
     zi.into()
 }
 
+/// Construct initial conditions for [lfilter][super::lfilter::LFilter] given input and output
+/// vectors.
+///
+/// Given a linear filter `(b, a)` and initial conditions on the output `y` and the input `x`,
+/// return the initial conditions on the state vector `zi` which is used by `lfilter` to generate
+/// the output given the input.
+///
+/// # Parameters
+/// * `b` : array_like
+///   Linear filter term.
+/// * `a` : array_like
+///   Linear filter term.
+/// * `y` : array_like
+///   Initial conditions. If `N = len(a) - 1`, then `y = {y[-1], y[-2], ..., y[-N]}`, most recent
+///   first. If `y` is too short, it is padded with zeros.
+/// * `x` : array_like, optional
+///   Initial conditions. If `M = len(b) - 1`, then `x = {x[-1], x[-2], ..., x[-M]}`, most recent
+///   first. If `x` is not given, its initial conditions are assumed zero. If `x` is too short,
+///   it is padded with zeros.
+///
+/// # Returns
+/// * `zi` : ndarray
+///   The state vector `zi = {z_0[-1], z_1[-1], ..., z_K-1[-1]}`, where `K = max(M, N)`.
+///
+/// # Panics
+/// If `a` is empty or `a[0]` is zero.
+///
+/// # Examples
+/// Resuming a filter from the last samples of its input and output:
+/// ```
+/// use ndarray::{array, ArrayView1};
+/// use sci_rs::signal::filter::{lfilter, lfiltic_dyn};
+///
+/// let (b, a) = (array![0.5, 0.5], array![1., -0.5]);
+/// let x = array![1., 2., 3., 4.];
+/// let (whole, _) = lfilter(b.view(), a.view(), x.view(), None, None).unwrap();
+///
+/// let zi = lfiltic_dyn(b.as_slice().unwrap(), a.as_slice().unwrap(), &[whole[1]], Some(&[x[1]]));
+/// let zi = ArrayView1::from(&zi);
+/// let (rest, _) = lfilter(b.view(), a.view(), x.slice(ndarray::s![2..]), None, Some(zi)).unwrap();
+/// assert_eq!(rest[0], whole[2]);
+/// assert_eq!(rest[1], whole[3]);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.lfiltic.html>
+pub fn lfiltic_dyn<F>(b: &[F], a: &[F], y: &[F], x: Option<&[F]>) -> Vec<F>
+where
+    F: RealField + Copy,
+{
+    let a0 = *a
+        .first()
+        .expect("There must be at least one `a` coefficient.");
+    assert!(a0 != F::zero(), "First `a` coefficient must be nonzero.");
+    let n = a.len() - 1;
+    let m = b.len().saturating_sub(1);
+    let k = m.max(n);
+
+    // Past samples, most recent first and zero padded.
+    let past = |v: &[F], i: usize| v.get(i).copied().unwrap_or_else(F::zero);
+    let x = x.unwrap_or(&[]);
+
+    let mut zi = vec![F::zero(); k];
+    for (j, zj) in zi.iter_mut().enumerate() {
+        for i in j..m {
+            *zj += b[i + 1] * past(x, i - j);
+        }
+        for i in j..n {
+            *zj -= a[i + 1] * past(y, i - j);
+        }
+        *zj /= a0;
+    }
+    zi
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -108,4 +180,69 @@ mod tests {
             assert_relative_eq!(e, a, max_relative = 1e-6);
         })
     }
+
+    #[test]
+    fn shorter_numerator() {
+        // zi = lfilter_zi([0.5], [1, -0.5]) is the steady state of y[n] = 0.5 x[n] + 0.5 y[n-1]
+        // for a unit step, z = 0.5 * 1.
+        let zi = lfilter_zi_dyn(&[0.5], &[1., -0.5]);
+        assert_eq!(zi.len(), 1);
+        assert_relative_eq!(zi[0], 0.5, max_relative = 1e-15);
+
+        let zi = lfilter_zi_dyn(&[1.], &[2., -0.5, 0.25]);
+        assert_eq!(zi.len(), 2);
+        // The step response of 2 y[n] = x[n] + 0.5 y[n-1] - 0.25 y[n-2] settles at y = 4/7, with
+        // z[1] = -0.125 y and z[0] = 0.25 y + z[1].
+        assert_relative_eq!(zi[0], 1. / 14., max_relative = 1e-12);
+        assert_relative_eq!(zi[1], -1. / 14., max_relative = 1e-12);
+    }
+
+    #[test]
+    fn lfiltic_resumes_lfilter() {
+        use crate::signal::filter::lfilter;
+        use ndarray::{array, s, ArrayView1};
+
+        // zi = lfiltic([0.2, 0.3], [1, -0.6, 0.25], y=[1, 2], x=[3]), evaluated by hand as
+        // [0.3 * 3 + 0.6 * 1 - 0.25 * 2, -0.25 * 1].
+        let zi = lfiltic_dyn(&[0.2, 0.3], &[1., -0.6, 0.25], &[1., 2.], Some(&[3.]));
+        assert_relative_eq!(zi[0], 1., max_relative = 1e-15);
+        assert_relative_eq!(zi[1], -0.25, max_relative = 1e-15);
+        // Missing input history is zero, and extra history is ignored.
+        let zi = lfiltic_dyn(&[0.2, 0.3], &[1., -0.6, 0.25], &[1., 2., 7.], None);
+        assert_relative_eq!(zi[0], 0.1, max_relative = 1e-12);
+        assert_relative_eq!(zi[1], -0.25, max_relative = 1e-15);
+
+        // Filtering the rest of a signal from the state built from the history of its start is
+        // filtering the signal in one go, even for an unnormalized filter.
+        let b = [0.4, -0.2, 0.6, 0.1];
+        let a = [2., -1.2, 0.5];
+        let x = Array1::from_iter((0..40).map(|i| (i as f64 * 0.7).cos() + 0.2));
+        let (whole, _) = lfilter(
+            ArrayView1::from(&b),
+            ArrayView1::from(&a),
+            x.view(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let split = 25;
+        let mut y_past: Vec<f64> = whole.iter().take(split).copied().collect();
+        let mut x_past = x.slice(s![..split]).to_vec();
+        y_past.reverse();
+        x_past.reverse();
+        let zi = lfiltic_dyn(&b, &a, &y_past, Some(&x_past));
+        assert_eq!(zi.len(), 3);
+        let (rest, _) = lfilter(
+            ArrayView1::from(&b),
+            ArrayView1::from(&a),
+            x.slice(s![split..]),
+            None,
+            Some(ArrayView1::from(&zi)),
+        )
+        .unwrap();
+        for (r, e) in rest.iter().zip(whole.iter().skip(split)) {
+            assert_relative_eq!(r, e, max_relative = 1e-12);
+        }
+    }
 }
@@ -0,0 +1,124 @@
+use core::cmp::Ordering;
+use ndarray::ArrayView1;
+use num_traits::Num;
+use sci_rs_core::num_rs::pad;
+use sci_rs_core::{Error, Result};
+
+use super::BoundaryMode;
+
+use alloc::vec::Vec;
+
+/// Perform a median filter on a signal.
+///
+/// Apply a median filter to the input using a local window-size given by `kernel_size`.
+///
+/// # Parameters
+/// * `volume` : array_like
+///   A one-dimensional input array.
+/// * `kernel_size` : int, optional
+///   The size of the median filter window, which must be odd. Defaults to 3.
+/// * `mode` : [BoundaryMode]
+///   How the signal is extended beyond its ends. scipy's `medfilt` zero-pads, which is the
+///   default, but this pulls the edges of a signal with a constant offset towards zero.
+///   [BoundaryMode::Reflect] or [BoundaryMode::Edge] avoid this, like the modes of
+///   `scipy.ndimage.median_filter`.
+///
+/// # Returns
+/// * `out` : ndarray
+///   An array the same size as input containing the median filtered result.
+///
+/// # Errors
+/// [Error::InvalidArg] if `kernel_size` is even.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::{medfilt_dyn, BoundaryMode};
+///
+/// let x = [2., 6., 5., 4., 0., 3., 5., 7., 9., 2., 0., 1.];
+/// let y = medfilt_dyn(&x, Some(5), BoundaryMode::Zero).unwrap();
+/// assert_eq!(y, [2., 4., 4., 4., 4., 4., 5., 5., 5., 2., 1., 0.]);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.medfilt.html>
+pub fn medfilt_dyn<F>(
+    volume: &[F],
+    kernel_size: Option<usize>,
+    mode: BoundaryMode,
+) -> Result<Vec<F>>
+where
+    F: PartialOrd + Copy + Num,
+{
+    let kernel_size = kernel_size.unwrap_or(3);
+    if kernel_size.is_multiple_of(2) {
+        return Err(Error::InvalidArg {
+            arg: "kernel_size".into(),
+            reason: "Each element of kernel_size should be odd.".into(),
+        });
+    }
+
+    if volume.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let half = kernel_size / 2;
+    let padded = pad(
+        &ArrayView1::from(volume),
+        &[(half, half)],
+        mode.into(),
+        F::zero(),
+    )?;
+    let mut window = Vec::with_capacity(kernel_size);
+    let y = padded
+        .windows(kernel_size)
+        .into_iter()
+        .map(|w| {
+            window.clear();
+            window.extend(w.iter().copied());
+            let (_, median, _) = window
+                .select_nth_unstable_by(half, |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            *median
+        })
+        .collect();
+
+    Ok(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn medfilt_modes() {
+        // >>> medfilt([2, 6, 5, 4, 0, 3, 5, 7, 9, 2, 0, 1], 5)
+        // array([2., 4., 4., 4., 4., 4., 5., 5., 5., 2., 1., 0.])
+        let x = [2., 6., 5., 4., 0., 3., 5., 7., 9., 2., 0., 1.];
+        let y = medfilt_dyn(&x, Some(5), BoundaryMode::Zero).unwrap();
+        assert_eq!(y, [2., 4., 4., 4., 4., 4., 5., 5., 5., 2., 1., 0.]);
+
+        // >>> median_filter(x, 5, mode='mirror')
+        // array([5, 5, 4, 4, 4, 4, 5, 5, 5, 2, 1, 1])
+        let y = medfilt_dyn(&x, Some(5), BoundaryMode::Reflect).unwrap();
+        assert_eq!(y, [5., 5., 4., 4., 4., 4., 5., 5., 5., 2., 1., 1.]);
+
+        let y = medfilt_dyn(&x, None, BoundaryMode::Zero).unwrap();
+        assert_eq!(y.len(), x.len());
+        assert!(medfilt_dyn(&x, Some(4), BoundaryMode::Zero).is_err());
+    }
+
+    #[test]
+    fn reflect_avoids_edge_dip() {
+        // A constant offset is pulled to zero where the padding outnumbers the signal.
+        // >>> medfilt([5., 5., 5., 5.], 9)
+        // array([0., 0., 0., 0.])
+        let x = [5.; 4];
+        let y = medfilt_dyn(&x, Some(9), BoundaryMode::Zero).unwrap();
+        assert_eq!(y, [0.; 4]);
+
+        let y = medfilt_dyn(&x, Some(9), BoundaryMode::Reflect).unwrap();
+        assert_eq!(y, x);
+
+        let x: Vec<f64> = (0..20).map(|i| 5. + 0.1 * (i % 3) as f64).collect();
+        let y = medfilt_dyn(&x, Some(7), BoundaryMode::Reflect).unwrap();
+        assert!(y.iter().all(|&yi| yi >= 5.));
+    }
+}
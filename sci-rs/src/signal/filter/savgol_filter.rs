@@ -291,6 +291,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     pub fn can_coeffs() {
         let actual = savgol_coeffs_dyn::<f32>(5, 2, None, None);
         let expected = [-0.08571429, 0.34285714, 0.48571429, 0.34285714, -0.08571429];
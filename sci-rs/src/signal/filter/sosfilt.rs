@@ -7,8 +7,19 @@ use num_traits::Float;
 
 use super::design::Sos;
 
+#[cfg(feature = "alloc")]
+use super::arraytools::check_and_get_axis_dyn;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use ndarray::{
+    Array, Array2, Array3, ArrayBase, ArrayD, ArrayView2, ArrayViewD, Axis, Data, Dimension, IxDyn,
+};
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
+#[cfg(feature = "alloc")]
+type SosFiltResult<F, D> = (Array<F, D>, Option<ArrayD<F>>);
 
 ///
 /// A series of Second Order Sections may be used to
@@ -209,6 +220,256 @@ where
     })
 }
 
+/// Number of channels filtered together by [sosfilt_lanes_dyn].
+#[cfg(feature = "alloc")]
+const SOSFILT_LANES: usize = 8;
+
+/// Number of samples per channel held in the interleaved buffer of [sosfilt_lanes_dyn].
+#[cfg(feature = "alloc")]
+const SOSFILT_CHUNK: usize = 256;
+
+///
+/// Second Order Sections filter each row (channel) of `x`
+///
+/// The recursion over samples is sequential, so channels are instead filtered in blocks of 8,
+/// with the state of each section held in fixed-size arrays across the block. This lets the
+/// compiler vectorize the coefficient multiplies across channels. Rows left over after the
+/// blocks are filtered one at a time as with [sosfilt_dyn].
+///
+/// Each channel starts from the state held in `sos`, which is left unchanged.
+///
+/// On a (8, 100000) array, this is about 1.8x faster than filtering each row with
+/// [sosfilt_dyn] (see `benches/sosfilt.rs`).
+///
+#[cfg(feature = "alloc")]
+pub fn sosfilt_lanes_dyn<F>(x: ArrayView2<F>, sos: &[Sos<F>]) -> Array2<F>
+where
+    F: RealField + Copy,
+{
+    let x = x.as_standard_layout();
+    let (rows, n) = x.dim();
+    let mut out = Array2::<F>::zeros((rows, n));
+    if n == 0 {
+        return out;
+    }
+
+    let x_rows = x.as_slice().expect("standard layout").chunks_exact(n);
+    let out_rows = out
+        .as_slice_mut()
+        .expect("standard layout")
+        .chunks_exact_mut(n);
+    let x_rows = x_rows.collect::<Vec<_>>();
+    let mut out_rows = out_rows.collect::<Vec<_>>();
+
+    let mut x_blocks = x_rows.chunks_exact(SOSFILT_LANES);
+    let mut out_blocks = out_rows.chunks_exact_mut(SOSFILT_LANES);
+    let mut zi0 = alloc::vec![[F::zero(); SOSFILT_LANES]; sos.len()];
+    let mut zi1 = zi0.clone();
+    // Samples of a block interleaved by channel, filtered in place one section at a time.
+    let mut lanes = [[F::zero(); SOSFILT_LANES]; SOSFILT_CHUNK];
+    for (xb, ob) in (&mut x_blocks).zip(&mut out_blocks) {
+        for (s, (z0, z1)) in sos.iter().zip(zi0.iter_mut().zip(zi1.iter_mut())) {
+            *z0 = [s.zi0; SOSFILT_LANES];
+            *z1 = [s.zi1; SOSFILT_LANES];
+        }
+        for start in (0..n).step_by(SOSFILT_CHUNK) {
+            let end = (start + SOSFILT_CHUNK).min(n);
+            let lanes = &mut lanes[..end - start];
+            for (l, xr) in xb.iter().enumerate() {
+                for (v, xi) in lanes.iter_mut().zip(&xr[start..end]) {
+                    v[l] = *xi;
+                }
+            }
+            for (s, (zi0, zi1)) in sos.iter().zip(zi0.iter_mut().zip(zi1.iter_mut())) {
+                let ([b0, b1, b2], [_, a1, a2]) = (s.b, s.a);
+                let (mut z0, mut z1) = (*zi0, *zi1);
+                for v in lanes.iter_mut() {
+                    for l in 0..SOSFILT_LANES {
+                        let x_new = b0 * v[l] + z0[l];
+                        z0[l] = b1 * v[l] - a1 * x_new + z1[l];
+                        z1[l] = b2 * v[l] - a2 * x_new;
+                        v[l] = x_new;
+                    }
+                }
+                (*zi0, *zi1) = (z0, z1);
+            }
+            for (l, or) in ob.iter_mut().enumerate() {
+                for (o, v) in or[start..end].iter_mut().zip(lanes.iter()) {
+                    *o = v[l];
+                }
+            }
+        }
+    }
+
+    for (xr, or) in x_blocks
+        .remainder()
+        .iter()
+        .zip(out_blocks.into_remainder().iter_mut())
+    {
+        let mut sos = sos.to_vec();
+        or.copy_from_slice(&sosfilt_dyn(xr.iter(), &mut sos));
+    }
+
+    out
+}
+
+/// Filter data along one dimension using cascaded second-order sections.
+///
+/// Filter a data sequence, `x`, using a digital IIR filter defined by `sos`. Each section is a
+/// direct form II transposed filter, and the output of each section is the input of the next.
+/// Second-order sections have fewer numerical problems than the transfer function of
+/// [lfilter][super::lfilter], and should be preferred for most filtering tasks.
+///
+/// ## Parameters
+/// * `sos` : array_like
+///   Array of second-order filter coefficients, as designed with ``output='sos'``. The filter
+///   delay values held in `sos` are ignored in favour of `zi`.
+/// * `x` : array_like
+///   An N-dimensional input array.
+/// * `axis`: `Option<isize>`
+///   The axis of the input data array along which to apply the linear filter. The filter is
+///   applied to each subarray along this axis. Default to `-1` if `None`.
+/// * `zi` : array_like, optional
+///   Initial conditions for the cascaded filter delays. It is an array of shape
+///   ``(n_sections, ..., 2, ...)``, where ``..., 2, ...`` denotes the shape of `x`, but with
+///   ``x.shape[axis]`` replaced by 2. An array of shape ``(n_sections, 2)`` is also accepted, and
+///   used as the initial conditions of every subarray. If `zi` is None or is not given then
+///   initial rest (i.e. all zeros) is assumed. See [super::sosfilt_zi_dyn].
+///
+/// ## Returns
+/// * `y` : ndarray
+///   The output of the digital filter.
+/// * `zf` : ndarray, optional
+///   If `zi` is None, this is not returned, otherwise, `zf` holds the final filter delay values,
+///   of shape ``(n_sections, ..., 2, ...)``.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `x` is 0-dimensional, `sos` is empty, `axis` is out of range, or `zi`
+/// is of the wrong shape.
+///
+/// # Examples
+/// ```
+/// use ndarray::{array, Array2};
+/// use sci_rs::signal::filter::{design::Sos, sosfilt, sosfilt_dyn};
+///
+/// let sos = [Sos::new([0.5, 0.5, 0.], [1., -0.5, 0.])];
+/// let x: Array2<f64> = array![[1., 2., 3.], [4., 5., 6.]];
+/// let (y, zf) = sosfilt(&sos, x.view(), Some(0), None).unwrap();
+/// assert!(zf.is_none());
+/// for j in 0..3 {
+///     let column = sosfilt_dyn(x.column(j), &mut sos.clone());
+///     assert_eq!(y.column(j).to_vec(), column);
+/// }
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.sosfilt.html>
+#[cfg(feature = "alloc")]
+pub fn sosfilt<F, S, D>(
+    sos: &[Sos<F>],
+    x: ArrayBase<S, D>,
+    axis: Option<isize>,
+    zi: Option<ArrayViewD<F>>,
+) -> Result<SosFiltResult<F, D>>
+where
+    F: RealField + Copy,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let ndim = x.ndim();
+    if ndim == 0 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "Linear filter requires at least 1-dimensional `x`.".into(),
+        });
+    }
+    if sos.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "sos".into(),
+            reason: "There must be at least one second-order section.".into(),
+        });
+    }
+    let axis = check_and_get_axis_dyn(axis, &x)?;
+
+    let n_sections = sos.len();
+    let n_lanes: usize = x
+        .shape()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &len)| (i != axis).then_some(len))
+        .product();
+    let mut zi_shape = alloc::vec![n_sections];
+    zi_shape.extend_from_slice(x.shape());
+    zi_shape[axis + 1] = 2;
+    // The lanes of `x` are visited in the logical order of the other axes, so moving the axis of
+    // length 2 of `zi` last visits its lanes in the same order.
+    let perm: Vec<usize> = (0..=ndim)
+        .filter(|&i| i != axis + 1)
+        .chain([axis + 1])
+        .collect();
+
+    let zi = match zi {
+        None => None,
+        Some(zi) if zi.shape() == [n_sections, 2] => Some(Array3::from_shape_fn(
+            (n_sections, n_lanes, 2),
+            |(s, _, k)| zi[[s, k]],
+        )),
+        Some(zi) if zi.shape() == zi_shape.as_slice() => {
+            let zi = zi.permuted_axes(perm.clone());
+            Some(
+                Array3::from_shape_vec((n_sections, n_lanes, 2), zi.iter().copied().collect())
+                    .expect("zi has n_sections * n_lanes * 2 elements"),
+            )
+        }
+        Some(_) => {
+            return Err(Error::InvalidArg {
+                arg: "zi".into(),
+                reason: "Expected zi of shape (n_sections, ..., 2, ...) or (n_sections, 2).".into(),
+            })
+        }
+    };
+
+    let mut z = zi
+        .clone()
+        .unwrap_or_else(|| Array3::zeros((n_sections, n_lanes, 2)));
+    let mut y = Array::zeros(x.raw_dim());
+    for (lane, (xl, mut yl)) in x
+        .lanes(Axis(axis))
+        .into_iter()
+        .zip(y.lanes_mut(Axis(axis)))
+        .enumerate()
+    {
+        let mut sections: Vec<Sos<F>> = sos
+            .iter()
+            .enumerate()
+            .map(|(s, section)| Sos {
+                zi0: z[[s, lane, 0]],
+                zi1: z[[s, lane, 1]],
+                ..*section
+            })
+            .collect();
+        for (yi, xi) in yl.iter_mut().zip(xl) {
+            *yi = sosfilt_item(xi, &mut sections);
+        }
+        for (s, section) in sections.iter().enumerate() {
+            z[[s, lane, 0]] = section.zi0;
+            z[[s, lane, 1]] = section.zi1;
+        }
+    }
+
+    let zf = zi.map(|_| {
+        let permuted_shape: Vec<usize> = perm.iter().map(|&i| zi_shape[i]).collect();
+        let mut inverse = alloc::vec![0; perm.len()];
+        perm.iter().enumerate().for_each(|(i, &p)| inverse[p] = i);
+        ArrayD::from_shape_vec(IxDyn(&permuted_shape), z.into_iter().collect())
+            .expect("zf has the shape of zi")
+            .permuted_axes(inverse)
+            .as_standard_layout()
+            .into_owned()
+    });
+
+    Ok((y, zf))
+}
+
 type Sos32 = Sos<f32>;
 
 #[inline(always)]
@@ -562,4 +823,176 @@ mod tests {
             assert_relative_eq!(*a, *b);
         }
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfilt_lanes_matches_scalar() {
+        let filter: [f64; 12] = [
+            0.0004165461390757479,
+            0.0008330922781514958,
+            0.0004165461390757479,
+            1.0,
+            -1.4797988943791147,
+            0.5578633880646523,
+            1.0,
+            2.0,
+            1.0,
+            1.0,
+            -1.7008528670285624,
+            0.7878402004051043,
+        ];
+        let mut sos = Sos::from_scipy_dyn(2, filter.to_vec());
+        sos[0].zi0 = 0.25;
+
+        // Two full blocks of lanes and a remainder.
+        let rows = 2 * SOSFILT_LANES + 3;
+        let x = Array2::from_shape_fn((rows, 300), |(r, t)| {
+            ((r + 1) as f64 * 0.05 * t as f64).sin() + r as f64
+        });
+        let y = sosfilt_lanes_dyn(x.view(), &sos);
+        assert_eq!(y.dim(), x.dim());
+        for (xr, yr) in x.outer_iter().zip(y.outer_iter()) {
+            let expected = sosfilt_dyn(xr.iter(), &mut sos.clone());
+            for (a, e) in yr.iter().zip(&expected) {
+                assert_relative_eq!(*a, *e, max_relative = 1e-12);
+            }
+        }
+
+        // Column-major input is filtered the same.
+        let xt = x.t().to_owned();
+        let y_f = sosfilt_lanes_dyn(xt.t(), &sos);
+        assert_eq!(y, y_f);
+        assert_eq!(sos[0].zi0, 0.25);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfilt_butterworth_bandpass_on_ramp() {
+        use super::super::lfilter::lfilter_df2t;
+        use crate::signal::filter::design::{
+            iirfilter_dyn, DigitalFilter, FilterBandType, FilterOutputType, FilterType,
+        };
+        use alloc::vec;
+        use ndarray::Array1;
+
+        // butter(4, [10, 50], btype='bandpass', fs=1666)
+        let design = |output| {
+            iirfilter_dyn::<f64>(
+                4,
+                vec![10., 50.],
+                None,
+                None,
+                Some(FilterBandType::Bandpass),
+                Some(FilterType::Butterworth),
+                Some(false),
+                Some(output),
+                Some(1666.),
+            )
+        };
+        let DigitalFilter::Sos(sos) = design(FilterOutputType::Sos) else {
+            panic!("Expected a filter in SOS format.");
+        };
+        let DigitalFilter::Ba(ba) = design(FilterOutputType::Ba) else {
+            panic!("Expected a filter in BA format.");
+        };
+
+        let n = 3000;
+        let x = Array1::from_iter((0..n).map(|i| i as f64));
+        let (y, zf) = sosfilt(&sos.sos, x.view(), None, None).unwrap();
+        assert!(zf.is_none());
+        assert_eq!(y.to_vec(), sosfilt_dyn(x.iter(), &mut sos.sos.clone()));
+
+        // The transfer function of the same filter, up to its loss of precision.
+        let expected = lfilter_df2t(&ba.b, &ba.a, x.iter().copied(), &[0.; 8]);
+        for (a, e) in y.iter().zip(&expected) {
+            assert_relative_eq!(*a, *e, epsilon = 1e-4);
+        }
+        // The double zero at DC rejects the ramp, once the transient has decayed.
+        assert!(y.iter().skip(n - 100).all(|yi| yi.abs() < 1e-6));
+        assert!(y.iter().take(200).any(|yi| yi.abs() > 1.));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfilt_along_axis_with_zi() {
+        use alloc::vec;
+        use ndarray::{array, s, Array3};
+
+        let filter: [f64; 12] = [
+            0.0004165461390757479,
+            0.0008330922781514958,
+            0.0004165461390757479,
+            1.0,
+            -1.4797988943791147,
+            0.5578633880646523,
+            1.0,
+            2.0,
+            1.0,
+            1.0,
+            -1.7008528670285624,
+            0.7878402004051043,
+        ];
+        let sos = Sos::from_scipy_dyn(2, filter.to_vec());
+        let x = Array3::from_shape_fn((3, 40, 2), |(i, t, j)| {
+            ((i + 1) as f64 * 0.1 * t as f64).sin() + j as f64
+        });
+
+        // Each lane along the axis is filtered independently.
+        let (y, _) = sosfilt(&sos, x.view(), Some(1), None).unwrap();
+        for i in 0..3 {
+            for j in 0..2 {
+                let expected = sosfilt_dyn(x.slice(s![i, .., j]), &mut sos.clone());
+                assert_eq!(y.slice(s![i, .., j]).to_vec(), expected);
+            }
+        }
+
+        // Filtering in two parts, passing zf as the zi of the second, is filtering the whole.
+        let zi = ArrayD::zeros(IxDyn(&[2, 3, 2, 2]));
+        let (y0, zf) = sosfilt(&sos, x.slice(s![.., ..25, ..]), Some(-2), Some(zi.view())).unwrap();
+        let zf = zf.unwrap();
+        assert_eq!(zf.shape(), &[2, 3, 2, 2]);
+        let (y1, _) = sosfilt(&sos, x.slice(s![.., 25.., ..]), Some(1), Some(zf.view())).unwrap();
+        for (a, e) in y0
+            .iter()
+            .chain(&y1)
+            .zip(
+                y.slice(s![.., ..25, ..])
+                    .iter()
+                    .chain(y.slice(s![.., 25.., ..])),
+            )
+        {
+            assert_relative_eq!(*a, *e, max_relative = 1e-12);
+        }
+
+        // Initial conditions of shape (n_sections, 2) apply to every lane.
+        let zi = array![[0.5, -0.25], [1., 0.]].into_dyn();
+        let (y, zf) = sosfilt(&sos, x.view(), Some(1), Some(zi.view())).unwrap();
+        let zf = zf.unwrap();
+        for i in 0..3 {
+            for j in 0..2 {
+                let mut lane = sos.clone();
+                (lane[0].zi0, lane[0].zi1, lane[1].zi0, lane[1].zi1) = (0.5, -0.25, 1., 0.);
+                let expected = sosfilt_dyn(x.slice(s![i, .., j]), &mut lane);
+                assert_eq!(y.slice(s![i, .., j]).to_vec(), expected);
+                for (k, section) in lane.iter().enumerate() {
+                    assert_eq!(zf[[k, i, 0, j]], section.zi0);
+                    assert_eq!(zf[[k, i, 1, j]], section.zi1);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfilt_rejects_invalid_arguments() {
+        let sos = [Sos::new([1., 0., 0.], [1., 0., 0.])];
+        let x = Array2::<f64>::zeros((2, 3));
+        assert!(sosfilt(&[], x.view(), None, None).is_err());
+        assert!(sosfilt(&sos, x.view(), Some(2), None).is_err());
+        assert!(sosfilt(&sos, ndarray::arr0(1.), None, None).is_err());
+        let zi = ArrayD::zeros(IxDyn(&[1, 3, 2]));
+        assert!(sosfilt(&sos, x.view(), None, Some(zi.view())).is_err());
+        let zi = ArrayD::zeros(IxDyn(&[1, 2, 2]));
+        assert!(sosfilt(&sos, x.view(), None, Some(zi.view())).is_ok());
+    }
 }
@@ -0,0 +1,169 @@
+use core::{borrow::Borrow, iter::Sum, ops::SubAssign};
+use nalgebra::{RealField, Scalar};
+use num_traits::{One, Zero};
+
+use super::design::Sos;
+use super::{sosfilt_dyn, sosfilt_item, sosfilt_zi_dyn};
+
+use alloc::vec::Vec;
+
+///
+/// Second Order Sections filter for streaming samples, seeded from the steady state of the filter.
+///
+/// The state of the sections is initialised from [sosfilt_zi_dyn] scaled by the first sample of
+/// the signal, as with `zi = sosfilt_zi(sos) * x[0]` in scipy. The filter then starts in the
+/// steady state for a constant input of `x[0]`, so a signal with a DC offset does not produce a
+/// startup transient.
+///
+/// The filter state carries over between calls to [SosFilterState::filter] and
+/// [SosFilterState::filter_item], so a signal can be filtered in chunks as it arrives.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::{design::Sos, SosFilterState};
+///
+/// let sos = vec![Sos::new([0.25f64, 0.5, 0.25], [1., -0.5, 0.25])];
+/// let mut filter = SosFilterState::new(sos, 10.);
+/// let y = filter.filter([10., 10., 10.]);
+/// let steady = 10. * filter.dc_gain();
+/// assert!(y.iter().all(|yi| (yi - steady).abs() < 1e-12));
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct SosFilterState<F>
+where
+    F: RealField + Copy,
+{
+    sos: Vec<Sos<F>>,
+    /// Steady state of each section for a unit step input.
+    zi: Vec<[F; 2]>,
+    dc_gain: F,
+}
+
+impl<F> SosFilterState<F>
+where
+    F: RealField + Copy + PartialEq + Scalar + Zero + One + Sum + SubAssign,
+{
+    /// Returns a filter for the sections `sos`, seeded for a signal starting at `x0`.
+    ///
+    /// Any state already held in `sos` is replaced.
+    pub fn new(mut sos: Vec<Sos<F>>, x0: F) -> Self {
+        sosfilt_zi_dyn::<_, _, Sos<F>>(sos.iter_mut());
+        let zi = sos.iter().map(|s| [s.zi0, s.zi1]).collect();
+        let dc_gain = sos
+            .iter()
+            .map(|s| s.b.iter().copied().sum::<F>() / s.a.iter().copied().sum::<F>())
+            .fold(F::one(), |gain, g| gain * g);
+        let mut state = SosFilterState { sos, zi, dc_gain };
+        state.reset(x0);
+        state
+    }
+
+    /// Re-seed the filter state for a signal resuming at `x0`, discarding the current state.
+    pub fn reset(&mut self, x0: F) {
+        for (s, zi) in self.sos.iter_mut().zip(&self.zi) {
+            s.zi0 = zi[0] * x0;
+            s.zi1 = zi[1] * x0;
+        }
+    }
+
+    /// Gain of the cascade for a constant input, the product of `sum(b) / sum(a)` over the
+    /// sections.
+    ///
+    /// A constant input `x0` is filtered to a constant `x0 * dc_gain()`.
+    pub fn dc_gain(&self) -> F {
+        self.dc_gain
+    }
+
+    /// The sections of the filter, holding its current state.
+    pub fn sos(&self) -> &[Sos<F>] {
+        &self.sos
+    }
+
+    /// Filter the samples `x`, continuing from the current state.
+    pub fn filter<YI>(&mut self, x: YI) -> Vec<F>
+    where
+        YI: IntoIterator,
+        YI::Item: Borrow<F>,
+    {
+        sosfilt_dyn(x, &mut self.sos)
+    }
+
+    /// Filter a single sample `x`, continuing from the current state.
+    pub fn filter_item<B>(&mut self, x: B) -> F
+    where
+        B: Borrow<F>,
+    {
+        sosfilt_item(x, &mut self.sos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{
+        butter_dyn, DigitalFilter, FilterBandType, FilterOutputType, SosFormatFilter,
+    };
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    fn butter_sos(btype: FilterBandType) -> Vec<Sos<f64>> {
+        let DigitalFilter::Sos(SosFormatFilter { sos }) = butter_dyn(
+            4,
+            vec![0.1],
+            Some(btype),
+            None,
+            Some(FilterOutputType::Sos),
+            None,
+        ) else {
+            panic!("Expected sos output")
+        };
+        sos
+    }
+
+    #[test]
+    fn constant_offset_has_no_transient() {
+        let mut filter = SosFilterState::new(butter_sos(FilterBandType::Lowpass), 5.);
+        assert_relative_eq!(filter.dc_gain(), 1., max_relative = 1e-10);
+        for yi in filter.filter(vec![5.; 100]) {
+            assert_relative_eq!(yi, 5., max_relative = 1e-10);
+        }
+
+        // A highpass filter removes the offset from the very first sample.
+        let mut filter = SosFilterState::new(butter_sos(FilterBandType::Highpass), 5.);
+        for _ in 0..100 {
+            assert!(filter.filter_item(5.).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn unseeded_filter_has_transient() {
+        let mut filter = SosFilterState::new(butter_sos(FilterBandType::Lowpass), 0.);
+        let y = filter.filter(vec![5.; 100]);
+        assert!((y[0] - 5.).abs() > 1.);
+    }
+
+    #[test]
+    fn reset_reseeds_state() {
+        let mut filter = SosFilterState::new(butter_sos(FilterBandType::Lowpass), 0.);
+        let ramp: Vec<f64> = (0..50).map(|i| i as f64).collect();
+        filter.filter(&ramp);
+
+        filter.reset(-2.);
+        for yi in filter.filter(vec![-2.; 100]) {
+            assert_relative_eq!(yi, -2., max_relative = 1e-10);
+        }
+    }
+
+    #[test]
+    fn chunks_match_whole_signal() {
+        let x: Vec<f64> = (0..200).map(|i| 1. + (i as f64 / 7.).sin()).collect();
+        let mut whole = SosFilterState::new(butter_sos(FilterBandType::Lowpass), x[0]);
+        let expected = whole.filter(&x);
+
+        let mut chunked = SosFilterState::new(butter_sos(FilterBandType::Lowpass), x[0]);
+        let mut y = chunked.filter(&x[..80]);
+        y.extend(x[80..].iter().map(|xi| chunked.filter_item(xi)));
+        assert_eq!(y, expected);
+    }
+}
@@ -8,6 +8,8 @@ use super::design::Sos;
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use ndarray::Array2;
 
 ///
 /// Construct initial conditions for sosfilt for step response steady-state.
@@ -36,16 +38,63 @@ where
     }
 }
 
+/// Construct initial conditions for sosfilt for step response steady-state.
+///
+/// Compute an initial state `zi` for the [sosfilt][super::sosfilt] function that corresponds to
+/// the steady state of the step response, as [sosfilt_zi_dyn] but without modifying `sos`.
+///
+/// Each row is the [lfilter_zi_dyn] of a section, scaled by the DC gain of the sections preceding
+/// it, as that is the steady state of the input to the section.
+///
+/// ## Returns
+/// * `zi` : ndarray
+///   Initial conditions of shape ``(n_sections, 2)``, which may be scaled by the first sample of
+///   a signal and passed as the `zi` of [sosfilt][super::sosfilt].
+///
+/// # Examples
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::filter::{design::Sos, sosfilt, sosfilt_zi};
+///
+/// let sos = [
+///     Sos::new([0.25, 0.5, 0.25], [1., -0.5, 0.5]),
+///     Sos::new([1., 0., 0.], [1., -0.5, 0.]),
+/// ];
+/// let zi = sosfilt_zi(&sos);
+/// assert_eq!(zi.dim(), (2, 2));
+///
+/// // A constant signal starts in its steady state, so is passed through with the DC gain of 2.
+/// let x = Array1::from_elem(10, 3f64);
+/// let zi = (zi * 3.).into_dyn();
+/// let (y, _) = sosfilt(&sos, x.view(), None, Some(zi.view())).unwrap();
+/// assert!(y.iter().all(|yi| (yi - 6.).abs() < 1e-12));
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.sosfilt_zi.html>
+#[cfg(feature = "alloc")]
+pub fn sosfilt_zi<F>(sos: &[Sos<F>]) -> Array2<F>
+where
+    F: RealField + Copy + PartialEq + Scalar + Zero + One + Sum + SubAssign,
+{
+    let mut sections = sos.to_vec();
+    sosfilt_zi_dyn::<_, _, Sos<F>>(sections.iter_mut());
+    Array2::from_shape_fn((sections.len(), 2), |(s, k)| {
+        if k == 0 {
+            sections[s].zi0
+        } else {
+            sections[s].zi1
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
-    #[cfg(feature = "std")]
-    #[test]
-    fn scipy_example_dyn() {
-        // sos = signal.butter(9, 0.125, output='sos')
-        let mut sos: Vec<Sos<f64>> = Sos::from_scipy_dyn(
+    /// sos = signal.butter(9, 0.125, output='sos')
+    fn butter_sos() -> Vec<Sos<f64>> {
+        Sos::from_scipy_dyn(
             5,
             [
                 1.55854712e-07,
@@ -80,25 +129,54 @@ mod tests {
                 8.75376926e-01,
             ]
             .to_vec(),
-        );
-        assert_eq!(sos.len(), 5);
+        )
+    }
+
+    /// zi = signal.sosfilt_zi(sos)
+    const EXPECTED_ZI: [[f64; 2]; 5] = [
+        [1.72292381e-06, 1.55854712e-07],
+        [6.52357932e-05, -2.97332383e-05],
+        [2.21320188e-03, -1.17932460e-03],
+        [6.90969677e-02, -4.61691178e-02],
+        [9.28622716e-01, -8.75376926e-01],
+    ];
 
-        // zi = signal.sosfilt_zi(sos)
-        let expected_zi = nalgebra::matrix!(
-                1.72292381e-06,  1.55854712e-07;
-                6.52357932e-05, -2.97332383e-05;
-                2.21320188e-03, -1.17932460e-03;
-                6.90969677e-02, -4.61691178e-02;
-                9.28622716e-01, -8.75376926e-01
-        );
+    #[cfg(feature = "std")]
+    #[test]
+    fn scipy_example_dyn() {
+        let mut sos = butter_sos();
+        assert_eq!(sos.len(), 5);
 
         // Compute zi inplace on Sos
         sosfilt_zi_dyn::<_, _, Sos<f64>>(sos.iter_mut());
 
-        for (i, row) in expected_zi.row_iter().enumerate() {
-            let section = sos[i];
+        for (row, section) in EXPECTED_ZI.iter().zip(&sos) {
             assert_relative_eq!(row[0], section.zi0, max_relative = 1e-6);
             assert_relative_eq!(row[1], section.zi1, max_relative = 1e-6);
         }
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scipy_example() {
+        use crate::signal::filter::sosfilt;
+        use ndarray::Array1;
+
+        let sos = butter_sos();
+        let zi = sosfilt_zi(&sos);
+        assert_eq!(zi.dim(), (5, 2));
+        for (row, expected) in zi.rows().into_iter().zip(EXPECTED_ZI) {
+            assert_relative_eq!(row[0], expected[0], max_relative = 1e-6);
+            assert_relative_eq!(row[1], expected[1], max_relative = 1e-6);
+        }
+        // The sections themselves are untouched.
+        assert!(sos.iter().all(|s| s.zi0 == 0. && s.zi1 == 0.));
+
+        // Scaled by the first sample, a step continues in its steady state.
+        let x = Array1::from_elem(50, -2.);
+        let zi = (zi * -2.).into_dyn();
+        let (y, _) = sosfilt(&sos, x.view(), None, Some(zi.view())).unwrap();
+        y.iter()
+            .for_each(|&yi| assert_relative_eq!(yi, -2., max_relative = 1e-5));
+    }
 }
@@ -1,11 +1,50 @@
 use core::{borrow::Borrow, cmp::min, iter::Sum, ops::SubAssign};
-use nalgebra::{DVector, RealField, Scalar};
+use nalgebra::{RealField, Scalar};
 use num_traits::{Float, One, Zero};
 
-use super::{design::Sos, pad, sosfilt_dyn, sosfilt_zi_dyn, Pad};
+use super::{design::Sos, sosfilt_dyn, sosfilt_item, sosfilt_zi_dyn};
 
+#[cfg(feature = "alloc")]
+use super::{check_and_get_axis_dyn, filtfilt::validate_pad, FiltFiltPad};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use ndarray::{
+    Array, ArrayBase, ArrayView1, Axis, Data, Dimension, RemoveAxis, SliceArg, SliceInfo,
+    SliceInfoElem,
+};
+#[cfg(feature = "alloc")]
+use sci_rs_core::num_rs::{pad, PadMode};
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
+/// Default padding length of [sosfiltfilt] and [sosfiltfilt_dyn],
+/// ``3 * (2 * len(sos) + 1 - min((sos[:, 2] == 0).sum(), (sos[:, 5] == 0).sum()))``.
+///
+/// Sections that are only first order, with both `b[2]` and `a[2]` zero, do not lengthen the
+/// padding. The signal must be longer than this along the filtered axis to be filtered with the
+/// default padding, which can be checked before calling [sosfiltfilt].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::{default_padlen_sos, design::Sos};
+///
+/// let sos = [
+///     Sos::new([1., 2., 1.], [1., -0.5, 0.25]),
+///     Sos::new([1., 1., 0.], [1., -0.5, 0.]),
+/// ];
+/// assert_eq!(default_padlen_sos(&sos), 12);
+/// ```
+pub fn default_padlen_sos<F: RealField + Copy>(sos: &[Sos<F>]) -> usize {
+    3 * sos_ntaps(sos)
+}
+
+/// Number of taps of the transfer function of `sos`, discounting first order sections.
+fn sos_ntaps<F: RealField + Copy>(sos: &[Sos<F>]) -> usize {
+    let bzeros = sos.iter().filter(|s| s.b[2] == F::zero()).count();
+    let azeros = sos.iter().filter(|s| s.a[2] == F::zero()).count();
+    2 * sos.len() + 1 - min(bzeros, azeros)
+}
 
 ///
 /// A forward-backward digital filter using cascaded second-order sections
@@ -20,20 +59,25 @@ where
     YI: Iterator,
     YI::Item: Borrow<F>,
 {
-    let n = sos.len();
-    let ntaps = 2 * n + 1;
-    let bzeros = sos.iter().filter(|s| s.b[2] == F::zero()).count();
-    let azeros = sos.iter().filter(|s| s.a[2] == F::zero()).count();
-    let ntaps = ntaps - min(bzeros, azeros);
+    let edge = default_padlen_sos(sos);
     let y = y.map(|yi| *yi.borrow()).collect::<Vec<F>>();
     let y_len = y.len();
-    let x = DVector::<F>::from_vec(y);
-    let (edge, ext) = pad(Pad::Odd, None, x, 0, ntaps);
+    assert!(
+        y_len > edge,
+        "The length of the input vector x must be greater than padlen"
+    );
+    let ext = pad(
+        &ArrayView1::from(&y),
+        &[(edge, edge)],
+        PadMode::Odd,
+        F::zero(),
+    )
+    .unwrap();
 
     let mut init_sos = sos.to_vec();
     sosfilt_zi_dyn::<_, _, Sos<F>>(init_sos.iter_mut());
 
-    let x0 = *ext.index(0);
+    let x0 = ext[0];
     let mut sos_x = init_sos.clone();
     for s in sos_x.iter_mut() {
         s.zi0 *= x0;
@@ -56,6 +100,121 @@ where
     z
 }
 
+/// A forward-backward digital filter using cascaded second-order sections, along an axis of `x`.
+///
+/// Apply the filter `sos` to each subarray of `x` along `axis` twice, once forward and once
+/// backwards, as [FiltFilt::filtfilt][super::FiltFilt::filtfilt]. The combined filter has zero
+/// phase and a filter order twice that of the original. Second-order sections have fewer
+/// numerical problems than the transfer function, so this should be preferred for most
+/// zero-phase filtering, particularly with high-order filters.
+///
+/// ## Parameters
+/// * `sos` : array_like
+///   Array of second-order filter coefficients, as designed with ``output='sos'``. The filter
+///   delay values held in `sos` are ignored.
+/// * `x` : array_like
+///   The array of data to be filtered.
+/// * `axis`: `Option<isize>`
+///   The axis of `x` to which the filter is applied. Default to `-1` if `None`.
+/// * `padding` : [FiltFiltPad], optional
+///   The type and length of the extension of `x` at both ends of `axis`, as for
+///   [FiltFilt::filtfilt][super::FiltFilt::filtfilt]. [Option::None] here denotes a deliberate
+///   absence of padding. The default padding length is
+///   ``3 * (2 * len(sos) + 1 - min((sos[:, 2] == 0).sum(), (sos[:, 5] == 0).sum()))``, which
+///   discounts sections that are only first order.
+///
+/// ## Returns
+/// * `y` : ndarray
+///   The filtered output with the same shape as `x`.
+///
+/// ## Errors
+/// [Error::InvalidArg] if `x` is 0-dimensional, `sos` is empty, `axis` is out of range, or the
+/// padding is not shorter than `x` along `axis`.
+///
+/// # Examples
+/// ```
+/// use ndarray::Array2;
+/// use sci_rs::signal::filter::{design::Sos, sosfiltfilt, sosfiltfilt_dyn, FiltFiltPad};
+///
+/// let sos = [Sos::new([0.25, 0.5, 0.25], [1., -0.5, 0.25])];
+/// let x = Array2::from_shape_fn((2, 20), |(i, j)| ((i + 1) * j) as f64);
+/// let y = sosfiltfilt(&sos, x.view(), Some(1), Some(FiltFiltPad::default())).unwrap();
+/// assert_eq!(y.row(1).to_vec(), sosfiltfilt_dyn(x.row(1).iter(), &sos));
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.sosfiltfilt.html>
+#[cfg(feature = "alloc")]
+pub fn sosfiltfilt<F, S, D>(
+    sos: &[Sos<F>],
+    x: ArrayBase<S, D>,
+    axis: Option<isize>,
+    padding: Option<FiltFiltPad>,
+) -> Result<Array<F, D>>
+where
+    F: RealField + Copy + PartialEq + Scalar + Zero + One + Sum + SubAssign,
+    S: Data<Elem = F>,
+    D: Dimension + RemoveAxis,
+    SliceInfo<Vec<SliceInfoElem>, D, D>: SliceArg<D, OutDim = D>,
+{
+    if x.ndim() == 0 {
+        return Err(Error::InvalidArg {
+            arg: "x".into(),
+            reason: "Linear filter requires at least 1-dimensional `x`.".into(),
+        });
+    }
+    if sos.is_empty() {
+        return Err(Error::InvalidArg {
+            arg: "sos".into(),
+            reason: "There must be at least one second-order section.".into(),
+        });
+    }
+    let axis = check_and_get_axis_dyn(axis, &x)?;
+
+    let mut y = Array::zeros(x.raw_dim());
+    if y.is_empty() {
+        return Ok(y);
+    }
+
+    let (edge, ext) = validate_pad(padding, x.view(), axis, default_padlen_sos(sos))?;
+
+    let mut init_sos = sos.to_vec();
+    sosfilt_zi_dyn::<_, _, Sos<F>>(init_sos.iter_mut());
+    let scaled = |x0: F| -> Vec<Sos<F>> {
+        init_sos
+            .iter()
+            .map(|s| Sos {
+                zi0: s.zi0 * x0,
+                zi1: s.zi1 * x0,
+                ..*s
+            })
+            .collect()
+    };
+
+    for (xl, mut yl) in ext
+        .lanes(Axis(axis))
+        .into_iter()
+        .zip(y.lanes_mut(Axis(axis)))
+    {
+        let mut sections = scaled(xl[0]);
+        let forward: Vec<F> = xl
+            .iter()
+            .map(|xi| sosfilt_item(xi, &mut sections))
+            .collect();
+
+        let mut sections = scaled(forward[forward.len() - 1]);
+        let backward: Vec<F> = forward
+            .iter()
+            .rev()
+            .map(|yi| sosfilt_item(yi, &mut sections))
+            .collect();
+        yl.iter_mut()
+            .zip(backward.iter().rev().skip(edge))
+            .for_each(|(yi, &b)| *yi = b);
+    }
+
+    Ok(y)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +319,145 @@ mod tests {
         println!("{:?}", &bp_wave[..10]);
         println!("{:?}", &sin_wave[..10]);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfiltfilt_butterworth_bandpass() {
+        use crate::signal::filter::design::{
+            iirfilter_dyn, DigitalFilter, FilterBandType, FilterOutputType, FilterType,
+        };
+        use crate::signal::filter::FiltFilt;
+        use alloc::vec;
+        use approx::assert_relative_eq;
+        use ndarray::{Array1, Array2};
+
+        // butter(4, [10, 50], btype='bandpass', fs=1666), of order 8
+        let design = |output| {
+            iirfilter_dyn::<f64>(
+                4,
+                vec![10., 50.],
+                None,
+                None,
+                Some(FilterBandType::Bandpass),
+                Some(FilterType::Butterworth),
+                Some(false),
+                Some(output),
+                Some(1666.),
+            )
+        };
+        let DigitalFilter::Sos(sos) = design(FilterOutputType::Sos) else {
+            panic!("Expected a filter in SOS format.");
+        };
+        let DigitalFilter::Ba(ba) = design(FilterOutputType::Ba) else {
+            panic!("Expected a filter in BA format.");
+        };
+        let sos = sos.sos;
+        assert_eq!(sos.len(), 4);
+
+        // A 25 Hz sine in the passband, with a 400 Hz tone and an offset in the stopband.
+        let n = 1666;
+        let x = Array1::from_iter((0..n).map(|i| {
+            let t = i as f64 / 1666.;
+            (2. * core::f64::consts::PI * 25. * t).sin()
+                + 0.5 * (2. * core::f64::consts::PI * 400. * t).sin()
+                + 2.
+        }));
+        let y = sosfiltfilt(&sos, x.view(), None, Some(FiltFiltPad::default())).unwrap();
+        assert_eq!(y.len(), n);
+
+        // Both pad by 3 * 9 samples, so match the 1-D implementation and, up to its loss of
+        // precision, the transfer function of the same filter.
+        let expected = sosfiltfilt_dyn(x.iter(), &sos);
+        for (a, e) in y.iter().zip(&expected) {
+            assert_relative_eq!(*a, *e, epsilon = 1e-12);
+        }
+        let expected = ndarray::ArrayView1::filtfilt(
+            (&ba.b).into(),
+            (&ba.a).into(),
+            x.view(),
+            None,
+            Some(FiltFiltPad::default()),
+        )
+        .unwrap();
+        for (a, e) in y.iter().zip(&expected) {
+            assert_relative_eq!(*a, *e, epsilon = 1e-6);
+        }
+
+        // Zero phase: away from the edges, only the sine remains, without any delay.
+        let gain = y
+            .iter()
+            .skip(400)
+            .take(800)
+            .fold(0f64, |m, yi| m.max(yi.abs()));
+        for i in 400..1200 {
+            let t = i as f64 / 1666.;
+            let sine = (2. * core::f64::consts::PI * 25. * t).sin();
+            assert_relative_eq!(y[i], gain * sine, epsilon = 1e-2);
+        }
+
+        // Each row of a 2-D input is filtered independently along the axis.
+        let x2 = Array2::from_shape_fn((3, n), |(r, i)| (r as f64 + 1.) * x[i]);
+        let y2 = sosfiltfilt(&sos, x2.view(), Some(1), Some(FiltFiltPad::default())).unwrap();
+        let y2t = sosfiltfilt(&sos, x2.t(), Some(0), Some(FiltFiltPad::default())).unwrap();
+        assert_eq!(y2, y2t.t());
+        for (r, row) in y2.rows().into_iter().enumerate() {
+            for (a, e) in row.iter().zip(&y) {
+                assert_relative_eq!(*a, (r as f64 + 1.) * e, max_relative = 1e-9, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sosfiltfilt_invalid_args() {
+        use ndarray::Array1;
+
+        // A section with unit gain at DC.
+        let sos = [Sos::new([0.25, 0.5, 0.25], [1., -0.5, 0.5])];
+        let x = Array1::from_elem(9, 1.);
+        // The default padding of 3 * 3 samples is too long for the signal.
+        assert!(sosfiltfilt(&sos, x.view(), None, Some(FiltFiltPad::default())).is_err());
+        assert!(sosfiltfilt::<f64, _, _>(&[], x.view(), None, None).is_err());
+        assert!(sosfiltfilt(&sos, x.view(), Some(1), None).is_err());
+
+        // Without padding, the initial conditions preserve a constant.
+        let y = sosfiltfilt(&sos, x.view(), None, None).unwrap();
+        y.iter()
+            .for_each(|&yi| approx::assert_relative_eq!(yi, 1., epsilon = 1e-12));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn default_padlen_matches_scipy() {
+        use crate::signal::filter::design::{
+            iirfilter_dyn, DigitalFilter, FilterBandType, FilterOutputType, FilterType,
+        };
+        use alloc::vec;
+        use ndarray::Array1;
+
+        // The default padlen of sosfiltfilt, as computed by scipy for butter(order, 0.2).
+        for (order, padlen) in [(4, 15), (5, 18), (1, 6)] {
+            let DigitalFilter::Sos(sos) = iirfilter_dyn::<f64>(
+                order,
+                vec![0.2],
+                None,
+                None,
+                Some(FilterBandType::Lowpass),
+                Some(FilterType::Butterworth),
+                Some(false),
+                Some(FilterOutputType::Sos),
+                None,
+            ) else {
+                panic!("Expected a filter in SOS format.");
+            };
+            assert_eq!(default_padlen_sos(&sos.sos), padlen);
+
+            // Signals must be longer than the default padlen.
+            let pad = Some(FiltFiltPad::default());
+            let x = Array1::from_elem(padlen, 1.);
+            assert!(sosfiltfilt(&sos.sos, x.view(), None, pad).is_err());
+            let x = Array1::from_elem(padlen + 1, 1.);
+            assert!(sosfiltfilt(&sos.sos, x.view(), None, pad).is_ok());
+        }
+    }
 }
@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+use ndarray::ArrayView1;
+use num_traits::{Num, Zero};
+use sci_rs_core::num_rs::{pad, PadMode};
+
+/// Upsample, FIR filter, and downsample.
+///
+/// The input signal `x` is upsampled by inserting `up - 1` zeros after each sample, filtered
+/// with the FIR filter `h`, and downsampled by keeping every `down`-th sample. This is
+/// `scipy.signal.upfirdn` with its default zero boundary, and the core of polyphase resampling.
+/// [upfirdn_mode] extends `x` in other ways.
+///
+/// Only the products with the samples of `x` are computed, rather than those with the inserted
+/// zeros or of the discarded outputs, so the cost is that of the output alone.
+///
+/// # Parameters
+/// * `h` : array_like
+///   FIR (finite-impulse response) filter coefficients.
+/// * `x` : array_like
+///   Input signal array.
+/// * `up` : int
+///   Upsampling rate.
+/// * `down` : int
+///   Downsampling rate.
+///
+/// # Returns
+/// * `y` : ndarray
+///   The output signal, of length `ceil(((x.len() - 1) * up + h.len()) / down)`, or empty if `x`
+///   is.
+///
+/// # Panics
+/// If `h` is empty, or `up` or `down` is zero.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::upfirdn;
+///
+/// // Upsampling with zero insertion, and with sample repetition.
+/// assert_eq!(upfirdn(&[1], &[1, 2, 3], 3, 1), vec![1, 0, 0, 2, 0, 0, 3]);
+/// assert_eq!(upfirdn(&[1, 1, 1], &[1, 2, 3], 3, 1), vec![1, 1, 1, 2, 2, 2, 3, 3, 3]);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.upfirdn.html>
+pub fn upfirdn<T>(h: &[T], x: &[T], up: usize, down: usize) -> Vec<T>
+where
+    T: Copy + Zero + core::ops::Mul<Output = T>,
+{
+    assert!(!h.is_empty(), "h must have non-zero length");
+    assert!(up > 0 && down > 0, "up and down must be positive");
+    if x.is_empty() {
+        return Vec::new();
+    }
+
+    let n_out = ((x.len() - 1) * up + h.len()).div_ceil(down);
+    upfirdn_from(h, x, up, down, 0, n_out)
+}
+
+/// Upsample, FIR filter, and downsample, extending `x` beyond its ends as per `mode`.
+///
+/// This is `scipy.signal.upfirdn` with its `mode` and `cval` arguments. As [upfirdn], but the
+/// samples beyond either end of `x`, which the filter overhangs at the start and end of the
+/// output, are those of [pad] rather than zeros. scipy's `constant`, `edge`, `reflect`,
+/// `symmetric`, `wrap` and `antireflect` modes are [PadMode::Constant], [PadMode::Edge],
+/// [PadMode::Reflect], [PadMode::Symmetric], [PadMode::Wrap] and [PadMode::Odd].
+///
+/// # Parameters
+/// * `h` : array_like
+///   FIR (finite-impulse response) filter coefficients.
+/// * `x` : array_like
+///   Input signal array.
+/// * `up` : int
+///   Upsampling rate.
+/// * `down` : int
+///   Downsampling rate.
+/// * `mode` : [PadMode]
+///   How `x` is extended beyond its ends.
+/// * `cval` : scalar
+///   The value of the extension with [PadMode::Constant], and the end value of
+///   [PadMode::LinearRamp].
+///
+/// # Returns
+/// * `y` : ndarray
+///   The output signal, of the same length as that of [upfirdn].
+///
+/// # Panics
+/// If `h` is empty, or `up` or `down` is zero.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::filter::{upfirdn, upfirdn_mode};
+/// use sci_rs_core::num_rs::PadMode;
+///
+/// // Zeros roll off the ends of a moving average, whereas repeating the edges does not.
+/// let x = [1., 1., 1., 1.];
+/// let h = [0.5, 0.5];
+/// assert_eq!(upfirdn(&h, &x, 1, 1), vec![0.5, 1., 1., 1., 0.5]);
+/// assert_eq!(upfirdn_mode(&h, &x, 1, 1, PadMode::Edge, 0.), vec![1.; 5]);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.upfirdn.html>
+pub fn upfirdn_mode<T>(h: &[T], x: &[T], up: usize, down: usize, mode: PadMode, cval: T) -> Vec<T>
+where
+    T: Copy + Num,
+{
+    if mode == PadMode::Constant && cval.is_zero() {
+        return upfirdn(h, x, up, down);
+    }
+    assert!(!h.is_empty(), "h must have non-zero length");
+    assert!(up > 0 && down > 0, "up and down must be positive");
+    if x.is_empty() {
+        return Vec::new();
+    }
+
+    // The filter overhangs either end of `x` by at most this many samples
+    let width = h.len().div_ceil(up) - 1;
+    let padded = pad(&ArrayView1::from(x), &[(width, width)], mode, cval).expect("x is not empty");
+    let n_out = ((x.len() - 1) * up + h.len()).div_ceil(down);
+    upfirdn_from(h, padded.as_slice().unwrap(), up, down, width * up, n_out)
+}
+
+/// The first `n_out` samples of [upfirdn] of `x`, starting from the sample at the upsampled index
+/// `start`.
+fn upfirdn_from<T>(h: &[T], x: &[T], up: usize, down: usize, start: usize, n_out: usize) -> Vec<T>
+where
+    T: Copy + Zero + core::ops::Mul<Output = T>,
+{
+    (0..n_out)
+        .map(|m| {
+            // The taps aligned with a sample of `x`, rather than an inserted zero, at the
+            // upsampled index `n`
+            let n = start + m * down;
+            (n % up..h.len().min(n + 1))
+                .step_by(up)
+                .map(|k| ((n - k) / up, k))
+                .filter(|&(i, _)| i < x.len())
+                .fold(T::zero(), |acc, (i, k)| acc + h[k] * x[i])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn upfirdn_scipy_examples() {
+        // >>> upfirdn([1, 1, 1], [1, 1, 1])
+        // array([1, 2, 3, 2, 1])
+        assert_eq!(upfirdn(&[1, 1, 1], &[1, 1, 1], 1, 1), vec![1, 2, 3, 2, 1]);
+        // >>> upfirdn([.5, 1, .5], [1, 1, 1], 2)
+        // array([0.5, 1. , 1. , 1. , 1. , 1. , 0.5])
+        assert_eq!(
+            upfirdn(&[0.5, 1., 0.5], &[1., 1., 1.], 2, 1),
+            vec![0.5, 1., 1., 1., 1., 1., 0.5]
+        );
+        // >>> upfirdn([1], np.arange(10), 1, 3)
+        // array([0., 3., 6., 9.])
+        let x: Vec<f64> = (0..10).map(f64::from).collect();
+        assert_eq!(upfirdn(&[1.], &x, 1, 3), vec![0., 3., 6., 9.]);
+        // >>> upfirdn([.5, 1, .5], np.arange(10), 2, 3)
+        // array([0. , 1. , 2.5, 4. , 5.5, 7. , 8.5])
+        let expected = [0., 1., 2.5, 4., 5.5, 7., 8.5];
+        let y = upfirdn(&[0.5, 1., 0.5], &x, 2, 3);
+        assert_eq!(y.len(), expected.len());
+        for (a, b) in y.iter().zip(expected) {
+            assert_relative_eq!(*a, b, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn upfirdn_up_3_down_2() {
+        // >>> upfirdn([1, 2, 3, 4], np.arange(6), 3, 2)
+        // array([ 0,  0,  2,  6,  6,  6, 16, 12, 10, 20])
+        let x: Vec<f64> = (0..6).map(f64::from).collect();
+        let y = upfirdn(&[1., 2., 3., 4.], &x, 3, 2);
+        assert_eq!(y.len(), ((x.len() - 1) * 3 + 4).div_ceil(2));
+        assert_eq!(y, vec![0., 0., 2., 6., 6., 6., 16., 12., 10., 20.]);
+
+        // Downsampling a longer filter, including taps past the end of the signal.
+        let y = upfirdn(&[1., -1., 2., 0.5, 3.], &x[..2], 3, 2);
+        assert_eq!(y, vec![0., 0., -1., 0.5]);
+        assert!(upfirdn(&[1.], &[], 3, 2).is_empty());
+    }
+
+    #[test]
+    fn upfirdn_modes() {
+        // >>> x = [1., 2., 4., 3., .5, -1.]
+        // >>> h = [.5, 1., -.25, 2., .75]
+        // >>> upfirdn(h, x, 2, 3, mode=mode, cval=-2.)
+        let x = [1., 2., 4., 3., 0.5, -1.];
+        let h = [0.5, 1., -0.25, 2., 0.75];
+        for (mode, expected) in [
+            (PadMode::Constant, [-0.5, 4., 2., 6.5, -0.375]),
+            (PadMode::Edge, [1., 4., 2., 6.5, 0.125]),
+            (PadMode::Reflect, [3., 4., 2., 6.5, 0.875]),
+            (PadMode::Symmetric, [1.75, 4., 2., 6.5, 0.125]),
+            (PadMode::Wrap, [1.125, 4., 2., 6.5, 1.125]),
+            (PadMode::Odd, [-1., 4., 2., 6.5, -0.625]),
+        ] {
+            let y = upfirdn_mode(&h, &x, 2, 3, mode, -2.);
+            assert_eq!(y.len(), expected.len());
+            for (a, b) in y.iter().zip(expected) {
+                assert_relative_eq!(*a, b, epsilon = 1e-12);
+            }
+        }
+
+        // Zero constant padding is that of upfirdn.
+        assert_eq!(
+            upfirdn_mode(&h, &x, 2, 3, PadMode::Constant, 0.),
+            upfirdn(&h, &x, 2, 3)
+        );
+    }
+}
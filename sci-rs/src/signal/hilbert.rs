@@ -0,0 +1,187 @@
+use nalgebra::Complex;
+use ndarray::{Array1, ArrayView1};
+use num_traits::{Float, Zero};
+use rustfft::{FftNum, FftPlanner};
+
+/// Compute the analytic signal, using the Hilbert transform.
+///
+/// The transformation is done along the only axis of `x`, with the FFT of length `x.len()`.
+///
+/// # Parameters
+/// * `x` : array_like
+///   Signal data. Must be real.
+///
+/// # Returns
+/// * `xa` : ndarray
+///   Analytic signal of `x`, whose real part is `x` and whose imaginary part is the Hilbert
+///   transform of `x`.
+///
+/// # Notes
+/// The analytic signal `x_a(t)` of signal `x(t)` is:
+/// $$
+/// x_a = F^{-1}(F(x) 2U) = x + i y
+/// $$
+/// where `F` is the Fourier transform, `U` the unit step function, and `y` the Hilbert transform
+/// of `x`. In other words, the negative half of the frequency spectrum is zeroed out, turning
+/// the real-valued signal into a complex signal. The DC component, and the Nyquist component of
+/// an even length signal, are kept as is.
+///
+/// # Examples
+/// The Hilbert transform of a cosine is a sine:
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::hilbert::hilbert;
+///
+/// let x = Array1::from_iter((0..16).map(|n| (core::f64::consts::PI * n as f64 / 4.).cos()));
+/// let xa = hilbert(x.view());
+/// for (n, xi) in xa.iter().enumerate() {
+///     assert!((xi.im - (core::f64::consts::PI * n as f64 / 4.).sin()).abs() < 1e-12);
+/// }
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.hilbert.html>
+pub fn hilbert<F: Float + FftNum>(x: ArrayView1<F>) -> Array1<Complex<F>> {
+    let n = x.len();
+    let mut xa: Vec<Complex<F>> = x.iter().map(|&xi| Complex::new(xi, F::zero())).collect();
+    if n == 0 {
+        return xa.into();
+    }
+
+    let mut planner = FftPlanner::<F>::new();
+    planner.plan_fft_forward(n).process(&mut xa);
+
+    // Double the positive frequencies and zero the negative ones, keeping DC and Nyquist.
+    let two = F::from(2).unwrap();
+    let positive = n.div_ceil(2);
+    xa[1..positive]
+        .iter_mut()
+        .for_each(|xi| *xi = xi.scale(two));
+    xa[n / 2 + 1..]
+        .iter_mut()
+        .for_each(|xi| *xi = Complex::zero());
+
+    planner.plan_fft_inverse(n).process(&mut xa);
+    let scale = F::one() / F::from(n).unwrap();
+    xa.into_iter().map(|xi| xi.scale(scale)).collect()
+}
+
+/// Single-sideband amplitude modulation of a real signal.
+///
+/// The message `x` is modulated onto a carrier of frequency `fc`, keeping only the sideband
+/// above the carrier if `upper`, and only the sideband below it otherwise. This is the
+/// phasing method, with the quadrature component given by the Hilbert transform `x_h` of `x`:
+/// $$
+/// y(t) = x(t) \cos(2 \pi f_c t) \mp x_h(t) \sin(2 \pi f_c t)
+/// $$
+/// or equivalently the real part of the analytic signal of `x` (or its conjugate for the lower
+/// sideband), shifted up in frequency by `fc`.
+///
+/// # Parameters
+/// * `x` : array_like
+///   Message signal, sampled at `fs`.
+/// * `fc` : float
+///   Frequency of the carrier, in the same units as `fs`.
+/// * `fs` : float
+///   Sampling frequency of `x`.
+/// * `upper` : bool
+///   Whether to keep the upper sideband rather than the lower one.
+///
+/// # Returns
+/// * `y` : ndarray
+///   The modulated signal, of the same length as `x`.
+///
+/// # Notes
+/// As the Hilbert transform is computed with an FFT, `x` is treated as periodic. Frequencies
+/// of `x` that are not a whole number of cycles over its length leak into the other sideband,
+/// which can be reduced by windowing `x`. The sideband must also fit below the Nyquist
+/// frequency, `fs / 2`, or it is aliased.
+///
+/// # Examples
+/// The upper sideband of a 50 Hz tone on a 200 Hz carrier is a 250 Hz tone:
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::hilbert::ssb_modulate;
+///
+/// let fs = 1000.;
+/// let tone = |f: f64| {
+///     Array1::from_iter((0..100).map(|n| (2. * core::f64::consts::PI * f * n as f64 / fs).cos()))
+/// };
+/// let y = ssb_modulate(tone(50.).view(), 200., fs, true);
+/// for (yi, ei) in y.iter().zip(tone(250.).iter()) {
+///     assert!((yi - ei).abs() < 1e-12);
+/// }
+/// ```
+pub fn ssb_modulate<F: Float + FftNum>(x: ArrayView1<F>, fc: F, fs: F, upper: bool) -> Array1<F> {
+    let w = F::from(2).unwrap() * F::from(core::f64::consts::PI).unwrap() * fc / fs;
+    hilbert(x)
+        .iter()
+        .enumerate()
+        .map(|(n, xa)| {
+            let xa = if upper { *xa } else { xa.conj() };
+            let carrier = Complex::from_polar(F::one(), w * F::from(n).unwrap());
+            (xa * carrier).re
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn hilbert_of_even_and_odd_lengths() {
+        // The analytic signal of cos(w n) + 0.5 is exp(i w n) + 0.5, when there are a whole
+        // number of cycles in x.
+        for (n, cycles) in [(16, 3.), (15, 2.)] {
+            let w = 2. * PI * cycles / n as f64;
+            let x = Array1::from_iter((0..n).map(|i| (w * i as f64).cos() + 0.5));
+            let xa = hilbert(x.view());
+            assert_eq!(xa.len(), n);
+            for (i, xi) in xa.iter().enumerate() {
+                assert_abs_diff_eq!(xi.re, x[i], epsilon = 1e-12);
+                assert_abs_diff_eq!(xi.im, (w * i as f64).sin(), epsilon = 1e-12);
+            }
+        }
+
+        // The Nyquist frequency has no Hilbert transform, so is kept as is.
+        let x = Array1::from_iter((0..8).map(|i| if i % 2 == 0 { 1f64 } else { -1. }));
+        let xa = hilbert(x.view());
+        for (xi, ei) in xa.iter().zip(&x) {
+            assert_abs_diff_eq!(xi.re, *ei, epsilon = 1e-12);
+            assert_abs_diff_eq!(xi.im, 0., epsilon = 1e-12);
+        }
+
+        assert!(hilbert(ArrayView1::<f64>::from(&[])).is_empty());
+    }
+
+    #[test]
+    fn ssb_keeps_only_selected_sideband() {
+        let (fs, fc, n) = (1000., 200., 1000);
+        // A message of two tones, at 30 and 70 Hz.
+        let x = Array1::from_iter((0..n).map(|i| {
+            let t = i as f64 / fs;
+            (2. * PI * 30. * t).cos() + 0.5 * (2. * PI * 70. * t).sin()
+        }));
+
+        // With a 1 Hz bin width, the magnitude spectrum at each frequency in Hz.
+        let spectrum = |y: &Array1<f64>| {
+            let mut y: Vec<Complex<f64>> = y.iter().map(|&yi| Complex::new(yi, 0.)).collect();
+            FftPlanner::new().plan_fft_forward(n).process(&mut y);
+            y.iter().take(n / 2).map(|yi| yi.norm()).collect::<Vec<_>>()
+        };
+        for (upper, sideband) in [(true, [230, 270]), (false, [170, 130])] {
+            let y = ssb_modulate(x.view(), fc, fs, upper);
+            let amp = spectrum(&y);
+            // Each tone of amplitude A shows as a peak of A * n / 2.
+            assert_abs_diff_eq!(amp[sideband[0]], 500., epsilon = 1e-8);
+            assert_abs_diff_eq!(amp[sideband[1]], 250., epsilon = 1e-8);
+            for (f, a) in amp.iter().enumerate() {
+                if !sideband.contains(&f) {
+                    assert_abs_diff_eq!(*a, 0., epsilon = 1e-8);
+                }
+            }
+        }
+    }
+}
@@ -25,8 +25,23 @@ pub mod convolve;
 pub mod windows;
 
 /// Signal Resampling  
-/// This contains only the
+/// This contains the FFT-based
 /// [`resample`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.resample.html#scipy.signal.resample)
-/// function from `scipy.signal`.
+/// and the polyphase
+/// [`resample_poly`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.resample_poly.html)
+/// functions from `scipy.signal`.
 #[cfg(feature = "std")]
 pub mod resample;
+
+/// Spectral Analysis  
+/// Contains functions from the [Spectral Analysis section of
+/// `scipy.signal`](<https://docs.scipy.org/doc/scipy/reference/signal.html#spectral-analysis>).
+#[cfg(feature = "std")]
+pub mod spectral;
+
+/// Analytic Signals  
+/// Contains the
+/// [`hilbert`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.hilbert.html)
+/// function from `scipy.signal`, and single-sideband modulation built on it.
+#[cfg(feature = "std")]
+pub mod hilbert;
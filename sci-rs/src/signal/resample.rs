@@ -1,6 +1,13 @@
-use nalgebra::Complex;
-use num_traits::{Float, Zero};
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+use crate::signal::filter::design::{firwin_dyn, FilterBandType};
+use crate::signal::filter::upfirdn;
+use crate::signal::windows::{get_window, GetWindow, GetWindowBuilder, WindowCache};
+use crate::special::Bessel;
+use nalgebra::{Complex, RealField};
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension};
+use num_traits::{Float, MulAdd, Pow, Zero};
 use rustfft::FftNum;
+use sci_rs_core::{Error, Result};
 
 ///
 /// Resample the data to the desired number of samples using the Fourier transform.
@@ -8,55 +15,481 @@ use rustfft::FftNum;
 /// This method is similar but not exactly equivalent to the SciPy method of resampling:
 /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.resample.html>
 ///
-/// It skips some complexity of the SciPy method, such as windowing and handling odd vs. even-length signals.
+/// Windowing is done by [resample_window].
 ///
 /// Procedure:
 /// 1. Convert to the frequency-domain.
-///   a. If upsampling, pad higher frequency bins with 0
-///   b. If downsampling, truncate higher frequency bins
-/// 2. Convert back to the time-domain.
+///    a. If upsampling, pad higher frequency bins with 0
+///    b. If downsampling, truncate higher frequency bins
+///    c. For an even number of kept bins, split (upsampling) or join (downsampling) the Nyquist bin
+/// 2. Convert back to the time-domain, scaling by `n / x.len()`.
 ///
 pub fn resample<F: Float + FftNum>(x: &[F], n: usize) -> Vec<F> {
+    resample_tapered(x, n, None)
+}
+
+///
+/// Resample the data to the desired number of samples using the Fourier transform, tapering its
+/// spectrum with a window.
+///
+/// This is [resample] with scipy's `window` argument. The window is built with `x.len()` points
+/// as by `get_window(window, x.len())`, and multiplies the spectrum of `x` with its peak at DC and
+/// its ends at the Nyquist frequency. As the FFT places DC in the first bin rather than in the
+/// middle, the spectrum is `fftshift`ed before the multiply and `ifftshift`ed after.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::resample::resample_window;
+/// use sci_rs::signal::windows::GetWindowBuilder;
+///
+/// // A constant is unaffected, as the window is 1 at DC.
+/// let y = resample_window(&[2f64; 8], 16, GetWindowBuilder::Hamming);
+/// assert!(y.iter().all(|yi| (yi - 2.).abs() < 1e-12));
+/// ```
+///
+pub fn resample_window<F>(x: &[F], n: usize, window: GetWindowBuilder<'_, F>) -> Vec<F>
+where
+    F: Float + FftNum + RealField + Bessel,
+{
+    let taper = get_window(window, x.len(), None).get_window();
+    resample_tapered(x, n, Some(&taper))
+}
+
+///
+/// [resample_window], looking up the window in `cache` rather than computing it on every call.
+///
+/// This is useful when resampling many signals of the same length with the same window. See
+/// [WindowCache].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::resample::{resample_window, resample_window_with_cache};
+/// use sci_rs::signal::windows::{GetWindowBuilder, WindowCache};
+///
+/// let cache = WindowCache::new();
+/// let x = [1f64, 3., 2., 0., -1., 2., 4., 1.];
+/// let y = resample_window_with_cache(&x, 12, GetWindowBuilder::Hamming, &cache);
+/// assert_eq!(y, resample_window(&x, 12, GetWindowBuilder::Hamming));
+/// assert_eq!(cache.len(), 1);
+/// ```
+///
+pub fn resample_window_with_cache<F>(
+    x: &[F],
+    n: usize,
+    window: GetWindowBuilder<'_, F>,
+    cache: &WindowCache<F>,
+) -> Vec<F>
+where
+    F: Float + FftNum + RealField + Bessel,
+{
+    let taper = cache.get_window(&get_window(window, x.len(), None));
+    resample_tapered(x, n, Some(&taper))
+}
+
+/// [resample], multiplying the centered spectrum of `x` by `taper` if given.
+fn resample_tapered<F: Float + FftNum>(x: &[F], n: usize, taper: Option<&[F]>) -> Vec<F> {
     // SciPy style 'Fourier' resampling
     // 1. Compute FFT of x
     // 2. Fill vec of zeros with the desired length, y.
     // 3. Set the from beginning of y to the first half of x
     // 4. Set the from end of y to the second half of x
-    // 5. Compute IFFT of y
-    // 6. Multiply y by (n / x.len())
-    // 7. Take the real part of y
-
-    // Compute FFT of x
-    let mut fft_planner = rustfft::FftPlanner::<F>::new();
-    let fft = fft_planner.plan_fft_forward(x.len());
-    let ifft = fft_planner.plan_fft_inverse(n);
-
-    let scratch_length = std::cmp::max(
-        fft.get_inplace_scratch_len(),
-        ifft.get_inplace_scratch_len(),
-    );
-    let mut scratch = vec![Complex::zero(); scratch_length];
-    let mut x = x
-        .into_iter()
+    // 5. Split or join the Nyquist bin of an even number of bins
+    // 6. Compute IFFT of y
+    // 7. Multiply y by (n / x.len())
+    // 8. Take the real part of y
+    let x = x
+        .iter()
         .map(|x| Complex::new(*x, F::zero()))
         .collect::<Vec<_>>();
-    fft.process_with_scratch(&mut x, &mut scratch);
+    let y = resample_spectrum(fft(x), n, taper);
 
-    // Fill y with halfs of x
+    // Take the scaled real domain as the resampled result
+    y.iter().map(|y| y.re).collect()
+}
+
+/// The domain of the input to [resample_with_domain], `domain` in scipy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResampleDomain {
+    /// The input is a signal in the time domain.
+    Time,
+    /// The input is the FFT of a signal, such as from a prior computation, which is resampled
+    /// without a redundant forward transform.
+    Freq,
+}
+
+///
+/// Resample complex data to the desired number of samples using the Fourier transform, with
+/// scipy's `domain` argument.
+///
+/// As [resample], but for complex data, which is either a signal in the time domain or its FFT
+/// as given by `domain`. When `x` is the FFT of a real signal, the real part of the output is the
+/// output of [resample] for that signal.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The data to be resampled, or its FFT if `domain` is [ResampleDomain::Freq].
+/// * `num` : int
+///   The number of samples in the resampled signal.
+/// * `domain` : [ResampleDomain]
+///   Whether `x` is in the time or frequency domain.
+///
+/// # Returns
+/// * `resampled_x` : ndarray
+///   The resampled signal, in the time domain.
+///
+/// # Examples
+/// ```
+/// use nalgebra::Complex;
+/// use sci_rs::signal::resample::{resample, resample_with_domain, ResampleDomain};
+///
+/// let x = [1f64, 0., -1., 0.];
+/// // The FFT of x.
+/// let spectrum = [0., 2., 0., 2.].map(|re| Complex::new(re, 0.));
+/// let y = resample_with_domain(&spectrum, 8, ResampleDomain::Freq);
+/// for (yi, ei) in y.iter().zip(resample(&x, 8)) {
+///     assert!((yi.re - ei).abs() < 1e-12);
+/// }
+/// ```
+///
+pub fn resample_with_domain<F: Float + FftNum>(
+    x: &[Complex<F>],
+    num: usize,
+    domain: ResampleDomain,
+) -> Vec<Complex<F>> {
+    let x = x.to_vec();
+    let x = match domain {
+        ResampleDomain::Time => fft(x),
+        ResampleDomain::Freq => x,
+    };
+    resample_spectrum(x, num, None)
+}
+
+/// The forward FFT of `x`.
+fn fft<F: Float + FftNum>(mut x: Vec<Complex<F>>) -> Vec<Complex<F>> {
+    let mut fft_planner = rustfft::FftPlanner::<F>::new();
+    fft_planner.plan_fft_forward(x.len()).process(&mut x);
+    x
+}
+
+/// Resample the signal of spectrum `x` to `n` samples in the time domain, multiplying the
+/// centered spectrum by `taper` if given.
+fn resample_spectrum<F: Float + FftNum>(
+    mut x: Vec<Complex<F>>,
+    n: usize,
+    taper: Option<&[F]>,
+) -> Vec<Complex<F>> {
+    if let Some(taper) = taper {
+        // fftshift to line up DC with the peak of the window, then ifftshift back.
+        let mid = x.len() / 2;
+        x.rotate_right(mid);
+        x.iter_mut()
+            .zip(taper)
+            .for_each(|(xi, wi)| *xi = xi.scale(*wi));
+        x.rotate_left(mid);
+    }
+
+    // Keep the lowest frequencies common to both lengths, with the positive half including the
+    // Nyquist bin of an even count.
+    let nx = x.len();
+    let bins = nx.min(n);
     let mut y = vec![Complex::zero(); n];
-    let bins = std::cmp::min(x.len(), n);
-    let half_spectrum = bins / 2;
-    y[..half_spectrum].copy_from_slice(&x[..half_spectrum]);
-    y[n - half_spectrum..].copy_from_slice(&x[x.len() - half_spectrum..]);
+    let positive = (bins / 2 + 1).min(bins);
+    let negative = bins - positive;
+    y[..positive].copy_from_slice(&x[..positive]);
+    y[n - negative..].copy_from_slice(&x[nx - negative..]);
+
+    // The Nyquist bin of an even count stands for both +fs/2 and -fs/2, so it is joined with
+    // its alias when downsampling, and split in half between them when upsampling.
+    if bins > 0 && bins.is_multiple_of(2) {
+        let half = bins / 2;
+        if n < nx {
+            y[n - half] = y[n - half] + x[nx - half];
+        } else if nx < n {
+            y[half] = y[half].scale(F::from(0.5).unwrap());
+            y[n - half] = y[half];
+        }
+    }
 
     // Compute iFFT of y
-    ifft.process_with_scratch(&mut y, &mut scratch);
+    let mut fft_planner = rustfft::FftPlanner::<F>::new();
+    fft_planner.plan_fft_inverse(n).process(&mut y);
 
-    // Take the scaled real domain as the resampled result
     let scale_factor = F::from(1.0 / x.len() as f64).unwrap();
-    let y = y.iter().map(|x| x.re * scale_factor).collect::<Vec<_>>();
+    y.iter().map(|y| y.scale(scale_factor)).collect()
+}
+
+///
+/// Resample the data to the desired number of samples, along with its sample positions.
+///
+/// This is [resample] with scipy's `t` argument: the positions of the samples of `x`, which must
+/// be equally spaced as FFT resampling assumes uniform sampling. The positions of the resampled
+/// data are returned alongside it.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The data to be resampled.
+/// * `num` : int
+///   The number of samples in the resampled signal.
+/// * `t` : array_like
+///   The equally spaced sample positions associated with the signal data in `x`.
+///
+/// # Returns
+/// * `resampled_x` : ndarray
+///   The resampled array.
+/// * `resampled_t` : ndarray
+///   The positions of the resampled samples, `t[0] + arange(num) * (t[1] - t[0]) * x.len() / num`.
+///   These span the same period as `t` including the step after its last sample, so unlike
+///   `linspace(t[0], t[-1], num)` the last position is short of `t[-1]` by one new step.
+///
+/// # Errors
+/// [Error::InvalidArg] if `t` is not of the same length as `x`, or is not uniformly spaced.
+///
+pub fn resample_with_t<F: Float + FftNum>(
+    x: &[F],
+    num: usize,
+    t: &[F],
+) -> Result<(Vec<F>, Vec<F>)> {
+    if t.len() != x.len() {
+        return Err(Error::InvalidArg {
+            arg: "t".into(),
+            reason: "t must have the same length as x.".into(),
+        });
+    }
+
+    let dt = if t.len() > 1 { t[1] - t[0] } else { F::zero() };
+    let tol = Float::sqrt(F::epsilon()) * Float::abs(dt);
+    if t.windows(2).any(|w| Float::abs(w[1] - w[0] - dt) > tol) {
+        return Err(Error::InvalidArg {
+            arg: "t".into(),
+            reason: "t must be uniformly spaced for FFT resampling.".into(),
+        });
+    }
+
+    // Evaluated in the same order as scipy so the positions agree to the last bit
+    let y = resample(x, num);
+    let (nx, num_f) = (F::from(x.len()).unwrap(), F::from(num).unwrap());
+    let new_t = (0..num)
+        .map(|i| F::from(i).unwrap() * dt * nx / num_f + t[0])
+        .collect();
+    Ok((y, new_t))
+}
+
+/// Resample `x` by the rational factor `up / down` using polyphase filtering.
+///
+/// This is `scipy.signal.resample_poly` along a single axis with its default zero padding. The
+/// signal is upsampled by `up`, filtered with a zero-phase low-pass FIR, and downsampled by
+/// `down` with [upfirdn], computing only the samples kept. `up` and `down` are first reduced by
+/// their greatest common divisor.
+///
+/// The anti-aliasing filter is `firwin(2 * half_len + 1, 1 / max(up, down), window) * up` with
+/// `half_len = 10 * max(up, down)`, and is delayed by zeros in front so that the output samples
+/// are centred on the filter: sample `i * down` of the output lines up with sample `i * up` of
+/// the input, as in scipy.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The data to be resampled.
+/// * `up` : int
+///   The upsampling factor.
+/// * `down` : int
+///   The downsampling factor.
+/// * `window` : [GetWindowBuilder], optional
+///   The window used to design the anti-aliasing filter, sampled symmetrically. Defaults to
+///   `Kaiser { beta: 5.0 }`.
+///
+/// # Returns
+/// * `resampled_x` : ndarray
+///   The resampled array, of length `ceil(x.len() * up / down)`.
+///
+/// # Panics
+/// If `up` or `down` is zero.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::resample::resample_poly;
+///
+/// let x: Vec<f64> = (0..20).map(|i| (0.1 * core::f64::consts::PI * i as f64).sin()).collect();
+/// let y = resample_poly(&x, 3, 2, None);
+/// assert_eq!(y.len(), 30);
+/// // Every third output sample falls on an input sample.
+/// assert!((y[6] - x[4]).abs() < 1e-2);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.resample_poly.html>
+pub fn resample_poly<T>(
+    x: &[T],
+    up: usize,
+    down: usize,
+    window: Option<GetWindowBuilder<'_, T>>,
+) -> Vec<T>
+where
+    T: Float + FftNum + RealField + Bessel + MulAdd<Output = T> + Pow<T, Output = T>,
+{
+    PolyphaseFilter::new(x.len(), up, down, window).apply(x)
+}
+
+/// Resample `x` along `axis` by the rational factor `up / down` using polyphase filtering.
+///
+/// This is [resample_poly] with scipy's `axis` argument, resampling every lane of a
+/// multichannel array with the same anti-aliasing filter.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The data to be resampled.
+/// * `up` : int
+///   The upsampling factor.
+/// * `down` : int
+///   The downsampling factor.
+/// * `window` : [GetWindowBuilder], optional
+///   The window used to design the anti-aliasing filter, as for [resample_poly].
+/// * `axis` : int, optional
+///   The axis of `x` that is resampled. Defaults to 0, as in scipy.
+///
+/// # Returns
+/// * `resampled_x` : ndarray
+///   The resampled array, whose length along `axis` is `ceil(n * up / down)` for the input
+///   length `n` along it.
+///
+/// # Errors
+/// [Error::InvalidArg] if `axis` is out of range.
+///
+/// # Panics
+/// If `up` or `down` is zero.
+///
+/// # Examples
+/// ```
+/// use ndarray::{array, Axis};
+/// use sci_rs::signal::resample::{resample_poly, resample_poly_axis};
+///
+/// let x = array![[0f64, 1., 0., -1., 0., 1.], [1., 1., 1., 1., 1., 1.]];
+/// let y = resample_poly_axis(&x, 2, 3, None, Some(1)).unwrap();
+/// assert_eq!(y.shape(), &[2, 4]);
+/// for (yi, xi) in y.axis_iter(Axis(0)).zip(x.axis_iter(Axis(0))) {
+///     assert_eq!(yi.to_vec(), resample_poly(&xi.to_vec(), 2, 3, None));
+/// }
+/// ```
+pub fn resample_poly_axis<T, S, D>(
+    x: &ArrayBase<S, D>,
+    up: usize,
+    down: usize,
+    window: Option<GetWindowBuilder<'_, T>>,
+    axis: Option<isize>,
+) -> Result<Array<T, D>>
+where
+    T: Float + FftNum + RealField + Bessel + MulAdd<Output = T> + Pow<T, Output = T>,
+    S: Data<Elem = T>,
+    D: Dimension,
+{
+    let axis = Axis(check_and_get_axis_dyn(Some(axis.unwrap_or(0)), x)?);
+    let filter = PolyphaseFilter::new(x.len_of(axis), up, down, window);
 
-    y
+    let mut shape = x.raw_dim();
+    shape[axis.index()] = filter.n_out;
+    let mut y = Array::zeros(shape);
+    y.lanes_mut(axis)
+        .into_iter()
+        .zip(x.lanes(axis))
+        .for_each(|(mut y, x)| {
+            let resampled = filter.apply(&x.to_vec());
+            y.iter_mut().zip(resampled).for_each(|(yi, ri)| *yi = ri);
+        });
+    Ok(y)
+}
+
+/// The anti-aliasing filter of [resample_poly] for inputs of a given length, zero-padded so
+/// that the output samples fall at its centre.
+struct PolyphaseFilter<T> {
+    /// The padded filter, empty when `up` and `down` reduce to one.
+    h: Vec<T>,
+    /// The reduced upsampling factor.
+    up: usize,
+    /// The reduced downsampling factor.
+    down: usize,
+    /// The number of leading outputs of [upfirdn] that are discarded.
+    n_pre_remove: usize,
+    /// The number of outputs kept.
+    n_out: usize,
+}
+
+impl<T> PolyphaseFilter<T>
+where
+    T: Float + FftNum + RealField + Bessel + MulAdd<Output = T> + Pow<T, Output = T>,
+{
+    fn new(n_in: usize, up: usize, down: usize, window: Option<GetWindowBuilder<'_, T>>) -> Self {
+        assert!(up > 0 && down > 0, "up and down must be positive");
+        let g = gcd(up, down);
+        let (up, down) = (up / g, down / g);
+        let n_out = (n_in * up).div_ceil(down);
+        if up == 1 && down == 1 {
+            return PolyphaseFilter {
+                h: Vec::new(),
+                up,
+                down,
+                n_pre_remove: 0,
+                n_out,
+            };
+        }
+
+        let max_rate = up.max(down);
+        let half_len = 10 * max_rate;
+        let numtaps = 2 * half_len + 1;
+        let window = window.unwrap_or(GetWindowBuilder::Kaiser {
+            beta: T::from(5.).unwrap(),
+        });
+        let cutoff = T::one() / T::from(max_rate).unwrap();
+        let h: Vec<T> = firwin_dyn(
+            numtaps,
+            &[cutoff],
+            None,
+            Some(&get_window(window, numtaps, Some(false))),
+            &FilterBandType::Lowpass,
+            None,
+            None,
+        )
+        .expect("cutoff is within (0, 1)");
+
+        // Zero-pad the filter so that the output samples fall at its centre
+        let n_pre_pad = down - half_len % down;
+        let n_pre_remove = (half_len + n_pre_pad) / down;
+        let output_len = |len_h: usize| ((n_in.max(1) - 1) * up + len_h - 1) / down + 1;
+        let mut n_post_pad = 0;
+        while output_len(numtaps + n_pre_pad + n_post_pad) < n_out + n_pre_remove {
+            n_post_pad += 1;
+        }
+        let up_t = T::from(up).unwrap();
+        let h: Vec<T> = core::iter::repeat_n(T::zero(), n_pre_pad)
+            .chain(h.into_iter().map(|h| h * up_t))
+            .chain(core::iter::repeat_n(T::zero(), n_post_pad))
+            .collect();
+
+        PolyphaseFilter {
+            h,
+            up,
+            down,
+            n_pre_remove,
+            n_out,
+        }
+    }
+
+    /// Resample `x`, which must be of the length the filter was made for.
+    fn apply(&self, x: &[T]) -> Vec<T> {
+        if self.h.is_empty() {
+            return x.to_vec();
+        }
+        let y = upfirdn(&self.h, x, self.up, self.down);
+        y.into_iter()
+            .skip(self.n_pre_remove)
+            .take(self.n_out)
+            .collect()
+    }
+}
+
+/// The greatest common divisor of `a` and `b`.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
 }
 
 #[cfg(test)]
@@ -67,17 +500,57 @@ mod tests {
     use super::*;
 
     #[test]
-    #[should_panic]
     fn can_resample_like_scipy() {
         let x = vec![1., 2., 3., 4., 5., 6., 7., 8., 9.];
         let y = resample(&x, 5);
-        let expected = vec![3., 2.18649851, 5.01849831, 5.98150169, 8.81350149];
+        let expected = [3., 2.18649851, 5.01849831, 5.98150169, 8.81350149];
         assert_eq!(y.len(), expected.len());
 
-        // Fails due to slight algorithmic differences
-        println!("y: {:?}, scipy: {:?}", y, expected);
         for (y, expected) in y.iter().zip(expected.iter()) {
-            assert_relative_eq!(y, expected, epsilon = 1e-10);
+            assert_relative_eq!(y, expected, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn resample_sine_splits_nyquist_like_scipy() {
+        // >>> x = np.sin(0.7 * np.arange(10))
+        let x: Vec<f64> = (0..10).map(|i| (0.7 * i as f64).sin()).collect();
+
+        // >>> signal.resample(x, 15)
+        let expected = [
+            2.886579864025407e-16,
+            0.377152513016021,
+            0.8496472987009552,
+            0.9854497299884599,
+            0.9363302296658722,
+            0.7351212438631655,
+            0.3349881501559044,
+            -0.12311633212493947,
+            -0.5661957927019566,
+            -0.8715757724135876,
+            -0.9739279171394302,
+            -0.9501665025995101,
+            -0.6312666378723211,
+            -0.11878579041019026,
+            0.029246455744685696,
+        ];
+        let y = resample(&x, 15);
+        assert_eq!(y.len(), expected.len());
+        for (a, e) in y.iter().zip(expected) {
+            assert_relative_eq!(*a, e, epsilon = 1e-12);
+        }
+
+        // >>> signal.resample(x, 4)
+        let expected = [
+            0.19357526833369149,
+            0.9755190418593344,
+            -0.39281445382955554,
+            -0.7728396227973032,
+        ];
+        let y = resample(&x, 4);
+        assert_eq!(y.len(), expected.len());
+        for (a, e) in y.iter().zip(expected) {
+            assert_relative_eq!(*a, e, epsilon = 1e-12);
         }
     }
 
@@ -103,4 +576,191 @@ mod tests {
             assert_eq!(y.len(), target_len);
         }
     }
+
+    #[test]
+    fn resample_with_t_checks_spacing() {
+        let x: Vec<f64> = (0..8).map(|i| (i as f64).sin()).collect();
+
+        // >>> x = np.sin(np.arange(8))
+        // >>> t = 0.5 + 0.25 * np.arange(8)
+        // >>> resample(x, 4, t=t)[1]
+        // array([0.5, 1. , 1.5, 2. ])
+        let t: Vec<f64> = (0..8).map(|i| 0.5 + 0.25 * i as f64).collect();
+        let (y, new_t) = resample_with_t(&x, 4, &t).unwrap();
+        assert_eq!(y, resample(&x, 4));
+        for (a, e) in new_t.iter().zip([0.5, 1., 1.5, 2.]) {
+            assert_relative_eq!(*a, e, epsilon = 1e-12);
+        }
+
+        let mut t = t;
+        t[5] += 0.01;
+        assert!(matches!(
+            resample_with_t(&x, 4, &t),
+            Err(Error::InvalidArg { .. })
+        ));
+        assert!(resample_with_t(&x, 4, &t[..7]).is_err());
+    }
+
+    #[test]
+    fn resample_with_t_matches_scipy_spacing() {
+        // >>> t = 0.1 + 0.1 * np.arange(7)
+        // >>> resample(np.ones(7), 5, t=t)[1]
+        // array([0.1 , 0.24, 0.38, 0.52, 0.66])
+        let t: Vec<f64> = (0..7).map(|i| 0.1 + 0.1 * i as f64).collect();
+        let (_, new_t) = resample_with_t(&[1.; 7], 5, &t).unwrap();
+        // Exactly, as scipy gives 0.5200000000000001 for the fourth position.
+        assert_eq!(
+            new_t,
+            [0.1, 0.24000000000000002, 0.38, 0.5200000000000001, 0.66]
+        );
+        // The step is a fifth of the 7 * 0.1 period, not of t[6] - t[0] as with linspace.
+        assert!(new_t[4] < t[6]);
+    }
+
+    #[test]
+    fn resample_window_matches_scipy() {
+        // >>> x = [1., -2., 3.5, 0.25, -1., 2., 0.5, -0.75]
+        // >>> resample(x, 16, window='hamming')
+        let x = [1., -2., 3.5, 0.25, -1., 2., 0.5, -0.75];
+        let expected = [
+            -0.0925,
+            -0.2710277986,
+            -0.045,
+            0.7462267927,
+            1.4875,
+            1.4540782977,
+            0.71,
+            0.0198307445,
+            -0.0225,
+            0.4778118904,
+            0.965,
+            0.9879079464,
+            0.5575,
+            0.0891376105,
+            -0.06,
+            -0.0039654836,
+        ];
+        let y = resample_window(&x, 16, GetWindowBuilder::Hamming);
+        assert_eq!(y.len(), expected.len());
+        for (y, e) in y.iter().zip(expected) {
+            assert_relative_eq!(*y, e, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn resample_window_tapers_symmetrically() {
+        // The periodic Hamming window, ifftshifted so that its peak is at DC.
+        let nx = 16;
+        let taper = |k: usize| {
+            let n = (k + nx / 2) % nx;
+            0.54 - 0.46 * (2. * core::f64::consts::PI * n as f64 / nx as f64).cos()
+        };
+
+        for k in 0..=nx / 2 {
+            let tone = |n: usize| (2. * core::f64::consts::PI * (k * n) as f64 / nx as f64).cos();
+            let x: Vec<f64> = (0..nx).map(tone).collect();
+            let y = resample_window(&x, nx, GetWindowBuilder::Hamming);
+
+            // The tone at +k and -k is attenuated by the same gain, keeping it a real cosine.
+            assert_relative_eq!(taper(k), taper(nx - k), epsilon = 1e-12);
+            for (n, yi) in y.iter().enumerate() {
+                assert_relative_eq!(*yi, taper(k) * tone(n), epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn resample_with_domain_skips_forward_fft() {
+        let x = [1., -2., 3.5, 0.25, -1., 2., 0.5, -0.75];
+        let xc: Vec<Complex<f64>> = x.iter().map(|&xi| Complex::new(xi, 0.)).collect();
+        let spectrum = fft(xc.clone());
+
+        for num in [5, 8, 16] {
+            let expected = resample(&x, num);
+            let from_time = resample_with_domain(&xc, num, ResampleDomain::Time);
+            let from_freq = resample_with_domain(&spectrum, num, ResampleDomain::Freq);
+            assert_eq!(from_freq.len(), num);
+            for ((t, f), e) in from_time.iter().zip(&from_freq).zip(expected) {
+                assert_relative_eq!(t.re, f.re, epsilon = 1e-12);
+                assert_relative_eq!(t.im, f.im, epsilon = 1e-12);
+                assert_relative_eq!(f.re, e, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn resample_poly_sine_matches_scipy() {
+        // >>> x = np.sin(2 * np.pi * 0.05 * np.arange(20))
+        // >>> signal.resample_poly(x, 3, 2)
+        let x: Vec<f64> = (0..20)
+            .map(|n| (2. * core::f64::consts::PI * 0.05 * n as f64).sin())
+            .collect();
+        let expected = [
+            3.218922419614494e-18,
+            0.1870184151620462,
+            0.4202485662338842,
+            0.5881415521677131,
+            0.7375599227801222,
+            0.871681041071238,
+            0.9516330216034792,
+            0.9930136773468824,
+            0.997441806510063,
+            0.9516330216034793,
+            0.8660121281886566,
+            0.7445032020911515,
+            0.5881415521677132,
+            0.4069169701124308,
+            0.20819299170610098,
+            1.225389147649714e-16,
+            -0.2081929917061007,
+            -0.4069169701124305,
+            -0.588141552167713,
+            -0.7445032020911516,
+            -0.8660121281886566,
+            -0.9516330216034792,
+            -0.9974418065100629,
+            -0.9930136773468825,
+            -0.9516330216034793,
+            -0.871681041071238,
+            -0.7375599227801221,
+            -0.5881415521677134,
+            -0.4202485662338844,
+            -0.18701841516204634,
+        ];
+        let y = resample_poly(&x, 3, 2, None);
+        assert_eq!(y.len(), expected.len());
+        for (a, b) in y.iter().zip(expected) {
+            assert_relative_eq!(*a, b, epsilon = 1e-12);
+        }
+
+        // The factors are reduced by their gcd, and a unit ratio is a copy.
+        assert_eq!(resample_poly(&x, 6, 4, None), y);
+        assert_eq!(resample_poly(&x, 5, 5, None), x);
+        assert!(resample_poly::<f64>(&[], 3, 2, None).is_empty());
+    }
+
+    #[test]
+    fn resample_poly_axis_matches_rows() {
+        let x = ndarray::Array2::from_shape_fn((2, 1000), |(i, j)| {
+            let t = j as f64 / 1000.;
+            if i == 0 {
+                (2. * core::f64::consts::PI * 13. * t).sin()
+            } else {
+                (40. * t * t).cos() + 0.1 * t
+            }
+        });
+        let y = resample_poly_axis(&x, 3, 2, None, Some(1)).unwrap();
+        assert_eq!(y.shape(), &[2, 1500]);
+        for (yi, xi) in y.outer_iter().zip(x.outer_iter()) {
+            assert_eq!(yi.to_vec(), resample_poly(&xi.to_vec(), 3, 2, None));
+        }
+
+        // Along the default axis 0, each column is resampled.
+        let y = resample_poly_axis(&x.t(), 3, 2, None, None).unwrap();
+        assert_eq!(y.shape(), &[1500, 2]);
+        for (yi, xi) in y.axis_iter(Axis(1)).zip(x.outer_iter()) {
+            assert_eq!(yi.to_vec(), resample_poly(&xi.to_vec(), 3, 2, None));
+        }
+        assert!(resample_poly_axis(&x, 3, 2, None, Some(2)).is_err());
+    }
 }
@@ -0,0 +1,1427 @@
+use crate::signal::windows::{get_window, GetWindow, GetWindowBuilder, WindowCache};
+use crate::special::Bessel;
+use core::ops::{Add, Div, Mul, Sub, SubAssign};
+use nalgebra::{Complex, RealField};
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2, ArrayViewMut1};
+use num_traits::{Float, Zero};
+use rustfft::{FftNum, FftPlanner};
+use sci_rs_core::{Error, Result};
+
+/// Frequencies, segment times and STFT returned by [stft].
+type StftResult<F> = (Vec<F>, Vec<F>, Array2<Complex<F>>);
+
+/// Sample types accepted by the spectral estimators.
+///
+/// Real input yields a one-sided spectrum by default, whilst complex (e.g. IQ/baseband) input is
+/// inherently two-sided.
+pub trait SpectralSample<F>: Copy {
+    /// Whether the samples are complex valued.
+    const IS_COMPLEX: bool;
+
+    /// Widen the sample into a complex number for the FFT.
+    fn to_complex(self) -> Complex<F>;
+}
+
+macro_rules! spectral_sample_impl {
+    ($($F:ty),*) => {
+        $(
+            impl SpectralSample<$F> for $F {
+                const IS_COMPLEX: bool = false;
+
+                #[inline(always)]
+                fn to_complex(self) -> Complex<$F> {
+                    Complex::new(self, 0.)
+                }
+            }
+
+            impl SpectralSample<$F> for Complex<$F> {
+                const IS_COMPLEX: bool = true;
+
+                #[inline(always)]
+                fn to_complex(self) -> Complex<$F> {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+spectral_sample_impl!(f32, f64);
+
+/// Selects between computing the power spectral density or the power spectrum.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpectralScaling {
+    /// Power spectral density, with units of V\*\*2/Hz if `x` is measured in V and `fs` in Hz.
+    #[default]
+    Density,
+    /// Squared magnitude spectrum, with units of V\*\*2 if `x` is measured in V.
+    Spectrum,
+}
+
+/// Detrending applied to each segment prior to the FFT.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DetrendType {
+    /// Leave the segment untouched.
+    None,
+    /// Subtract the mean of the segment.
+    #[default]
+    Constant,
+    /// Subtract the linear least-squares fit of the segment.
+    Linear,
+}
+
+/// Remove a linear or constant trend from data.
+///
+/// # Parameters
+/// * `x` : array_like
+///   The input data.
+/// * `dtype` : [DetrendType]
+///   The type of detrending. [DetrendType::Linear] subtracts the least-squares linear fit of `x`,
+///   [DetrendType::Constant] subtracts only the mean, and [DetrendType::None] returns `x`
+///   unchanged.
+///
+/// # Returns
+/// * `ret` : ndarray
+///   The detrended input data.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::signal::spectral::{detrend, DetrendType};
+///
+/// let x = array![1., 3., 5., 7.];
+/// let y = detrend(x.view(), DetrendType::Linear);
+/// assert!(y.iter().all(|yi: &f64| yi.abs() < 1e-12));
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.detrend.html>
+pub fn detrend<F>(x: ArrayView1<'_, F>, dtype: DetrendType) -> Array1<F>
+where
+    F: Float + RealField,
+{
+    let mut y = x.to_owned();
+    detrend_inplace(&mut y.view_mut(), dtype);
+    y
+}
+
+/// Remove a linear or constant trend from data in place.
+///
+/// This is [detrend] with scipy's `overwrite_data=True`, which avoids allocating a second array
+/// the size of `x`.
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.detrend.html>
+pub fn detrend_inplace<F>(x: &mut ArrayViewMut1<'_, F>, dtype: DetrendType)
+where
+    F: Float + RealField,
+{
+    detrend_with::<F, F>(x, dtype)
+}
+
+/// Remove the trend of `x`, for samples `T` that scale by the real type `F`.
+fn detrend_with<T, F>(x: &mut ArrayViewMut1<'_, T>, dtype: DetrendType)
+where
+    T: Copy
+        + Zero
+        + Add<Output = T>
+        + Sub<Output = T>
+        + SubAssign
+        + Mul<F, Output = T>
+        + Div<F, Output = T>,
+    F: Float,
+{
+    if x.is_empty() {
+        return;
+    }
+    let n = F::from(x.len()).unwrap();
+    let mean = x.iter().fold(T::zero(), |acc, &s| acc + s) / n;
+    match dtype {
+        DetrendType::None => {}
+        DetrendType::Constant => x.iter_mut().for_each(|s| *s -= mean),
+        DetrendType::Linear => {
+            let t_mean = (n - F::one()) / (F::one() + F::one());
+            let (num, den) =
+                x.iter()
+                    .enumerate()
+                    .fold((T::zero(), F::zero()), |(num, den), (i, &s)| {
+                        let t = F::from(i).unwrap() - t_mean;
+                        (num + (s - mean) * t, den + t * t)
+                    });
+            let slope = if den > F::zero() {
+                num / den
+            } else {
+                T::zero()
+            };
+            x.iter_mut().enumerate().for_each(|(i, s)| {
+                let t = F::from(i).unwrap() - t_mean;
+                *s -= mean + slope * t
+            });
+        }
+    }
+}
+
+/// Estimate power spectral density using a periodogram.
+///
+/// # Parameters
+/// * `x` : array_like
+///   Time series of measurement values. May be real or [Complex]; see [SpectralSample].
+/// * `fs` : float, optional
+///   Sampling frequency of the `x` time series. Defaults to 1.0.
+/// * `window` : [GetWindowBuilder], optional
+///   Desired window to use. Defaults to [GetWindowBuilder::Boxcar].
+/// * `nfft` : int, optional
+///   Length of the FFT used. If `None` the length of `x` will be used.
+/// * `detrend` : [DetrendType], optional
+///   Specifies how to detrend `x` prior to computing the spectrum. Defaults to
+///   [DetrendType::Constant].
+/// * `return_onesided` : bool, optional
+///   If true (default), return a one-sided spectrum for real data. If false return a two-sided
+///   spectrum. For complex data, a two-sided spectrum is always returned.
+/// * `scaling` : [SpectralScaling], optional
+///   Defaults to [SpectralScaling::Density].
+///
+/// # Returns
+/// * `f` : ndarray
+///   Array of sample frequencies. Two-sided frequencies are in the order of `numpy.fft.fftfreq`.
+/// * `Pxx` : ndarray
+///   Power spectral density or power spectrum of `x`.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `nfft` is shorter than `x`.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::spectral::{periodogram, SpectralScaling};
+///
+/// // Amplitude 2 sine, exactly on a frequency bin.
+/// let x: Vec<f64> = (0..64)
+///     .map(|n| 2. * (2. * core::f64::consts::PI * 8. * n as f64 / 64.).sin())
+///     .collect();
+/// let (f, pxx) = periodogram(&x, Some(64.), None, None, None, None, Some(SpectralScaling::Spectrum))
+///     .unwrap();
+/// assert_eq!(f[8], 8.);
+/// assert!((pxx[8] - 2.).abs() < 1e-10);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.periodogram.html>
+pub fn periodogram<T, F>(
+    x: &[T],
+    fs: Option<F>,
+    window: Option<GetWindowBuilder<'_, F>>,
+    nfft: Option<usize>,
+    detrend: Option<DetrendType>,
+    return_onesided: Option<bool>,
+    scaling: Option<SpectralScaling>,
+) -> Result<(Vec<F>, Vec<F>)>
+where
+    T: SpectralSample<F>,
+    F: Float + RealField + FftNum + Bessel,
+{
+    let n = x.len();
+    let nfft = nfft.unwrap_or(n);
+    if nfft < n {
+        return Err(Error::InvalidArg {
+            arg: "nfft".into(),
+            reason: "nfft must be greater than or equal to the length of x.".into(),
+        });
+    }
+    let window = window.unwrap_or(GetWindowBuilder::Boxcar);
+
+    welch_helper(
+        x,
+        fs,
+        window,
+        n,
+        0,
+        nfft,
+        detrend,
+        return_onesided,
+        scaling,
+        None,
+    )
+}
+
+/// Estimate power spectral density using Welch's method.
+///
+/// Welch's method computes an estimate of the power spectral density by dividing the data into
+/// overlapping segments, computing a modified periodogram for each segment and averaging the
+/// periodograms.
+///
+/// # Parameters
+/// * `x` : array_like
+///   Time series of measurement values. May be real or [Complex]; see [SpectralSample].
+/// * `fs` : float, optional
+///   Sampling frequency of the `x` time series. Defaults to 1.0.
+/// * `window` : [GetWindowBuilder], optional
+///   Desired window to use. Defaults to a Hann window.
+/// * `nperseg` : int, optional
+///   Length of each segment. Defaults to 256, or the length of `x` if shorter.
+/// * `noverlap`: int, optional
+///   Number of points to overlap between segments. Defaults to `nperseg / 2`.
+/// * `nfft` : int, optional
+///   Length of the FFT used, if a zero padded FFT is desired. Padding each segment interpolates
+///   the spectrum onto a finer frequency grid, without improving its resolution. Defaults to
+///   `nperseg`.
+/// * `detrend` : [DetrendType], optional
+///   Specifies how to detrend each segment. Defaults to [DetrendType::Constant].
+/// * `return_onesided` : bool, optional
+///   If true (default), return a one-sided spectrum for real data. If false return a two-sided
+///   spectrum. For complex data, a two-sided spectrum is always returned, as the spectrum of a
+///   complex signal is in general not symmetric about 0 Hz.
+/// * `scaling` : [SpectralScaling], optional
+///   Defaults to [SpectralScaling::Density].
+///
+/// # Returns
+/// * `f` : ndarray
+///   Array of sample frequencies. Two-sided frequencies are in the order of `numpy.fft.fftfreq`.
+/// * `Pxx` : ndarray
+///   Power spectral density or power spectrum of `x`.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `nperseg` is zero, if `noverlap` is not less than `nperseg`, or
+/// if `nfft` is less than `nperseg`.
+///
+/// # Examples
+/// The spectrum of a complex exponential only peaks at its (positive) frequency:
+/// ```
+/// use nalgebra::Complex;
+/// use sci_rs::signal::spectral::welch;
+///
+/// let (fs, f0) = (1000., 125.);
+/// let x: Vec<Complex<f64>> = (0..1024)
+///     .map(|n| Complex::from_polar(1., 2. * core::f64::consts::PI * f0 * n as f64 / fs))
+///     .collect();
+/// let (f, pxx) = welch(&x, Some(fs), None, None, None, None, None, None, None).unwrap();
+///
+/// let peak = (0..pxx.len()).fold(0, |m, i| if pxx[i] > pxx[m] { i } else { m });
+/// assert_eq!(f[peak], f0);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.welch.html>
+#[allow(clippy::too_many_arguments)]
+pub fn welch<T, F>(
+    x: &[T],
+    fs: Option<F>,
+    window: Option<GetWindowBuilder<'_, F>>,
+    nperseg: Option<usize>,
+    noverlap: Option<usize>,
+    nfft: Option<usize>,
+    detrend: Option<DetrendType>,
+    return_onesided: Option<bool>,
+    scaling: Option<SpectralScaling>,
+) -> Result<(Vec<F>, Vec<F>)>
+where
+    T: SpectralSample<F>,
+    F: Float + RealField + FftNum + Bessel,
+{
+    welch_cached(
+        x,
+        fs,
+        window,
+        nperseg,
+        noverlap,
+        nfft,
+        detrend,
+        return_onesided,
+        scaling,
+        None,
+    )
+}
+
+/// [welch], looking up the window in `cache` rather than computing it on every call.
+///
+/// This is useful when estimating the spectra of many signals with the same window and
+/// `nperseg`. See [WindowCache].
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::spectral::{welch, welch_with_cache};
+/// use sci_rs::signal::windows::WindowCache;
+///
+/// let cache = WindowCache::new();
+/// let x: Vec<f64> = (0..512).map(|n| (n as f64 / 3.).sin()).collect();
+/// for _ in 0..3 {
+///     let cached = welch_with_cache(&x, None, None, None, None, None, None, None, None, &cache);
+///     let expected = welch(&x, None, None, None, None, None, None, None, None);
+///     assert_eq!(cached.unwrap(), expected.unwrap());
+/// }
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn welch_with_cache<T, F>(
+    x: &[T],
+    fs: Option<F>,
+    window: Option<GetWindowBuilder<'_, F>>,
+    nperseg: Option<usize>,
+    noverlap: Option<usize>,
+    nfft: Option<usize>,
+    detrend: Option<DetrendType>,
+    return_onesided: Option<bool>,
+    scaling: Option<SpectralScaling>,
+    cache: &WindowCache<F>,
+) -> Result<(Vec<F>, Vec<F>)>
+where
+    T: SpectralSample<F>,
+    F: Float + RealField + FftNum + Bessel,
+{
+    welch_cached(
+        x,
+        fs,
+        window,
+        nperseg,
+        noverlap,
+        nfft,
+        detrend,
+        return_onesided,
+        scaling,
+        Some(cache),
+    )
+}
+
+/// Shared body of [welch] and [welch_with_cache].
+#[allow(clippy::too_many_arguments)]
+fn welch_cached<T, F>(
+    x: &[T],
+    fs: Option<F>,
+    window: Option<GetWindowBuilder<'_, F>>,
+    nperseg: Option<usize>,
+    noverlap: Option<usize>,
+    nfft: Option<usize>,
+    detrend: Option<DetrendType>,
+    return_onesided: Option<bool>,
+    scaling: Option<SpectralScaling>,
+    cache: Option<&WindowCache<F>>,
+) -> Result<(Vec<F>, Vec<F>)>
+where
+    T: SpectralSample<F>,
+    F: Float + RealField + FftNum + Bessel,
+{
+    // scipy warns and truncates nperseg to the input length.
+    let nperseg = nperseg.unwrap_or(256).min(x.len());
+    let noverlap = noverlap.unwrap_or(nperseg / 2);
+    let nfft = nfft.unwrap_or(nperseg);
+    if nfft < nperseg {
+        return Err(Error::InvalidArg {
+            arg: "nfft".into(),
+            reason: "nfft must be greater than or equal to nperseg.".into(),
+        });
+    }
+    // Hann window.
+    let window = window.unwrap_or(GetWindowBuilder::GeneralHamming {
+        coefficient: F::from(0.5).unwrap(),
+    });
+
+    welch_helper(
+        x,
+        fs,
+        window,
+        nperseg,
+        noverlap,
+        nfft,
+        detrend,
+        return_onesided,
+        scaling,
+        cache,
+    )
+}
+
+/// Check whether the Constant OverLap Add (COLA) constraint is met.
+///
+/// The inverse STFT only reconstructs a modified STFT without distortion when the overlapping
+/// windows add up to a constant, which [istft] checks with this function.
+///
+/// # Parameters
+/// * `window` : [GetWindowBuilder]
+///   Desired window to use, which is generated periodic as with `fftbins=true`.
+/// * `nperseg` : int
+///   Length of each segment.
+/// * `noverlap` : int
+///   Number of points to overlap between segments.
+/// * `tol` : float, optional
+///   The allowed variance of a bin's weighted sum from the median bin sum. Defaults to `1e-10`.
+///
+/// # Returns
+/// * `verdict` : bool
+///   `true` if the chosen combination satisfies COLA within `tol`, `false` otherwise.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `nperseg` is zero, or if `noverlap` is not less than
+/// `nperseg`.
+///
+/// # Examples
+/// A rectangular window satisfies COLA at 75% overlap, but not at 25%:
+/// ```
+/// use sci_rs::signal::spectral::check_cola;
+/// use sci_rs::signal::windows::GetWindowBuilder;
+///
+/// assert!(check_cola::<f64>(GetWindowBuilder::Boxcar, 100, 75, None).unwrap());
+/// assert!(!check_cola::<f64>(GetWindowBuilder::Boxcar, 100, 25, None).unwrap());
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.check_cola.html>
+pub fn check_cola<F>(
+    window: GetWindowBuilder<'_, F>,
+    nperseg: usize,
+    noverlap: usize,
+    tol: Option<F>,
+) -> Result<bool>
+where
+    F: Float + RealField + Bessel,
+{
+    if nperseg == 0 {
+        return Err(Error::InvalidArg {
+            arg: "nperseg".into(),
+            reason: "nperseg must be a positive integer.".into(),
+        });
+    }
+    if noverlap >= nperseg {
+        return Err(Error::InvalidArg {
+            arg: "noverlap".into(),
+            reason: "noverlap must be less than nperseg.".into(),
+        });
+    }
+    let tol = tol.unwrap_or_else(|| F::from(1e-10).unwrap());
+
+    let win: Vec<F> = get_window(window, nperseg, Some(true)).get_window();
+    let step = nperseg - noverlap;
+    let mut binsums = vec![F::zero(); step];
+    for chunk in win.chunks_exact(step) {
+        binsums.iter_mut().zip(chunk).for_each(|(b, &w)| *b += w);
+    }
+    // The incomplete final chunk wraps around onto the start of the bins.
+    let rem = nperseg % step;
+    binsums
+        .iter_mut()
+        .zip(&win[nperseg - rem..])
+        .for_each(|(b, &w)| *b += w);
+
+    let mut sorted = binsums.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / (F::one() + F::one())
+    } else {
+        sorted[mid]
+    };
+
+    Ok(binsums.iter().all(|&b| Float::abs(b - median) < tol))
+}
+
+/// Compute the Short Time Fourier Transform (STFT).
+///
+/// STFTs can be used as a way of quantifying the change of a nonstationary signal's frequency
+/// and phase content over time. As in scipy, the segments are not detrended.
+///
+/// # Parameters
+/// * `x` : array_like
+///   Time series of measurement values. May be real or [Complex]; see [SpectralSample].
+/// * `fs` : float, optional
+///   Sampling frequency of the `x` time series. Defaults to 1.0.
+/// * `window` : [GetWindowBuilder], optional
+///   Desired window to use. Defaults to a Hann window.
+/// * `nperseg` : int, optional
+///   Length of each segment. Defaults to 256, or the length of `x` if shorter.
+/// * `noverlap`: int, optional
+///   Number of points to overlap between segments. Defaults to `nperseg / 2`.
+/// * `nfft` : int, optional
+///   Length of the FFT used, if a zero padded FFT is desired. Defaults to `nperseg`.
+/// * `return_onesided` : bool, optional
+///   If true (default), return a one-sided spectrum for real data. For complex data, a two-sided
+///   spectrum is always returned.
+/// * `boundary` : bool, optional
+///   Whether to extend `x` at both ends by `nperseg / 2` zeros, so that the first segment is
+///   centred on the first sample. Defaults to true, as scipy's `boundary='zeros'`.
+/// * `padded` : bool, optional
+///   Whether to zero-pad the end of `x` to a whole number of segments. Defaults to true.
+/// * `scaling` : [SpectralScaling], optional
+///   Scale each segment by the square root of the [SpectralScaling::Spectrum] (default) or
+///   [SpectralScaling::Density] scaling of [welch], giving a magnitude or density spectrum.
+///
+/// # Returns
+/// * `f` : ndarray
+///   Array of sample frequencies. Two-sided frequencies are in the order of `numpy.fft.fftfreq`.
+/// * `t` : ndarray
+///   Array of segment times.
+/// * `Zxx` : ndarray
+///   STFT of `x`, with a row for each frequency in `f` and a column for each time in `t`.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `nperseg` is zero, if `noverlap` is not less than `nperseg`, or
+/// if `nfft` is less than `nperseg`.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::spectral::stft;
+///
+/// let x: Vec<f64> = (0..1000).map(|n| (0.25 * n as f64).sin()).collect();
+/// let (f, t, zxx) = stft(&x, Some(10.), None, Some(100), None, None, None, None, None, None)
+///     .unwrap();
+/// assert_eq!((f.len(), t.len()), (51, 21));
+/// assert_eq!(zxx.dim(), (51, 21));
+/// assert_eq!(t[1], 5.);
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.stft.html>
+#[allow(clippy::too_many_arguments)]
+pub fn stft<T, F>(
+    x: &[T],
+    fs: Option<F>,
+    window: Option<GetWindowBuilder<'_, F>>,
+    nperseg: Option<usize>,
+    noverlap: Option<usize>,
+    nfft: Option<usize>,
+    return_onesided: Option<bool>,
+    boundary: Option<bool>,
+    padded: Option<bool>,
+    scaling: Option<SpectralScaling>,
+) -> Result<StftResult<F>>
+where
+    T: SpectralSample<F> + Zero,
+    F: Float + RealField + FftNum + Bessel,
+{
+    if nperseg == Some(0) {
+        return Err(Error::InvalidArg {
+            arg: "nperseg".into(),
+            reason: "nperseg must be a positive integer.".into(),
+        });
+    }
+    if x.is_empty() {
+        return Ok((Vec::new(), Vec::new(), Array2::zeros((0, 0))));
+    }
+    // scipy warns and truncates nperseg to the input length.
+    let nperseg = nperseg.unwrap_or(256).min(x.len());
+    let (noverlap, nfft) = stft_segment_args(nperseg, noverlap, nfft)?;
+    let fs = fs.unwrap_or_else(F::one);
+    let onesided = return_onesided.unwrap_or(true) && !T::IS_COMPLEX;
+    let win = stft_window(window, nperseg);
+
+    // Extend both ends with zeros, and pad the end to a whole number of segments.
+    let step = nperseg - noverlap;
+    let boundary = boundary.unwrap_or(true);
+    let edge = if boundary { nperseg / 2 } else { 0 };
+    let mut ext: Vec<T> = core::iter::repeat_n(T::zero(), edge)
+        .chain(x.iter().copied())
+        .chain(core::iter::repeat_n(T::zero(), edge))
+        .collect();
+    if padded.unwrap_or(true) {
+        let nadd = (step - (ext.len() - nperseg) % step) % step;
+        ext.resize(ext.len() + nadd, T::zero());
+    }
+
+    let scale = Float::sqrt(spectral_scale(
+        &win,
+        fs,
+        scaling.unwrap_or(SpectralScaling::Spectrum),
+    ));
+    let segments = fft_segments(
+        &ext,
+        &win,
+        nperseg,
+        noverlap,
+        nfft,
+        DetrendType::None,
+        onesided,
+    );
+    let nbins = segments.first().map_or(0, Vec::len);
+    let zxx = Array2::from_shape_fn((nbins, segments.len()), |(k, m)| {
+        segments[m][k].scale(scale)
+    });
+
+    // The centre of each segment, less half a segment when extended as in scipy, which
+    // places the first segment at time zero even for odd nperseg.
+    let half = F::from(nperseg).unwrap() / (F::one() + F::one());
+    let start = if boundary { F::zero() } else { half };
+    let t = (0..segments.len())
+        .map(|m| (start + F::from(m * step).unwrap()) / fs)
+        .collect();
+
+    Ok((sample_frequencies(nfft, fs, onesided), t, zxx))
+}
+
+/// Perform the inverse Short Time Fourier Transform (iSTFT).
+///
+/// The one-sided STFT `Zxx` of a real signal, as from [stft], is inverted by overlap-adding the
+/// inverse FFT of each segment, weighted by the window and normalized by the sum of the squared
+/// windows.
+///
+/// Unlike scipy, which only warns when the squared windows do not overlap-add to a nonzero sum
+/// (the NOLA constraint), the window and overlap must meet the stricter constant overlap-add
+/// constraint, as per [check_cola]. Otherwise, any modification of `Zxx`, such as filtering,
+/// would be distorted on resynthesis.
+///
+/// # Parameters
+/// * `Zxx` : ndarray
+///   STFT of the signal to be reconstructed, with a row for each frequency and a column for each
+///   segment.
+/// * `fs` : float, optional
+///   Sampling frequency of the time series. Defaults to 1.0.
+/// * `window` : [GetWindowBuilder], optional
+///   Desired window to use, as for [stft]. Defaults to a Hann window.
+/// * `nperseg` : int, optional
+///   Number of data points per segment. Defaults to `2 * (Zxx.nrows() - 1)`.
+/// * `noverlap`: int, optional
+///   Number of points to overlap between segments. Defaults to `nperseg / 2`.
+/// * `nfft` : int, optional
+///   Length of the FFT used, as for [stft]. Defaults to `nperseg` if `nperseg` is
+///   `2 * Zxx.nrows() - 1`, that is, for an odd `nperseg`, and to `2 * (Zxx.nrows() - 1)`
+///   otherwise.
+/// * `boundary` : bool, optional
+///   Whether the signal was extended at its boundaries by [stft], in which case the extension
+///   is removed. Defaults to true.
+/// * `scaling` : [SpectralScaling], optional
+///   The scaling of `Zxx`, as for [stft]. Defaults to [SpectralScaling::Spectrum].
+///
+/// # Returns
+/// * `t` : ndarray
+///   Array of output data times.
+/// * `x` : ndarray
+///   iSTFT of `Zxx`.
+///
+/// # Errors
+/// Returns [Error::InvalidArg] if `nperseg` is zero, if `noverlap` is not less than `nperseg`, if
+/// `nfft` is less than `nperseg`, or if the window does not satisfy the COLA constraint for
+/// `nperseg` and `noverlap`.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::spectral::{istft, stft};
+/// use sci_rs::signal::windows::GetWindowBuilder;
+///
+/// let x: Vec<f64> = (0..1000).map(|n| (0.25 * n as f64).sin()).collect();
+/// let (_, _, zxx) = stft(&x, None, None, Some(100), None, None, None, None, None, None).unwrap();
+/// let (_, y) = istft(zxx.view(), None, None, Some(100), None, None, None, None).unwrap();
+/// assert!(x.iter().zip(&y).all(|(xi, yi)| (xi - yi).abs() < 1e-12));
+///
+/// // A rectangular window does not overlap-add to a constant at 25% overlap.
+/// let boxcar = Some(GetWindowBuilder::Boxcar);
+/// let (_, _, zxx) =
+///     stft(&x, None, boxcar.clone(), Some(100), Some(25), None, None, None, None, None).unwrap();
+/// assert!(istft(zxx.view(), None, boxcar, Some(100), Some(25), None, None, None).is_err());
+/// ```
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.istft.html>
+#[allow(clippy::too_many_arguments)]
+pub fn istft<F>(
+    zxx: ArrayView2<'_, Complex<F>>,
+    fs: Option<F>,
+    window: Option<GetWindowBuilder<'_, F>>,
+    nperseg: Option<usize>,
+    noverlap: Option<usize>,
+    nfft: Option<usize>,
+    boundary: Option<bool>,
+    scaling: Option<SpectralScaling>,
+) -> Result<(Vec<F>, Vec<F>)>
+where
+    F: Float + RealField + FftNum + Bessel,
+{
+    let (nbins, nseg) = zxx.dim();
+    let n_default = 2 * nbins.saturating_sub(1);
+    let nperseg = nperseg.unwrap_or(n_default);
+    if nperseg == 0 {
+        return Err(Error::InvalidArg {
+            arg: "nperseg".into(),
+            reason: "nperseg must be a positive integer.".into(),
+        });
+    }
+    // An odd nperseg without FFT padding is the only one with nperseg / 2 + 1 bins.
+    let nfft = nfft.unwrap_or(if nperseg == n_default + 1 {
+        nperseg
+    } else {
+        n_default
+    });
+    let (noverlap, nfft) = stft_segment_args(nperseg, noverlap, Some(nfft))?;
+    let window = window.unwrap_or(GetWindowBuilder::GeneralHamming {
+        coefficient: F::from(0.5).unwrap(),
+    });
+    if !check_cola(window.clone(), nperseg, noverlap, None)? {
+        return Err(Error::InvalidArg {
+            arg: "window".into(),
+            reason: "Window, STFT shape and noverlap do not satisfy the COLA constraint.".into(),
+        });
+    }
+    let fs = fs.unwrap_or_else(F::one);
+    let win = stft_window(Some(window), nperseg);
+
+    // Undo the scaling of the forward transform, and the normalization of the inverse FFT.
+    let scaling = scaling.unwrap_or(SpectralScaling::Spectrum);
+    let scale =
+        F::one() / (Float::sqrt(spectral_scale(&win, fs, scaling)) * F::from(nfft).unwrap());
+
+    let mut planner = FftPlanner::new();
+    let ifft = planner.plan_fft_inverse(nfft);
+    let mut scratch = vec![Complex::zero(); ifft.get_inplace_scratch_len()];
+    let step = nperseg - noverlap;
+    let len = nperseg + nseg.saturating_sub(1) * step;
+    let mut x = vec![F::zero(); len];
+    let mut norm = vec![F::zero(); len];
+    let mut buf = vec![Complex::zero(); nfft];
+    for (m, seg) in zxx.columns().into_iter().enumerate() {
+        // Complete the Hermitian spectrum of the real segment, as irfft.
+        buf.fill(Complex::zero());
+        for (k, &zk) in seg.iter().enumerate().take(nfft / 2 + 1) {
+            buf[k] = zk;
+            if k > 0 && k < nfft - k {
+                buf[nfft - k] = zk.conj();
+            }
+        }
+        ifft.process_with_scratch(&mut buf, &mut scratch);
+
+        let start = m * step;
+        for (i, (&b, &w)) in buf.iter().zip(&win).enumerate() {
+            x[start + i] += b.re * scale * w;
+            norm[start + i] += w * w;
+        }
+    }
+
+    let edge = if boundary.unwrap_or(true) {
+        nperseg / 2
+    } else {
+        0
+    };
+    let end = len.saturating_sub(edge).max(edge);
+    let tiny = F::from(1e-10).unwrap();
+    let x: Vec<F> = x[edge..end]
+        .iter()
+        .zip(&norm[edge..end])
+        .map(|(&xi, &ni)| if ni > tiny { xi / ni } else { xi })
+        .collect();
+    let t = (0..x.len()).map(|i| F::from(i).unwrap() / fs).collect();
+    Ok((t, x))
+}
+
+/// The overlap and FFT length of the segments of [stft] and [istft], as given or by default.
+fn stft_segment_args(
+    nperseg: usize,
+    noverlap: Option<usize>,
+    nfft: Option<usize>,
+) -> Result<(usize, usize)> {
+    let noverlap = noverlap.unwrap_or(nperseg / 2);
+    if noverlap >= nperseg {
+        return Err(Error::InvalidArg {
+            arg: "noverlap".into(),
+            reason: "noverlap must be less than nperseg.".into(),
+        });
+    }
+    let nfft = nfft.unwrap_or(nperseg);
+    if nfft < nperseg {
+        return Err(Error::InvalidArg {
+            arg: "nfft".into(),
+            reason: "nfft must be greater than or equal to nperseg.".into(),
+        });
+    }
+    Ok((noverlap, nfft))
+}
+
+/// The periodic window of [stft] and [istft], defaulting to a Hann window.
+fn stft_window<F>(window: Option<GetWindowBuilder<'_, F>>, nperseg: usize) -> Vec<F>
+where
+    F: Float + RealField + Bessel,
+{
+    let window = window.unwrap_or(GetWindowBuilder::GeneralHamming {
+        coefficient: F::from(0.5).unwrap(),
+    });
+    get_window(window, nperseg, None).get_window()
+}
+
+/// The factor by which the squared magnitude of each windowed segment's FFT is scaled.
+fn spectral_scale<F: Float>(win: &[F], fs: F, scaling: SpectralScaling) -> F {
+    match scaling {
+        SpectralScaling::Density => {
+            F::one() / (fs * win.iter().fold(F::zero(), |acc, &w| acc + w * w))
+        }
+        SpectralScaling::Spectrum => {
+            let s = win.iter().fold(F::zero(), |acc, &w| acc + w);
+            F::one() / (s * s)
+        }
+    }
+}
+
+/// Shared body of [periodogram] and [welch] once the segment lengths are resolved.
+#[allow(clippy::too_many_arguments)]
+fn welch_helper<T, F>(
+    x: &[T],
+    fs: Option<F>,
+    window: GetWindowBuilder<'_, F>,
+    nperseg: usize,
+    noverlap: usize,
+    nfft: usize,
+    detrend: Option<DetrendType>,
+    return_onesided: Option<bool>,
+    scaling: Option<SpectralScaling>,
+    cache: Option<&WindowCache<F>>,
+) -> Result<(Vec<F>, Vec<F>)>
+where
+    T: SpectralSample<F>,
+    F: Float + RealField + FftNum + Bessel,
+{
+    if x.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    if nperseg == 0 {
+        return Err(Error::InvalidArg {
+            arg: "nperseg".into(),
+            reason: "nperseg must be a positive integer.".into(),
+        });
+    }
+    if noverlap >= nperseg {
+        return Err(Error::InvalidArg {
+            arg: "noverlap".into(),
+            reason: "noverlap must be less than nperseg.".into(),
+        });
+    }
+
+    let fs = fs.unwrap_or_else(F::one);
+    let onesided = return_onesided.unwrap_or(true) && !T::IS_COMPLEX;
+    let window = get_window(window, nperseg, None);
+    let win: Vec<F> = match cache {
+        Some(cache) => cache.get_window(&window),
+        None => window.get_window(),
+    };
+    let scale = spectral_scale(&win, fs, scaling.unwrap_or_default());
+
+    let segments = fft_segments(
+        x,
+        &win,
+        nperseg,
+        noverlap,
+        nfft,
+        detrend.unwrap_or_default(),
+        onesided,
+    );
+    let nseg = F::from(segments.len()).unwrap();
+    let mut pxx = vec![F::zero(); segments.first().map_or(0, Vec::len)];
+    for seg in segments.iter() {
+        pxx.iter_mut()
+            .zip(seg)
+            .for_each(|(p, s)| *p += s.norm_sqr() * scale);
+    }
+    pxx.iter_mut().for_each(|p| *p /= nseg);
+
+    if onesided {
+        // Fold the power of the negative frequencies onto the positive ones, excluding DC and,
+        // for even nfft, the Nyquist bin.
+        let two = F::one() + F::one();
+        let end = if nfft.is_multiple_of(2) {
+            pxx.len() - 1
+        } else {
+            pxx.len()
+        };
+        pxx.iter_mut().take(end).skip(1).for_each(|p| *p *= two);
+    }
+
+    Ok((sample_frequencies(nfft, fs, onesided), pxx))
+}
+
+/// FFT of each detrended and windowed segment of `x`, without boundary extension or padding.
+///
+/// One-sided spectra are truncated to the first `nfft / 2 + 1` bins.
+fn fft_segments<T, F>(
+    x: &[T],
+    win: &[F],
+    nperseg: usize,
+    noverlap: usize,
+    nfft: usize,
+    detrend: DetrendType,
+    onesided: bool,
+) -> Vec<Vec<Complex<F>>>
+where
+    T: SpectralSample<F>,
+    F: Float + RealField + FftNum,
+{
+    let step = nperseg - noverlap;
+    let nseg = (x.len() - noverlap) / step;
+    let nbins = if onesided { nfft / 2 + 1 } else { nfft };
+
+    let mut planner = FftPlanner::new();
+    let fft = planner.plan_fft_forward(nfft);
+    let mut scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+
+    (0..nseg)
+        .map(|k| {
+            let mut buf = vec![Complex::zero(); nfft];
+            buf.iter_mut()
+                .zip(&x[k * step..k * step + nperseg])
+                .for_each(|(b, &xi)| *b = xi.to_complex());
+            detrend_complex(&mut buf[..nperseg], detrend);
+            buf.iter_mut().zip(win).for_each(|(b, &w)| *b = b.scale(w));
+
+            fft.process_with_scratch(&mut buf, &mut scratch);
+            buf.truncate(nbins);
+            buf
+        })
+        .collect()
+}
+
+/// Remove the trend of a complex segment, treating real and imaginary parts independently.
+fn detrend_complex<F>(seg: &mut [Complex<F>], detrend: DetrendType)
+where
+    F: Float + RealField,
+{
+    detrend_with::<_, F>(&mut ArrayViewMut1::from(seg), detrend)
+}
+
+/// Frequencies of the FFT bins, as `numpy.fft.rfftfreq` if `onesided`, else as
+/// `numpy.fft.fftfreq`.
+fn sample_frequencies<F>(nfft: usize, fs: F, onesided: bool) -> Vec<F>
+where
+    F: Float,
+{
+    let df = fs / F::from(nfft).unwrap();
+    if onesided {
+        (0..=nfft / 2).map(|k| F::from(k).unwrap() * df).collect()
+    } else {
+        let npos = nfft.div_ceil(2);
+        (0..npos)
+            .map(|k| F::from(k).unwrap())
+            .chain((1..=nfft - npos).rev().map(|k| -F::from(k).unwrap()))
+            .map(|k| k * df)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    fn argmax(x: &[f64]) -> usize {
+        (0..x.len()).fold(0, |m, i| if x[i] > x[m] { i } else { m })
+    }
+
+    #[test]
+    fn frequencies_match_numpy() {
+        // np.fft.fftfreq(5, 1/10)
+        assert_eq!(sample_frequencies(5, 10., false), [0., 2., 4., -4., -2.]);
+        // np.fft.fftfreq(4, 1/8)
+        assert_eq!(sample_frequencies(4, 8., false), [0., 2., -4., -2.]);
+        // np.fft.rfftfreq(5, 1/10)
+        assert_eq!(sample_frequencies(5, 10., true), [0., 2., 4.]);
+    }
+
+    #[test]
+    fn detrend_inplace_matches_detrend() {
+        let x = ndarray::Array1::from_iter(
+            (0..50).map(|n| (0.4 * n as f64).sin() + 0.3 * n as f64 - 2.),
+        );
+        for dtype in [
+            DetrendType::None,
+            DetrendType::Constant,
+            DetrendType::Linear,
+        ] {
+            let expected = detrend(x.view(), dtype);
+            let mut y = x.clone();
+            detrend_inplace(&mut y.view_mut(), dtype);
+            assert_eq!(y, expected);
+        }
+
+        // Only the sine remains once the line is removed, so the residual has zero mean.
+        let y = detrend(x.view(), DetrendType::Linear);
+        assert_relative_eq!(y.sum(), 0., epsilon = 1e-12);
+        // The constant detrend of a line leaves a line through the origin at its midpoint.
+        let line = ndarray::Array1::from_iter((0..5).map(|n| 2. * n as f64 + 1.));
+        assert_eq!(
+            detrend(line.view(), DetrendType::Constant).to_vec(),
+            [-4., -2., 0., 2., 4.]
+        );
+
+        // Detrending a strided view leaves the other elements untouched.
+        let mut z = x.clone();
+        let mut odd = z.slice_mut(ndarray::s![1..;2]);
+        detrend_inplace(&mut odd, DetrendType::Linear);
+        let expected = detrend(x.slice(ndarray::s![1..;2]), DetrendType::Linear);
+        assert_eq!(z.slice(ndarray::s![1..;2]), expected);
+        assert_eq!(z.slice(ndarray::s![..;2]), x.slice(ndarray::s![..;2]));
+    }
+
+    #[test]
+    fn periodogram_real_sine() {
+        let (fs, a, f0) = (64., 2., 8.);
+        let x: Vec<f64> = (0..64)
+            .map(|n| a * (2. * PI * f0 * n as f64 / fs).sin() + 0.5)
+            .collect();
+
+        let (f, pxx) = periodogram(
+            &x,
+            Some(fs),
+            None,
+            None,
+            None,
+            None,
+            Some(SpectralScaling::Spectrum),
+        )
+        .unwrap();
+        assert_eq!(f.len(), 33);
+        assert_eq!(f[argmax(&pxx)], f0);
+        assert_relative_eq!(pxx[8], a * a / 2., max_relative = 1e-12);
+        // DC offset is removed by the constant detrend.
+        assert!(pxx[0] < 1e-20);
+
+        // Parseval: the density integrates to the variance of the sine.
+        let (f, pxx) = periodogram(&x, Some(fs), None, None, None, None, None).unwrap();
+        let df = f[1] - f[0];
+        assert_relative_eq!(
+            pxx.iter().sum::<f64>() * df,
+            a * a / 2.,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn welch_complex_exponential_is_two_sided() {
+        let (fs, f0) = (1000., 125.);
+        let x: Vec<Complex<f64>> = (0..1024)
+            .map(|n| Complex::from_polar(1., 2. * PI * f0 * n as f64 / fs))
+            .collect();
+
+        // return_onesided is ignored for complex input.
+        let (f, pxx) = welch(&x, Some(fs), None, None, None, None, None, Some(true), None).unwrap();
+        assert_eq!(f.len(), 256);
+        assert_eq!(pxx.len(), 256);
+
+        let peak = argmax(&pxx);
+        assert_eq!(f[peak], f0);
+        let neg = f.iter().position(|&fi| fi == -f0).unwrap();
+        assert!(pxx[neg] < pxx[peak] * 1e-12);
+
+        let (_, pxx) = periodogram(
+            &x,
+            Some(fs),
+            None,
+            None,
+            None,
+            None,
+            Some(SpectralScaling::Spectrum),
+        )
+        .unwrap();
+        assert_eq!(pxx.len(), 1024);
+        assert_relative_eq!(pxx[128], 1., max_relative = 1e-10);
+    }
+
+    #[test]
+    fn welch_real_onesided_matches_two_sided() {
+        let x: Vec<f64> = (0..500)
+            .map(|n| (0.3 * n as f64).sin() + 0.1 * (1.7 * n as f64).cos() + 1e-3 * n as f64)
+            .collect();
+        let (f1, p1) = welch(
+            &x,
+            None,
+            None,
+            Some(64),
+            None,
+            None,
+            Some(DetrendType::Linear),
+            None,
+            None,
+        )
+        .unwrap();
+        let (f2, p2) = welch(
+            &x,
+            None,
+            None,
+            Some(64),
+            None,
+            None,
+            Some(DetrendType::Linear),
+            Some(false),
+            None,
+        )
+        .unwrap();
+        assert_eq!(f1.len(), 33);
+        assert_eq!(f2.len(), 64);
+        assert_relative_eq!(p1[0], p2[0], max_relative = 1e-12);
+        assert_relative_eq!(p1[32], p2[32], max_relative = 1e-12);
+        for k in 1..32 {
+            assert_relative_eq!(p1[k], p2[k] + p2[64 - k], max_relative = 1e-10);
+        }
+    }
+
+    #[test]
+    fn welch_nfft_zero_pads_segments() {
+        let (fs, f0) = (100., 12.5);
+        let x: Vec<f64> = (0..400)
+            .map(|n| (2. * PI * f0 * n as f64 / fs).sin())
+            .collect();
+        let (f, pxx) = welch(&x, Some(fs), None, Some(64), None, None, None, None, None).unwrap();
+        let (f_pad, pxx_pad) = welch(
+            &x,
+            Some(fs),
+            None,
+            Some(64),
+            None,
+            Some(256),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(f.len(), 33);
+        assert_eq!(f_pad.len(), 129);
+        assert_relative_eq!(f_pad[1] - f_pad[0], (f[1] - f[0]) / 4.);
+        assert_eq!(f[argmax(&pxx)], f0);
+        assert_eq!(f_pad[argmax(&pxx_pad)], f0);
+        // Padding interpolates between the original bins, which it passes through.
+        for k in 0..f.len() {
+            assert_relative_eq!(
+                pxx_pad[4 * k],
+                pxx[k],
+                max_relative = 1e-10,
+                epsilon = 1e-20
+            );
+        }
+    }
+
+    #[test]
+    fn welch_invalid_overlap() {
+        let x = [1., 2., 3., 4., 5., 6., 7., 8.];
+        assert!(welch(&x, None, None, Some(4), Some(4), None, None, None, None).is_err());
+        assert!(welch(&x, None, None, Some(4), None, Some(3), None, None, None).is_err());
+        assert!(periodogram(&x, None, None, Some(4), None, None, None).is_err());
+    }
+
+    #[test]
+    fn check_cola_flags_non_cola() {
+        // >>> check_cola(signal.windows.boxcar(100), 100, 75)
+        // True
+        // >>> check_cola(signal.windows.boxcar(100), 100, 25)
+        // False
+        assert!(check_cola::<f64>(GetWindowBuilder::Boxcar, 100, 75, None).unwrap());
+        assert!(!check_cola::<f64>(GetWindowBuilder::Boxcar, 100, 25, None).unwrap());
+
+        // >>> check_cola('hann', 256, 128)
+        // True
+        // >>> check_cola('hann', 256, 100)
+        // False
+        let hann = GetWindowBuilder::GeneralHamming { coefficient: 0.5 };
+        assert!(check_cola(hann.clone(), 256, 128, None).unwrap());
+        assert!(!check_cola(hann.clone(), 256, 100, None).unwrap());
+
+        assert!(check_cola(hann, 256, 256, None).is_err());
+    }
+
+    #[test]
+    fn stft_matches_scipy() {
+        let x = [1f64, 2., -1., 0.5, 3., -2., 0., 1.5, -0.5, 2.5];
+
+        // >>> f, t, Zxx = signal.stft(x, nperseg=4)
+        let (f, t, zxx) =
+            stft(&x, None, None, Some(4), None, None, None, None, None, None).unwrap();
+        assert_eq!(f, [0., 0.25, 0.5]);
+        assert_eq!(t, [0., 2., 4., 6., 8., 10.]);
+        let expected = [
+            [
+                (1., 0.),
+                (0.125, 0.),
+                (1.125, 0.),
+                (-0.125, 0.),
+                (0.75, 0.),
+                (0.625, 0.),
+            ],
+            [
+                (-0.5, 0.5),
+                (0.5, -0.375),
+                (-1.5, -0.625),
+                (0., 0.875),
+                (0.25, 0.25),
+                (0., -0.625),
+            ],
+            [
+                (0., 0.),
+                (-1.125, 0.),
+                (1.875, 0.),
+                (0.125, 0.),
+                (-1.25, 0.),
+                (-0.625, 0.),
+            ],
+        ];
+        assert_eq!(zxx.dim(), (3, 6));
+        for (row, expected) in zxx.rows().into_iter().zip(expected) {
+            for (z, (re, im)) in row.iter().zip(expected) {
+                assert!(
+                    (z - Complex::new(re, im)).norm() < 1e-12,
+                    "{z} != {re} + {im}i"
+                );
+            }
+        }
+
+        // >>> f, t, Zxx = signal.stft(x, fs=2, nperseg=5, noverlap=2, nfft=8, boundary=None,
+        // ...                         scaling='psd')
+        let (f, t, zxx) = stft(
+            &x,
+            Some(2.),
+            None,
+            Some(5),
+            Some(2),
+            Some(8),
+            None,
+            Some(false),
+            None,
+            Some(SpectralScaling::Density),
+        )
+        .unwrap();
+        assert_eq!(f, [0., 0.25, 0.5, 0.75, 1.]);
+        assert_eq!(t, [1.25, 2.75, 4.25]);
+        let expected = [
+            [
+                (0.6585121346920461, 0.),
+                (-0.13132265697326326, 0.),
+                (1.201788926292533, 0.),
+            ],
+            [
+                (-0.44806171775882064, 0.049634957670758394),
+                (0.11085041170374278, 0.5557053799291554),
+                (-0.636466022757682, -0.7813899120505636),
+            ],
+            [
+                (1.002319314140993, -0.1232790000324108),
+                (1.2017889262925332, -0.5352331346596346),
+                (0.23354308974067872, 0.900098881373577),
+            ],
+            [
+                (-0.6224045515604494, -0.8845374012919573),
+                (-0.6460835463633776, -1.3126393379962757),
+                (0.6364660227576824, -1.248476091531921),
+            ],
+            [
+                (-0.5222182243354917, 0.),
+                (-1.201788926292533, 0.),
+                (-1.6688751057738906, 0.),
+            ],
+        ];
+        assert_eq!(zxx.dim(), (5, 3));
+        for (row, expected) in zxx.rows().into_iter().zip(expected) {
+            for (z, (re, im)) in row.iter().zip(expected) {
+                assert!(
+                    (z - Complex::new(re, im)).norm() < 1e-12,
+                    "{z} != {re} + {im}i"
+                );
+            }
+        }
+
+        assert!(stft(&x, None, None, Some(0), None, None, None, None, None, None).is_err());
+        assert!(stft(
+            &x,
+            None,
+            None,
+            Some(4),
+            Some(4),
+            None,
+            None,
+            None,
+            None,
+            None
+        )
+        .is_err());
+        assert!(stft(
+            &x,
+            None,
+            None,
+            Some(4),
+            None,
+            Some(3),
+            None,
+            None,
+            None,
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn istft_inverts_stft() {
+        let x: Vec<f64> = (0..301)
+            .map(|n| (0.05 * n as f64).sin() + 0.5 * (0.31 * n as f64).cos())
+            .collect();
+
+        let (_, _, zxx) =
+            stft(&x, None, None, Some(64), None, None, None, None, None, None).unwrap();
+        let (t, y) = istft(zxx.view(), None, None, None, None, None, None, None).unwrap();
+        assert_eq!(t[1], 1.);
+        // Padded up to a whole number of segments.
+        assert_eq!(y.len(), 320);
+        for (xi, yi) in x.iter().zip(&y) {
+            assert!((xi - yi).abs() < 1e-12);
+        }
+        assert!(y[x.len()..].iter().all(|yi| yi.abs() < 1e-12));
+
+        // Odd segments, zero padded FFTs and density scaling.
+        let hamming = Some(GetWindowBuilder::Hamming);
+        let (fs, psd) = (Some(10.), Some(SpectralScaling::Density));
+        let (_, _, zxx) = stft(
+            &x,
+            fs,
+            hamming.clone(),
+            Some(51),
+            Some(34),
+            Some(64),
+            None,
+            None,
+            None,
+            psd,
+        )
+        .unwrap();
+        let (t, y) = istft(
+            zxx.view(),
+            fs,
+            hamming,
+            Some(51),
+            Some(34),
+            Some(64),
+            None,
+            psd,
+        )
+        .unwrap();
+        assert_eq!(t[1], 0.1);
+        for (xi, yi) in x.iter().zip(&y) {
+            assert!((xi - yi).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn istft_rejects_non_cola() {
+        let x: Vec<f64> = (0..256).map(|n| (0.1 * n as f64).sin()).collect();
+        let boxcar = Some(GetWindowBuilder::Boxcar);
+        let (_, _, zxx) = stft(
+            &x,
+            None,
+            boxcar.clone(),
+            Some(100),
+            Some(25),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = istft(
+            zxx.view(),
+            None,
+            boxcar,
+            Some(100),
+            Some(25),
+            None,
+            None,
+            None,
+        );
+        assert!(matches!(result, Err(Error::InvalidArg { arg, .. }) if arg == "window"));
+
+        // A Hann window only overlap-adds to a constant at certain overlaps.
+        let (_, _, zxx) = stft(
+            &x,
+            None,
+            None,
+            Some(64),
+            Some(20),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let result = istft(zxx.view(), None, None, None, Some(20), None, None, None);
+        assert!(matches!(result, Err(Error::InvalidArg { arg, .. }) if arg == "window"));
+        assert!(istft(zxx.view(), None, None, None, Some(64), None, None, None).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+use nalgebra::RealField;
+use ndarray::{Array, ArrayBase, Data, Dimension};
+
+/// Frequency sweep of [chirp].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChirpMethod {
+    /// `f(t) = f0 + (f1 - f0) * t / t1`
+    Linear,
+    /// `f(t) = f0 + (f1 - f0) * t**2 / t1**2` if `vertex_zero`, or
+    /// `f(t) = f1 - (f1 - f0) * (t1 - t)**2 / t1**2` otherwise.
+    ///
+    /// The vertex of the parabola is at `t = 0` or `t = t1` respectively.
+    Quadratic {
+        /// Whether the vertex of the parabola of the frequency is at `t = 0`.
+        vertex_zero: bool,
+    },
+    /// `f(t) = f0 * (f1/f0)**(t/t1)`
+    ///
+    /// `f0` and `f1` must be nonzero and have the same sign.
+    Logarithmic,
+    /// `f(t) = f0*f1*t1 / ((f0 - f1)*t + f1*t1)`
+    ///
+    /// `f0` and `f1` must be nonzero.
+    Hyperbolic,
+}
+
+/// Frequency-swept cosine generator.
+///
+/// In the following, 'Hz' should be interpreted as 'cycles per unit';
+/// there is no requirement here that the unit is one second.  The
+/// important distinction is that the units of rotation are cycles, not
+/// radians. Likewise, `t` could be a measurement of space instead of time.
+///
+/// Parameters
+/// ----------
+/// t : array_like
+///   Times at which to evaluate the waveform.
+/// f0 : float
+///   Frequency (e.g. Hz) at time t=0.
+/// t1 : float
+///   Time at which `f1` is specified.
+/// f1 : float
+///   Frequency (e.g. Hz) of the waveform at time `t1`.
+/// method : [ChirpMethod]
+///   Kind of frequency sweep.
+/// phi : float
+///   Phase offset, in degrees.
+///
+/// Returns
+/// -------
+/// y : ndarray
+///   An array containing the signal evaluated at `t` with the requested
+///   time-varying frequency, `cos(phase + (pi/180)*phi)`, where `phase` is
+///   the integral (from 0 to `t`) of `2*pi*f(t)`.
+///
+/// Panics
+/// ------
+/// For [ChirpMethod::Logarithmic] and [ChirpMethod::Hyperbolic], if `f0` and `f1` are not as
+/// documented there.
+///
+/// Examples
+/// --------
+/// A linear chirp from 6 Hz to 1 Hz over 10 seconds:
+///
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::wave::{chirp, ChirpMethod};
+///
+/// let t = Array1::linspace(0., 10., 1500);
+/// let w = chirp(&t, 6., 10., 1., ChirpMethod::Linear, 0.);
+/// assert_eq!(w[0], 1.);
+/// ```
+///
+/// See Also
+/// --------
+/// [chirp_continued]
+///
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.chirp.html>
+pub fn chirp<F, S, D>(
+    t: &ArrayBase<S, D>,
+    f0: F,
+    t1: F,
+    f1: F,
+    method: ChirpMethod,
+    phi: F,
+) -> Array<F, D>
+where
+    F: RealField + Copy,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let phi = phi * F::pi() / F::from_f64(180.).unwrap();
+    chirp_continued(t, f0, t1, f1, method, phi).0
+}
+
+/// Frequency-swept cosine generator, continuing from a given phase.
+///
+/// As [chirp], but with the phase offset `phase` in radians, and additionally returning the phase
+/// of the sweep at `t1`, wrapped to `[0, 2*pi)`. A long sweep can then be generated in segments,
+/// each with its own time vector starting from 0 and ending at `t1`, by passing the returned
+/// phase of each segment as the `phase` of the next. The segments join without a phase
+/// discontinuity, and when the frequency at the start of each segment is that at the end of the
+/// previous, they join into the sweep of a single call over the combined time vector.
+///
+/// Parameters
+/// ----------
+/// t, f0, t1, f1, method :
+///   As [chirp].
+/// phase : float
+///   Phase offset at `t = 0`, in radians.
+///
+/// Returns
+/// -------
+/// y : ndarray
+///   The signal `cos(phase + 2*pi*integral(f))`, evaluated at `t`.
+/// phase_t1 : float
+///   The phase of the signal at `t1`, in radians.
+///
+/// Examples
+/// --------
+/// A sweep from 10 Hz to 30 Hz over 2 seconds, in two segments of 1 second:
+///
+/// ```
+/// use ndarray::Array1;
+/// use sci_rs::signal::wave::{chirp, chirp_continued, ChirpMethod};
+///
+/// let t = Array1::from_iter((0..1000).map(|i| i as f64 / 1000.));
+/// let (first, phase) = chirp_continued(&t, 10., 1., 20., ChirpMethod::Linear, 0.);
+/// let (second, _) = chirp_continued(&t, 20., 1., 30., ChirpMethod::Linear, phase);
+///
+/// let t = Array1::from_iter((0..2000).map(|i| i as f64 / 1000.));
+/// let whole = chirp(&t, 10., 2., 30., ChirpMethod::Linear, 0.);
+/// for (yi, ei) in first.iter().chain(&second).zip(&whole) {
+///     assert!((yi - ei).abs() < 1e-9);
+/// }
+/// ```
+pub fn chirp_continued<F, S, D>(
+    t: &ArrayBase<S, D>,
+    f0: F,
+    t1: F,
+    f1: F,
+    method: ChirpMethod,
+    phase: F,
+) -> (Array<F, D>, F)
+where
+    F: RealField + Copy,
+    S: Data<Elem = F>,
+    D: Dimension,
+{
+    let y = t.mapv(|ti| (phase + chirp_phase(ti, f0, t1, f1, method)).cos());
+    let phase_t1 = (phase + chirp_phase(t1, f0, t1, f1, method)) % F::two_pi();
+    // Because % is the reminder and not the modulo operator, phase_t1 can be negative.
+    let phase_t1 = if phase_t1 < F::zero() {
+        phase_t1 + F::two_pi()
+    } else {
+        phase_t1
+    };
+    (y, phase_t1)
+}
+
+/// The phase of [chirp] at `t`, `2*pi` times the integral of the frequency from 0 to `t`.
+fn chirp_phase<F: RealField + Copy>(t: F, f0: F, t1: F, f1: F, method: ChirpMethod) -> F {
+    let three = F::from_f64(3.).unwrap();
+    match method {
+        ChirpMethod::Linear => {
+            let beta = (f1 - f0) / t1;
+            F::two_pi() * (f0 * t + beta * t * t / F::from_f64(2.).unwrap())
+        }
+        ChirpMethod::Quadratic { vertex_zero } => {
+            let beta = (f1 - f0) / (t1 * t1);
+            if vertex_zero {
+                F::two_pi() * (f0 * t + beta * t.powi(3) / three)
+            } else {
+                F::two_pi() * (f1 * t + beta * ((t1 - t).powi(3) - t1.powi(3)) / three)
+            }
+        }
+        ChirpMethod::Logarithmic => {
+            if f0 * f1 <= F::zero() {
+                panic!(
+                    "For a logarithmic chirp, f0 and f1 must be nonzero and have the same sign."
+                );
+            }
+            if f0 == f1 {
+                F::two_pi() * f0 * t
+            } else {
+                let beta = t1 / (f1 / f0).ln();
+                F::two_pi() * beta * f0 * ((f1 / f0).powf(t / t1) - F::one())
+            }
+        }
+        ChirpMethod::Hyperbolic => {
+            if f0 == F::zero() || f1 == F::zero() {
+                panic!("For a hyperbolic chirp, f0 and f1 must be nonzero.");
+            }
+            if f0 == f1 {
+                F::two_pi() * f0 * t
+            } else {
+                // Singular point: the analytic continuation of the frequency is infinite here.
+                let sing = -f1 * t1 / (f0 - f1);
+                F::two_pi() * (-sing * f0) * (F::one() - t / sing).abs().ln()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::{FRAC_1_SQRT_2, TAU};
+    use ndarray::{arr1, Array1};
+
+    #[test]
+    fn chirp_phase_is_integral_of_frequency() {
+        let t = arr1(&[0., 0.5, 1., 1.5, 2.]);
+        let cases = [
+            // cos(2*pi*(t + t**2/2))
+            (
+                ChirpMethod::Linear,
+                0.,
+                [1., -FRAC_1_SQRT_2, -1., -FRAC_1_SQRT_2, 1.],
+            ),
+            // cos(2*pi*(t + t**3/6) + pi/6)
+            (
+                ChirpMethod::Quadratic { vertex_zero: true },
+                30.,
+                [0.8660254, -0.79335334, 0., 0.60876143, -0.8660254],
+            ),
+            // cos(2*pi*(3*t + ((2 - t)**3 - 8)/6))
+            (
+                ChirpMethod::Quadratic { vertex_zero: false },
+                0.,
+                [1., -0.13052619, 0.5, 0.38268343, -0.5],
+            ),
+        ];
+        for (method, phi, expected) in cases {
+            // f0 = 1 and f1 = 3 at t1 = 2.
+            let y = chirp(&t, 1., 2., 3., method, phi);
+            for (yi, ei) in y.iter().zip(expected) {
+                assert_abs_diff_eq!(*yi, ei, epsilon = 1e-7);
+            }
+        }
+
+        // The phase of a logarithmic chirp at t1 is 2*pi * (f1 - f0) * t1 / ln(f1 / f0).
+        let (_, phase) = chirp_continued(&t, 1., 2., 4., ChirpMethod::Logarithmic, 0.);
+        assert_abs_diff_eq!(phase, (TAU * 3. * 2. / 4f64.ln()) % TAU, epsilon = 1e-12);
+
+        // The frequency of a hyperbolic chirp is f0 * f1 * t1 / ((f0 - f1) * t + f1 * t1).
+        let phase = |ti: f64| chirp_phase(ti, 2., 1., 1., ChirpMethod::Hyperbolic);
+        let dt = 1e-6;
+        let freq = (phase(0.5 + dt) - phase(0.5 - dt)) / (2. * dt) / TAU;
+        assert_abs_diff_eq!(freq, 2. / 1.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn segments_join_into_single_chirp() {
+        let fs = 1000.;
+        let segment = Array1::from_iter((0..500).map(|i| i as f64 / fs));
+        let whole = Array1::from_iter((0..1500).map(|i| i as f64 / fs));
+
+        // Sweeps from 5 Hz to 50 Hz over 1.5 s, as three segments of 0.5 s, whose band edges are
+        // the frequency of the whole sweep at 0, 0.5, 1 and 1.5 s.
+        let linear = [5., 20., 35., 50.];
+        let logarithmic = [5., 5. * 10f64.powf(1. / 3.), 5. * 10f64.powf(2. / 3.), 50.];
+        for (method, edges) in [
+            (ChirpMethod::Linear, linear),
+            (ChirpMethod::Logarithmic, logarithmic),
+        ] {
+            let expected = chirp(&whole, 5., 1.5, 50., method, 45.);
+
+            let mut phase = 45f64.to_radians();
+            let mut y = Vec::new();
+            for f in edges.windows(2) {
+                let (yk, next) = chirp_continued(&segment, f[0], 0.5, f[1], method, phase);
+                y.extend(yk);
+                phase = next;
+            }
+
+            assert_eq!(y.len(), expected.len());
+            for (yi, ei) in y.iter().zip(&expected) {
+                assert_abs_diff_eq!(*yi, *ei, epsilon = 1e-9);
+            }
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use nalgebra::RealField;
-use ndarray::{Array, ArrayBase, Data, Dimension, RawData};
+use ndarray::{Array, Array1, ArrayBase, Data, Dimension, RawData};
+
+mod chirp;
+pub use chirp::*;
 
 /// Return a periodic square-wave waveform.
 ///
@@ -71,9 +74,51 @@ where
     })
 }
 
+/// Return a Dirac comb: unit impulses every `period` samples, starting at sample `phase`.
+///
+/// The frequency response of a filter can be measured from its response to an impulse train,
+/// which is also useful as a periodic excitation, e.g. the glottal source in speech synthesis.
+///
+/// Parameters
+/// ----------
+/// n : int  
+///   The number of samples in the output.
+/// period : int  
+///   The number of samples between successive impulses. Must be positive.
+/// phase : int  
+///   The index of the first impulse.
+///
+/// Returns
+/// -------
+/// y : ndarray  
+///   Output array of zeros, with ones at `phase`, `phase + period`, `phase + 2 * period`, ...
+///
+/// Examples
+/// --------
+/// ```
+/// use ndarray::arr1;
+/// use sci_rs::signal::wave::impulse_train;
+///
+/// let comb = impulse_train::<f64>(8, 3, 1);
+/// assert_eq!(comb, arr1(&[0., 1., 0., 0., 1., 0., 0., 1.]));
+/// ```
+pub fn impulse_train<F>(n: usize, period: usize, phase: usize) -> Array1<F>
+where
+    F: RealField,
+{
+    assert!(period > 0, "period must be positive");
+    let mut y = Array1::zeros(n);
+    y.iter_mut()
+        .skip(phase)
+        .step_by(period)
+        .for_each(|yi| *yi = F::one());
+    y
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
     use approx::assert_abs_diff_eq;
     use ndarray::{arr1, arr3};
 
@@ -152,6 +197,32 @@ mod tests {
         assert_vec_eq(result, expected);
     }
 
+    #[test]
+    fn test_impulse_train() {
+        let comb = impulse_train::<f64>(24, 6, 2);
+        let ones: Vec<usize> = (0..24).filter(|&i| comb[i] == 1.).collect();
+        assert_eq!(ones, [2, 8, 14, 20]);
+        assert_eq!(comb.sum(), 4.);
+
+        assert_eq!(impulse_train::<f64>(5, 2, 7).sum(), 0.);
+
+        // The DFT of a comb with 4 impulses over 24 samples is a comb with 4 bins between
+        // impulses of magnitude 4, for any phase.
+        let magnitudes: Vec<f64> = (0..24)
+            .map(|k| {
+                let (re, im) = comb.iter().enumerate().fold((0., 0.), |(re, im), (n, &x)| {
+                    let w = -2. * core::f64::consts::PI * (k * n) as f64 / 24.;
+                    (re + x * w.cos(), im + x * w.sin())
+                });
+                (re * re + im * im).sqrt()
+            })
+            .collect();
+        for (k, m) in magnitudes.iter().enumerate() {
+            let expected = if k % 4 == 0 { 4. } else { 0. };
+            assert_abs_diff_eq!(*m, expected, epsilon = 1e-10);
+        }
+    }
+
     #[track_caller]
     fn assert_vec_eq<D: Dimension>(a: Array<f32, D>, b: Array<f32, D>) {
         assert_eq!(a.shape(), b.shape());
@@ -0,0 +1,172 @@
+use super::{GetWindow, Window, WindowKey};
+use crate::special;
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+/// Memoizes computed windows by their [WindowKey], so that repeatedly designing filters or
+/// estimating spectra with the same window does not recompute it.
+///
+/// The cache is opt-in: [WindowCache::with_cache] wraps a [Window] into a [CachedWindow], which
+/// can be passed wherever a [GetWindow] is expected, such as to
+/// [firwin_dyn](crate::signal::filter::design::firwin_dyn). Functions taking a
+/// [GetWindowBuilder](super::GetWindowBuilder) instead have `_with_cache` variants, such as
+/// [welch_with_cache](crate::signal::spectral::welch_with_cache) and
+/// [resample_window_with_cache](crate::signal::resample::resample_window_with_cache).
+///
+/// Windows are never evicted; use [WindowCache::clear] to release them.
+///
+/// # Examples
+/// ```
+/// use sci_rs::signal::windows::{get_window, GetWindow, GetWindowBuilder, WindowCache};
+///
+/// let cache = WindowCache::<f64>::new();
+/// let kaiser = get_window(GetWindowBuilder::Kaiser { beta: 8. }, 64, None);
+/// let a: Vec<f64> = cache.with_cache(kaiser.clone()).get_window();
+/// let b: Vec<f64> = cache.with_cache(kaiser).get_window();
+/// assert_eq!(a, b);
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct WindowCache<W> {
+    windows: Mutex<HashMap<WindowKey, Vec<W>>>,
+}
+
+impl<W> WindowCache<W>
+where
+    W: Real + Float + RealField + special::Bessel,
+{
+    /// Returns an empty cache.
+    pub fn new() -> Self {
+        WindowCache {
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the samples of `window`, computing and storing them on the first request.
+    pub fn get_window<F: Real>(&self, window: &Window<F>) -> Vec<W> {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        windows
+            .entry(window.window_key())
+            .or_insert_with(|| window.get_window())
+            .clone()
+    }
+
+    /// Wraps `window` so that [GetWindow::get_window] looks it up in this cache.
+    pub fn with_cache<F: Real>(&self, window: Window<F>) -> CachedWindow<'_, F, W> {
+        CachedWindow {
+            window,
+            cache: self,
+        }
+    }
+
+    /// The number of distinct windows in the cache.
+    pub fn len(&self) -> usize {
+        self.windows.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Whether the cache holds no windows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all windows from the cache.
+    pub fn clear(&self) {
+        self.windows
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// A [Window] whose samples are looked up in a [WindowCache], as returned by
+/// [WindowCache::with_cache].
+#[derive(Debug)]
+pub struct CachedWindow<'a, F, W>
+where
+    F: Real,
+{
+    window: Window<F>,
+    cache: &'a WindowCache<W>,
+}
+
+impl<F, W> GetWindow<W> for CachedWindow<'_, F, W>
+where
+    F: Real,
+    W: Real + Float + RealField + special::Bessel,
+{
+    fn get_window(&self) -> Vec<W> {
+        self.cache.get_window(&self.window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::filter::design::{firwin_dyn, FilterBandType};
+    use crate::signal::windows::{get_window, GetWindowBuilder, Hamming};
+
+    #[test]
+    fn cache_hits_and_misses() {
+        let cache = WindowCache::<f64>::new();
+        assert!(cache.is_empty());
+
+        let kaiser = get_window(GetWindowBuilder::Kaiser { beta: 8. }, 33, Some(false));
+        let tukey = get_window(GetWindowBuilder::Tukey { alpha: 0.25 }, 33, Some(false));
+        let expected_kaiser: Vec<f64> = kaiser.get_window();
+        let expected_tukey: Vec<f64> = tukey.get_window();
+
+        let first = cache.get_window(&kaiser);
+        assert_eq!(first, expected_kaiser);
+        assert_eq!(cache.len(), 1);
+        // A hit returns the stored window without adding another.
+        assert_eq!(cache.get_window(&kaiser), first);
+        assert_eq!(cache.len(), 1);
+
+        // A different window is not confused with the first.
+        assert_eq!(cache.get_window(&tukey), expected_tukey);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get_window(&kaiser), expected_kaiser);
+        // Neither is the same window with a different length.
+        let shorter = get_window(GetWindowBuilder::Kaiser { beta: 8. }, 32, Some(false));
+        assert_eq!(cache.get_window(&shorter).len(), 32);
+        assert_eq!(cache.len(), 3);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn cached_window_in_firwin() {
+        let cache = WindowCache::<f64>::new();
+        let design = |window: Option<&CachedWindow<'_, f64, f64>>| {
+            firwin_dyn(
+                21,
+                &[0.3f64],
+                None,
+                window,
+                &FilterBandType::Lowpass,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+        let hamming = get_window(GetWindowBuilder::Hamming, 21, Some(false));
+        let expected: Vec<f64> = firwin_dyn(
+            21,
+            &[0.3f64],
+            None,
+            Some(&Hamming::new(21, true)),
+            &FilterBandType::Lowpass,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(design(Some(&cache.with_cache(hamming.clone()))), expected);
+        assert_eq!(design(Some(&cache.with_cache(hamming))), expected);
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -0,0 +1,231 @@
+use super::{extend, len_guard, truncate};
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Chebwin` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chebwin<F>
+where
+    F: Real,
+{
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Attenuation of the sidelobes, in dB.
+    pub at: F,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl<F> Chebwin<F>
+where
+    F: Real,
+{
+    /// Returns a Chebwin struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `at` : float  
+    ///   Attenuation of the sidelobes, in dB. Only its magnitude is used.
+    /// * `sym`:  
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, at: F, sym: bool) -> Self {
+        Chebwin { m, at, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, W> GetWindow<W> for Chebwin<F>
+where
+    F: Real,
+    W: Real + Float + RealField,
+{
+    /// Return a Dolph-Chebyshev window.
+    ///
+    /// # Parameters
+    /// `self`: [Chebwin]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window, with the maximum value always normalized to 1.
+    ///
+    /// # Notes
+    /// This window optimizes for the narrowest main lobe width for a given order `M`, and all
+    /// sidelobes are at the attenuation `at`. It is computed as the inverse DFT of the
+    /// Chebyshev polynomial of order `M - 1`,
+    /// $$W(k) = T_{M-1}\left(\beta \cos\left(\frac{\pi k}{M}\right)\right)$$
+    /// with `β` chosen so that the ratio of the main lobe to the sidelobes is `at`. The DFT is
+    /// evaluated directly, taking `O(M^2)` operations.
+    ///
+    /// For low attenuations, below about 45 dB, the window has impulses at its ends, as with the
+    /// scipy implementation.
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{Chebwin, GetWindow};
+    /// let window: Vec<f64> = Chebwin::new(51, 100., true).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.chebwin(51, at=100)
+    /// ```
+    ///
+    /// # References
+    /// [[1]] C. Dolph, "A current distribution for broadside arrays which optimizes the
+    /// relationship between beam width and side-lobe level", Proceedings of the IEEE, Vol. 34,
+    /// Issue 6  
+    /// [[2]] Scipy,
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.chebwin.html>
+    ///
+    /// [1]: #references
+    /// [2]: #references
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let one = W::one();
+        let m_f = W::from(m).unwrap();
+        let order = m_f - one;
+        let at = Float::abs(W::from(self.at).unwrap());
+        let ripple = Float::powf(W::from(10).unwrap(), at / W::from(20).unwrap());
+        let beta = Float::cosh(Float::acosh(ripple) / order);
+
+        // Chebyshev polynomial of order M - 1, evaluated on the unit circle.
+        let p: Vec<W> = (0..m)
+            .map(|k| {
+                let x = beta * Float::cos(W::pi() * W::from(k).unwrap() / m_f);
+                if x > one {
+                    Float::cosh(order * Float::acosh(x))
+                } else if x < -one {
+                    let sign = if m % 2 == 1 { one } else { -one };
+                    sign * Float::cosh(order * Float::acosh(-x))
+                } else {
+                    Float::cos(order * Float::acos(x))
+                }
+            })
+            .collect();
+
+        // Real part of the DFT of p, which for even M is first shifted by half a sample.
+        let half_shift = if m % 2 == 1 { W::zero() } else { one };
+        let dft = |k: usize| {
+            p.iter().enumerate().fold(W::zero(), |sum, (n, &pn)| {
+                let n_f = W::from(n).unwrap();
+                let k_f = W::from(k).unwrap();
+                let theta = W::pi() * n_f * (half_shift - W::from(2).unwrap() * k_f) / m_f;
+                sum + pn * Float::cos(theta)
+            })
+        };
+        let n = m / 2 + 1;
+        let half: Vec<W> = (0..n).map(dft).collect();
+        let w: Vec<W> = if m % 2 == 1 {
+            half[1..].iter().rev().chain(&half).copied().collect()
+        } else {
+            half[1..].iter().rev().chain(&half[1..]).copied().collect()
+        };
+        let max = w.iter().copied().fold(W::neg_infinity(), Float::max);
+        let w = w.into_iter().map(|wi| wi / max).collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn chebwin_scipy_example() {
+        // from scipy.signal.windows import chebwin
+        // w = chebwin(51, at=100)
+        // w[[0, 1, 5, 10, 20, 25, 50]]
+        let w: Vec<f64> = Chebwin::new(51, 100., true).get_window();
+        assert_eq!(w.len(), 51);
+        let expected = [
+            (0, 0.0004089069368090797),
+            (1, 0.00117162265079637),
+            (5, 0.017622393929880836),
+            (10, 0.12622012001241956),
+            (20, 0.8086074482832342),
+            (25, 1.),
+            (50, 0.0004089069368090797),
+        ];
+        for (i, e) in expected {
+            assert_abs_diff_eq!(w[i], e, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn chebwin_achieves_attenuation() {
+        let (m, at) = (51, 100.);
+        let w: Vec<f64> = Chebwin::new(m, at, true).get_window();
+
+        // The main lobe ends where the argument of the Chebyshev polynomial falls to 1.
+        let order = (m - 1) as f64;
+        let beta = (f64::acosh(10f64.powf(at / 20.)) / order).cosh();
+        let lobe_edge = (1. / beta).acos() / core::f64::consts::PI;
+
+        let response = |f: f64| {
+            let (re, im) = w.iter().enumerate().fold((0., 0.), |(re, im), (n, wn)| {
+                let theta = 2. * core::f64::consts::PI * f * n as f64;
+                (re + wn * theta.cos(), im - wn * theta.sin())
+            });
+            f64::hypot(re, im)
+        };
+        let peak = response(0.);
+        let max_sidelobe = (0..=2000)
+            .map(|i| 0.5 * i as f64 / 2000.)
+            .filter(|f| *f > lobe_edge * 1.01)
+            .map(response)
+            .fold(0., f64::max);
+        let sidelobe_db = 20. * (max_sidelobe / peak).log10();
+        assert!((sidelobe_db + at).abs() < 0.1, "{sidelobe_db} dB");
+    }
+
+    #[test]
+    fn chebwin_even_and_periodic() {
+        // from scipy.signal.windows import chebwin
+        // chebwin(8, 50)
+        let h = Chebwin::new(8, 50., true);
+        let expected = vec![
+            0.09455132, 0.34937508, 0.71822375, 1., 1., 0.71822375, 0.34937508, 0.09455132,
+        ];
+        assert_vec_eq(expected, h.get_window());
+
+        // chebwin(8, 50, False)
+        let h = Chebwin::new(8, 50., false);
+        let expected = vec![
+            0.077447, 0.27604701, 0.58352321, 0.87760373, 1., 0.87760373, 0.58352321, 0.27604701,
+        ];
+        assert_vec_eq(expected, h.get_window());
+
+        // chebwin(7, 60)
+        let h = Chebwin::new(7, 60., true);
+        let expected = vec![
+            0.08706263, 0.38002526, 0.79472445, 1., 0.79472445, 0.38002526, 0.08706263,
+        ];
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use super::{extend, len_guard, truncate};
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Cosine` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cosine {
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl Cosine {
+    /// Returns a Cosine struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `sym`:
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, sym: bool) -> Self {
+        Cosine { m, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<W> GetWindow<W> for Cosine
+where
+    W: Real + Float + RealField,
+{
+    /// Return a window with a simple cosine shape.
+    ///
+    /// # Parameters
+    /// * `M` : int  
+    ///   Number of points in the output window. If zero, an empty array is returned. An
+    ///   exception is thrown when it is negative.
+    /// * `sym` : bool, optional  
+    ///   When True (default), generates a symmetric window, for use in filter
+    ///   design.  
+    ///   When False, generates a periodic window, for use in spectral analysis.
+    ///
+    /// # Returns
+    /// `w` : ndarray  
+    ///     The window, with the maximum value normalized to 1 (though the value 1 does not appear
+    ///     if `M` is even and `sym` is True).
+    ///
+    /// # Notes
+    /// This is the half-cosine, or sine, window `sin(pi * (n + 0.5) / M)` for `n` in `0..M`. The
+    /// samples are offset by half a sample from the ends, so the window does not reach zero.
+    ///
+    /// # References
+    /// [[1]] Scipy,
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.cosine.html>
+    ///
+    /// Examples
+    /// --------
+    /// ```custom,{class=language-python}
+    /// >>> from scipy import signal
+    /// >>> window = signal.windows.cosine(51)
+    /// ```
+    ///
+    /// The equivalent is:
+    /// ```
+    /// use sci_rs::signal::windows::{Cosine, GetWindow};
+    /// let window: Vec<f64> = Cosine::new(51, true).get_window();
+    /// ```
+    ///
+    /// [1]: #references
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let half = W::from(0.5).unwrap();
+        let step = W::pi() / W::from(m).unwrap();
+        let w = (0..m)
+            .map(|n| Float::sin(step * (W::from(n).unwrap() + half)))
+            .collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn cosine_3() {
+        // from scipy.signal.windows import cosine
+        // cosine(3)
+        let h = Cosine::new(3, true);
+        let expected = [5.00000000e-01, 1.00000000e+00, 5.00000000e-01].into();
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn cosine_4() {
+        // from scipy.signal.windows import cosine
+        // cosine(4)
+        let h = Cosine::new(4, true);
+        let expected = [
+            3.82683432e-01,
+            9.23879533e-01,
+            9.23879533e-01,
+            3.82683432e-01,
+        ]
+        .into();
+        assert_vec_eq(expected, h.get_window());
+
+        // cosine(4, False)
+        let h = Cosine::new(4, false);
+        let expected = [
+            3.09016994e-01,
+            8.09016994e-01,
+            1.00000000e+00,
+            8.09016994e-01,
+        ]
+        .into();
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn cosine_10() {
+        // from scipy.signal.windows import cosine
+        // cosine(10)
+        let h = Cosine::new(10, true);
+        let expected = [
+            1.56434465e-01,
+            4.53990500e-01,
+            core::f64::consts::FRAC_1_SQRT_2,
+            8.91006524e-01,
+            9.87688341e-01,
+            9.87688341e-01,
+            8.91006524e-01,
+            core::f64::consts::FRAC_1_SQRT_2,
+            4.53990500e-01,
+            1.56434465e-01,
+        ]
+        .into();
+        assert_vec_eq(expected, h.get_window());
+
+        // cosine(10, False)
+        let h = Cosine::new(10, false);
+        let expected = [
+            1.42314838e-01,
+            4.15415013e-01,
+            6.54860734e-01,
+            8.41253533e-01,
+            9.59492974e-01,
+            1.00000000e+00,
+            9.59492974e-01,
+            8.41253533e-01,
+            6.54860734e-01,
+            4.15415013e-01,
+        ]
+        .into();
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn cosine_single_point() {
+        // from scipy.signal.windows import cosine
+        // cosine(1)
+        for sym in [true, false] {
+            let w: Vec<f64> = Cosine::new(1, sym).get_window();
+            assert_eq!(w, vec![1.]);
+        }
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
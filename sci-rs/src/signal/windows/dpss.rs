@@ -0,0 +1,259 @@
+use super::{extend, len_guard, truncate};
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use nalgebra::{DMatrix, SymmetricEigen};
+
+/// Normalization of a [Dpss] window.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DpssNorm {
+    /// Unit energy, as returned by the eigensolver. This is `norm=2` in scipy.
+    L2,
+    /// Maximum of 1, scaled by `M^2 / (M^2 + NW)` for even `M` to approximate the
+    /// [DpssNorm::Subsample] correction.
+    #[default]
+    Approximate,
+    /// Maximum of 1, scaled for even `M` so that the window would have a maximum of 1 if resampled
+    /// by a half-sample shift.
+    Subsample,
+}
+
+/// Collection of arguments for window `Dpss` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dpss<F>
+where
+    F: Real,
+{
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Standardized half bandwidth corresponding to `2*NW = BW/f0 = BW*M*dt` where `dt` is taken
+    /// as 1.
+    pub nw: F,
+    /// Normalization of the window.
+    pub norm: DpssNorm,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl<F> Dpss<F>
+where
+    F: Real,
+{
+    /// Returns a Dpss struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `nw` : float  
+    ///   Standardized half bandwidth corresponding to `2*NW = BW/f0 = BW*M*dt` where `dt` is
+    ///   taken as 1. Must be positive and less than `M / 2`.
+    /// * `norm` : [DpssNorm]  
+    ///   Normalization of the window, [DpssNorm::Approximate] by default in scipy.
+    /// * `sym`:  
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, nw: F, norm: DpssNorm, sym: bool) -> Self {
+        Dpss { m, nw, norm, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, W> GetWindow<W> for Dpss<F>
+where
+    F: Real,
+    W: Real + Float + RealField,
+{
+    /// Return the first Discrete Prolate Spheroidal Sequence (DPSS), or Slepian sequence.
+    ///
+    /// # Parameters
+    /// `self`: [Dpss]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window, normalized as per [Dpss::norm].
+    ///
+    /// # Notes
+    /// The DPSS is the sequence whose energy is maximally concentrated in the band `[-W, W]`, with
+    /// `W = NW / M`. It is the eigenvector of the largest eigenvalue of a symmetric tridiagonal
+    /// matrix, which is solved for with a dense symmetric eigensolver. By convention, the sign of
+    /// the sequence is chosen to give it a positive sum. [[1]]
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{Dpss, DpssNorm, GetWindow};
+    /// let window: Vec<f64> = Dpss::new(51, 2.5, DpssNorm::Approximate, true).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.dpss(51, 2.5)
+    /// ```
+    ///
+    /// # References
+    /// [[1]] Percival DB, Walden WT. Spectral Analysis for Physical Applications: Multitaper and
+    /// Conventional Univariate Techniques. Cambridge University Press; 1993.  
+    /// [[2]] Scipy,
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.dpss.html>
+    ///
+    /// [1]: #references
+    /// [2]: #references
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let two = W::from(2).unwrap();
+        let m_f = W::from(m).unwrap();
+        let nw = W::from(self.nw).unwrap();
+        let bw = nw / m_f;
+        let cos_bw = Float::cos(two * W::pi() * bw);
+        let tridiagonal = DMatrix::from_fn(m, m, |i, j| {
+            if i == j {
+                Float::powi((m_f - W::one() - two * W::from(i).unwrap()) / two, 2) * cos_bw
+            } else if i + 1 == j || j + 1 == i {
+                let n = W::from(i.max(j)).unwrap();
+                n * (m_f - n) / two
+            } else {
+                W::zero()
+            }
+        });
+        let eigen = SymmetricEigen::new(tridiagonal);
+        let (k, _) = eigen.eigenvalues.iter().enumerate().fold(
+            (0, W::neg_infinity()),
+            |(k, max), (i, &ev)| {
+                if ev > max {
+                    (i, ev)
+                } else {
+                    (k, max)
+                }
+            },
+        );
+        let mut w: Vec<W> = eigen.eigenvectors.column(k).iter().copied().collect();
+
+        // The first taper is symmetric, so is given a positive average.
+        if w.iter().fold(W::zero(), |sum, &wi| sum + wi) < W::zero() {
+            w.iter_mut().for_each(|wi| *wi = -*wi);
+        }
+
+        if self.norm != DpssNorm::L2 {
+            let max = w.iter().copied().fold(W::neg_infinity(), Float::max);
+            let correction = if m % 2 == 1 {
+                W::one()
+            } else if self.norm == DpssNorm::Approximate {
+                m_f * m_f / (m_f * m_f + nw)
+            } else {
+                // Real part of the spectrum shifted by half a sample, summed to give the value
+                // at the shifted peak.
+                let normalized = || w.iter().map(|&wi| wi / max);
+                let mut total = normalized().fold(W::zero(), |sum, wi| sum + wi);
+                for k in 1..=m / 2 {
+                    let k_f = W::from(k).unwrap();
+                    let phase = W::pi() * (W::one() - W::one() / m_f) * k_f;
+                    let re = normalized().enumerate().fold(W::zero(), |sum, (n, wi)| {
+                        let theta = two * W::pi() * k_f * W::from(n).unwrap() / m_f;
+                        sum + wi * Float::cos(phase - theta)
+                    });
+                    total += two * re;
+                }
+                m_f / total
+            };
+            w.iter_mut().for_each(|wi| *wi = *wi / max * correction);
+        }
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn dpss_first_taper() {
+        // from scipy.signal.windows import dpss
+        // w = dpss(64, 2.5)
+        // w[[0, 1, 8, 16, 31, 32, 63]]
+        let w: Vec<f64> = Dpss::new(64, 2.5, DpssNorm::Approximate, true).get_window();
+        assert_eq!(w.len(), 64);
+        let expected = [
+            (0, 0.005608457340452715),
+            (1, 0.010275143779176344),
+            (8, 0.10824079832636027),
+            (16, 0.4156831810187485),
+            (31, 0.9993900207392948),
+            (32, 0.9993900207392671),
+            (63, 0.005608457340407573),
+        ];
+        for (i, e) in expected {
+            assert_abs_diff_eq!(w[i], e, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn dpss_norms() {
+        // from scipy.signal.windows import dpss
+        // dpss(64, 2.5, norm='subsample')[[0, 1, 2, 31]]
+        let w: Vec<f64> = Dpss::new(64, 2.5, DpssNorm::Subsample, true).get_window();
+        let expected = [
+            (0, 0.005607039572092851),
+            (1, 0.01027254631380238),
+            (2, 0.016677792835165104),
+            (31, 0.9991373837903875),
+        ];
+        for (i, e) in expected {
+            assert_abs_diff_eq!(w[i], e, epsilon = 1e-9);
+        }
+
+        // dpss(64, 2.5, norm=2)[[0, 1, 31]]
+        let w: Vec<f64> = Dpss::new(64, 2.5, DpssNorm::L2, true).get_window();
+        let expected = [
+            (0, 0.001228970994060645),
+            (1, 0.002251573453778126),
+            (31, 0.21899450645426816),
+        ];
+        for (i, e) in expected {
+            assert_abs_diff_eq!(w[i], e, epsilon = 1e-9);
+        }
+        assert_abs_diff_eq!(w.iter().map(|wi| wi * wi).sum::<f64>(), 1., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn dpss_odd_and_periodic() {
+        // from scipy.signal.windows import dpss
+        // dpss(9, 2.)
+        let expected = vec![
+            0.07409072, 0.27352337, 0.58217418, 0.87726573, 1., 0.87726573, 0.58217418, 0.27352337,
+            0.07409072,
+        ];
+        assert_vec_eq(
+            expected.clone(),
+            Dpss::new(9, 2., DpssNorm::Approximate, true).get_window(),
+        );
+
+        // dpss(8, 2., sym=False)
+        assert_vec_eq(
+            expected[..8].to_vec(),
+            Dpss::new(8, 2., DpssNorm::Approximate, false).get_window(),
+        );
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use super::{extend, len_guard, truncate};
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Exponential` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exponential<F>
+where
+    F: Real,
+{
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Position of the peak of the window, defaulting to the middle of the window if `None`.
+    ///
+    /// Only valid for periodic windows.
+    pub center: Option<F>,
+    /// Decay parameter, the distance over which the window decays by a factor of `e`.
+    pub tau: F,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl<F> Exponential<F>
+where
+    F: Real,
+{
+    /// Returns an Exponential struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `center` : float, optional  
+    ///   Parameter defining the center location of the window function. The default value if
+    ///   not given is `center = (M-1) / 2`. This parameter must take its default value for
+    ///   symmetric windows.
+    /// * `tau` : float  
+    ///   Parameter defining the decay. For `center = 0` use `tau = -(M-1) / ln(x)` if `x` is the
+    ///   fraction of the window remaining at the end.
+    /// * `sym`:  
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, center: Option<F>, tau: F, sym: bool) -> Self {
+        Exponential {
+            m,
+            center,
+            tau,
+            sym,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, W> GetWindow<W> for Exponential<F>
+where
+    F: Real,
+    W: Real + Float,
+{
+    /// Return an exponential (or Poisson) window.
+    ///
+    /// # Parameters
+    /// `self`: [Exponential]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window, with the maximum value normalized to 1 (though the value 1 does not appear
+    ///     if `M` is even and `sym` is True).
+    ///
+    /// # Panics
+    /// If `center` is given for a symmetric window. Use [try_get_window](super::try_get_window)
+    /// to have this reported as an error instead.
+    ///
+    /// # Notes
+    /// The Exponential window is defined as  
+    /// $$w(n) = e^{-|n-center| / \tau}$$
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{Exponential, GetWindow};
+    /// let window: Vec<f64> = Exponential::new(51, None, 3., true).get_window();
+    /// let asymmetric: Vec<f64> = Exponential::new(51, Some(0.), 10., false).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.exponential(51, tau=3.0)
+    /// asymmetric = signal.windows.exponential(51, 0, 10, False)
+    /// ```
+    ///
+    /// # References
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.exponential.html>
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        assert!(
+            !(self.sym && self.center.is_some()),
+            "If sym is true, center must be None."
+        );
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let center = match self.center {
+            Some(center) => W::from(center).unwrap(),
+            None => (W::from(m).unwrap() - W::one()) / W::from(2).unwrap(),
+        };
+        let tau = W::from(self.tau).unwrap();
+        let w = (0..m)
+            .map(|n| Float::exp(-Float::abs(W::from(n).unwrap() - center) / tau))
+            .collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn exponential_default_center_sym() {
+        // from scipy.signal.windows import exponential
+        // exponential(8, tau=3.)
+        let h = Exponential::new(8, None, 3., true);
+        let expected = vec![
+            0.31140322, 0.43459821, 0.60653066, 0.84648172, 0.84648172, 0.60653066, 0.43459821,
+            0.31140322,
+        ];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn exponential_default_center_periodic() {
+        // from scipy.signal.windows import exponential
+        // exponential(8, tau=1., sym=False)
+        let h = Exponential::new(8, None, 1., false);
+        let expected = vec![
+            0.01831564, 0.04978707, 0.13533528, 0.36787944, 1., 0.36787944, 0.13533528, 0.04978707,
+        ];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn exponential_explicit_center() {
+        // from scipy.signal.windows import exponential
+        // exponential(8, 0, 2., False)
+        let h = Exponential::new(8, Some(0.), 2., false);
+        let expected = vec![
+            1., 0.60653066, 0.36787944, 0.22313016, 0.13533528, 0.082085, 0.04978707, 0.03019738,
+        ];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    #[should_panic]
+    fn exponential_rejects_center_when_sym() {
+        let _: Vec<f64> = Exponential::new(8, Some(0.), 2., true).get_window();
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use super::GeneralCosine;
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Collection of arguments for window `Flattop` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flattop {
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl Flattop {
+    /// Returns a Flattop struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `sym`: bool   
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, sym: bool) -> Self {
+        Flattop { m, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<W> GetWindow<W> for Flattop
+where
+    W: Real + Float + RealField,
+{
+    /// Return a flat top window.
+    ///
+    /// # Parameters
+    /// * `M` : int  
+    ///   Number of points in the output window. If zero, an empty array is returned. An
+    ///   exception is thrown when it is negative.
+    /// * `sym` : bool, optional  
+    ///   When True (default), generates a symmetric window, for use in filter
+    ///   design.  
+    ///   When False, generates a periodic window, for use in spectral analysis.
+    ///
+    /// # Returns
+    /// `w` : ndarray  
+    ///     The window, with the maximum value normalized to 1 (though the value 1 does not appear
+    ///     if `M` is even and `sym` is True).
+    ///
+    /// # Notes
+    /// Flat top windows are used for taking accurate measurements of signal amplitude in the
+    /// frequency domain, with minimal scalloping error from the center of a frequency bin to its
+    /// edges, compared to others. This is a 5th-order cosine window, with the 5 terms optimized
+    /// to make the main lobe maximally flat. [[1]] The window takes negative values near its
+    /// edges.
+    ///
+    /// # References
+    /// [[1]] D'Antona, Gabriele, and A. Ferrero, "Digital Signal Processing for Measurement
+    /// Systems", Springer Media, 2006, p. 70 :doi:`10.1007/0-387-28666-7`.  
+    /// [[2]] Scipy,
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.flattop.html>
+    ///
+    /// Examples
+    /// --------
+    /// ```custom,{class=language-python}
+    /// >>> from scipy import signal
+    /// >>> window = signal.windows.flattop(51)
+    /// ```
+    ///
+    /// The equivalent is:
+    /// ```
+    /// use sci_rs::signal::windows::{Flattop, GetWindow};
+    /// let window: Vec<f64> = Flattop::new(51, true).get_window();
+    /// ```
+    ///
+    /// [1]: #references
+    /// [2]: #references
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        GeneralCosine::<W>::new(
+            self.m,
+            [
+                0.21557895,
+                0.41663158,
+                0.277263158,
+                0.083578947,
+                0.006947368,
+            ]
+            .map(|f| W::from(f).unwrap())
+            .into_iter()
+            .collect(),
+            self.sym,
+        )
+        .get_window()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn flattop_periodic() {
+        // from scipy.signal.windows import flattop
+        // flattop(8, False)
+        let h = Flattop::new(8, false);
+        let expected = [
+            -4.21051000e-04,
+            -2.68721933e-02,
+            -5.47368400e-02,
+            4.44135357e-01,
+            1.00000000e+00,
+            4.44135357e-01,
+            -5.47368400e-02,
+            -2.68721933e-02,
+        ]
+        .into();
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn flattop_negative_near_edges() {
+        // from scipy.signal.windows import flattop
+        // flattop(9)
+        let w: Vec<f64> = Flattop::new(9, true).get_window();
+        assert_eq!(w.len(), 9);
+        assert!(w[..3].iter().chain(&w[6..]).all(|wi| *wi < 0.));
+        assert_abs_diff_eq!(w[4], 1., epsilon = 1e-6);
+        assert_abs_diff_eq!(w[0], w[8], epsilon = 1e-15);
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use super::{extend, len_guard, truncate};
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Gaussian` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gaussian<F>
+where
+    F: Real,
+{
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// The standard deviation, σ.
+    pub std: F,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl<F> Gaussian<F>
+where
+    F: Real,
+{
+    /// Returns a Gaussian struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `std` : float  
+    ///   The standard deviation, σ.
+    /// * `sym`:  
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, std: F, sym: bool) -> Self {
+        Gaussian { m, std, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, W> GetWindow<W> for Gaussian<F>
+where
+    F: Real,
+    W: Real + Float,
+{
+    /// Return a Gaussian window.
+    ///
+    /// # Parameters
+    /// `self`: [Gaussian]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window, with the maximum value normalized to 1 (though the value 1 does not appear
+    ///     if `M` is even and `sym` is True).
+    ///
+    /// # Notes
+    /// The Gaussian window is defined as  
+    /// $$w(n) = e^{ -\frac{1}{2}\left(\frac{n}{\sigma}\right)^2 }$$  
+    /// with `n` centered on the middle of the window.
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{Gaussian, GetWindow};
+    /// let window: Vec<f64> = Gaussian::new(51, 7., true).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.gaussian(51, std=7)
+    /// ```
+    ///
+    /// # References
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.gaussian.html>
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let two = W::from(2).unwrap();
+        let center = (W::from(m).unwrap() - W::one()) / two;
+        let std = W::from(self.std).unwrap();
+        let sig2 = two * std * std;
+        let w = (0..m)
+            .map(|k| {
+                let n = W::from(k).unwrap() - center;
+                Float::exp(-n * n / sig2)
+            })
+            .collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn gaussian_scipy_example() {
+        // from scipy.signal.windows import gaussian
+        // w = gaussian(51, std=7)
+        // w[[0, 10, 20, 25, 30, 40, 50]]
+        let w: Vec<f64> = Gaussian::new(51, 7., true).get_window();
+        assert_eq!(w.len(), 51);
+        let expected = [
+            (0, 1.69927937e-03),
+            (10, 1.00668900e-01),
+            (20, 7.74837429e-01),
+            (25, 1.),
+            (30, 7.74837429e-01),
+            (40, 1.00668900e-01),
+            (50, 1.69927937e-03),
+        ];
+        for (i, e) in expected {
+            assert_abs_diff_eq!(w[i], e, epsilon = 1e-9);
+        }
+
+        // The peak is exactly one, at the center.
+        let peak = w.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(peak, 1.);
+        assert_eq!(w[25], 1.);
+    }
+
+    #[test]
+    fn gaussian_even_sym() {
+        // from scipy.signal.windows import gaussian
+        // gaussian(8, 2.)
+        let h = Gaussian::new(8, 2., true);
+        let expected = vec![
+            0.21626517, 0.45783336, 0.7548396, 0.96923323, 0.96923323, 0.7548396, 0.45783336,
+            0.21626517,
+        ];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn gaussian_periodic() {
+        // from scipy.signal.windows import gaussian
+        // gaussian(8, 1.5, False)
+        let h = Gaussian::new(8, 1.5, false);
+        let expected = vec![
+            0.0285655, 0.13533528, 0.41111229, 0.8007374, 1., 0.8007374, 0.41111229, 0.13533528,
+        ];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
@@ -86,7 +86,7 @@ where
     #[cfg(feature = "alloc")]
     fn get_window(&self) -> Vec<W> {
         if len_guard(self.m) {
-            return Vec::<W>::new();
+            return vec![W::one(); self.m];
         }
         let (m, needs_trunc) = extend(self.m, self.sym);
 
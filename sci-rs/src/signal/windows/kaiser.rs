@@ -175,11 +175,11 @@ where
     #[cfg(feature = "alloc")]
     fn get_window(&self) -> Vec<W> {
         if len_guard(self.m) {
-            return Vec::<W>::new();
+            return vec![W::one(); self.m];
         }
         let (m, needs_trunc) = extend(self.m, self.sym);
         let n = (0..m);
-        let alpha = W::from(self.m - 1).unwrap() / W::from(2).unwrap();
+        let alpha = W::from(m - 1).unwrap() / W::from(2).unwrap();
         let beta = W::from(self.beta).unwrap();
         // let w: Vec<W> = n
         //     .map(|ni| W::from(ni).unwrap() - alpha)
@@ -187,14 +187,14 @@ where
         //     .map(|ni| (W::one() - ni).sqrt())
         //     .map(|ni| i0(beta * ni) / i0(beta))
         //     .collect();
+        // i0(beta * r) / i0(beta) is evaluated as i0e(beta * r) / i0e(beta) * exp(beta * (r - 1)),
+        // which stays finite for large beta where i0 alone overflows.
         let w: Vec<W> = n
             .map(|ni| {
-                (beta
-                    * (W::one()
-                        - ((W::from(ni).unwrap() - alpha) / alpha).powf(W::from(2).unwrap()))
-                    .sqrt())
-                .i0()
-                    / (beta.i0())
+                let r = (W::one()
+                    - ((W::from(ni).unwrap() - alpha) / alpha).powf(W::from(2).unwrap()))
+                .sqrt();
+                (beta * r).i0e() / beta.i0e() * (beta * (r - W::one())).exp()
             })
             .collect();
         truncate(w, needs_trunc)
@@ -220,6 +220,21 @@ mod tests {
         assert_vec_eq(expected, k.get_window());
     }
 
+    #[test]
+    fn kaiser_large_beta() {
+        // from scipy.signal.windows import kaiser
+        // kaiser(9, beta = 1000)
+        // i0(1000) overflows, so the window must be normalized through i0e.
+        let w: Vec<f64> = Kaiser::new(9, 1000., true).get_window();
+        assert!(w.iter().all(|x| x.is_finite()));
+        assert_abs_diff_eq!(w[4], 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(w[3] / 1.645701254e-14, 1., epsilon = 1e-8);
+        assert_abs_diff_eq!(w[2] / 7.027732782e-59, 1., epsilon = 1e-8);
+        assert_abs_diff_eq!(w[1] / 1.132661465e-147, 1., epsilon = 1e-8);
+        assert_eq!(w[0], 0.);
+        assert_eq!(w[..4], w[5..].iter().rev().copied().collect::<Vec<_>>()[..]);
+    }
+
     #[track_caller]
     fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
         for (a, b) in a.into_iter().zip(b) {
@@ -0,0 +1,186 @@
+use crate::special::Bessel;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::{GetWindow, Kaiser};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Collection of arguments for window `KaiserBesselDerived` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KaiserBesselDerived<F>
+where
+    F: Real,
+{
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    /// Otherwise, it must be even.
+    pub m: usize,
+    /// Kaiser window shape parameter.
+    pub beta: F,
+    /// Whether the window is symmetric.
+    ///
+    /// The window is only defined for symmetric shapes, so this must be true.
+    pub sym: bool,
+}
+
+impl<F> KaiserBesselDerived<F>
+where
+    F: Real,
+{
+    /// Returns a KaiserBesselDerived struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned. Otherwise,
+    ///   it must be even.
+    /// * `beta` : float  
+    ///   Kaiser window shape parameter.
+    /// * `sym`:  
+    ///   Must be true, as the window is only defined for symmetric shapes.
+    pub fn new(m: usize, beta: F, sym: bool) -> Self {
+        KaiserBesselDerived { m, beta, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, W> GetWindow<W> for KaiserBesselDerived<F>
+where
+    F: Real,
+    W: Real + Float + Bessel,
+{
+    /// Return a Kaiser-Bessel derived window.
+    ///
+    /// # Parameters
+    /// `self`: [KaiserBesselDerived]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window, normalized to fulfil the Princen-Bradley condition.
+    ///
+    /// # Panics
+    /// If `m` is odd or `sym` is false. Use [try_get_window](super::try_get_window) to have this
+    /// reported as an error instead.
+    ///
+    /// # Notes
+    /// It is designed to be suitable for use with the modified discrete cosine transform (MDCT)
+    /// and is mainly used in audio signal processing and audio coding. Each half of the window is
+    /// the square root of the normalized cumulative sum of a [Kaiser] window of length `M/2 + 1`,
+    /// so that overlapping halves satisfy `w[n]^2 + w[n + M/2]^2 = 1`. [[1]]
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{GetWindow, KaiserBesselDerived};
+    /// let window: Vec<f64> = KaiserBesselDerived::new(50, 9., true).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.kaiser_bessel_derived(50, beta=9)
+    /// ```
+    ///
+    /// # References
+    /// [[1]] Bosi, Marina, and Richard E. Goldberg. Introduction to Digital Audio Coding and
+    /// Standards. Dordrecht: Kluwer, 2003.  
+    /// [[2]] Scipy,
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.kaiser_bessel_derived.html>
+    ///
+    /// [1]: #references
+    /// [2]: #references
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        assert!(
+            self.sym,
+            "Kaiser-Bessel Derived windows are only defined for symmetric shapes."
+        );
+        if self.m == 0 {
+            return Vec::new();
+        }
+        assert!(
+            self.m.is_multiple_of(2),
+            "Kaiser-Bessel Derived windows are only defined for even number of points."
+        );
+
+        let kaiser: Vec<W> = Kaiser::new(self.m / 2 + 1, self.beta, true).get_window();
+        let csum: Vec<W> = kaiser
+            .iter()
+            .scan(W::zero(), |sum, &k| {
+                *sum = *sum + k;
+                Some(*sum)
+            })
+            .collect();
+        let total = csum[csum.len() - 1];
+        let half: Vec<W> = csum[..csum.len() - 1]
+            .iter()
+            .map(|&c| Float::sqrt(c / total))
+            .collect();
+
+        half.iter().chain(half.iter().rev()).copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn kaiser_bessel_derived_10() {
+        use core::f64::consts::FRAC_1_SQRT_2;
+
+        // from scipy.signal.windows import kaiser_bessel_derived
+        // kaiser_bessel_derived(10, beta=8)
+        let w: Vec<f64> = KaiserBesselDerived::new(10, 8., true).get_window();
+        let expected = [
+            0.032770784525445656,
+            0.32435071615866967,
+            FRAC_1_SQRT_2,
+            0.9459368969055801,
+            0.9994628935991505,
+            0.9994628935991505,
+            0.9459368969055801,
+            FRAC_1_SQRT_2,
+            0.32435071615866967,
+            0.032770784525445656,
+        ];
+        assert_eq!(w.len(), expected.len());
+        for (w, e) in w.iter().zip(expected) {
+            assert_abs_diff_eq!(*w, e, epsilon = 1e-12);
+        }
+
+        // Princen-Bradley condition.
+        for n in 0..5 {
+            assert_abs_diff_eq!(w[n] * w[n] + w[n + 5] * w[n + 5], 1., epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn kaiser_bessel_derived_8() {
+        // from scipy.signal.windows import kaiser_bessel_derived
+        // kaiser_bessel_derived(8, beta=4)
+        let w: Vec<f64> = KaiserBesselDerived::new(8, 4., true).get_window();
+        let expected = [
+            0.19027811, 0.54350958, 0.83940296, 0.98173023, 0.98173023, 0.83940296, 0.54350958,
+            0.19027811,
+        ];
+        assert_eq!(w.len(), expected.len());
+        for (w, e) in w.iter().zip(expected) {
+            assert_abs_diff_eq!(*w, e, epsilon = 1e-6);
+        }
+
+        let w: Vec<f64> = KaiserBesselDerived::new(0, 4., true).get_window();
+        assert!(w.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn kaiser_bessel_derived_rejects_odd_length() {
+        let _: Vec<f64> = KaiserBesselDerived::new(9, 8., true).get_window();
+    }
+
+    #[test]
+    #[should_panic]
+    fn kaiser_bessel_derived_rejects_periodic() {
+        let _: Vec<f64> = KaiserBesselDerived::new(10, 8., false).get_window();
+    }
+}
@@ -0,0 +1,161 @@
+use super::{extend, len_guard, truncate};
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Lanczos` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lanczos {
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl Lanczos {
+    /// Returns a Lanczos struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `sym`:
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, sym: bool) -> Self {
+        Lanczos { m, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<W> GetWindow<W> for Lanczos
+where
+    W: Real + Float + RealField,
+{
+    /// Return a Lanczos window, also known as a sinc window.
+    ///
+    /// # Parameters
+    /// `self`: [Lanczos]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window, with the maximum value normalized to 1 (though the value 1 does not appear
+    ///     if `M` is even and `sym` is True).
+    ///
+    /// # Notes
+    /// The Lanczos window is defined as  
+    /// $$w(n) = \mathrm{sinc}\left(\frac{2n}{M - 1} - 1\right)$$  
+    /// where $\mathrm{sinc}(x) = \sin(\pi x) / (\pi x)$ is the normalized sinc function. Only the
+    /// right half is computed, and mirrored, so that the window is exactly symmetric.
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{GetWindow, Lanczos};
+    /// let window: Vec<f64> = Lanczos::new(51, true).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.lanczos(51)
+    /// ```
+    ///
+    /// # References
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.lanczos.html>
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let two = W::from(2).unwrap();
+        let denom = W::from(m - 1).unwrap();
+        let right: Vec<W> = (m.div_ceil(2)..m)
+            .map(|n| {
+                let x = two * W::from(n).unwrap() / denom - W::one();
+                (x * W::pi()).sinc()
+            })
+            .collect();
+        let center = (m % 2 == 1).then(W::one);
+        let w = right
+            .iter()
+            .rev()
+            .copied()
+            .chain(center)
+            .chain(right.iter().copied())
+            .collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn lanczos_7() {
+        // from scipy.signal.windows import lanczos
+        // lanczos(7)
+        let h = Lanczos::new(7, true);
+        let expected = vec![0., 0.41349667, 0.82699334, 1., 0.82699334, 0.41349667, 0.];
+        assert_vec_eq(expected, h.get_window());
+
+        // lanczos(7, sym=False)
+        let h = Lanczos::new(7, false);
+        let expected = vec![
+            0., 0.34841057, 0.72410145, 0.96676639, 0.96676639, 0.72410145, 0.34841057,
+        ];
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn lanczos_8() {
+        use core::f64::consts::FRAC_2_PI;
+
+        // from scipy.signal.windows import lanczos
+        // lanczos(8)
+        let h = Lanczos::new(8, true);
+        let expected = vec![
+            0., 0.34841057, 0.72410145, 0.96676639, 0.96676639, 0.72410145, 0.34841057, 0.,
+        ];
+        assert_vec_eq(expected, h.get_window());
+
+        // lanczos(8, sym=False)
+        let h = Lanczos::new(8, false);
+        let expected = vec![
+            0., 0.30010544, FRAC_2_PI, 0.90031632, 1., 0.90031632, FRAC_2_PI, 0.30010544,
+        ];
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn lanczos_degenerate() {
+        for sym in [true, false] {
+            assert_eq!(
+                GetWindow::<f64>::get_window(&Lanczos::new(0, sym)),
+                Vec::<f64>::new()
+            );
+            assert_eq!(
+                GetWindow::<f64>::get_window(&Lanczos::new(1, sym)),
+                vec![1.]
+            );
+        }
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
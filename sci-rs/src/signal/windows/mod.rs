@@ -1,9 +1,11 @@
 use crate::special;
 use nalgebra::RealField;
 use num_traits::{real::Real, Float};
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 /// Corresponding window representation for tuple-structs of [Window] variants.
 #[cfg(feature = "alloc")]
@@ -62,22 +64,48 @@ fn truncate<W>(mut w: Vec<W>, needed: bool) -> Vec<W> {
 
 mod blackman;
 mod boxcar;
+#[cfg(feature = "std")]
+mod cache;
+mod chebwin;
+mod cosine;
+mod dpss;
+mod exponential;
+mod flattop;
+mod gaussian;
 mod general_cosine;
 mod general_gaussian;
 mod general_hamming;
 mod hamming;
 mod kaiser;
+mod kaiser_bessel_derived;
+mod lanczos;
 mod nuttall;
+mod parzen;
+mod taylor;
 mod triangle;
+mod tukey;
 pub use blackman::Blackman;
 pub use boxcar::Boxcar;
+#[cfg(feature = "std")]
+pub use cache::{CachedWindow, WindowCache};
+pub use chebwin::Chebwin;
+pub use cosine::Cosine;
+pub use dpss::{Dpss, DpssNorm};
+pub use exponential::Exponential;
+pub use flattop::Flattop;
+pub use gaussian::Gaussian;
 pub use general_cosine::GeneralCosine;
 pub use general_gaussian::GeneralGaussian;
 pub use general_hamming::GeneralHamming;
 pub use hamming::Hamming;
 pub use kaiser::Kaiser;
+pub use kaiser_bessel_derived::KaiserBesselDerived;
+pub use lanczos::Lanczos;
 pub use nuttall::Nuttall;
+pub use parzen::Parzen;
+pub use taylor::Taylor;
 pub use triangle::Triangle;
+pub use tukey::Tukey;
 
 /// This collects all structs that implement the [GetWindow] trait.  
 /// This allows for running `.get_window()` on the struct, which can then be, for example, used in
@@ -102,23 +130,37 @@ where
     Hamming(Hamming),
     // Hann,
     // Bartlett,
-    // Flattop,
-    // Parzen,
+    /// [Flattop] window.
+    Flattop(Flattop),
+    /// [Parzen] window.
+    Parzen(Parzen),
     // Bohman,
     // BlackmanHarris,
     /// [Nuttall] window.
     Nuttall(Nuttall),
     // BartHann,
-    // Cosine,
-    // Exponential,
-    // Tukey,
-    // Taylor,
-    // Lanczos,
+    /// [Cosine] window.
+    Cosine(Cosine),
+    /// [Exponential] window, also known as the Poisson window.
+    // Needs Center, Decay
+    Exponential(Exponential<F>),
+    /// [Tukey] window, also known as a tapered cosine window.
+    // Needs Taper Fraction
+    Tukey(Tukey<F>),
+    /// [Taylor] window.
+    // Needs Sidelobe Count, Sidelobe Level, Normalization
+    Taylor(Taylor<F>),
+    /// [Lanczos] window, also known as a sinc window.
+    Lanczos(Lanczos),
     /// [Kaiser] window.
     // Needs Beta
     Kaiser(Kaiser<F>),
-    // KaiserBesselDerived, // Needs Beta
-    // Gaussian, // Needs Standard Deviation
+    /// [KaiserBesselDerived] window, only defined for symmetric windows of even length.
+    // Needs Beta
+    KaiserBesselDerived(KaiserBesselDerived<F>),
+    /// [Gaussian] window.
+    // Needs Standard Deviation
+    Gaussian(Gaussian<F>),
     /// [GeneralCosine] window, a generic weighted sum of cosine term windows.
     // Needs Weighting Coefficients
     GeneralCosine(GeneralCosine<F>),
@@ -128,8 +170,12 @@ where
     /// [GeneralHamming] window.
     // Needs Window Coefficients.
     GeneralHamming(GeneralHamming<F>),
-    // Dpss, // Needs Normalized Half-Bandwidth.
-    // Chebwin, // Needs Attenuation.
+    /// [Dpss] window, the first Discrete Prolate Spheroidal Sequence.
+    // Needs Normalized Half-Bandwidth.
+    Dpss(Dpss<F>),
+    /// [Chebwin] window, the Dolph-Chebyshev window.
+    // Needs Attenuation.
+    Chebwin(Chebwin<F>),
 }
 
 impl<F, W> GetWindow<W> for Window<F>
@@ -143,15 +189,164 @@ where
             Window::Triangle(x) => x.get_window(),
             Window::Blackman(x) => x.get_window(),
             Window::Hamming(x) => x.get_window(),
+            Window::Flattop(x) => x.get_window(),
+            Window::Parzen(x) => x.get_window(),
             Window::Nuttall(x) => x.get_window(),
+            Window::Cosine(x) => x.get_window(),
+            Window::Exponential(x) => x.get_window(),
+            Window::Tukey(x) => x.get_window(),
+            Window::Taylor(x) => x.get_window(),
+            Window::Lanczos(x) => x.get_window(),
             Window::Kaiser(x) => x.get_window(),
+            Window::KaiserBesselDerived(x) => x.get_window(),
+            Window::Gaussian(x) => x.get_window(),
             Window::GeneralCosine(x) => x.get_window(),
             Window::GeneralGaussian(x) => x.get_window(),
             Window::GeneralHamming(x) => x.get_window(),
+            Window::Dpss(x) => x.get_window(),
+            Window::Chebwin(x) => x.get_window(),
+        }
+    }
+}
+
+/// Hashable descriptor of a [Window], for use as a key when caching windows.
+///
+/// Floating point parameters are stored by the bit pattern of their `f64` value, so two windows
+/// have equal keys exactly when they have the same type, length, symmetry and parameters. As
+/// with any bitwise comparison, `0.` and `-0.` give different keys.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowKey {
+    /// Name of the window type, as in scipy.
+    pub name: &'static str,
+    /// Number of points in the window.
+    pub m: usize,
+    /// Whether the window is symmetric.
+    pub sym: bool,
+    /// Bit patterns of the parameters of the window, in the order of its fields.
+    pub params: Vec<u64>,
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Window<F>
+where
+    F: Real,
+{
+    /// Returns a [WindowKey] describing this window, which unlike [Window] implements [Eq] and
+    /// [Hash](core::hash::Hash).
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::signal::windows::{get_window, GetWindowBuilder};
+    ///
+    /// let a = get_window(GetWindowBuilder::Kaiser { beta: 8. }, 16, None);
+    /// let b = get_window(GetWindowBuilder::Kaiser { beta: 8. }, 16, None);
+    /// assert_eq!(a.window_key(), b.window_key());
+    /// ```
+    pub fn window_key(&self) -> WindowKey {
+        let bits = |x: &F| x.to_f64().map_or(u64::MAX, f64::to_bits);
+        let (name, m, sym, params) = match self {
+            Window::Boxcar(w) => ("boxcar", w.m, w.sym, Vec::new()),
+            Window::Triangle(w) => ("triang", w.m, w.sym, Vec::new()),
+            Window::Blackman(w) => ("blackman", w.m, w.sym, Vec::new()),
+            Window::Hamming(w) => ("hamming", w.m, w.sym, Vec::new()),
+            Window::Flattop(w) => ("flattop", w.m, w.sym, Vec::new()),
+            Window::Parzen(w) => ("parzen", w.m, w.sym, Vec::new()),
+            Window::Nuttall(w) => ("nuttall", w.m, w.sym, Vec::new()),
+            Window::Cosine(w) => ("cosine", w.m, w.sym, Vec::new()),
+            Window::Exponential(w) => (
+                "exponential",
+                w.m,
+                w.sym,
+                w.center.iter().chain([&w.tau]).map(bits).collect(),
+            ),
+            Window::Tukey(w) => ("tukey", w.m, w.sym, vec![bits(&w.alpha)]),
+            Window::Taylor(w) => (
+                "taylor",
+                w.m,
+                w.sym,
+                vec![w.nbar as u64, bits(&w.sll), w.norm as u64],
+            ),
+            Window::Lanczos(w) => ("lanczos", w.m, w.sym, Vec::new()),
+            Window::Kaiser(w) => ("kaiser", w.m, w.sym, vec![bits(&w.beta)]),
+            Window::KaiserBesselDerived(w) => {
+                ("kaiser_bessel_derived", w.m, w.sym, vec![bits(&w.beta)])
+            }
+            Window::Gaussian(w) => ("gaussian", w.m, w.sym, vec![bits(&w.std)]),
+            Window::GeneralCosine(w) => {
+                ("general_cosine", w.m, w.sym, w.a.iter().map(bits).collect())
+            }
+            Window::GeneralGaussian(w) => (
+                "general_gaussian",
+                w.m,
+                w.sym,
+                vec![bits(&w.p), bits(&w.sigma)],
+            ),
+            Window::GeneralHamming(w) => ("general_hamming", w.m, w.sym, vec![bits(&w.alpha)]),
+            Window::Dpss(w) => ("dpss", w.m, w.sym, vec![bits(&w.nw), w.norm as u64]),
+            Window::Chebwin(w) => ("chebwin", w.m, w.sym, vec![bits(&w.at)]),
+        };
+        WindowKey {
+            name,
+            m,
+            sym,
+            params,
         }
     }
 }
 
+/// Scaling applied to a window by [Window::normalize].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowNorm {
+    /// The window as returned by [GetWindow::get_window].
+    #[default]
+    None,
+    /// Scale the window to a maximum magnitude of 1, as is wanted for overlap-add synthesis.
+    Peak,
+    /// Scale the window to unit energy, so that the sum of its squares is 1.
+    Energy,
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Window<F>
+where
+    F: Real,
+{
+    /// Returns the window, scaled as per `norm`.
+    ///
+    /// Windows which are all zeros, or empty, are returned unscaled.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::signal::windows::{get_window, GetWindowBuilder, WindowNorm};
+    ///
+    /// // The symmetric Hamming window of even length does not reach 1.
+    /// let window = get_window(GetWindowBuilder::<f64>::Hamming, 8, Some(false));
+    /// let w: Vec<f64> = window.normalize(WindowNorm::Peak);
+    /// assert_eq!(w[3], 1.);
+    ///
+    /// let w: Vec<f64> = window.normalize(WindowNorm::Energy);
+    /// assert!((w.iter().map(|wi| wi * wi).sum::<f64>() - 1.).abs() < 1e-12);
+    /// ```
+    pub fn normalize<W>(&self, norm: WindowNorm) -> Vec<W>
+    where
+        W: Real + Float + RealField + special::Bessel,
+    {
+        let mut w = self.get_window();
+        let scale = match norm {
+            WindowNorm::None => return w,
+            WindowNorm::Peak => w
+                .iter()
+                .fold(W::zero(), |max, &wi| Float::max(max, Float::abs(wi))),
+            WindowNorm::Energy => Float::sqrt(w.iter().fold(W::zero(), |sum, &wi| sum + wi * wi)),
+        };
+        if scale > W::zero() {
+            w.iter_mut().for_each(|wi| *wi /= scale);
+        }
+        w
+    }
+}
+
 /// This provides a set of enum variants that for use in [get_window].
 #[derive(Debug, Clone, PartialEq)] // Derive eq?
 pub enum GetWindowBuilder<'a, F>
@@ -169,25 +364,56 @@ where
     Hamming,
     // Hann,
     // Bartlett,
-    // Flattop,
-    // Parzen,
+    /// [Flattop] window.
+    Flattop,
+    /// [Parzen] window.
+    Parzen,
     // Bohman,
     // BlackmanHarris,
     /// [Nuttall] window.
     Nuttall,
     // BartHann,
-    // Cosine,
-    // Exponential,
-    // Tukey,
-    // Taylor,
-    // Lanczos,
+    /// [Cosine] window.
+    Cosine,
+    /// [Exponential] window, also known as the Poisson window.
+    Exponential {
+        /// Position of the peak, please refer to [Exponential].
+        center: Option<F>,
+        /// Decay parameter `τ`, please refer to [Exponential].
+        tau: F,
+    },
+    /// [Tukey] window, also known as a tapered cosine window.
+    Tukey {
+        /// Fraction of the window inside the cosine tapered region, please refer to [Tukey].
+        alpha: F,
+    },
+    /// [Taylor] window.
+    Taylor {
+        /// Number of nearly constant level sidelobes adjacent to the mainlobe, please refer to
+        /// [Taylor].
+        nbar: usize,
+        /// Suppression of the sidelobe level in dB, please refer to [Taylor].
+        sll: F,
+        /// Whether to normalize the window to 1 at its center, please refer to [Taylor].
+        norm: bool,
+    },
+    /// [Lanczos] window, also known as a sinc window.
+    Lanczos,
     /// [Kaiser] window.
     Kaiser {
         /// Shape parameter `β`, please refer to [Kaiser].
         beta: F,
     },
-    // KaiserBesselDerived, // Needs Beta
-    // Gaussian, // Needs Standard Deviation
+    /// [KaiserBesselDerived] window, which requires an even `Nx` and `fftbins` to be false.
+    KaiserBesselDerived {
+        /// Shape parameter `β`, please refer to [KaiserBesselDerived].
+        beta: F,
+    },
+    /// [Gaussian] window.
+    Gaussian {
+        /// The standard deviation, σ.
+        std: F,
+    },
     /// [GeneralCosine] window: Generic weighted sum of cosine term windows.
     GeneralCosine {
         /// Weighting Coefficients `a`, please refer to [GeneralCosine].
@@ -206,8 +432,17 @@ where
         /// Window coefficient, ɑ
         coefficient: F,
     },
-    // Dpss, // Needs Normalized Half-Bandwidth.
-    // Chebwin, // Needs Attenuation.
+    /// [Dpss] window, the first Discrete Prolate Spheroidal Sequence, with the
+    /// [DpssNorm::Approximate] normalization.
+    Dpss {
+        /// Normalized half-bandwidth `NW`, please refer to [Dpss].
+        nw: F,
+    },
+    /// [Chebwin] window, the Dolph-Chebyshev window.
+    Chebwin {
+        /// Attenuation of the sidelobes in dB, please refer to [Chebwin].
+        at: F,
+    },
 }
 
 /// Return a window of a given length and type.
@@ -237,25 +472,25 @@ where
 /// * [Hamming]
 // Hann,
 // Bartlett,
-// Flattop,
-// Parzen,
+/// * [Flattop]
+/// * [Parzen]
 // Bohman,
 // BlackmanHarris,
 /// * [Nuttall]
 // BartHann,
-// Cosine,
-// Exponential,
-// Tukey,
-// Taylor,
-// Lanczos,
+/// * [Cosine]
+/// * [Exponential] // Needs Center, Decay
+/// * [Tukey] // Needs Taper Fraction
+/// * [Taylor] // Needs Sidelobe Count, Sidelobe Level, Normalization
+/// * [Lanczos]
 /// * [Kaiser] // Needs Beta
-// KaiserBesselDerived, // Needs Beta
-// Gaussian, // Needs Standard Deviation
+/// * [KaiserBesselDerived] // Needs Beta
+/// * [Gaussian] // Needs Standard Deviation
 /// * [GeneralCosine]
 /// * [GeneralGaussian] // Needs Power, Width
 /// * [GeneralHamming] // Needs Window Coefficients.
-// Dpss, // Needs Normalized Half-Bandwidth.
-// Chebwin, // Needs Attenuation.
+/// * [Dpss] // Needs Normalized Half-Bandwidth.
+/// * [Chebwin] // Needs Attenuation.
 ///
 /// Examples
 /// -----
@@ -301,15 +536,61 @@ where
             m: nx,
             sym: !fftbins.unwrap_or(true),
         }),
+        GetWindowBuilder::Flattop => Window::Flattop(Flattop {
+            m: nx,
+            sym: !fftbins.unwrap_or(true),
+        }),
+        GetWindowBuilder::Parzen => Window::Parzen(Parzen {
+            m: nx,
+            sym: !fftbins.unwrap_or(true),
+        }),
         GetWindowBuilder::Nuttall => Window::Nuttall(Nuttall {
             m: nx,
             sym: !fftbins.unwrap_or(true),
         }),
+        GetWindowBuilder::Cosine => Window::Cosine(Cosine {
+            m: nx,
+            sym: !fftbins.unwrap_or(true),
+        }),
+        GetWindowBuilder::Exponential { center, tau } => Window::Exponential(Exponential {
+            m: nx,
+            center,
+            tau,
+            sym: !fftbins.unwrap_or(true),
+        }),
+        GetWindowBuilder::Tukey { alpha } => Window::Tukey(Tukey {
+            m: nx,
+            alpha,
+            sym: !fftbins.unwrap_or(true),
+        }),
+        GetWindowBuilder::Taylor { nbar, sll, norm } => Window::Taylor(Taylor {
+            m: nx,
+            nbar,
+            sll,
+            norm,
+            sym: !fftbins.unwrap_or(true),
+        }),
+        GetWindowBuilder::Lanczos => Window::Lanczos(Lanczos {
+            m: nx,
+            sym: !fftbins.unwrap_or(true),
+        }),
         GetWindowBuilder::Kaiser { beta } => Window::Kaiser(Kaiser {
             m: nx,
             beta,
             sym: !fftbins.unwrap_or(true),
         }),
+        GetWindowBuilder::KaiserBesselDerived { beta } => {
+            Window::KaiserBesselDerived(KaiserBesselDerived {
+                m: nx,
+                beta,
+                sym: !fftbins.unwrap_or(true),
+            })
+        }
+        GetWindowBuilder::Gaussian { std } => Window::Gaussian(Gaussian {
+            m: nx,
+            std,
+            sym: !fftbins.unwrap_or(true),
+        }),
         GetWindowBuilder::GeneralCosine { weights } => Window::GeneralCosine(GeneralCosine {
             m: nx,
             a: weights.into(),
@@ -330,5 +611,811 @@ where
                 sym: !fftbins.unwrap_or(true),
             })
         }
+        GetWindowBuilder::Dpss { nw } => Window::Dpss(Dpss {
+            m: nx,
+            nw,
+            norm: DpssNorm::Approximate,
+            sym: !fftbins.unwrap_or(true),
+        }),
+        GetWindowBuilder::Chebwin { at } => Window::Chebwin(Chebwin {
+            m: nx,
+            at,
+            sym: !fftbins.unwrap_or(true),
+        }),
+    }
+}
+
+/// Return a window of a given length and type, after validating its parameters.
+///
+/// This is [get_window], but returns an error instead of producing a wrong window, or panicking
+/// later, when the parameters of `window` are invalid or conflict with `fftbins`.
+///
+/// Parameters
+/// ----------
+/// * `window`: [GetWindowBuilder]  
+///   The type of window to create.
+/// * `Nx`: usize  
+///   The number of samples in the window.
+/// * `fftbins`: bool, optional  
+///   If True (default), create a "periodic" window. If False, create a "symmetric" window.
+///
+/// Errors
+/// ------
+/// * [Error::InvalidArg] if a window parameter is out of its domain, such as a non-positive
+///   [Gaussian] or [GeneralGaussian] width or an empty set of [GeneralCosine] weights, or if the
+///   window is not defined for `Nx`, as for an odd-length [KaiserBesselDerived] window.
+/// * [Error::ConflictArg] if the window or one of its parameters is only valid for one of the
+///   periodic and symmetric variants, but the other was requested through `fftbins`, as for a
+///   periodic [KaiserBesselDerived] window or an [Exponential] window with a `center`.
+///
+/// Examples
+/// -----
+/// ```
+/// use sci_rs::signal::windows::{try_get_window, GetWindowBuilder};
+///
+/// let window = try_get_window(GetWindowBuilder::GeneralGaussian { p: 1.5, width: 0. }, 8, None);
+/// assert!(window.is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn try_get_window<F>(
+    window: GetWindowBuilder<'_, F>,
+    nx: usize,
+    fftbins: Option<bool>,
+) -> Result<Window<F>>
+where
+    F: Real,
+{
+    match &window {
+        GetWindowBuilder::GeneralCosine { weights: [] } => {
+            return Err(Error::InvalidArg {
+                arg: "weights".into(),
+                reason: "At least one weighting coefficient is required.".into(),
+            });
+        }
+        GetWindowBuilder::Gaussian { std } if *std <= F::zero() => {
+            return Err(Error::InvalidArg {
+                arg: "std".into(),
+                reason: "Standard deviation must be positive.".into(),
+            });
+        }
+        GetWindowBuilder::GeneralGaussian { width, .. } if *width <= F::zero() => {
+            return Err(Error::InvalidArg {
+                arg: "width".into(),
+                reason: "Standard deviation must be positive.".into(),
+            });
+        }
+        GetWindowBuilder::KaiserBesselDerived { .. } if fftbins != Some(false) => {
+            return Err(Error::ConflictArg {
+                reason: "Kaiser-Bessel Derived windows are only defined for symmetric shapes."
+                    .into(),
+            });
+        }
+        GetWindowBuilder::KaiserBesselDerived { .. } if nx % 2 == 1 => {
+            return Err(Error::InvalidArg {
+                arg: "nx".into(),
+                reason: "Kaiser-Bessel Derived windows are only defined for even number of points."
+                    .into(),
+            });
+        }
+        GetWindowBuilder::Taylor { sll, .. } if *sll <= F::zero() => {
+            return Err(Error::InvalidArg {
+                arg: "sll".into(),
+                reason: "Sidelobe level must be positive.".into(),
+            });
+        }
+        GetWindowBuilder::Dpss { nw } if *nw <= F::zero() => {
+            return Err(Error::InvalidArg {
+                arg: "nw".into(),
+                reason: "NW must be positive.".into(),
+            });
+        }
+        GetWindowBuilder::Dpss { nw } if *nw >= F::from(nx).unwrap() / F::from(2).unwrap() => {
+            return Err(Error::InvalidArg {
+                arg: "nw".into(),
+                reason: "NW must be less than M/2.".into(),
+            });
+        }
+        GetWindowBuilder::Exponential {
+            center: Some(_), ..
+        } if fftbins == Some(false) => {
+            return Err(Error::ConflictArg {
+                reason: "Exponential window center must be None for symmetric windows.".into(),
+            });
+        }
+        _ => {}
+    }
+
+    Ok(get_window(window, nx, fftbins))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_get_window_rejects_invalid_parameters() {
+        assert!(matches!(
+            try_get_window(
+                GetWindowBuilder::GeneralGaussian { p: 1.5, width: 0. },
+                8,
+                Some(false)
+            ),
+            Err(Error::InvalidArg { .. })
+        ));
+        assert!(matches!(
+            try_get_window::<f64>(GetWindowBuilder::GeneralCosine { weights: &[] }, 8, None),
+            Err(Error::InvalidArg { .. })
+        ));
+        assert!(matches!(
+            try_get_window(GetWindowBuilder::Gaussian { std: -1. }, 8, None),
+            Err(Error::InvalidArg { .. })
+        ));
+        assert!(matches!(
+            try_get_window(
+                GetWindowBuilder::Taylor {
+                    nbar: 4,
+                    sll: -30.,
+                    norm: true
+                },
+                8,
+                None
+            ),
+            Err(Error::InvalidArg { .. })
+        ));
+        let kbd = GetWindowBuilder::KaiserBesselDerived { beta: 8. };
+        assert!(matches!(
+            try_get_window(kbd.clone(), 9, Some(false)),
+            Err(Error::InvalidArg { .. })
+        ));
+        assert_eq!(
+            try_get_window(kbd.clone(), 10, Some(false)),
+            Ok(get_window(kbd, 10, Some(false)))
+        );
+        for nw in [0., 4.] {
+            assert!(matches!(
+                try_get_window(GetWindowBuilder::Dpss { nw }, 8, None),
+                Err(Error::InvalidArg { .. })
+            ));
+        }
+        assert!(matches!(
+            try_get_window(
+                GetWindowBuilder::Exponential {
+                    center: Some(0.),
+                    tau: 2.
+                },
+                8,
+                Some(false)
+            ),
+            Err(Error::ConflictArg { .. })
+        ));
+        assert!(try_get_window(
+            GetWindowBuilder::Exponential {
+                center: Some(0.),
+                tau: 2.
+            },
+            8,
+            None
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn try_get_window_reports_fftbins_conflicts() {
+        // Kaiser-Bessel derived windows are only symmetric, whether the periodic variant is
+        // requested explicitly or by default.
+        let kbd = GetWindowBuilder::KaiserBesselDerived { beta: 8. };
+        for fftbins in [None, Some(true)] {
+            assert!(matches!(
+                try_get_window::<f64>(kbd.clone(), 10, fftbins),
+                Err(Error::ConflictArg { .. })
+            ));
+        }
+        // The conflict is reported ahead of the invalid length.
+        assert!(matches!(
+            try_get_window::<f64>(kbd, 9, Some(true)),
+            Err(Error::ConflictArg { .. })
+        ));
+    }
+
+    #[test]
+    fn try_get_window_matches_get_window() {
+        let builders = [
+            GetWindowBuilder::Hamming,
+            GetWindowBuilder::Kaiser { beta: 8. },
+            GetWindowBuilder::Exponential {
+                center: None,
+                tau: 3.,
+            },
+            GetWindowBuilder::GeneralGaussian { p: 1.5, width: 2. },
+            GetWindowBuilder::GeneralCosine {
+                weights: &[0.5, 0.5],
+            },
+            GetWindowBuilder::Chebwin { at: 60. },
+            GetWindowBuilder::Taylor {
+                nbar: 4,
+                sll: 30.,
+                norm: true,
+            },
+        ];
+        for builder in builders {
+            for fftbins in [None, Some(true), Some(false)] {
+                assert_eq!(
+                    try_get_window::<f64>(builder.clone(), 9, fftbins),
+                    Ok(get_window(builder.clone(), 9, fftbins))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn equal_builders_have_equal_window_keys() {
+        let builders = [
+            GetWindowBuilder::Boxcar,
+            GetWindowBuilder::Hamming,
+            GetWindowBuilder::Kaiser { beta: 8. },
+            GetWindowBuilder::Exponential {
+                center: None,
+                tau: 3.,
+            },
+            GetWindowBuilder::Exponential {
+                center: Some(4.5),
+                tau: 3.,
+            },
+            GetWindowBuilder::Tukey { alpha: 0.5 },
+            GetWindowBuilder::Taylor {
+                nbar: 4,
+                sll: 30.,
+                norm: true,
+            },
+            GetWindowBuilder::Taylor {
+                nbar: 4,
+                sll: 30.,
+                norm: false,
+            },
+            GetWindowBuilder::Lanczos,
+            GetWindowBuilder::Gaussian { std: 2. },
+            GetWindowBuilder::GeneralGaussian { p: 1.5, width: 2. },
+            GetWindowBuilder::GeneralCosine {
+                weights: &[0.5, 0.5],
+            },
+            GetWindowBuilder::GeneralHamming { coefficient: 0.5 },
+            GetWindowBuilder::Dpss { nw: 2.5 },
+            GetWindowBuilder::Chebwin { at: 100. },
+        ];
+        for (i, a) in builders.iter().enumerate() {
+            for (j, b) in builders.iter().enumerate() {
+                let key_a = get_window::<f64>(a.clone(), 9, None).window_key();
+                let key_b = get_window::<f64>(b.clone(), 9, None).window_key();
+                assert_eq!(i == j, key_a == key_b);
+            }
+        }
+
+        let key = |builder, nx, fftbins| get_window::<f64>(builder, nx, fftbins).window_key();
+        let kaiser = |beta| GetWindowBuilder::Kaiser { beta };
+        assert_ne!(key(kaiser(8.), 9, None), key(kaiser(8.5), 9, None));
+        assert_ne!(key(kaiser(8.), 9, None), key(kaiser(8.), 10, None));
+        assert_ne!(key(kaiser(8.), 9, None), key(kaiser(8.), 9, Some(false)));
+    }
+
+    /// Every implemented window that scipy also defines as periodic.
+    fn periodic_builders() -> [GetWindowBuilder<'static, f64>; 20] {
+        [
+            GetWindowBuilder::Boxcar,
+            GetWindowBuilder::Triangle,
+            GetWindowBuilder::Blackman,
+            GetWindowBuilder::Hamming,
+            GetWindowBuilder::Flattop,
+            GetWindowBuilder::Parzen,
+            GetWindowBuilder::Nuttall,
+            GetWindowBuilder::Cosine,
+            GetWindowBuilder::Exponential {
+                center: None,
+                tau: 3.,
+            },
+            GetWindowBuilder::Tukey { alpha: 0.5 },
+            GetWindowBuilder::Taylor {
+                nbar: 4,
+                sll: 30.,
+                norm: true,
+            },
+            GetWindowBuilder::Lanczos,
+            GetWindowBuilder::Kaiser { beta: 8. },
+            GetWindowBuilder::Gaussian { std: 2. },
+            GetWindowBuilder::GeneralCosine {
+                weights: &[0.5, 0.3, 0.2],
+            },
+            GetWindowBuilder::GeneralGaussian { p: 1.5, width: 2. },
+            GetWindowBuilder::GeneralHamming { coefficient: 0.6 },
+            GetWindowBuilder::Dpss { nw: 0.5 },
+            GetWindowBuilder::Chebwin { at: 60. },
+            GetWindowBuilder::Chebwin { at: 100. },
+        ]
+    }
+
+    #[test]
+    fn default_windows_are_periodic() {
+        // scipy.signal.get_window(name, m), which is periodic by default, for m = 7 and 8.
+        let expected = [
+            (
+                GetWindowBuilder::Boxcar,
+                [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+                [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            ),
+            (
+                GetWindowBuilder::Triangle,
+                [0.125, 0.375, 0.625, 0.875, 0.875, 0.625, 0.375],
+                [0.2, 0.4, 0.6, 0.8, 1.0, 0.8, 0.6, 0.4],
+            ),
+            (
+                GetWindowBuilder::Blackman,
+                [
+                    0.,
+                    0.09045342435,
+                    0.4591829575,
+                    0.9203636181,
+                    0.9203636181,
+                    0.4591829575,
+                    0.09045342435,
+                ],
+                [
+                    0.,
+                    0.06644660941,
+                    0.34,
+                    0.7735533906,
+                    1.0,
+                    0.7735533906,
+                    0.34,
+                    0.06644660941,
+                ],
+            ),
+            (
+                GetWindowBuilder::Hamming,
+                [
+                    0.08,
+                    0.2531946911,
+                    0.6423596296,
+                    0.9544456792,
+                    0.9544456792,
+                    0.6423596296,
+                    0.2531946911,
+                ],
+                [
+                    0.08,
+                    0.2147308807,
+                    0.54,
+                    0.8652691193,
+                    1.0,
+                    0.8652691193,
+                    0.54,
+                    0.2147308807,
+                ],
+            ),
+            (
+                GetWindowBuilder::Flattop,
+                [
+                    -0.000421051,
+                    -0.03684078115,
+                    0.01070371672,
+                    0.7808739149,
+                    0.7808739149,
+                    0.01070371672,
+                    -0.03684078115,
+                ],
+                [
+                    -0.000421051,
+                    -0.02687219329,
+                    -0.05473684,
+                    0.4441353573,
+                    1.000000003,
+                    0.4441353573,
+                    -0.05473684,
+                    -0.02687219329,
+                ],
+            ),
+            (
+                GetWindowBuilder::Parzen,
+                [
+                    0.00390625, 0.10546875, 0.47265625, 0.91796875, 0.91796875, 0.47265625,
+                    0.10546875,
+                ],
+                [
+                    0.002743484225,
+                    0.07407407407,
+                    0.341563786,
+                    0.7695473251,
+                    1.0,
+                    0.7695473251,
+                    0.341563786,
+                    0.07407407407,
+                ],
+            ),
+            (
+                GetWindowBuilder::Nuttall,
+                [
+                    0.0003628,
+                    0.03777576895,
+                    0.34272762,
+                    0.8918518611,
+                    0.8918518611,
+                    0.34272762,
+                    0.03777576895,
+                ],
+                [
+                    0.0003628,
+                    0.02520556652,
+                    0.2269824,
+                    0.7019582335,
+                    1.0,
+                    0.7019582335,
+                    0.2269824,
+                    0.02520556652,
+                ],
+            ),
+            (
+                GetWindowBuilder::Cosine,
+                [
+                    0.195090322,
+                    0.555570233,
+                    0.8314696123,
+                    0.9807852804,
+                    0.9807852804,
+                    0.8314696123,
+                    0.555570233,
+                ],
+                [
+                    0.1736481777,
+                    0.5,
+                    0.7660444431,
+                    0.9396926208,
+                    1.0,
+                    0.9396926208,
+                    0.7660444431,
+                    0.5,
+                ],
+            ),
+            (
+                GetWindowBuilder::Exponential {
+                    center: None,
+                    tau: 3.,
+                },
+                [
+                    0.3114032239,
+                    0.4345982085,
+                    0.6065306597,
+                    0.8464817249,
+                    0.8464817249,
+                    0.6065306597,
+                    0.4345982085,
+                ],
+                [
+                    0.2635971381,
+                    0.3678794412,
+                    0.513417119,
+                    0.7165313106,
+                    1.0,
+                    0.7165313106,
+                    0.513417119,
+                    0.3678794412,
+                ],
+            ),
+            (
+                GetWindowBuilder::Tukey { alpha: 0.5 },
+                [0., 0.611260467, 1.0, 1.0, 1.0, 1.0, 0.611260467],
+                [0., 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5],
+            ),
+            (
+                GetWindowBuilder::Taylor {
+                    nbar: 4,
+                    sll: 30.,
+                    norm: true,
+                },
+                [
+                    0.2793462998,
+                    0.5149598982,
+                    0.7973015281,
+                    0.9756107181,
+                    0.9756107181,
+                    0.7973015281,
+                    0.5149598982,
+                ],
+                [
+                    0.2718834924,
+                    0.4669063199,
+                    0.7246751122,
+                    0.9246558253,
+                    1.0,
+                    0.9246558253,
+                    0.7246751122,
+                    0.4669063199,
+                ],
+            ),
+            (
+                GetWindowBuilder::Lanczos,
+                [
+                    0.,
+                    0.3484105663,
+                    0.7241014498,
+                    0.9667663853,
+                    0.9667663853,
+                    0.7241014498,
+                    0.3484105663,
+                ],
+                [
+                    0.,
+                    0.3001054387,
+                    core::f64::consts::FRAC_2_PI,
+                    0.9003163162,
+                    1.0,
+                    0.9003163162,
+                    core::f64::consts::FRAC_2_PI,
+                    0.3001054387,
+                ],
+            ),
+            (
+                GetWindowBuilder::Kaiser { beta: 8. },
+                [
+                    0.002338830513,
+                    0.109195811,
+                    0.4871186843,
+                    0.9261577377,
+                    0.9261577377,
+                    0.4871186843,
+                    0.109195811,
+                ],
+                [
+                    0.002338830513,
+                    0.08273981505,
+                    0.3689727226,
+                    0.7887524466,
+                    1.0,
+                    0.7887524466,
+                    0.3689727226,
+                    0.08273981505,
+                ],
+            ),
+            (
+                GetWindowBuilder::Gaussian { std: 2. },
+                [
+                    0.2162651668,
+                    0.4578333618,
+                    0.754839602,
+                    0.9692332345,
+                    0.9692332345,
+                    0.754839602,
+                    0.4578333618,
+                ],
+                [
+                    0.1353352832,
+                    0.3246524674,
+                    0.6065306597,
+                    0.8824969026,
+                    1.0,
+                    0.8824969026,
+                    0.6065306597,
+                    0.3246524674,
+                ],
+            ),
+            (
+                GetWindowBuilder::GeneralCosine {
+                    weights: &[0.5, 0.3, 0.2],
+                },
+                [
+                    0.4,
+                    0.2684488727,
+                    0.3865625066,
+                    0.8949886207,
+                    0.8949886207,
+                    0.3865625066,
+                    0.2684488727,
+                ],
+                [
+                    0.4,
+                    0.2878679656,
+                    0.3,
+                    0.7121320344,
+                    1.0,
+                    0.7121320344,
+                    0.3,
+                    0.2878679656,
+                ],
+            ),
+            (
+                GetWindowBuilder::GeneralGaussian { p: 1.5, width: 2. },
+                [
+                    0.06858458349,
+                    0.3766034507,
+                    0.8098246793,
+                    0.9922179383,
+                    0.9922179383,
+                    0.8098246793,
+                    0.3766034507,
+                ],
+                [
+                    0.01831563889,
+                    0.1849813999,
+                    0.6065306597,
+                    0.9394130628,
+                    1.0,
+                    0.9394130628,
+                    0.6065306597,
+                    0.1849813999,
+                ],
+            ),
+            (
+                GetWindowBuilder::GeneralHamming { coefficient: 0.6 },
+                [
+                    0.2,
+                    0.3506040793,
+                    0.6890083736,
+                    0.9603875472,
+                    0.9603875472,
+                    0.6890083736,
+                    0.3506040793,
+                ],
+                [
+                    0.2,
+                    0.3171572875,
+                    0.6,
+                    0.8828427125,
+                    1.0,
+                    0.8828427125,
+                    0.6,
+                    0.3171572875,
+                ],
+            ),
+            (
+                GetWindowBuilder::Dpss { nw: 0.5 },
+                [
+                    0.7395859034,
+                    0.8602018978,
+                    0.9469161984,
+                    0.992248062,
+                    0.992248062,
+                    0.9469161984,
+                    0.8602018978,
+                ],
+                [
+                    0.7335917254,
+                    0.843913154,
+                    0.9285810096,
+                    0.9818311612,
+                    1.0,
+                    0.9818311612,
+                    0.9285810096,
+                    0.843913154,
+                ],
+            ),
+            (
+                GetWindowBuilder::Chebwin { at: 60. },
+                [
+                    0.06847555416,
+                    0.3032191617,
+                    0.6868466208,
+                    1.0,
+                    1.0,
+                    0.6868466208,
+                    0.3032191617,
+                ],
+                [
+                    0.05186856359,
+                    0.2271239336,
+                    0.5379172016,
+                    0.8604844374,
+                    1.0,
+                    0.8604844374,
+                    0.5379172016,
+                    0.2271239336,
+                ],
+            ),
+            (
+                GetWindowBuilder::Chebwin { at: 100. },
+                [
+                    0.0363836809,
+                    0.225355076,
+                    0.6241595403,
+                    1.0,
+                    1.0,
+                    0.6241595403,
+                    0.225355076,
+                ],
+                [
+                    0.02182740748,
+                    0.1445056275,
+                    0.4434685884,
+                    0.8207710627,
+                    1.0,
+                    0.8207710627,
+                    0.4434685884,
+                    0.1445056275,
+                ],
+            ),
+        ];
+        for (builder, odd, even) in expected {
+            for expected in [&odd[..], &even[..]] {
+                let m = expected.len();
+                let w: Vec<f64> = get_window(builder.clone(), m, None).get_window();
+                assert_eq!(w.len(), m);
+                for (w, e) in w.iter().zip(expected) {
+                    assert!((w - e).abs() < 1e-9, "{builder:?}, {m}: {w} != {e}");
+                }
+            }
+        }
+
+        // As in scipy, the periodic window of length M is the symmetric window of length M + 1
+        // without its last sample, and that is the default.
+        for builder in periodic_builders() {
+            let single: Vec<f64> = get_window(builder.clone(), 1, None).get_window();
+            assert_eq!(single, vec![1.]);
+            for m in [2, 3, 8, 9, 16] {
+                let default: Vec<f64> = get_window(builder.clone(), m, None).get_window();
+                let periodic: Vec<f64> = get_window(builder.clone(), m, Some(true)).get_window();
+                let symmetric: Vec<f64> =
+                    get_window(builder.clone(), m + 1, Some(false)).get_window();
+                assert_eq!(default, periodic, "{builder:?}, {m}");
+                assert_eq!(default, symmetric[..m], "{builder:?}, {m}");
+            }
+        }
+
+        // Kaiser-Bessel derived windows are only symmetric, so have no valid default.
+        let kbd = GetWindowBuilder::KaiserBesselDerived { beta: 8. };
+        assert!(try_get_window(kbd, 8, None).is_err());
+    }
+
+    #[test]
+    fn normalize_window() {
+        let builders = [
+            GetWindowBuilder::Hamming,
+            GetWindowBuilder::Kaiser { beta: 8. },
+            GetWindowBuilder::Gaussian { std: 2. },
+            GetWindowBuilder::Taylor {
+                nbar: 4,
+                sll: 30.,
+                norm: false,
+            },
+            GetWindowBuilder::Chebwin { at: 60. },
+        ];
+        for builder in builders {
+            for (nx, fftbins) in [(8, None), (8, Some(false)), (9, Some(false))] {
+                let window = get_window::<f64>(builder.clone(), nx, fftbins);
+                let w: Vec<f64> = window.get_window();
+                assert_eq!(window.normalize::<f64>(WindowNorm::None), w);
+
+                let peak: Vec<f64> = window.normalize(WindowNorm::Peak);
+                assert_eq!(peak.iter().copied().fold(f64::NEG_INFINITY, f64::max), 1.);
+
+                let energy: Vec<f64> = window.normalize(WindowNorm::Energy);
+                let sum_sq = energy.iter().map(|wi| wi * wi).sum::<f64>();
+                assert!((sum_sq - 1.).abs() < 1e-12, "{sum_sq}");
+
+                // Both are rescalings of the window.
+                let max = w.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let norm = w.iter().map(|wi| wi * wi).sum::<f64>().sqrt();
+                for ((p, e), wi) in peak.iter().zip(&energy).zip(&w) {
+                    assert!((p * max - wi).abs() < 1e-12);
+                    assert!((e * norm - wi).abs() < 1e-12);
+                }
+            }
+        }
+
+        let empty = get_window::<f64>(GetWindowBuilder::Hamming, 0, None);
+        assert_eq!(
+            empty.normalize::<f64>(WindowNorm::Energy),
+            Vec::<f64>::new()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn window_key_caches_windows() {
+        use std::collections::HashMap;
+
+        let mut cache: HashMap<WindowKey, Vec<f64>> = HashMap::new();
+        for beta in [8., 5., 8.] {
+            let window = get_window(GetWindowBuilder::Kaiser { beta }, 16, None);
+            cache
+                .entry(window.window_key())
+                .or_insert_with(|| window.get_window());
+        }
+        assert_eq!(cache.len(), 2);
     }
 }
@@ -0,0 +1,194 @@
+use super::{extend, len_guard, truncate};
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Parzen` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parzen {
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl Parzen {
+    /// Returns a Parzen struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `sym`:
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, sym: bool) -> Self {
+        Parzen { m, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<W> GetWindow<W> for Parzen
+where
+    W: Real + Float,
+{
+    /// Return a Parzen window.
+    ///
+    /// # Parameters
+    /// * `M` : int  
+    ///   Number of points in the output window. If zero, an empty array is returned. An
+    ///   exception is thrown when it is negative.
+    /// * `sym` : bool, optional  
+    ///   When True (default), generates a symmetric window, for use in filter  
+    ///   design.  
+    ///   When False, generates a periodic window, for use in spectral analysis.
+    ///
+    /// # Returns
+    /// `w` : ndarray  
+    ///     The window, with the maximum value normalized to 1 (though the value 1 does not appear
+    ///     if `M` is even and `sym` is True).
+    ///
+    /// # Notes
+    /// The Parzen window is a piecewise cubic approximation of the Gaussian window, being the
+    /// 4th-order B-spline. With `n` centered on the middle of the window and `x = |n| / (M / 2)`,
+    /// it is `1 - 6 x^2 (1 - x)` for `|n| <= (M - 1) / 4`, and `2 (1 - x)^3` elsewhere. [[1]]
+    ///
+    /// # References
+    /// [[1]] E. Parzen, "Mathematical Considerations in the Estimation of Spectra",  
+    /// Technometrics, Vol. 3, No. 2 (May, 1961), pp. 167-190  
+    /// [[2]] Scipy,
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.parzen.html>
+    ///
+    /// Examples
+    /// --------
+    /// ```custom,{class=language-python}
+    /// >>> from scipy import signal
+    /// >>> window = signal.windows.parzen(51)
+    /// ```
+    ///
+    /// The equivalent is:
+    /// ```
+    /// use sci_rs::signal::windows::{Parzen, GetWindow};
+    /// let window: Vec<f64> = Parzen::new(51, true).get_window();
+    /// ```
+    ///
+    /// [1]: #references
+    /// [2]: #references
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let one = W::one();
+        let two = W::from(2).unwrap();
+        let six = W::from(6).unwrap();
+        let m_f = W::from(m).unwrap();
+        let center = (m_f - one) / two;
+        let w = (0..m)
+            .map(|k| {
+                let n = Float::abs(W::from(k).unwrap() - center);
+                let x = n / (m_f / two);
+                if n <= center / two {
+                    one - six * x * x * (one - x)
+                } else {
+                    two * Float::powi(one - x, 3)
+                }
+            })
+            .collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn parzen_even_sym() {
+        // from scipy.signal.windows import parzen
+        // parzen(8)
+        let h = Parzen::new(8, true);
+        let expected = [
+            3.90625000e-03,
+            1.05468750e-01,
+            4.72656250e-01,
+            9.17968750e-01,
+            9.17968750e-01,
+            4.72656250e-01,
+            1.05468750e-01,
+            3.90625000e-03,
+        ]
+        .into();
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn parzen_odd_sym() {
+        // from scipy.signal.windows import parzen
+        // parzen(9)
+        let h = Parzen::new(9, true);
+        let expected = [
+            2.74348422e-03,
+            7.40740741e-02,
+            3.41563786e-01,
+            7.69547325e-01,
+            1.00000000e+00,
+            7.69547325e-01,
+            3.41563786e-01,
+            7.40740741e-02,
+            2.74348422e-03,
+        ]
+        .into();
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn parzen_periodic() {
+        // from scipy.signal.windows import parzen
+        // parzen(8, False)
+        let h = Parzen::new(8, false);
+        let expected = [
+            2.74348422e-03,
+            7.40740741e-02,
+            3.41563786e-01,
+            7.69547325e-01,
+            1.00000000e+00,
+            7.69547325e-01,
+            3.41563786e-01,
+            7.40740741e-02,
+        ]
+        .into();
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn parzen_strictly_positive() {
+        for m in 0..200 {
+            for sym in [true, false] {
+                let w: Vec<f64> = Parzen::new(m, sym).get_window();
+                assert_eq!(w.len(), m);
+                assert!(w.iter().all(|wi| *wi > 0.), "m = {m}, sym = {sym}");
+            }
+        }
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use super::{extend, len_guard, truncate};
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Taylor` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Taylor<F>
+where
+    F: Real,
+{
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Number of nearly constant level sidelobes adjacent to the mainlobe.
+    pub nbar: usize,
+    /// Desired suppression of sidelobe level in decibels (dB) relative to the DC gain.
+    pub sll: F,
+    /// Whether to normalize the window to a maximum of 1, at the center of the window.
+    pub norm: bool,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl<F> Taylor<F>
+where
+    F: Real,
+{
+    /// Returns a Taylor struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `nbar` : int  
+    ///   Number of nearly constant level sidelobes adjacent to the mainlobe, 4 in scipy by
+    ///   default.
+    /// * `sll` : float  
+    ///   Desired suppression of sidelobe level in decibels (dB) relative to the DC gain. This
+    ///   should be a positive number, 30 in scipy by default.
+    /// * `norm` : bool  
+    ///   When true, divides the window by the largest (middle) value for odd-length windows, or
+    ///   the value that would occur between the two repeated middle values for even-length
+    ///   windows, such that all values are less than or equal to 1.
+    /// * `sym`:  
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, nbar: usize, sll: F, norm: bool, sym: bool) -> Self {
+        Taylor {
+            m,
+            nbar,
+            sll,
+            norm,
+            sym,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, W> GetWindow<W> for Taylor<F>
+where
+    F: Real,
+    W: Real + Float + RealField,
+{
+    /// Return a Taylor window.
+    ///
+    /// # Parameters
+    /// `self`: [Taylor]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window. When [Taylor::norm] is true, the window is normalized to 1 at its center.
+    ///
+    /// # Notes
+    /// The Taylor window allows for a selectable sidelobe suppression with a minimum broadening.
+    /// It is commonly used in radar and antenna design, and is given by the cosine series
+    /// $$w(n) = 1 + 2 \sum_{m=1}^{\bar{n}-1} F_m \cos\left(\frac{2 \pi m (n - M/2 + 1/2)}{M}\right)$$
+    /// where the coefficients `Fm` are determined by the sidelobe level `sll`. [[1]]
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{GetWindow, Taylor};
+    /// let window: Vec<f64> = Taylor::new(51, 4, 30., true, true).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.taylor(51, nbar=4, sll=30, norm=True)
+    /// ```
+    ///
+    /// # References
+    /// [[1]] W. Carrara, R. Goodman, and R. Majewski, "Spotlight Synthetic Aperture Radar: Signal
+    /// Processing Algorithms" Pages 512-513, July 1995.  
+    /// [[2]] Scipy,
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.taylor.html>
+    ///
+    /// [1]: #references
+    /// [2]: #references
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let one = W::one();
+        let two = W::from(2).unwrap();
+        let half = W::from(0.5).unwrap();
+        let b = Float::powf(
+            W::from(10).unwrap(),
+            W::from(self.sll).unwrap() / W::from(20).unwrap(),
+        );
+        let a = Float::acosh(b) / W::pi();
+        let nbar = W::from(self.nbar).unwrap();
+        let s2 = nbar * nbar / (a * a + Float::powi(nbar - half, 2));
+
+        let ma: Vec<W> = (1..self.nbar).map(|k| W::from(k).unwrap()).collect();
+        let fm: Vec<W> = ma
+            .iter()
+            .enumerate()
+            .map(|(mi, &mm)| {
+                let m2 = mm * mm;
+                let sign = if mi % 2 == 0 { one } else { -one };
+                let numer = ma.iter().fold(sign, |prod, &k| {
+                    prod * (one - m2 / s2 / (a * a + Float::powi(k - half, 2)))
+                });
+                let denom = ma
+                    .iter()
+                    .enumerate()
+                    .filter(|&(ki, _)| ki != mi)
+                    .fold(two, |prod, (_, &k)| prod * (one - m2 / (k * k)));
+                numer / denom
+            })
+            .collect();
+
+        let m_f = W::from(m).unwrap();
+        let w_at = |n: W| {
+            let x = two * W::pi() * (n - m_f / two + half) / m_f;
+            fm.iter()
+                .zip(&ma)
+                .fold(one, |sum, (&f, &k)| sum + two * f * Float::cos(k * x))
+        };
+        let scale = if self.norm {
+            one / w_at((m_f - one) / two)
+        } else {
+            one
+        };
+        let w = (0..m).map(|n| w_at(W::from(n).unwrap()) * scale).collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn taylor_scipy_example() {
+        // from scipy.signal.windows import taylor
+        // w = taylor(51, nbar=4, sll=30)
+        // w[[0, 1, 5, 10, 20, 25, 50]]
+        let w: Vec<f64> = Taylor::new(51, 4, 30., true, true).get_window();
+        assert_eq!(w.len(), 51);
+        let expected = [
+            (0, 0.2440026124980055),
+            (1, 0.25127259924503437),
+            (5, 0.3460519239748941),
+            (10, 0.5582959521488966),
+            (20, 0.9409141298140099),
+            (25, 1.),
+            (50, 0.2440026124980055),
+        ];
+        for (i, e) in expected {
+            assert_abs_diff_eq!(w[i], e, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn taylor_norm() {
+        // The normalized window peaks at exactly one.
+        let w: Vec<f64> = Taylor::new(51, 4, 30., true, true).get_window();
+        let peak = w.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        assert_abs_diff_eq!(peak, 1., epsilon = 1e-15);
+
+        // from scipy.signal.windows import taylor
+        // taylor(51, nbar=4, sll=30, norm=False)[[0, 10, 25]]
+        let unnormed: Vec<f64> = Taylor::new(51, 4, 30., false, true).get_window();
+        let expected = [
+            (0, 0.3801820807985772),
+            (10, 0.8698846074491329),
+            (25, 1.558106599377844),
+        ];
+        for (i, e) in expected {
+            assert_abs_diff_eq!(unnormed[i], e, epsilon = 1e-12);
+        }
+        for (n, u) in w.iter().zip(&unnormed) {
+            assert_abs_diff_eq!(n * unnormed[25], u, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn taylor_even_and_periodic() {
+        // from scipy.signal.windows import taylor
+        // taylor(8, 3, 25)
+        let h = Taylor::new(8, 3, 25., true, true);
+        let expected = vec![
+            0.38506079, 0.57979504, 0.82612637, 0.97975722, 0.97975722, 0.82612637, 0.57979504,
+            0.38506079,
+        ];
+        assert_vec_eq(expected, h.get_window());
+
+        // taylor(8, 3, 25, sym=False)
+        let h = Taylor::new(8, 3, 25., true, false);
+        let expected = vec![
+            0.37911489, 0.53902728, 0.76222891, 0.93671076, 1., 0.93671076, 0.76222891, 0.53902728,
+        ];
+        assert_vec_eq(expected, h.get_window());
+
+        // taylor(8, 3, 25, norm=False, sym=False)
+        let h = Taylor::new(8, 3, 25., false, false);
+        let expected = vec![
+            0.54731222, 0.77817102, 1.10039783, 1.35228994, 1.44365795, 1.35228994, 1.10039783,
+            0.77817102,
+        ];
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
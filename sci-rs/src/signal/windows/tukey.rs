@@ -0,0 +1,185 @@
+use super::{extend, len_guard, truncate, GeneralHamming};
+use nalgebra::RealField;
+use num_traits::{real::Real, Float};
+
+#[cfg(feature = "alloc")]
+use super::GetWindow;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Collection of arguments for window `Tukey` for use in [GetWindow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tukey<F>
+where
+    F: Real,
+{
+    /// Number of points in the output window. If zero, an empty array is returned in [GetWindow].
+    pub m: usize,
+    /// Shape parameter, the fraction of the window inside the cosine tapered region.
+    pub alpha: F,
+    /// Whether the window is symmetric.
+    ///
+    /// When true, generates a symmetric window, for use in filter design.  
+    /// When false, generates a periodic window, for use in spectral analysis.
+    pub sym: bool,
+}
+
+impl<F> Tukey<F>
+where
+    F: Real,
+{
+    /// Returns a Tukey struct.  
+    ///
+    /// # Parameters
+    /// * `m`:  
+    ///   Number of points in the output window. If zero, an empty array is returned.  
+    /// * `alpha` : float  
+    ///   Shape parameter of the Tukey window, representing the fraction of the window inside the
+    ///   cosine tapered region. If zero, the Tukey window is equivalent to a rectangular window.
+    ///   If one, the Tukey window is equivalent to a Hann window.
+    /// * `sym`:  
+    ///   When true, generates a symmetric window, for use in filter design.  
+    ///   When false, generates a periodic window, for use in spectral analysis.
+    pub fn new(m: usize, alpha: F, sym: bool) -> Self {
+        Tukey { m, alpha, sym }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F, W> GetWindow<W> for Tukey<F>
+where
+    F: Real,
+    W: Real + Float + RealField,
+{
+    /// Return a Tukey window, also known as a tapered cosine window.
+    ///
+    /// # Parameters
+    /// `self`: [Tukey]
+    ///
+    /// # Returns
+    /// `w`: `vec<F>`  
+    ///     The window, with the maximum value normalized to 1 (though the value 1 does not appear
+    ///     if `M` is even and `sym` is True).
+    ///
+    /// # Notes
+    /// The window rises from 0 with a half cosine over the first `alpha (M - 1) / 2` samples, is
+    /// flat at 1 through the middle, and falls back to 0 with a half cosine over the last
+    /// `alpha (M - 1) / 2` samples. `alpha <= 0` gives a [Boxcar](super::Boxcar) window and
+    /// `alpha >= 1` a Hann window.
+    ///
+    /// # Example
+    /// ```
+    /// use sci_rs::signal::windows::{GetWindow, Tukey};
+    /// let window: Vec<f64> = Tukey::new(51, 0.5, true).get_window();
+    /// ```
+    ///
+    /// This is equivalent to the Python code:
+    /// ```custom,{class=language-python}
+    /// from scipy import signal
+    /// window = signal.windows.tukey(51)
+    /// ```
+    ///
+    /// # References
+    /// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.signal.windows.tukey.html>
+    #[cfg(feature = "alloc")]
+    fn get_window(&self) -> Vec<W> {
+        if len_guard(self.m) {
+            return vec![W::one(); self.m];
+        }
+        let alpha = W::from(self.alpha).unwrap();
+        if alpha <= W::zero() {
+            return vec![W::one(); self.m];
+        } else if alpha >= W::one() {
+            return GeneralHamming::new(self.m, W::from(0.5).unwrap(), self.sym).get_window();
+        }
+        let (m, needs_trunc) = extend(self.m, self.sym);
+
+        let one = W::one();
+        let two = W::from(2).unwrap();
+        let half = W::from(0.5).unwrap();
+        let m_1 = W::from(m - 1).unwrap();
+        let width = Float::floor(alpha * m_1 / two).to_usize().unwrap();
+        let w = (0..m)
+            .map(|n| {
+                let n_f = W::from(n).unwrap();
+                if n <= width {
+                    half * (one + Float::cos(W::pi() * (-one + two * n_f / alpha / m_1)))
+                } else if n < m - width - 1 {
+                    one
+                } else {
+                    half * (one
+                        + Float::cos(W::pi() * (-two / alpha + one + two * n_f / alpha / m_1)))
+                }
+            })
+            .collect();
+
+        truncate(w, needs_trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn tukey_sym() {
+        // from scipy.signal.windows import tukey
+        // tukey(12, 0.5)
+        let h = Tukey::new(12, 0.5, true);
+        let expected = vec![
+            0., 0.29229249, 0.82743037, 1., 1., 1., 1., 1., 1., 0.82743037, 0.29229249, 0.,
+        ];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn tukey_periodic() {
+        // from scipy.signal.windows import tukey
+        // tukey(12, 0.5, False)
+        let h = Tukey::new(12, 0.5, false);
+        let expected = vec![0., 0.25, 0.75, 1., 1., 1., 1., 1., 1., 1., 0.75, 0.25];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn tukey_odd_narrow_taper() {
+        // from scipy.signal.windows import tukey
+        // tukey(9, 0.3)
+        let h = Tukey::new(9, 0.3, true);
+        let expected = vec![0., 0.9330127, 1., 1., 1., 1., 1., 0.9330127, 0.];
+
+        assert_vec_eq(expected, h.get_window());
+    }
+
+    #[test]
+    fn tukey_degenerate_limits() {
+        for m in [0, 1, 2, 7, 12] {
+            for sym in [true, false] {
+                for alpha in [0., -0.5] {
+                    let w: Vec<f64> = Tukey::new(m, alpha, sym).get_window();
+                    assert_eq!(w, vec![1.; m]);
+                }
+                if m <= 1 {
+                    let w: Vec<f64> = Tukey::new(m, 1., sym).get_window();
+                    assert_eq!(w, vec![1.; m]);
+                    continue;
+                }
+                let hann: Vec<f64> = GeneralHamming::new(m, 0.5, sym).get_window();
+                for alpha in [1., 1.5] {
+                    assert_vec_eq(hann.clone(), Tukey::new(m, alpha, sym).get_window());
+                }
+            }
+        }
+    }
+
+    #[track_caller]
+    fn assert_vec_eq(a: Vec<f64>, b: Vec<f64>) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.into_iter().zip(b) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
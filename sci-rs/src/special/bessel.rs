@@ -24,6 +24,13 @@ pub trait Bessel {
     ///   as a supposed wrapper over the Cephes routine. We try to define it over reasonable types in
     ///   the impl.
     fn i0e(&self) -> Self;
+
+    /// Exponentially scaled modified Bessel function of order 1, `exp(-|x|) * i1(x)`.
+    ///
+    /// ## Notes
+    /// * The range is partitioned into the two intervals [0, 8] and (8, infinity).
+    /// * Odd in `x`, matching `scipy.special.i1e`.
+    fn i1e(&self) -> Self;
 }
 
 #[cfg(feature = "std")]
@@ -38,6 +45,10 @@ where
     fn i0e(&self) -> Self {
         self.iter().map(Bessel::i0e).collect()
     }
+
+    fn i1e(&self) -> Self {
+        self.iter().map(Bessel::i1e).collect()
+    }
 }
 
 #[cfg(feature = "std")]
@@ -52,6 +63,10 @@ where
     fn i0e(&self) -> Self {
         self.map(Bessel::i0e)
     }
+
+    fn i1e(&self) -> Self {
+        self.map(Bessel::i1e)
+    }
 }
 
 #[cfg(test)]
@@ -63,7 +78,7 @@ mod tests {
     #[test]
     fn i0_vec_f64() {
         let inp = vec![0., 1., 0.213, 5., 30.546];
-        let result = vec![
+        let result = [
             1.,
             1.2660658777520082,
             1.0113744522192416,
@@ -1,6 +1,13 @@
 use nalgebra::min;
 use num_traits::{FromPrimitive, PrimInt};
 
+#[cfg(feature = "alloc")]
+use super::xsf;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use sci_rs_core::{Error, Result};
+
 /// Various combinatorics functions for integer types.
 pub trait Combinatoric {
     /// The number of combinations of `n` taken `k` at a time.
@@ -182,9 +189,77 @@ where
     k * primint_stirling2(n - Int::one(), k) + primint_stirling2(n - Int::one(), k - Int::one())
 }
 
+/// The multinomial coefficient.
+///
+/// This counts the ways to split `n = sum(ks)` distinct items into groups of the sizes given by
+/// `ks`, generalizing [Combinatoric::comb] to more than two groups:
+/// $$
+/// \begin{pmatrix}
+/// n \\\\ k_1, k_2, \ldots, k_m
+/// \end{pmatrix} = \frac{n!}{k_1! k_2! \cdots k_m!}
+/// $$
+///
+/// # Returns
+/// The coefficient as a float, being exact while it is at most `2^53`. Larger coefficients are
+/// evaluated through the log-gamma function, so that the factorials themselves never overflow,
+/// and are `inf` only if the coefficient itself exceeds the range of `f64`.
+///
+/// # Errors
+/// [Error::InvalidArg] if `sum(ks)` overflows `u64`.
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::multinomial;
+/// assert_eq!(multinomial(&[2, 3, 4]).unwrap(), 1260.);
+/// assert_eq!(multinomial(&[]).unwrap(), 1.);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn multinomial(ks: &[u64]) -> Result<f64> {
+    let n = ks
+        .iter()
+        .try_fold(0u64, |n, &k| n.checked_add(k))
+        .ok_or_else(|| Error::InvalidArg {
+            arg: "ks".into(),
+            reason: "The sum of ks must not overflow u64.".into(),
+        })?;
+
+    if let Some(m) = multinomial_exact(ks) {
+        if m <= 1 << f64::MANTISSA_DIGITS {
+            return Ok(m as f64);
+        }
+    }
+    let ln_m =
+        xsf::lgamma(n as f64 + 1.) - ks.iter().map(|&k| xsf::lgamma(k as f64 + 1.)).sum::<f64>();
+    Ok(ln_m.exp())
+}
+
+/// The multinomial coefficient as a product of binomial coefficients, or [None] if it overflows.
+#[cfg(feature = "alloc")]
+fn multinomial_exact(ks: &[u64]) -> Option<u128> {
+    // Starting from the largest group, whose binomial coefficient is 1, keeps the loop short.
+    let mut ks: Vec<u128> = ks.iter().map(|&k| k as u128).collect();
+    ks.sort_unstable_by(|a, b| b.cmp(a));
+    let Some((&first, rest)) = ks.split_first() else {
+        return Some(1);
+    };
+
+    let mut total = first;
+    let mut m: u128 = 1;
+    for &k in rest {
+        // Multiplying by comb(total + i, i) / comb(total + i - 1, i - 1) = (total + i) / i keeps
+        // the division exact.
+        for i in 1..=k {
+            m = m.checked_mul(total + i)? / i;
+        }
+        total += k;
+    }
+    Some(m)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
     use core::fmt;
 
     fn check_values<K, T>(ref_values: &[[K; 10]], func: fn(T, T) -> T, func_name: &str)
@@ -317,4 +392,44 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn multinomial_small() {
+        // 9! / (2! 3! 4!) = 362880 / (2 * 6 * 24)
+        assert_eq!(multinomial(&[2, 3, 4]).unwrap(), 1260.);
+        assert_eq!(multinomial(&[4, 2, 3]).unwrap(), 1260.);
+        assert_eq!(multinomial(&[1, 1, 1, 1]).unwrap(), 24.);
+        assert_eq!(multinomial(&[7]).unwrap(), 1.);
+        assert_eq!(multinomial(&[0, 0]).unwrap(), 1.);
+        assert_eq!(multinomial(&[]).unwrap(), 1.);
+        assert_eq!(multinomial(&[u64::MAX, 0]).unwrap(), 1.);
+
+        // Two groups is the binomial coefficient.
+        for n in 0..30u64 {
+            for k in 0..=n {
+                assert_eq!(multinomial(&[k, n - k]).unwrap(), n.comb(k) as f64);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn multinomial_large() {
+        // from math import comb, factorial
+        // float(comb(200, 100)), where 200! alone overflows f64.
+        assert_relative_eq!(
+            multinomial(&[100, 100]).unwrap(),
+            9.054851465610328e58,
+            max_relative = 1e-12
+        );
+        // float(factorial(300) // factorial(100)**3)
+        assert_relative_eq!(
+            multinomial(&[100, 100, 100]).unwrap(),
+            3.7652349356463108e140,
+            max_relative = 1e-12
+        );
+        assert!(multinomial(&[1000, 1000]).unwrap().is_infinite());
+        assert!(multinomial(&[u64::MAX, 1]).is_err());
+    }
 }
@@ -0,0 +1,120 @@
+use num_traits::Float;
+
+/// Expit (a.k.a. logistic sigmoid) function.
+///
+/// The expit function is defined as `expit(x) = 1/(1+exp(-x))`, and is the inverse of [logit].
+///
+/// Only the exponential of a non-positive number is taken, so that large negative `x` do not
+/// overflow `exp(-x)`:
+/// $$
+/// \text{expit}(x) = \begin{cases} 1/(1+e^{-x}) & x \ge 0 \\\\ e^x/(1+e^x) & x < 0 \end{cases}
+/// $$
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::expit;
+///
+/// assert_eq!(expit(0f64), 0.5);
+/// assert_eq!(expit(-1000f64), 0.);
+/// assert_eq!(expit(1000f64), 1.);
+/// ```
+///
+/// # References
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.expit.html>
+pub fn expit<F>(x: F) -> F
+where
+    F: Float,
+{
+    if x >= F::zero() {
+        F::one() / (F::one() + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (F::one() + e)
+    }
+}
+
+/// Logit function.
+///
+/// The logit function is defined as `logit(p) = log(p/(1-p))`, and is the inverse of [expit].
+///
+/// For `p` around 1/2, where `p/(1-p)` is close to 1, this is evaluated as
+/// `log1p(2p-1) - log1p(1-2p)` to keep the relative precision of the result near 0.
+///
+/// # Returns
+/// `-inf` at `p = 0`, `inf` at `p = 1`, and `NaN` for `p` outside of `[0, 1]`.
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::{expit, logit};
+///
+/// assert_eq!(logit(0.5f64), 0.);
+/// assert!((expit(logit(0.2f64)) - 0.2).abs() < 1e-15);
+/// assert_eq!(logit(1f64), f64::INFINITY);
+/// ```
+///
+/// # References
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.logit.html>
+pub fn logit<F>(p: F) -> F
+where
+    F: Float,
+{
+    let (lo, hi) = (F::from(0.3).unwrap(), F::from(0.7).unwrap());
+    if p < lo || p > hi {
+        (p / (F::one() - p)).ln()
+    } else {
+        let s = p + p - F::one();
+        s.ln_1p() - (-s).ln_1p()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn expit_values() {
+        // 1 / (1 + exp(-x)), evaluated in extended precision.
+        let x = [-20., -1., 0., 1., 20.];
+        let expected = [
+            2.0611536181902037e-09,
+            0.2689414213699951,
+            0.5,
+            0.7310585786300049,
+            0.9999999979388464,
+        ];
+        for (xi, ei) in x.into_iter().zip(expected) {
+            assert_relative_eq!(expit(xi), ei, max_relative = 1e-15);
+            assert_relative_eq!(logit(ei), xi, epsilon = 1e-7, max_relative = 1e-7);
+        }
+
+        // The naive formula overflows exp(-x) and gives 0 rather than NaN or a panic.
+        assert_eq!(expit(-800f64), 0.);
+        assert!(expit(-700f64) > 0.);
+        assert_eq!(expit(800f64), 1.);
+        assert_eq!(expit(f64::NEG_INFINITY), 0.);
+        assert_eq!(expit(f64::INFINITY), 1.);
+        assert_relative_eq!(expit(-50f32), 1.9287499e-22, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn logit_values() {
+        // log(p / (1 - p)), evaluated in extended precision.
+        let p = [1e-9, 0.25, 0.5, 0.6, 0.9];
+        let expected = [
+            -20.72326583594641,
+            -1.0986122886681098,
+            0.,
+            0.4054651081081644,
+            2.1972245773362196,
+        ];
+        for (pi, ei) in p.into_iter().zip(expected) {
+            assert_relative_eq!(logit(pi), ei, max_relative = 1e-15);
+        }
+
+        assert_eq!(logit(0f64), f64::NEG_INFINITY);
+        assert_eq!(logit(1f64), f64::INFINITY);
+        assert!(logit(-0.1f64).is_nan());
+        assert!(logit(1.1f64).is_nan());
+    }
+}
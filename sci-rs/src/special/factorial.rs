@@ -396,8 +396,12 @@ mod test {
     where
         Int: Factorial + FromPrimitive + ToPrimitive + fmt::Debug + fmt::Display + PartialEq,
     {
-        for index in 0..max.to_usize().unwrap() {
-            let ref_value = Int::from_u128(ABSOLUTE_KNOWN_FACTORIAL_VALUES[index]).unwrap();
+        for (index, &value) in ABSOLUTE_KNOWN_FACTORIAL_VALUES
+            .iter()
+            .enumerate()
+            .take(max.to_usize().unwrap())
+        {
+            let ref_value = Int::from_u128(value).unwrap();
             assert_eq!(
                 Int::factorial(Int::from_usize(index).unwrap()),
                 ref_value,
@@ -413,8 +417,12 @@ mod test {
     where
         Int: Factorial + FromPrimitive + ToPrimitive + fmt::Debug + fmt::Display + PartialEq,
     {
-        for index in 0..max.to_usize().unwrap() {
-            let ref_value = Int::from_u128(ABSOLUTE_KNOWN_FACTORIAL2_VALUES[index]).unwrap();
+        for (index, &value) in ABSOLUTE_KNOWN_FACTORIAL2_VALUES
+            .iter()
+            .enumerate()
+            .take(max.to_usize().unwrap())
+        {
+            let ref_value = Int::from_u128(value).unwrap();
             assert_eq!(
                 Int::factorial2(Int::from_usize(index).unwrap()),
                 ref_value,
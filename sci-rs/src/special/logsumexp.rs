@@ -0,0 +1,153 @@
+use ndarray::ArrayView1;
+use num_traits::Float;
+
+#[cfg(feature = "alloc")]
+use crate::signal::filter::arraytools::check_and_get_axis_dyn;
+#[cfg(feature = "alloc")]
+use ndarray::{Array, ArrayBase, Axis, Data, RemoveAxis};
+#[cfg(feature = "alloc")]
+use sci_rs_core::Result;
+
+/// Compute the log of the sum of exponentials of input elements.
+///
+/// This computes `log(sum(exp(x)))` without the overflow or underflow of `exp(x)`, by factoring
+/// out the largest element of `x`:
+/// $$
+/// \log\sum_i e^{x_i} = x_{max} + \log\sum_i e^{x_i - x_{max}}
+/// $$
+///
+/// # Returns
+/// The log of the sum of exponentials, being `-inf` if `x` is empty or all of its elements are
+/// `-inf`, `inf` if any element is `inf`, and `NaN` if any element is `NaN`.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::special::logsumexp;
+///
+/// let x = array![1000., 1000.];
+/// assert_eq!(logsumexp(x.view()), 1000. + 2f64.ln());
+/// ```
+///
+/// # References
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.logsumexp.html>
+pub fn logsumexp<F>(x: ArrayView1<F>) -> F
+where
+    F: Float,
+{
+    let max = x.iter().copied().fold(F::neg_infinity(), F::max);
+    // Infinite maxima are not factored out, so that inf - inf does not give NaN.
+    let shift = if max.is_finite() { max } else { F::zero() };
+    let sum = x
+        .iter()
+        .fold(F::zero(), |sum, &xi| sum + (xi - shift).exp());
+    sum.ln() + shift
+}
+
+/// Compute the log of the sum of exponentials of input elements along an axis.
+///
+/// This is [logsumexp] applied to each lane of `x` along `axis`, which may be negative to count
+/// from the last axis.
+///
+/// # Errors
+/// [sci_rs_core::Error::InvalidArg] if `axis` is out of range for `x`.
+///
+/// # Examples
+/// ```
+/// use ndarray::array;
+/// use sci_rs::special::logsumexp_axis;
+///
+/// let x = array![[0., 0.], [1., 1.]];
+/// let y = logsumexp_axis(&x, -1).unwrap();
+/// assert_eq!(y, array![2f64.ln(), 1. + 2f64.ln()]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn logsumexp_axis<F, S, D>(x: &ArrayBase<S, D>, axis: isize) -> Result<Array<F, D::Smaller>>
+where
+    F: Float,
+    S: Data<Elem = F>,
+    D: RemoveAxis,
+{
+    let axis = check_and_get_axis_dyn(Some(axis), x)?;
+    Ok(x.map_axis(Axis(axis), logsumexp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1};
+
+    fn naive(x: &[f64]) -> f64 {
+        x.iter().map(|xi| xi.exp()).sum::<f64>().ln()
+    }
+
+    #[test]
+    fn matches_naive_for_moderate_values() {
+        let x = [-3.5, 0.25, 1., 7.75, -10., 2.5];
+        assert_relative_eq!(
+            logsumexp(Array1::from(x.to_vec()).view()),
+            naive(&x),
+            max_relative = 1e-14
+        );
+
+        let x = [1e-3; 16];
+        assert_relative_eq!(
+            logsumexp(Array1::from(x.to_vec()).view()),
+            naive(&x),
+            max_relative = 1e-14
+        );
+    }
+
+    #[test]
+    fn does_not_overflow() {
+        // from scipy.special import logsumexp
+        // logsumexp([1000., 999., 1001.]) = 1001.4076059644444
+        let x = array![1000., 999., 1001.];
+        assert!(naive(x.as_slice().unwrap()).is_infinite());
+        assert_relative_eq!(
+            logsumexp(x.view()),
+            1001.4076059644444,
+            max_relative = 1e-15
+        );
+
+        let x = array![-1000., -999., -1001.];
+        assert_relative_eq!(
+            logsumexp(x.view()),
+            -998.5923940355556,
+            max_relative = 1e-15
+        );
+    }
+
+    #[test]
+    fn non_finite_inputs() {
+        let inf = f64::INFINITY;
+        assert_eq!(logsumexp(Array1::<f64>::zeros(0).view()), -inf);
+        assert_eq!(logsumexp(array![-inf, -inf].view()), -inf);
+        assert_eq!(logsumexp(array![-inf, 2.].view()), 2.);
+        assert_eq!(logsumexp(array![1., inf].view()), inf);
+        assert!(logsumexp(array![1., f64::NAN].view()).is_nan());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn along_axis() {
+        let x = array![
+            [[1., 2., 3.], [4., 5., 6.]],
+            [[1e3, 0., -1e3], [7., 8., 9.]]
+        ];
+        for axis in [0, 1, 2, -1, -3] {
+            let ax = if axis < 0 { 3 + axis } else { axis } as usize;
+            let y = logsumexp_axis(&x, axis).unwrap();
+            let expected = x.map_axis(Axis(ax), logsumexp);
+            assert_eq!(y, expected);
+        }
+        let y = logsumexp_axis(&x, -1).unwrap();
+        assert_relative_eq!(y[[1, 0]], 1e3, max_relative = 1e-15);
+        assert_relative_eq!(y[[0, 1]], naive(&[4., 5., 6.]), max_relative = 1e-14);
+
+        assert!(logsumexp_axis(&x, 3).is_err());
+        assert!(logsumexp_axis(&x, -4).is_err());
+    }
+}
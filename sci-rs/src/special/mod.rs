@@ -2,13 +2,22 @@
 //!
 //! # Available Functions
 //! - Factorial, double factorial, and `k`-factorial
-//! - Combinatorics (choice and permutations)
+//! - Combinatorics (choice, permutations and multinomial coefficients)
+//! - Log of the sum of exponentials
+//! - Logistic sigmoid and its inverse
+//! - Sine and cosine integrals
 
 mod combinatorics;
+mod expit;
 mod factorial;
+mod logsumexp;
+mod sici;
 
 pub use combinatorics::*;
+pub use expit::*;
 pub use factorial::*;
+pub use logsumexp::*;
+pub use sici::*;
 
 /// Adds the [Bessel] trait.
 mod bessel;
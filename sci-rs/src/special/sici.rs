@@ -0,0 +1,169 @@
+use core::f64::consts::FRAC_PI_2;
+use nalgebra::Complex;
+use num_traits::Float;
+
+/// Euler-Mascheroni constant.
+const EULER: f64 = 0.577_215_664_901_533;
+
+/// Sine and cosine integrals.
+///
+/// The sine integral and cosine integral are defined as
+/// $$
+/// \mathrm{Si}(x) = \int_0^x \frac{\sin t}{t} dt, \qquad
+/// \mathrm{Ci}(x) = \gamma + \log x + \int_0^x \frac{\cos t - 1}{t} dt
+/// $$
+/// where $\gamma$ is the Euler-Mascheroni constant.
+///
+/// # Returns
+/// The tuple `(Si(x), Ci(x))`. As in scipy, `Ci(x)` is the real part of the cosine integral for
+/// negative `x`, that is `Ci(-x) = Ci(x)`, and `Ci(0) = -inf`.
+///
+/// # Notes
+/// For `|x| <= 2` both integrals are summed from their power series. Otherwise they are computed
+/// from the auxiliary functions `f` and `g`,
+/// $$
+/// \mathrm{Si}(x) = \frac{\pi}{2} - f(x) \cos x - g(x) \sin x, \qquad
+/// \mathrm{Ci}(x) = f(x) \sin x - g(x) \cos x,
+/// $$
+/// with `g(x) - i f(x)` evaluated by the continued fraction of $e^{ix} E_1(ix)$, which, unlike
+/// the asymptotic series of `f` and `g`, converges to full precision for moderate `x`.
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::sici;
+///
+/// let (si, ci) = sici(2.);
+/// assert!((si - 1.6054129768026948f64).abs() < 1e-15);
+/// assert!((ci - 0.422980828774865f64).abs() < 1e-15);
+/// ```
+///
+/// # References
+/// <https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.sici.html>
+pub fn sici<F>(x: F) -> (F, F)
+where
+    F: Float,
+{
+    if x.is_nan() {
+        return (x, x);
+    }
+    let t = x.abs();
+    let (si, ci) = if t == F::zero() {
+        (F::zero(), F::neg_infinity())
+    } else if t.is_infinite() {
+        (F::from(FRAC_PI_2).unwrap(), F::zero())
+    } else if t <= F::from(2).unwrap() {
+        sici_series(t)
+    } else {
+        sici_auxiliary(t)
+    };
+    (if x < F::zero() { -si } else { si }, ci)
+}
+
+/// Power series of Si and Ci for small positive `t`.
+fn sici_series<F: Float>(t: F) -> (F, F) {
+    let t2 = t * t;
+    let mut si = t;
+    let mut ci = F::zero();
+    // Term t^k / k! with alternating sign, of which Si takes the odd and Ci the even powers.
+    let mut term = t;
+    let mut k = 1;
+    loop {
+        k += 1;
+        term = -term * t2 / F::from(k * (k + 1)).unwrap();
+        let ci_term = term * F::from(k + 1).unwrap() / (t * F::from(k).unwrap());
+        let si_term = term / F::from(k + 1).unwrap();
+        ci = ci + ci_term;
+        si = si + si_term;
+        k += 1;
+        if ci_term.abs() <= F::epsilon() * ci.abs() && si_term.abs() <= F::epsilon() * si.abs() {
+            break;
+        }
+    }
+    (si, F::from(EULER).unwrap() + t.ln() + ci)
+}
+
+/// Si and Ci from the auxiliary functions, for `t > 2`.
+fn sici_auxiliary<F: Float>(t: F) -> (F, F) {
+    let one = Complex::new(F::one(), F::zero());
+    let two = Complex::new(F::from(2).unwrap(), F::zero());
+    let tiny = F::min_positive_value() / F::epsilon();
+
+    // Modified Lentz evaluation of e^{it} E1(it) = 1 / (1 + it - 1 / (3 + it - 4 / (5 + it - ...
+    let mut b = Complex::new(F::one(), t);
+    let mut c = Complex::new(F::one() / tiny, F::zero());
+    let mut d = one / b;
+    let mut h = d;
+    for i in 1..1000 {
+        let a = Complex::new(-F::from(i * i).unwrap(), F::zero());
+        b = b + two;
+        d = one / (a * d + b);
+        c = b + a / c;
+        let delta = c * d;
+        h = h * delta;
+        if (delta.re - F::one()).abs() + delta.im.abs() < F::epsilon() {
+            break;
+        }
+    }
+    let (g, f) = (h.re, -h.im);
+
+    let (sin, cos) = t.sin_cos();
+    let si = F::from(FRAC_PI_2).unwrap() - f * cos - g * sin;
+    let ci = f * sin - g * cos;
+    (si, ci)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn matches_scipy() {
+        // from scipy.special import sici
+        // sici([0.5, 2, 10])
+        let expected = [
+            (0.5, 0.4931074180430667, -0.1777840788066129),
+            (2., 1.6054129768026948, 0.422980828774865),
+            (10., 1.6583475942188741, -0.04545643300445537),
+        ];
+        for (x, si, ci) in expected {
+            let (s, c) = sici(x);
+            assert_relative_eq!(s, si, max_relative = 1e-14);
+            assert_relative_eq!(c, ci, max_relative = 1e-13);
+        }
+    }
+
+    #[test]
+    fn continuous_between_methods() {
+        // sici([1.9, 2.1, 50, 1e6])
+        let expected = [
+            (1.9, 1.5577753137488184, 0.44194034968159884),
+            (2.1, 1.648698636244419, 0.40051198784439634),
+            (50., 1.551617072485936, -0.005628386324116306),
+            (1e6, 1.570795390043119, -3.499944389227205e-07),
+        ];
+        for (x, si, ci) in expected {
+            let (s, c) = sici(x);
+            assert_relative_eq!(s, si, max_relative = 1e-14);
+            assert_relative_eq!(c, ci, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        // sici(-3) = (-1.8486525279994683, 0.11962978600800032)
+        let (s, c) = sici(-3f64);
+        assert_relative_eq!(s, -1.8486525279994683, max_relative = 1e-14);
+        assert_relative_eq!(c, 0.11962978600800032, max_relative = 1e-13);
+
+        assert_eq!(sici(0f64), (0., f64::NEG_INFINITY));
+        assert_eq!(sici(f64::INFINITY), (FRAC_PI_2, 0.));
+        assert_eq!(sici(f64::NEG_INFINITY), (-FRAC_PI_2, 0.));
+        let (s, c) = sici(f64::NAN);
+        assert!(s.is_nan() && c.is_nan());
+
+        let (s, c) = sici(2f32);
+        assert_relative_eq!(s, 1.605_413, max_relative = 1e-6);
+        assert_relative_eq!(c, 0.422_980_8, max_relative = 1e-6);
+    }
+}
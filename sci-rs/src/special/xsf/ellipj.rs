@@ -0,0 +1,114 @@
+use core::f64::consts::FRAC_PI_2;
+use num_traits::Float;
+
+/// Jacobian elliptic functions `(sn, cn, dn, ph)` of parameter `m`, `0 <= m <= 1`, evaluated at
+/// `u`, where `ph` is the amplitude such that `sn = sin(ph)` and `cn = cos(ph)`.
+///
+/// Ported from the Cephes `ellpj`, which uses the descending Landen transformation by way of the
+/// arithmetic-geometric mean, and series in `m` or `1 - m` near either end of the range.
+/// Returns NaNs for `m` out of range.
+pub(crate) fn ellipj<F: Float>(u: F, m: F) -> (F, F, F, F) {
+    let (one, two) = (F::one(), F::from(2).unwrap());
+    let quarter = F::from(0.25).unwrap();
+    if m < F::zero() || m > one || m.is_nan() {
+        let nan = F::nan();
+        return (nan, nan, nan, nan);
+    }
+
+    if m < F::from(1e-9).unwrap() {
+        let (t, b) = u.sin_cos();
+        let ai = quarter * m * (u - t * b);
+        let sn = t - ai * b;
+        let cn = b + ai * t;
+        let ph = u - ai;
+        let dn = one - m * t * t / two;
+        return (sn, cn, dn, ph);
+    }
+
+    if m >= F::from(0.9999999999).unwrap() {
+        let mut ai = quarter * (one - m);
+        let b = u.cosh();
+        let t = u.tanh();
+        let phi = one / b;
+        let twon = b * u.sinh();
+        let sn = t + ai * (twon - u) / (b * b);
+        let ph = two * u.exp().atan() - F::from(FRAC_PI_2).unwrap() + ai * (twon - u) / b;
+        ai = ai * t * phi;
+        let cn = phi - ai * (twon - u);
+        let dn = phi + ai * (twon + u);
+        return (sn, cn, dn, ph);
+    }
+
+    // A. G. M. scale
+    let machep = F::epsilon() / two;
+    let mut a = [F::zero(); 9];
+    let mut c = [F::zero(); 9];
+    a[0] = one;
+    c[0] = m.sqrt();
+    let mut b = (one - m).sqrt();
+    let mut twon = one;
+    let mut i = 0;
+    while (c[i] / a[i]).abs() > machep && i < 8 {
+        let ai = a[i];
+        i += 1;
+        c[i] = (ai - b) / two;
+        let t = (ai * b).sqrt();
+        a[i] = (ai + b) / two;
+        b = t;
+        twon = twon * two;
+    }
+
+    // Backward recurrence
+    let mut phi = twon * a[i] * u;
+    let mut prev = phi;
+    while i > 0 {
+        let t = c[i] * phi.sin() / a[i];
+        prev = phi;
+        phi = (t.asin() + phi) / two;
+        i -= 1;
+    }
+
+    let (sn, cn) = phi.sin_cos();
+    let dn = cn / (phi - prev).cos();
+    (sn, cn, dn, phi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn ellipj_f64() {
+        // >>> from scipy.special import ellipj
+        for (u, m, expected) in [
+            (
+                0.3,
+                0.5,
+                [0.2934127331684554, 0.9559858618277871, 0.9782405041743613],
+            ),
+            (
+                1.2,
+                0.99,
+                [0.8348220329574986, 0.5505199118003901, 0.5568136596347311],
+            ),
+            (
+                0.7,
+                1e-12,
+                [0.6442176872376514, 0.7648421872845218, 0.9999999999997925],
+            ),
+            (
+                2.0,
+                0.9999999999999,
+                [0.9640275800758374, 0.26580222883400506, 0.2658022288341799],
+            ),
+        ] {
+            let (sn, cn, dn, ph) = ellipj(u, m);
+            assert_relative_eq!(sn, expected[0], max_relative = 1e-13);
+            assert_relative_eq!(cn, expected[1], max_relative = 1e-13);
+            assert_relative_eq!(dn, expected[2], max_relative = 1e-13);
+            assert_relative_eq!(ph.sin(), sn, max_relative = 1e-13);
+        }
+        assert!(ellipj(0.5f64, 1.5).0.is_nan());
+    }
+}
@@ -0,0 +1,39 @@
+use core::f64::consts::FRAC_PI_2;
+use num_traits::Float;
+
+/// Complete elliptic integral of the first kind, `K(m)`, for `0 <= m < 1`.
+///
+/// Evaluated as `K(1 - p)` with [ellipkm1], so `m` close to 1 loses precision; pass `1 - m`
+/// directly to [ellipkm1] in that case.
+pub(crate) fn ellipk<F: Float>(m: F) -> F {
+    ellipkm1(F::one() - m)
+}
+
+/// Complete elliptic integral of the first kind around `m = 1`, `K(1 - p)`, for `0 < p <= 1`.
+///
+/// Uses the arithmetic-geometric mean, `K(m) = pi / (2 AGM(1, sqrt(1 - m)))`, which converges
+/// quadratically.
+pub(crate) fn ellipkm1<F: Float>(p: F) -> F {
+    let two = F::from(2).unwrap();
+    let (mut a, mut g) = (F::one(), p.sqrt());
+    while (a - g).abs() > F::epsilon() * a {
+        (a, g) = ((a + g) / two, (a * g).sqrt());
+    }
+    F::from(FRAC_PI_2).unwrap() / a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn ellipk_f64() {
+        // from scipy.special import ellipk, ellipkm1
+        assert_relative_eq!(ellipk(0f64), FRAC_PI_2, max_relative = 1e-15);
+        assert_relative_eq!(ellipk(0.5f64), 1.8540746773013719, max_relative = 1e-15);
+        assert_relative_eq!(ellipk(0.99f64), 3.695637362989874, max_relative = 1e-14);
+        assert_relative_eq!(ellipkm1(1e-10f64), 12.8992198263876, max_relative = 1e-15);
+    }
+}
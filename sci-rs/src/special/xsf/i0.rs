@@ -91,6 +91,10 @@ impl Bessel for f64 {
         }
         xsf::chbevl(32. / x - 2., &I0_B_F64) / x.sqrt()
     }
+
+    fn i1e(&self) -> Self {
+        xsf::i1e_f64(*self)
+    }
 }
 
 // Chebyshev coefficients for exp(-x) I0(x)
@@ -182,6 +186,10 @@ impl Bessel for f32 {
         }
         xsf::chbevl(32. / x - 2., &I0_B_F32) / x.sqrt()
     }
+
+    fn i1e(&self) -> Self {
+        xsf::i1e_f32(*self)
+    }
 }
 
 #[cfg(test)]
@@ -209,6 +217,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn i0_f32() {
         let result: f32 = (0.).i0();
         let exp = 1.;
@@ -241,6 +250,24 @@ mod tests {
     }
 
     #[test]
+    fn i0e_f64_large() {
+        // i0 overflows past ~713, but the scaled function stays bounded.
+        assert!(1000f64.i0().is_infinite());
+        let result: f64 = 100.0.i0e();
+        let exp = 0.03994437929909668;
+        assert_relative_eq!(result, exp, epsilon = 1e-12);
+        let result: f64 = 710.0.i0e();
+        let exp = 0.014974675005024157;
+        assert_relative_eq!(result, exp, epsilon = 1e-12);
+        let result: f64 = 1000.0.i0e();
+        let exp = 0.012617240455891257;
+        assert_relative_eq!(result, exp, epsilon = 1e-12);
+        let result: f64 = (-1000.0).i0e();
+        assert_relative_eq!(result, exp, epsilon = 1e-12);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
     fn i0e_f32() {
         let result: f32 = (0.).i0e();
         let exp = 1.;
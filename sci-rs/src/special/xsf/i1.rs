@@ -0,0 +1,213 @@
+use super::super::xsf;
+use num_traits::real::Real;
+
+// Chebyshev coefficients for exp(-x) I1(x) / x
+// in the interval [0,8].
+//
+// lim(x->0){ exp(-x) I1(x) / x } = 1/2.
+#[allow(clippy::excessive_precision)]
+const I1_A_F64: [f64; 29] = [
+    2.77791411276104639959E-18,
+    -2.11142121435816608115E-17,
+    1.55363195773620046921E-16,
+    -1.10559694773538630805E-15,
+    7.60068429473540693410E-15,
+    -5.04218550472791168711E-14,
+    3.22379336594557470981E-13,
+    -1.98397439776494371520E-12,
+    1.17361862988909016308E-11,
+    -6.66348972350202774223E-11,
+    3.62559028155211703701E-10,
+    -1.88724975172282928790E-9,
+    9.38153738649577178388E-9,
+    -4.44505912879632808065E-8,
+    2.00329475355213526229E-7,
+    -8.56872026469545474066E-7,
+    3.47025130813767847674E-6,
+    -1.32731636560394358279E-5,
+    4.78156510755005422638E-5,
+    -1.61760815825896745588E-4,
+    5.12285956168575772895E-4,
+    -1.51357245063125314899E-3,
+    4.15642294431288815669E-3,
+    -1.05640848946261981558E-2,
+    2.47264490306265168283E-2,
+    -5.29459812080949914269E-2,
+    1.02643658689847095384E-1,
+    -1.76416518357834055153E-1,
+    2.52587186443633654823E-1,
+];
+
+// Chebyshev coefficients for exp(-x) sqrt(x) I1(x)
+// in the inverted interval [8,infinity].
+//
+// lim(x->inf){ exp(-x) sqrt(x) I1(x) } = 1/sqrt(2pi).
+#[allow(clippy::excessive_precision)]
+const I1_B_F64: [f64; 25] = [
+    7.51729631084210481353E-18,
+    4.41434832307170791151E-18,
+    -4.65030536848935832153E-17,
+    -3.20952592199342395980E-17,
+    2.96262899764595013876E-16,
+    3.30820231092092828324E-16,
+    -1.88035477551078244854E-15,
+    -3.81440307243700780478E-15,
+    1.04202769841288027642E-14,
+    4.27244001671195135429E-14,
+    -2.10154184277266431302E-14,
+    -4.08355111109219731823E-13,
+    -7.19855177624590851209E-13,
+    2.03562854414708950722E-12,
+    1.41258074366137813316E-11,
+    3.25260358301548823856E-11,
+    -1.89749581235054123450E-11,
+    -5.58974346219658380687E-10,
+    -3.83538038596423702205E-9,
+    -2.63146884688951950684E-8,
+    -2.51223623787020892529E-7,
+    -3.88256480887769039346E-6,
+    -1.10588938762623716291E-4,
+    -9.76109749136146840777E-3,
+    7.78576235018280120474E-1,
+];
+
+/// Exponentially scaled modified Bessel function of order 1, `exp(-|x|) * i1(x)`.
+pub(crate) fn i1e_f64(x: f64) -> f64 {
+    let z = x.abs();
+    let z = if z <= 8. {
+        xsf::chbevl(z / 2. - 2., &I1_A_F64) * z
+    } else {
+        xsf::chbevl(32. / z - 2., &I1_B_F64) / z.sqrt()
+    };
+    if x < 0. {
+        -z
+    } else {
+        z
+    }
+}
+
+// Chebyshev coefficients for exp(-x) I1(x) / x
+// in the interval [0,8].
+//
+// lim(x->0){ exp(-x) I1(x) / x } = 1/2.
+#[allow(clippy::excessive_precision)]
+const I1_A_F32: [f32; 29] = [
+    2.77791411276104639959E-18,
+    -2.11142121435816608115E-17,
+    1.55363195773620046921E-16,
+    -1.10559694773538630805E-15,
+    7.60068429473540693410E-15,
+    -5.04218550472791168711E-14,
+    3.22379336594557470981E-13,
+    -1.98397439776494371520E-12,
+    1.17361862988909016308E-11,
+    -6.66348972350202774223E-11,
+    3.62559028155211703701E-10,
+    -1.88724975172282928790E-9,
+    9.38153738649577178388E-9,
+    -4.44505912879632808065E-8,
+    2.00329475355213526229E-7,
+    -8.56872026469545474066E-7,
+    3.47025130813767847674E-6,
+    -1.32731636560394358279E-5,
+    4.78156510755005422638E-5,
+    -1.61760815825896745588E-4,
+    5.12285956168575772895E-4,
+    -1.51357245063125314899E-3,
+    4.15642294431288815669E-3,
+    -1.05640848946261981558E-2,
+    2.47264490306265168283E-2,
+    -5.29459812080949914269E-2,
+    1.02643658689847095384E-1,
+    -1.76416518357834055153E-1,
+    2.52587186443633654823E-1,
+];
+
+// Chebyshev coefficients for exp(-x) sqrt(x) I1(x)
+// in the inverted interval [8,infinity].
+//
+// lim(x->inf){ exp(-x) sqrt(x) I1(x) } = 1/sqrt(2pi).
+#[allow(clippy::excessive_precision)]
+const I1_B_F32: [f32; 25] = [
+    7.51729631084210481353E-18,
+    4.41434832307170791151E-18,
+    -4.65030536848935832153E-17,
+    -3.20952592199342395980E-17,
+    2.96262899764595013876E-16,
+    3.30820231092092828324E-16,
+    -1.88035477551078244854E-15,
+    -3.81440307243700780478E-15,
+    1.04202769841288027642E-14,
+    4.27244001671195135429E-14,
+    -2.10154184277266431302E-14,
+    -4.08355111109219731823E-13,
+    -7.19855177624590851209E-13,
+    2.03562854414708950722E-12,
+    1.41258074366137813316E-11,
+    3.25260358301548823856E-11,
+    -1.89749581235054123450E-11,
+    -5.58974346219658380687E-10,
+    -3.83538038596423702205E-9,
+    -2.63146884688951950684E-8,
+    -2.51223623787020892529E-7,
+    -3.88256480887769039346E-6,
+    -1.10588938762623716291E-4,
+    -9.76109749136146840777E-3,
+    7.78576235018280120474E-1,
+];
+
+/// Exponentially scaled modified Bessel function of order 1, `exp(-|x|) * i1(x)`.
+pub(crate) fn i1e_f32(x: f32) -> f32 {
+    let z = x.abs();
+    let z = if z <= 8. {
+        xsf::chbevl(z / 2. - 2., &I1_A_F32) * z
+    } else {
+        xsf::chbevl(32. / z - 2., &I1_B_F32) / z.sqrt()
+    };
+    if x < 0. {
+        -z
+    } else {
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::Bessel;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn i1e_f64() {
+        // scipy.special.i1e
+        let inp: [f64; 8] = [0., 1., 0.213, 5., -3.5, 30.546, 100., 1000.];
+        let exp = [
+            0.,
+            0.20791041534970845,
+            0.08655765814091242,
+            0.16397226694454236,
+            -0.18739997660305,
+            0.07128716815004885,
+            0.03974415302513025,
+            0.01261093025692863,
+        ];
+        for (x, e) in inp.iter().zip(exp) {
+            assert_relative_eq!(x.i1e(), e, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn i1e_f32() {
+        let inp: [f32; 5] = [0., 1., 0.213, 5., -3.5];
+        let exp = [
+            0.,
+            0.20791041534970845,
+            0.08655765814091242,
+            0.16397226694454236,
+            -0.18739997660305,
+        ];
+        for (x, e) in inp.iter().zip(exp) {
+            assert_relative_eq!(x.i1e(), e, epsilon = 1e-6);
+        }
+    }
+}
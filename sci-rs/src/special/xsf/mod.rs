@@ -1,4 +1,16 @@
 mod chbevl;
 pub(crate) use chbevl::*;
 
+mod ellipj;
+pub(crate) use ellipj::*;
+
+mod ellipk;
+pub(crate) use ellipk::*;
+
 mod i0;
+
+mod i1;
+pub(crate) use i1::*;
+
+mod loggamma;
+pub(crate) use loggamma::*;
@@ -0,0 +1,57 @@
+//! Checks [fftconvolve_mem_estimate] against the memory actually allocated by [fftconvolve_nd].
+//!
+//! Counting allocations needs a global allocator, which would replace the allocator of every
+//! other test in the same binary, so this lives in a test binary of its own.
+
+use ndarray::Array2;
+use sci_rs::signal::convolve::{fftconvolve_mem_estimate, fftconvolve_nd, ConvolveMode};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Tracks the current and peak bytes allocated by the calling thread.
+struct PeakAlloc;
+
+std::thread_local! {
+    static ALLOCATED: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATED.try_with(|a| {
+            let (cur, peak) = a.get();
+            a.set((cur + layout.size(), peak.max(cur + layout.size())));
+        });
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = ALLOCATED.try_with(|a| {
+            let (cur, peak) = a.get();
+            a.set((cur.saturating_sub(layout.size()), peak));
+        });
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAlloc = PeakAlloc;
+
+#[test]
+fn estimate_is_within_a_factor_of_2_in_2d() {
+    let in1 = Array2::from_shape_fn((120, 90), |(i, j)| (i as f64 * 0.1).sin() + j as f64);
+    let in2 = Array2::from_shape_fn((31, 17), |(i, j)| 0.5 / (1 + i + j) as f64);
+
+    for mode in [ConvolveMode::Full, ConvolveMode::Same, ConvolveMode::Valid] {
+        let estimate = fftconvolve_mem_estimate::<f64>(in1.shape(), in2.shape(), mode).unwrap();
+
+        ALLOCATED.with(|a| a.set((0, 0)));
+        let out = fftconvolve_nd(&in1, &in2, mode);
+        let (_, peak) = ALLOCATED.with(|a| a.get());
+        drop(out);
+
+        assert!(
+            estimate <= 2 * peak && peak <= 2 * estimate,
+            "{mode:?}: estimate {estimate} not within a factor of 2 of {peak}"
+        );
+    }
+}